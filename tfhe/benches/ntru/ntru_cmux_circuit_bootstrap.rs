@@ -87,6 +87,7 @@ fn criterion_benchmark_ntru_cmux_bootstrap(c: &mut Criterion) {
         );
 
         let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+            ntru_cmux_cbs_key.glwe_size(),
             polynomial_size,
             param.input_lwe_dimension(),
             param.br_decomp_base_log(),
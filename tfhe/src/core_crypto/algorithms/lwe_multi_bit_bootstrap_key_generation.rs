@@ -388,7 +388,7 @@ pub fn par_generate_lwe_multi_bit_bootstrap_key<
         );
 }
 
-fn combine_key_bits<Scalar>(bit_selector: usize, input_key_elements: &[Scalar]) -> Scalar
+pub(crate) fn combine_key_bits<Scalar>(bit_selector: usize, input_key_elements: &[Scalar]) -> Scalar
 where
     Scalar: UnsignedInteger + CastFrom<usize>,
 {
@@ -0,0 +1,216 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+pub fn convert_standard_glwe_scheme_switch_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_glwe_ss_key: &GlweSchemeSwitchKey<InputCont>,
+    fourier_glwe_ss_key: &mut FourierGlweSchemeSwitchKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        standard_glwe_ss_key.polynomial_size(),
+        fourier_glwe_ss_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        standard_glwe_ss_key.decomposition_base_log(),
+        fourier_glwe_ss_key.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        standard_glwe_ss_key.decomposition_level_count(),
+        fourier_glwe_ss_key.decomposition_level_count(),
+    );
+
+    let fft = Fft::new(fourier_glwe_ss_key.polynomial_size());
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        convert_standard_glwe_scheme_switch_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    convert_standard_glwe_scheme_switch_key_to_fourier_mem_optimized(
+        standard_glwe_ss_key,
+        fourier_glwe_ss_key,
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_glwe_scheme_switch_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+pub fn convert_standard_glwe_scheme_switch_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_glwe_ss_key: &GlweSchemeSwitchKey<InputCont>,
+    fourier_glwe_ss_key: &mut FourierGlweSchemeSwitchKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        standard_glwe_ss_key.glwe_size(),
+        fourier_glwe_ss_key.glwe_size(),
+    );
+
+    let glwe_dimension = standard_glwe_ss_key.glwe_size().to_glwe_dimension().0;
+    for output_index in 0..glwe_dimension {
+        for input_index in 0..glwe_dimension {
+            convert_standard_ntru_to_glwe_keyswitch_key_to_fourier_mem_optimized(
+                &standard_glwe_ss_key.get_ntru_to_glwe_keyswitch_key(output_index, input_index),
+                &mut fourier_glwe_ss_key.get_mut_fourier_ntru_to_glwe_keyswitch_key(output_index, input_index),
+                fft,
+                stack,
+            );
+        }
+    }
+}
+
+/// Scheme switch a GLWE ciphertext of m under S = (S_0, ..., S_{k-1}) to the k GLWE ciphertexts
+/// of -S_i * m, i.e., the first k rows of a level of a GGSW ciphertext of m.
+pub fn scheme_switch_glwe_ciphertext<Scalar, SSKeyCont, InputCont, OutputCont>(
+    glwe_scheme_switch_key: &FourierGlweSchemeSwitchKey<SSKeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    SSKeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = glwe_scheme_switch_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        scheme_switch_glwe_ciphertext_scratch::<Scalar>(
+            glwe_scheme_switch_key.glwe_size(),
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    scheme_switch_glwe_ciphertext_mem_optimized(
+        glwe_scheme_switch_key,
+        input_glwe_ciphertext,
+        output_glwe_ciphertext_list,
+        fft,
+        stack,
+    );
+}
+
+pub fn scheme_switch_glwe_ciphertext_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_ntru_to_glwe_scratch::<Scalar>(glwe_size, polynomial_size, fft)
+}
+
+pub fn scheme_switch_glwe_ciphertext_mem_optimized<Scalar, SSKeyCont, InputCont, OutputCont>(
+    glwe_scheme_switch_key: &FourierGlweSchemeSwitchKey<SSKeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    SSKeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let glwe_size = glwe_scheme_switch_key.glwe_size();
+    let glwe_dimension = glwe_size.to_glwe_dimension().0;
+
+    assert_eq!(input_glwe_ciphertext.glwe_size(), glwe_size);
+    assert_eq!(output_glwe_ciphertext_list.glwe_size(), glwe_size);
+    assert_eq!(
+        output_glwe_ciphertext_list.glwe_ciphertext_count().0,
+        glwe_dimension,
+        "The output list must contain one GLWE ciphertext per mask polynomial.",
+    );
+
+    assert_eq!(
+        glwe_scheme_switch_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        glwe_scheme_switch_key.polynomial_size(),
+        output_glwe_ciphertext_list.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext_list.ciphertext_modulus(),
+    );
+
+    assert!(
+        input_glwe_ciphertext
+            .ciphertext_modulus()
+            .is_power_of_two(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = input_glwe_ciphertext.polynomial_size();
+    let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
+
+    let input_glwe_mask = input_glwe_ciphertext.get_mask();
+    let input_glwe_mask_poly_list = input_glwe_mask.as_polynomial_list();
+    let input_glwe_body = input_glwe_ciphertext.get_body();
+    let input_glwe_body_poly = input_glwe_body.as_polynomial();
+
+    for (output_index, mut output_glwe_ciphertext) in output_glwe_ciphertext_list.iter_mut().enumerate() {
+        // Each mask polynomial A_j is seen as an NTRU ciphertext, keyswitched under -S_i * S_j
+        let keyswitch_key_and_inputs = input_glwe_mask_poly_list.iter()
+            .enumerate()
+            .map(|(input_index, input_glwe_mask_poly)| {
+                (
+                    glwe_scheme_switch_key.get_fourier_ntru_to_glwe_keyswitch_key(output_index, input_index),
+                    NtruCiphertext::from_container(
+                        input_glwe_mask_poly.into_container(),
+                        polynomial_size,
+                        ciphertext_modulus,
+                    ),
+                )
+            });
+
+        keyswitch_ntru_sum_to_glwe_mem_optimized(
+            &keyswitch_key_and_inputs,
+            &mut output_glwe_ciphertext.as_mut_view(),
+            fft,
+            stack,
+        );
+
+        let mut output_glwe_mask = output_glwe_ciphertext.get_mut_mask();
+        let mut output_glwe_mask_poly = output_glwe_mask.as_mut_polynomial_list();
+        let mut output_glwe_mask_poly = output_glwe_mask_poly.get_mut(output_index);
+
+        slice_wrapping_sub_assign(output_glwe_mask_poly.as_mut(), input_glwe_body_poly.as_ref());
+        slice_wrapping_opposite_assign(output_glwe_ciphertext.as_mut());
+    }
+}
@@ -0,0 +1,249 @@
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_mul;
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_add_scalar_mul_assign, slice_wrapping_scalar_div_assign,
+    slice_wrapping_scalar_mul_assign,
+};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{CompressionSeed, Distribution, Seeder, Uniform};
+use crate::core_crypto::commons::math::decomposition::DecompositionLevel;
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::*;
+
+pub fn generate_glwe_scheme_switch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    SSKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    glwe_scheme_switch_key: &mut GlweSchemeSwitchKey<SSKeyCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        glwe_secret_key.glwe_dimension().to_glwe_size() == glwe_scheme_switch_key.glwe_size(),
+        "Mismatch between glwe size of input glwe secret key and output glwe scheme switch key. \
+        Input {:?} and output {:?}.",
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_scheme_switch_key.glwe_size(),
+    );
+
+    assert!(
+        glwe_secret_key.polynomial_size() == glwe_scheme_switch_key.polynomial_size(),
+        "Mismatch between polynomial size of input glwe secret key and output glwe scheme switch key. \
+        Input {:?} and output {:?}.",
+        glwe_secret_key.polynomial_size(),
+        glwe_scheme_switch_key.polynomial_size(),
+    );
+
+    let ciphertext_modulus = glwe_scheme_switch_key.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus, currently.",
+    );
+
+    let glwe_dimension = glwe_secret_key.glwe_dimension().0;
+    let decomp_base_log = glwe_scheme_switch_key.decomposition_base_log();
+    let decomp_level_count = glwe_scheme_switch_key.decomposition_level_count();
+    let polynomial_size = glwe_scheme_switch_key.polynomial_size();
+
+    let glwe_sk_poly_list = glwe_secret_key.as_polynomial_list();
+
+    for output_index in 0..glwe_dimension {
+        for (input_index, glwe_sk_poly) in glwe_sk_poly_list.iter().enumerate() {
+            let mut ksk = glwe_scheme_switch_key
+                .get_mut_ntru_to_glwe_keyswitch_key(output_index, input_index);
+
+            // Shifting the i-th mask polynomial of an encryption of zero by S_j * B^{-j} gives an
+            // encryption of -S_i * S_j * B^{-j}
+            for (level, mut glwe_ciphertext) in (1..=decomp_level_count.0)
+                .rev()
+                .map(DecompositionLevel)
+                .zip(ksk.as_mut_glwe_ciphertext_list().iter_mut())
+            {
+                glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+
+                encrypt_glwe_ciphertext(
+                    glwe_secret_key,
+                    &mut glwe_ciphertext,
+                    &PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0)),
+                    noise_distribution,
+                    generator,
+                );
+
+                let mut glwe_mask = glwe_ciphertext.get_mut_mask();
+                let mut glwe_mask = glwe_mask.as_mut_polynomial_list();
+                let mut glwe_mask = glwe_mask.get_mut(output_index);
+
+                let log_scale = Scalar::BITS - decomp_base_log.0 * level.0;
+                slice_wrapping_add_scalar_mul_assign(
+                    glwe_mask.as_mut(),
+                    glwe_sk_poly.as_ref(),
+                    Scalar::ONE << log_scale,
+                );
+            }
+        }
+    }
+}
+
+pub fn allocate_and_generate_new_glwe_scheme_switch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweSchemeSwitchKeyOwned<Scalar> {
+    let mut new_glwe_scheme_switch_key = GlweSchemeSwitchKeyOwned::new(
+        Scalar::ZERO,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+    );
+
+    generate_glwe_scheme_switch_key(
+        glwe_secret_key,
+        &mut new_glwe_scheme_switch_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_glwe_scheme_switch_key
+}
+
+
+pub fn generate_seeded_glwe_scheme_switch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    SSKeyCont: ContainerMut<Element = Scalar>,
+    NoiseSeeder: Seeder + ?Sized,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    seeded_glwe_scheme_switch_key: &mut SeededGlweSchemeSwitchKey<SSKeyCont>,
+    noise_distribution: NoiseDistribution,
+    noise_seeder: &mut NoiseSeeder,
+) {
+    assert!(
+        glwe_secret_key.glwe_dimension().to_glwe_size() == seeded_glwe_scheme_switch_key.glwe_size(),
+        "Mismatch between glwe size of input glwe secret key and output glwe scheme switch key. \
+        Input {:?} and output {:?}.",
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        seeded_glwe_scheme_switch_key.glwe_size(),
+    );
+
+    assert!(
+        glwe_secret_key.polynomial_size() == seeded_glwe_scheme_switch_key.polynomial_size(),
+        "Mismatch between polynomial size of input glwe secret key and output glwe scheme switch key. \
+        Input {:?} and output {:?}.",
+        glwe_secret_key.polynomial_size(),
+        seeded_glwe_scheme_switch_key.polynomial_size(),
+    );
+
+    let ciphertext_modulus = seeded_glwe_scheme_switch_key.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus, currently.",
+    );
+
+    let glwe_dimension = glwe_secret_key.glwe_dimension().0;
+    let decomp_base_log = seeded_glwe_scheme_switch_key.decomposition_base_log();
+    let decomp_level_count = seeded_glwe_scheme_switch_key.decomposition_level_count();
+    let polynomial_size = seeded_glwe_scheme_switch_key.polynomial_size();
+
+    let glwe_sk_poly_list = glwe_secret_key.as_polynomial_list();
+
+    // The mask of a seeded ciphertext cannot be shifted by S_j * B^{-j} as in the standard key.
+    // Instead, encrypt -S_i * S_j * B^{-j}, which gives the same phase for every level.
+    let mut message_polynomials = PolynomialList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        PolynomialCount(glwe_dimension * glwe_dimension * decomp_level_count.0),
+    );
+    let mut glwe_sk_product = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    for (ksk_idx, mut ksk_message_polynomials) in message_polynomials
+        .chunks_exact_mut(decomp_level_count.0)
+        .enumerate()
+    {
+        let output_index = ksk_idx / glwe_dimension;
+        let input_index = ksk_idx % glwe_dimension;
+        polynomial_wrapping_mul(
+            &mut glwe_sk_product,
+            &glwe_sk_poly_list.get(output_index),
+            &glwe_sk_poly_list.get(input_index),
+        );
+
+        for (level, mut message_polynomial) in (1..=decomp_level_count.0)
+            .rev()
+            .map(DecompositionLevel)
+            .zip(ksk_message_polynomials.iter_mut())
+        {
+            message_polynomial.as_mut().copy_from_slice(glwe_sk_product.as_ref());
+
+            let log_scale = Scalar::BITS - decomp_base_log.0 * level.0;
+            slice_wrapping_scalar_mul_assign(
+                message_polynomial.as_mut(),
+                (Scalar::ONE << log_scale).wrapping_neg(),
+            );
+
+            slice_wrapping_scalar_div_assign(
+                message_polynomial.as_mut(),
+                ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+            );
+        }
+    }
+
+    encrypt_seeded_glwe_ciphertext_list(
+        glwe_secret_key,
+        &mut seeded_glwe_scheme_switch_key.as_mut_seeded_glwe_ciphertext_list(),
+        &PlaintextList::from_container(message_polynomials.as_ref()),
+        noise_distribution,
+        noise_seeder,
+    );
+}
+
+pub fn allocate_and_generate_new_seeded_glwe_scheme_switch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    NoiseSeeder: Seeder + ?Sized,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    noise_seeder: &mut NoiseSeeder,
+) -> SeededGlweSchemeSwitchKeyOwned<Scalar> {
+    let compression_seed: CompressionSeed = noise_seeder.seed().into();
+    let mut new_seeded_glwe_scheme_switch_key = SeededGlweSchemeSwitchKeyOwned::new(
+        Scalar::ZERO,
+        glwe_secret_key.glwe_dimension().to_glwe_size(),
+        glwe_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        compression_seed,
+        ciphertext_modulus,
+    );
+
+    generate_seeded_glwe_scheme_switch_key(
+        glwe_secret_key,
+        &mut new_seeded_glwe_scheme_switch_key,
+        noise_distribution,
+        noise_seeder,
+    );
+
+    new_seeded_glwe_scheme_switch_key
+}
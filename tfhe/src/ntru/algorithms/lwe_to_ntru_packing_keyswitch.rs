@@ -0,0 +1,183 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::ntru::algorithms::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+pub fn convert_standard_lwe_to_ntru_packing_keyswitch_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_packing_ksk: &LweToNtruPackingKeyswitchKey<InputCont>,
+    fourier_packing_ksk: &mut FourierLweToNtruPackingKeyswitchKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier(
+        &standard_packing_ksk.as_rlwe_to_ntru_keyswitch_key(),
+        &mut fourier_packing_ksk.as_mut_rlwe_to_ntru_keyswitch_key(),
+    );
+}
+
+pub fn convert_standard_lwe_to_ntru_packing_keyswitch_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier_mem_optimized_requirement(fft)
+}
+
+pub fn convert_standard_lwe_to_ntru_packing_keyswitch_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_packing_ksk: &LweToNtruPackingKeyswitchKey<InputCont>,
+    fourier_packing_ksk: &mut FourierLweToNtruPackingKeyswitchKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier_mem_optimized(
+        &standard_packing_ksk.as_rlwe_to_ntru_keyswitch_key(),
+        &mut fourier_packing_ksk.as_mut_rlwe_to_ntru_keyswitch_key(),
+        fft,
+        stack,
+    );
+}
+
+/// Pack the LWE ciphertexts (a_k, b_k) of the input list into an NTRU ciphertext encrypting
+/// sum_k m_k X^k. Writing A_i = sum_k a_{k,i} X^k and B = sum_k b_k X^k, the output is
+/// KS_{1->f}(B) - sum_i KS_{s_i->f}(A_i), so the unused coefficients encrypt zero.
+pub fn keyswitch_lwe_ciphertext_list_and_pack_in_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
+    lwe_to_ntru_packing_keyswitch_key: &FourierLweToNtruPackingKeyswitchKey<KskCont>,
+    input_lwe_ciphertext_list: &LweCiphertextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = lwe_to_ntru_packing_keyswitch_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        keyswitch_lwe_ciphertext_list_and_pack_in_ntru_ciphertext_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    keyswitch_lwe_ciphertext_list_and_pack_in_ntru_ciphertext_mem_optimized(
+        lwe_to_ntru_packing_keyswitch_key,
+        input_lwe_ciphertext_list,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn keyswitch_lwe_ciphertext_list_and_pack_in_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn keyswitch_lwe_ciphertext_list_and_pack_in_ntru_ciphertext_mem_optimized<Scalar, KskCont, InputCont, OutputCont>(
+    lwe_to_ntru_packing_keyswitch_key: &FourierLweToNtruPackingKeyswitchKey<KskCont>,
+    input_lwe_ciphertext_list: &LweCiphertextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input_lwe_ciphertext_list.lwe_ciphertext_count().0 <= output_ntru_ciphertext.polynomial_size().0,
+        "Cannot pack {} LWE ciphertexts in an NTRU ciphertext of polynomial size {}",
+        input_lwe_ciphertext_list.lwe_ciphertext_count().0,
+        output_ntru_ciphertext.polynomial_size().0,
+    );
+
+    assert_eq!(
+        input_lwe_ciphertext_list.lwe_size().to_lwe_dimension(),
+        lwe_to_ntru_packing_keyswitch_key.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        lwe_to_ntru_packing_keyswitch_key.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_lwe_ciphertext_list.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
+    assert!(
+        output_ntru_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = output_ntru_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_ntru_ciphertext.ciphertext_modulus();
+
+    let (buf, stack) = stack.make_aligned_raw::<Scalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut buf = NtruCiphertextMutView::from_container(
+        &mut *buf,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    output_ntru_ciphertext.as_mut().fill(Scalar::ZERO);
+
+    // -A_i is keyswitched from s_i to f
+    for idx in 0..lwe_to_ntru_packing_keyswitch_key.input_lwe_dimension().0 {
+        buf.as_mut().fill(Scalar::ZERO);
+        for (coeff, lwe) in buf.as_mut().iter_mut().zip(input_lwe_ciphertext_list.iter()) {
+            *coeff = lwe.get_mask().as_ref()[idx].wrapping_neg();
+        }
+
+        add_ntru_external_product_assign(
+            &mut output_ntru_ciphertext.as_mut_view(),
+            lwe_to_ntru_packing_keyswitch_key
+                .get_ntru_keyswitch_key(idx)
+                .as_fourier_ngsw_ciphertext(),
+            buf.as_view(),
+            fft,
+            stack,
+        );
+    }
+
+    // B is keyswitched from the constant 1 to f
+    buf.as_mut().fill(Scalar::ZERO);
+    for (coeff, lwe) in buf.as_mut().iter_mut().zip(input_lwe_ciphertext_list.iter()) {
+        *coeff = *lwe.get_body().data;
+    }
+
+    add_ntru_external_product_assign(
+        &mut output_ntru_ciphertext.as_mut_view(),
+        lwe_to_ntru_packing_keyswitch_key
+            .get_ntru_switching_key()
+            .as_fourier_ngsw_ciphertext(),
+        buf.as_view(),
+        fft,
+        stack,
+    );
+}
@@ -1,6 +1,8 @@
 pub mod polynomial_for_ntru;
 pub mod ntru_secret_key_generation;
 pub mod ntru_encryption;
+pub mod ntru_public_key_generation;
+pub mod ntru_public_key_encryption;
 pub mod ntru_sample_extraction;
 pub mod ntru_linear_algebra;
 pub mod ntru_keyswitch_key_generation;
@@ -8,6 +10,7 @@ pub mod ntru_keyswitch;
 pub mod ngsw_encryption;
 pub mod ngsw_conversion;
 pub mod ngsw_external_product;
+pub mod ntru_cmux;
 pub mod ntru_switching_key_generation;
 pub mod ntru_switching;
 pub mod ntru_scheme_switch_key_generation;
@@ -16,19 +19,41 @@ pub mod ntru_automorphism_key_generation;
 pub mod ntru_automorphism;
 pub mod ntru_trace_key_generation;
 pub mod ntru_trace;
+pub mod ntru_ring_packing;
 pub mod ntru_cmux_bootstrap_key_generation;
 pub mod ntru_cmux_bootstrap;
-pub mod ntru_to_rlwe_keyswitch_key_generation;
-pub mod ntru_to_rlwe_keyswitch;
-pub mod rlwe_scheme_switch_key_generation;
-pub mod rlwe_scheme_switch;
+pub mod ntru_multi_bit_bootstrap_key_generation;
+pub mod ntru_multi_bit_bootstrap;
+pub mod ntru_programmable_bootstrap_lut;
+pub mod ntru_automorphism_bootstrap_key_generation;
+pub mod ntru_automorphism_bootstrap;
+pub mod ntru_cmux_bootstrap_ntt64;
+pub mod ntru_cmux_bootstrap_f128;
+pub mod ntru_to_glwe_keyswitch_key_generation;
+pub mod seeded_ntru_to_glwe_keyswitch_key_decompression;
+pub mod ntru_to_glwe_keyswitch;
+pub mod ntru_to_lwe_keyswitch_key_generation;
+pub mod ntru_to_lwe_keyswitch;
+pub mod lwe_to_ntru_packing_keyswitch_key_generation;
+pub mod lwe_to_ntru_packing_keyswitch;
+pub mod rlwe_to_ntru_keyswitch_key_generation;
+pub mod rlwe_to_ntru_keyswitch;
+pub mod glwe_scheme_switch_key_generation;
+pub mod seeded_glwe_scheme_switch_key_decompression;
+pub mod glwe_scheme_switch;
 pub mod modulus_switch_lut_many;
 pub mod ntru_cmux_circuit_bootstrap_key_generation;
+pub mod seeded_ntru_cmux_circuit_bootstrap_key_decompression;
 pub mod ntru_cmux_circuit_bootstrap;
+pub mod ntru_cmux_circuit_bootstrap_vertical_packing;
+pub mod ntru_cmux_ngsw_circuit_bootstrap_key_generation;
+pub mod ntru_cmux_ngsw_circuit_bootstrap;
 
 pub use polynomial_for_ntru::*;
 pub use ntru_secret_key_generation::*;
 pub use ntru_encryption::*;
+pub use ntru_public_key_generation::*;
+pub use ntru_public_key_encryption::*;
 pub use ntru_linear_algebra::*;
 pub use ntru_sample_extraction::*;
 pub use ntru_keyswitch_key_generation::*;
@@ -36,6 +61,7 @@ pub use ntru_keyswitch::*;
 pub use ngsw_encryption::*;
 pub use ngsw_conversion::*;
 pub use ngsw_external_product::*;
+pub use ntru_cmux::*;
 pub use ntru_switching_key_generation::*;
 pub use ntru_switching::*;
 pub use ntru_scheme_switch_key_generation::*;
@@ -44,12 +70,32 @@ pub use ntru_automorphism_key_generation::*;
 pub use ntru_automorphism::*;
 pub use ntru_trace_key_generation::*;
 pub use ntru_trace::*;
+pub use ntru_ring_packing::*;
 pub use ntru_cmux_bootstrap_key_generation::*;
 pub use ntru_cmux_bootstrap::*;
-pub use ntru_to_rlwe_keyswitch_key_generation::*;
-pub use ntru_to_rlwe_keyswitch::*;
-pub use rlwe_scheme_switch_key_generation::*;
-pub use rlwe_scheme_switch::*;
+pub use ntru_multi_bit_bootstrap_key_generation::*;
+pub use ntru_multi_bit_bootstrap::*;
+pub use ntru_programmable_bootstrap_lut::*;
+pub use ntru_automorphism_bootstrap_key_generation::*;
+pub use ntru_automorphism_bootstrap::*;
+pub use ntru_cmux_bootstrap_ntt64::*;
+pub use ntru_cmux_bootstrap_f128::*;
+pub use ntru_to_glwe_keyswitch_key_generation::*;
+pub use seeded_ntru_to_glwe_keyswitch_key_decompression::*;
+pub use ntru_to_glwe_keyswitch::*;
+pub use ntru_to_lwe_keyswitch_key_generation::*;
+pub use ntru_to_lwe_keyswitch::*;
+pub use lwe_to_ntru_packing_keyswitch_key_generation::*;
+pub use lwe_to_ntru_packing_keyswitch::*;
+pub use rlwe_to_ntru_keyswitch_key_generation::*;
+pub use rlwe_to_ntru_keyswitch::*;
+pub use glwe_scheme_switch_key_generation::*;
+pub use seeded_glwe_scheme_switch_key_decompression::*;
+pub use glwe_scheme_switch::*;
 pub use modulus_switch_lut_many::*;
 pub use ntru_cmux_circuit_bootstrap_key_generation::*;
+pub use seeded_ntru_cmux_circuit_bootstrap_key_decompression::*;
 pub use ntru_cmux_circuit_bootstrap::*;
+pub use ntru_cmux_circuit_bootstrap_vertical_packing::*;
+pub use ntru_cmux_ngsw_circuit_bootstrap_key_generation::*;
+pub use ntru_cmux_ngsw_circuit_bootstrap::*;
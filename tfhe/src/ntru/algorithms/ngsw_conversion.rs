@@ -1,6 +1,8 @@
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::ntt::ntt64::{Ntt64, Ntt64View};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
 use crate::ntru::entities::*;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use tfhe_fft::c64;
@@ -14,12 +16,14 @@ pub fn convert_standard_ngsw_ciphertext_to_fourier<Scalar, InputCont, OutputCont
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = c64>,
 {
+    let fft_type = output_ngsw.fft_type();
     assert!(
-        input_ngsw.ciphertext_modulus().into_modulus_log().0 > output_ngsw.fft_type().split_base_log(),
-        "Log of ciphertext modulus should be greater than FFT split base log. \
-        Log of ciphertext modulus is {:?}, and split base log is {}.",
+        input_ngsw.ciphertext_modulus().into_modulus_log().0
+            > fft_type.split_base_log() * (fft_type.num_split() - 1),
+        "Log of ciphertext modulus should be greater than FFT split base log * (num split - 1). \
+        Log of ciphertext modulus is {:?}, and FFT type is {:?}.",
         input_ngsw.ciphertext_modulus().into_modulus_log(),
-        output_ngsw.fft_type().split_base_log(),
+        fft_type,
     );
 
     let fft = Fft::new(output_ngsw.polynomial_size());
@@ -60,3 +64,66 @@ pub fn convert_standard_ngsw_ciphertext_to_fourier_mem_optimized<Scalar, InputCo
         .as_mut_view()
         .fill_with_forward_fourier(input_ngsw.as_view(), fft, stack);
 }
+
+pub fn convert_standard_ngsw_ciphertext_to_ntt64<InputCont, OutputCont>(
+    input_ngsw: &NgswCiphertext<InputCont>,
+    output_ngsw: &mut NttNgswCiphertext<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    let ntt = Ntt64::new(input_ngsw.ciphertext_modulus(), input_ngsw.polynomial_size());
+    let ntt = ntt.as_view();
+
+    convert_standard_ngsw_ciphertext_to_ntt64_mem_optimized(input_ngsw, output_ngsw, ntt);
+}
+
+pub fn convert_standard_ngsw_ciphertext_to_ntt64_mem_optimized<InputCont, OutputCont>(
+    input_ngsw: &NgswCiphertext<InputCont>,
+    output_ngsw: &mut NttNgswCiphertext<OutputCont>,
+    ntt: Ntt64View<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(input_ngsw.polynomial_size(), output_ngsw.polynomial_size());
+    assert_eq!(input_ngsw.decomposition_base_log(), output_ngsw.decomposition_base_log());
+    assert_eq!(input_ngsw.decomposition_level_count(), output_ngsw.decomposition_level_count());
+    assert_eq!(input_ngsw.ciphertext_modulus(), output_ngsw.ciphertext_modulus());
+
+    // The NGSW side of the external product is normalized once here, so that the inverse NTT of
+    // the accumulated products does not need to be.
+    for (input_poly, output_poly) in input_ngsw.as_polynomial_list().iter()
+        .zip(output_ngsw.as_mut_polynomial_list().iter_mut())
+    {
+        ntt.forward_normalized(output_poly, input_poly);
+    }
+}
+
+pub fn convert_standard_ngsw_ciphertext_to_fourier128<Scalar, InputCont, OutputCont>(
+    input_ngsw: &NgswCiphertext<InputCont>,
+    output_ngsw: &mut Fourier128NgswCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    let fft = Fft128::new(output_ngsw.polynomial_size());
+    let fft = fft.as_view();
+
+    convert_standard_ngsw_ciphertext_to_fourier128_mem_optimized(input_ngsw, output_ngsw, fft);
+}
+
+pub fn convert_standard_ngsw_ciphertext_to_fourier128_mem_optimized<Scalar, InputCont, OutputCont>(
+    input_ngsw: &NgswCiphertext<InputCont>,
+    output_ngsw: &mut Fourier128NgswCiphertext<OutputCont>,
+    fft: Fft128View<'_>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    output_ngsw
+        .as_mut_view()
+        .fill_with_forward_fourier(input_ngsw, fft);
+}
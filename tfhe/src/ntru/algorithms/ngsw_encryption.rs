@@ -4,7 +4,12 @@ use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::commons::ciphertext_modulus::{CiphertextModulus, CiphertextModulusKind};
-use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm, SignedDecomposer};
+use crate::core_crypto::commons::math::decomposition::{
+    DecompositionLevel,
+    DecompositionTerm,
+    DecompositionTermNonNative,
+    SignedDecomposer,
+};
 use crate::core_crypto::commons::math::random::{Distribution, Uniform};
 
 use crate::core_crypto::prelude::slice_algorithms::slice_wrapping_scalar_mul_assign;
@@ -17,11 +22,6 @@ pub fn ngsw_encryption_multiplicative_factor<Scalar: UnsignedInteger> (
     decomp_base_log: DecompositionBaseLog,
     cleartext: Cleartext<Scalar>,
 ) -> Scalar {
-    assert!(
-        ciphertext_modulus.is_power_of_two(),
-        "Only support power-of-two modulus currently."
-    );
-
     match ciphertext_modulus.kind() {
         CiphertextModulusKind::Native | CiphertextModulusKind::NonNativePowerOfTwo => {
             let native_decomp_term = DecompositionTerm::new(decomp_level, decomp_base_log, cleartext.0)
@@ -29,7 +29,13 @@ pub fn ngsw_encryption_multiplicative_factor<Scalar: UnsignedInteger> (
             native_decomp_term
                 .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus())
         },
-        CiphertextModulusKind::Other => Scalar::ONE
+        CiphertextModulusKind::Other => DecompositionTermNonNative::new(
+            decomp_level,
+            decomp_base_log,
+            cleartext.0,
+            ciphertext_modulus,
+        )
+        .to_approximate_recomposition_summand(),
     }
 }
 
@@ -63,8 +69,9 @@ pub fn encrypt_constant_ngsw_ciphertext<Scalar, NoiseDistribution, KeyCont, Outp
     );
 
     assert!(
-        output.ciphertext_modulus().is_power_of_two(),
-        "Only support poewr-of-two modulus currently."
+        output.ciphertext_modulus().is_power_of_two()
+            || output.ciphertext_modulus().get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus currently."
     );
 
     let polynomial_size = output.polynomial_size();
@@ -93,6 +100,20 @@ pub fn encrypt_constant_ngsw_ciphertext<Scalar, NoiseDistribution, KeyCont, Outp
             ciphertext_modulus,
         );
 
+        if !ciphertext_modulus.is_power_of_two() {
+            let custom_modulus = Scalar::cast_from(ciphertext_modulus.get_custom_modulus());
+            polynomial_karatsuba_wrapping_mul_custom_mod(
+                &mut ntru_ciphertext.as_mut_polynomial(),
+                &buf,
+                &sk_inv_poly,
+                custom_modulus,
+            );
+
+            ntru_ciphertext.as_mut()[0] = ntru_ciphertext.as_mut()[0]
+                .wrapping_add_custom_mod(factor, custom_modulus);
+            continue;
+        }
+
         polynomial_wrapping_mul(
             &mut ntru_ciphertext.as_mut_polynomial(),
             &buf,
@@ -143,8 +164,9 @@ pub fn encrypt_monomial_ngsw_ciphertext<Scalar, NoiseDistribution, KeyCont, Outp
     );
 
     assert!(
-        output.ciphertext_modulus().is_power_of_two(),
-        "Only support poewr-of-two modulus currently."
+        output.ciphertext_modulus().is_power_of_two()
+            || output.ciphertext_modulus().get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus currently."
     );
 
     let polynomial_size = output.polynomial_size();
@@ -167,8 +189,8 @@ pub fn encrypt_monomial_ngsw_ciphertext<Scalar, NoiseDistribution, KeyCont, Outp
             ciphertext_modulus,
             level,
             decomp_base_log,
-            Cleartext(Scalar::ONE),
-        ).wrapping_mul(sign);
+            Cleartext(sign),
+        );
 
         generator.unsigned_integer_slice_wrapping_add_random_noise_from_distribution_custom_mod_assign(
             buf.as_mut(),
@@ -176,6 +198,20 @@ pub fn encrypt_monomial_ngsw_ciphertext<Scalar, NoiseDistribution, KeyCont, Outp
             ciphertext_modulus,
         );
 
+        if !ciphertext_modulus.is_power_of_two() {
+            let custom_modulus = Scalar::cast_from(ciphertext_modulus.get_custom_modulus());
+            polynomial_karatsuba_wrapping_mul_custom_mod(
+                &mut ntru_ciphertext.as_mut_polynomial(),
+                &buf,
+                &sk_inv_poly,
+                custom_modulus,
+            );
+
+            ntru_ciphertext.as_mut()[monomial_degree] = ntru_ciphertext.as_mut()[monomial_degree]
+                .wrapping_add_custom_mod(factor, custom_modulus);
+            continue;
+        }
+
         polynomial_wrapping_mul(
             &mut ntru_ciphertext.as_mut_polynomial(),
             &buf,
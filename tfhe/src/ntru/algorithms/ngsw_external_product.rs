@@ -1,11 +1,23 @@
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::algorithms::slice_algorithms::*;
-use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::algorithms::lwe_programmable_bootstrapping::ntt64_pbs::update_with_fmadd_ntt64;
+use crate::core_crypto::commons::math::decomposition::{
+    SignedDecomposer,
+    SignedDecomposerNonNative,
+    TensorSignedDecompositionLendingIterNonNative,
+};
+use crate::core_crypto::commons::math::ntt::ntt64::Ntt64View;
 use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{collect_next_term, update_with_fmadd};
 use crate::core_crypto::fft_impl::fft64::math::decomposition::TensorSignedDecompositionLendingIter;
 use crate::core_crypto::fft_impl::fft64::math::fft::FftView;
 use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomialMutView;
+use crate::core_crypto::fft_impl::fft128::crypto::ggsw::{
+    add_external_product_assign as add_ggsw_external_product_f128_assign,
+    add_external_product_assign_scratch as add_ggsw_external_product_f128_assign_scratch,
+};
+use crate::core_crypto::fft_impl::fft128::math::fft::Fft128View;
 use crate::ntru::entities::*;
 use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
@@ -28,7 +40,7 @@ pub fn add_ntru_external_product_assign_scratch<Scalar>(
         substack1.try_and(standard_scratch)?,
         fft.backward_scratch()?,
     ])?;
-    substack0.try_and(fourier_scratch)
+    substack0.try_and(fourier_scratch)?.try_and(standard_scratch)
 }
 
 pub fn add_ntru_external_product_assign<Scalar>(
@@ -49,10 +61,16 @@ pub fn add_ntru_external_product_assign<Scalar>(
     let ciphertext_modulus = out.ciphertext_modulus();
 
     let fft_type = ngsw.fft_type();
+    let num_split = fft_type.num_split();
     let split_base_log = fft_type.split_base_log();
 
-    let mut external_product_buffer = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let (external_product_buffer, stack)
+        = stack.make_aligned_with::<Scalar>(polynomial_size.0, CACHELINE_ALIGN, |_| Scalar::ZERO);
+    let mut external_product_buffer
+        = NtruCiphertextMutView::from_container(&mut *external_product_buffer, polynomial_size, ciphertext_modulus);
 
+    // Horner evaluation from the most significant split: the buffer is scaled by 2^split_base_log
+    // after each split but the least significant one
     ngsw.into_splits().rev().enumerate()
         .for_each(|(i, ngsw_split)| {
             add_ntru_split_external_product_assign(
@@ -62,7 +80,7 @@ pub fn add_ntru_external_product_assign<Scalar>(
                 fft,
                 stack,
             );
-            if i == 0 {
+            if i + 1 < num_split {
                 slice_wrapping_scalar_mul_assign(
                     external_product_buffer.as_mut(),
                     Scalar::ONE << split_base_log,
@@ -166,3 +184,146 @@ fn add_ntru_split_external_product_assign<Scalar>(
             .for_each(|x| *x = signed_decomposer.closest_representable(*x));
     }
 }
+
+pub fn add_ntru_external_product_ntt64_assign_scratch(
+    polynomial_size: PolynomialSize,
+    _ntt: Ntt64View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let standard_scratch = StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?;
+    let decomp_sign_scratch = StackReq::try_new_aligned::<u8>(polynomial_size.0, CACHELINE_ALIGN)?;
+    let ntt_scratch = StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?;
+    let ntt_scratch_single = StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?;
+
+    let substack2 = ntt_scratch_single;
+    let substack1 = substack2.try_and(standard_scratch)?;
+    let substack0 = substack1
+        .try_and(standard_scratch)?
+        .try_and(decomp_sign_scratch)?;
+    substack0.try_and(ntt_scratch)
+}
+
+pub fn add_ntru_external_product_ntt64_assign(
+    out: &mut NtruCiphertextMutView<'_, u64>,
+    ngsw: NttNgswCiphertextView<'_, u64>,
+    ntru: NtruCiphertextView<u64>,
+    ntt: Ntt64View<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(ngsw.polynomial_size(), ntru.polynomial_size());
+    assert_eq!(ngsw.polynomial_size(), out.polynomial_size());
+    assert_eq!(ngsw.polynomial_size(), ntt.polynomial_size());
+    assert_eq!(out.ciphertext_modulus(), ntru.ciphertext_modulus());
+    assert_eq!(ngsw.ciphertext_modulus(), ntru.ciphertext_modulus());
+
+    let polynomial_size = out.polynomial_size().0;
+
+    let decomposer = SignedDecomposerNonNative::<u64>::new(
+        ngsw.decomposition_base_log(),
+        ngsw.decomposition_level_count(),
+        out.ciphertext_modulus(),
+    );
+
+    let (output_ntt_buffer, substack0)
+        = stack.make_aligned_raw::<u64>(polynomial_size, CACHELINE_ALIGN);
+    let mut is_output_uninit = true;
+
+    {
+        let (mut decomposition, substack1) = TensorSignedDecompositionLendingIterNonNative::new(
+            &decomposer,
+            ntru.as_ref(),
+            ntt.custom_modulus(),
+            substack0,
+        );
+
+        ngsw.as_polynomial_list().iter().for_each(|ngsw_decomp_poly| {
+            let (_, ntru_decomp_poly, substack2)
+                = decomposition.collect_next_term(substack1, CACHELINE_ALIGN);
+
+            let (ntt_poly, _) = substack2.make_aligned_raw::<u64>(polynomial_size, CACHELINE_ALIGN);
+            ntt.forward(
+                PolynomialMutView::from_container(&mut *ntt_poly),
+                PolynomialView::from_container(&*ntru_decomp_poly),
+            );
+
+            update_with_fmadd_ntt64(
+                output_ntt_buffer,
+                ngsw_decomp_poly.as_ref(),
+                ntt_poly,
+                is_output_uninit,
+                polynomial_size,
+                ntt,
+            );
+
+            is_output_uninit = false;
+        });
+    }
+
+    if !is_output_uninit {
+        ntt.add_backward(
+            out.as_mut_polynomial(),
+            PolynomialMutView::from_container(output_ntt_buffer),
+        );
+    }
+}
+
+pub fn add_ntru_external_product_f128_assign_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ggsw_external_product_f128_assign_scratch::<Scalar>(GlweSize(1), polynomial_size, fft)
+}
+
+pub fn add_ntru_external_product_f128_assign<Scalar>(
+    out: &mut NtruCiphertextMutView<'_, Scalar>,
+    ngsw: Fourier128NgswCiphertextView<'_>,
+    ntru: NtruCiphertextView<Scalar>,
+    fft: Fft128View<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+{
+    assert_eq!(ngsw.polynomial_size(), ntru.polynomial_size());
+    assert_eq!(ngsw.polynomial_size(), out.polynomial_size());
+    assert_eq!(ngsw.polynomial_size(), fft.polynomial_size());
+    assert_eq!(out.ciphertext_modulus(), ntru.ciphertext_modulus());
+
+    let polynomial_size = out.polynomial_size();
+    let ciphertext_modulus = out.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    // An NGSW ciphertext has the layout of a GGSW ciphertext with a GLWE size of one, and an NTRU
+    // ciphertext the one of a GLWE ciphertext with a GLWE size of one.
+    let mut out_glwe = GlweCiphertext::from_container(
+        out.as_mut(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+    let ntru_glwe = GlweCiphertext::from_container(
+        ntru.into_container(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    add_ggsw_external_product_f128_assign(
+        &mut out_glwe,
+        &ngsw.as_fourier128_ggsw_ciphertext(),
+        &ntru_glwe,
+        fft,
+        stack,
+    );
+
+    if !ciphertext_modulus.is_native_modulus() {
+        // Keep the data in the MSBs, see add_ntru_split_external_product_assign
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        out.as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+}
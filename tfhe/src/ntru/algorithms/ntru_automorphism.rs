@@ -1,8 +1,16 @@
+use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{collect_next_term, update_with_fmadd};
+use crate::core_crypto::fft_impl::fft64::math::decomposition::TensorSignedDecompositionLendingIter;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomialMutView;
 use crate::ntru::entities::*;
 use crate::ntru::algorithms::*;
+use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use tfhe_fft::c64;
 
@@ -82,6 +90,51 @@ pub fn automorphism_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
     KskCont: Container<Element = c64>,
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_automorphism_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        automorphism_ntru_ciphertext_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    automorphism_ntru_ciphertext_mem_optimized(
+        ntru_automorphism_key,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn automorphism_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn automorphism_ntru_ciphertext_mem_optimized<Scalar, KskCont, InputCont, OutputCont>(
+    ntru_automorphism_key: &FourierNtruAutomorphismKey<KskCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
 {
     assert_eq!(
         input_ntru_ciphertext.polynomial_size(),
@@ -108,8 +161,10 @@ pub fn automorphism_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
     let polynomial_size = ntru_automorphism_key.polynomial_size();
     let ciphertext_modulus = input_ntru_ciphertext.ciphertext_modulus();
 
-    let mut frobenius_ntru_ciphertext = NtruCiphertext::new(
-        Scalar::ZERO,
+    let (frobenius_ntru_ciphertext, stack)
+        = stack.make_aligned_raw::<Scalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut frobenius_ntru_ciphertext = NtruCiphertextMutView::from_container(
+        &mut *frobenius_ntru_ciphertext,
         polynomial_size,
         ciphertext_modulus,
     );
@@ -119,9 +174,342 @@ pub fn automorphism_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
         ntru_automorphism_key.automorphism_index(),
     );
 
-    keyswitch_ntru_ciphertext(
+    keyswitch_ntru_ciphertext_mem_optimized(
         &ntru_automorphism_key.as_fourier_ntru_keyswitch_key(),
         &frobenius_ntru_ciphertext,
         output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn fourier_automorphism_permutation_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let polynomial_size = fft.polynomial_size().0;
+    let fourier_polynomial_size = fft.polynomial_size().to_fourier_polynomial_size().0;
+
+    StackReq::try_new_aligned::<usize>(fourier_polynomial_size, CACHELINE_ALIGN)?
+        .try_and(StackReq::try_new_aligned::<usize>(2 * polynomial_size, CACHELINE_ALIGN)?)?
+        .try_and(StackReq::try_new_aligned::<c64>(fourier_polynomial_size, CACHELINE_ALIGN)?)
+}
+
+/// The i-th Fourier coefficient of a is a(w^{e_i}) with w = exp(i*pi/N) and e_i odd, one for each
+/// pair of conjugate roots. The e_i are read off the Fourier coefficients of X, and
+/// index_of_exponent[e_i] = i is set for each of them.
+fn fourier_exponent_list(
+    exponent_list: &mut [usize],
+    index_of_exponent: &mut [usize],
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    let polynomial_size = fft.polynomial_size().0;
+    let fourier_polynomial_size = fft.polynomial_size().to_fourier_polynomial_size().0;
+    let double_polynomial_size = 2 * polynomial_size;
+
+    let (fourier_x, _) = stack.make_aligned_raw::<c64>(fourier_polynomial_size, CACHELINE_ALIGN);
+    let twist = fft.incomplete_monomial_forward_as_integer(
+        FourierPolynomialMutView { data: &mut *fourier_x },
+        1,
+    );
+
+    index_of_exponent.fill(usize::MAX);
+    for (i, (exponent, x)) in exponent_list.iter_mut().zip(fourier_x.iter()).enumerate() {
+        let root = *x * twist;
+        let angle = root.im.atan2(root.re);
+        let e = (angle * polynomial_size as f64 / core::f64::consts::PI).round() as i64;
+        *exponent = e.rem_euclid(double_polynomial_size as i64) as usize;
+        index_of_exponent[*exponent] = i;
+    }
+}
+
+/// sigma(a)(w^e) = a(w^{e * h}), and a(w^{-e}) is the conjugate of a(w^e) as a is real
+fn fourier_automorphism_permutation_from_exponent_list(
+    automorphism_index: AutomorphismIndex,
+    permutation: &mut [usize],
+    exponent_list: &[usize],
+    index_of_exponent: &[usize],
+) {
+    let fourier_polynomial_size = exponent_list.len();
+    let double_polynomial_size = index_of_exponent.len();
+
+    for (p, &e) in permutation.iter_mut().zip(exponent_list.iter()) {
+        let image = (e * automorphism_index.0) % double_polynomial_size;
+        *p = if index_of_exponent[image] != usize::MAX {
+            index_of_exponent[image]
+        } else {
+            let conj_index = index_of_exponent[double_polynomial_size - image];
+            assert!(conj_index != usize::MAX);
+            conj_index + fourier_polynomial_size
+        };
+    }
+}
+
+/// Compute the action of the automorphism X -> X^{automorphism_index} on Fourier coefficients.
+/// The i-th Fourier coefficient of the image is the permutation[i]-th coefficient of the input if
+/// permutation[i] < N/2, and the conjugate of the (permutation[i] - N/2)-th coefficient otherwise.
+pub fn fourier_automorphism_permutation(
+    automorphism_index: AutomorphismIndex,
+    permutation: &mut [usize],
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    let polynomial_size = fft.polynomial_size().0;
+    let fourier_polynomial_size = fft.polynomial_size().to_fourier_polynomial_size().0;
+
+    assert_eq!(permutation.len(), fourier_polynomial_size);
+    assert!(
+        automorphism_index.0 % 2 == 1,
+        "Automorphism index {} is not odd",
+        automorphism_index.0,
+    );
+
+    let (exponent_list, stack) = stack.make_aligned_raw::<usize>(fourier_polynomial_size, CACHELINE_ALIGN);
+    let (index_of_exponent, stack) = stack.make_aligned_raw::<usize>(2 * polynomial_size, CACHELINE_ALIGN);
+    fourier_exponent_list(exponent_list, index_of_exponent, fft, stack);
+
+    fourier_automorphism_permutation_from_exponent_list(
+        automorphism_index,
+        permutation,
+        exponent_list,
+        index_of_exponent,
+    );
+}
+
+pub fn fourier_trace_automorphism_permutations_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let polynomial_size = fft.polynomial_size();
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let log_polynomial_size = polynomial_size.0.ilog2() as usize;
+
+    StackReq::try_new_aligned::<usize>(log_polynomial_size * fourier_polynomial_size, CACHELINE_ALIGN)?
+        .try_and(fourier_automorphism_permutation_requirement(fft)?)
+}
+
+/// Compute in the stack the permutations of [`fourier_automorphism_permutation`] for the trace
+/// automorphisms X -> X^{2^k + 1}, k = 1, ..., log2(N), stored one after the other by increasing k,
+/// and return them with the rest of the stack. The Fourier exponents are computed once for all the
+/// automorphisms, so this takes a single FFT and the permutations only need integer arithmetic.
+pub fn fourier_trace_automorphism_permutations<'a>(
+    fft: FftView<'_>,
+    stack: &'a mut PodStack,
+) -> (&'a mut [usize], &'a mut PodStack) {
+    let polynomial_size = fft.polynomial_size();
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let log_polynomial_size = polynomial_size.0.ilog2() as usize;
+
+    let (permutation_list, stack) = stack.make_aligned_raw::<usize>(
+        log_polynomial_size * fourier_polynomial_size,
+        CACHELINE_ALIGN,
+    );
+
+    {
+        let (exponent_list, substack0)
+            = stack.make_aligned_raw::<usize>(fourier_polynomial_size, CACHELINE_ALIGN);
+        let (index_of_exponent, substack1)
+            = substack0.make_aligned_raw::<usize>(2 * polynomial_size.0, CACHELINE_ALIGN);
+        fourier_exponent_list(exponent_list, index_of_exponent, fft, substack1);
+
+        for (k, permutation) in (1..=log_polynomial_size)
+            .zip(permutation_list.chunks_exact_mut(fourier_polynomial_size))
+        {
+            fourier_automorphism_permutation_from_exponent_list(
+                AutomorphismIndex((1 << k) + 1),
+                permutation,
+                exponent_list,
+                index_of_exponent,
+            );
+        }
+    }
+
+    (permutation_list, stack)
+}
+
+/// Return the permutation of the trace automorphism X -> X^{automorphism_index} from the output of
+/// [`fourier_trace_automorphism_permutations`], automorphism_index being of the form 2^k + 1.
+pub fn get_fourier_trace_automorphism_permutation(
+    fourier_trace_permutations: &[usize],
+    automorphism_index: AutomorphismIndex,
+    polynomial_size: PolynomialSize,
+) -> &[usize] {
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let k = (automorphism_index.0 - 1).ilog2() as usize;
+    assert!(
+        automorphism_index.0 == (1 << k) + 1 && k >= 1,
+        "Automorphism index {} is not a trace automorphism index",
+        automorphism_index.0,
     );
+
+    &fourier_trace_permutations[(k - 1) * fourier_polynomial_size..k * fourier_polynomial_size]
+}
+
+pub fn ntru_ciphertext_add_automorphism_assign_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let standard_scratch = StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?;
+    let fourier_scratch = StackReq::try_new_aligned::<c64>(fourier_polynomial_size, CACHELINE_ALIGN)?;
+    let digit_scratch = StackReq::try_new_aligned::<c64>(
+        fourier_polynomial_size * decomp_level_count.0,
+        CACHELINE_ALIGN,
+    )?;
+
+    let decompose_scratch = standard_scratch
+        .try_and(standard_scratch)?
+        .try_and(fourier_scratch)?
+        .try_and(fft.forward_scratch()?)?;
+    let accumulate_scratch = fourier_scratch.try_and(fft.backward_scratch()?)?;
+
+    standard_scratch
+        .try_and(digit_scratch)?
+        .try_and(StackReq::try_any_of([decompose_scratch, accumulate_scratch])?)
+}
+
+/// Add the automorphism of rhs to lhs, where fourier_permutation is the permutation computed by
+/// `fourier_automorphism_permutation` for the automorphism index of the key. The automorphism is
+/// applied as a permutation of the Fourier transforms of the decomposition of rhs instead of a
+/// Frobenius map on rhs, and the keyswitched image is added to lhs in place.
+///
+/// With L decomposition levels and s splits of the key, this takes L forward FFTs, shared by all
+/// the splits, and s backward FFTs, whereas `automorphism_ntru_ciphertext` goes through
+/// `add_ntru_external_product_assign` and takes s * L forward and s backward FFTs.
+pub fn ntru_ciphertext_add_automorphism_assign_mem_optimized<Scalar, KeyCont, LhsCont, RhsCont>(
+    ntru_automorphism_key: &FourierNtruAutomorphismKey<KeyCont>,
+    fourier_permutation: &[usize],
+    lhs: &mut NtruCiphertext<LhsCont>,
+    rhs: &NtruCiphertext<RhsCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    LhsCont: ContainerMut<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        lhs.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+
+    assert_eq!(
+        rhs.polynomial_size(),
+        ntru_automorphism_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus(),
+    );
+
+    assert!(
+        rhs.ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = rhs.polynomial_size();
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let ciphertext_modulus = rhs.ciphertext_modulus();
+
+    assert_eq!(fourier_permutation.len(), fourier_polynomial_size);
+
+    let decomp_base_log = ntru_automorphism_key.decomposition_base_log();
+    let decomp_level_count = ntru_automorphism_key.decomposition_level_count();
+    let fft_type = ntru_automorphism_key.fft_type();
+    let num_split = fft_type.num_split();
+    let split_base_log = fft_type.split_base_log();
+
+    let decomposer = SignedDecomposer::<Scalar>::new(decomp_base_log, decomp_level_count);
+
+    let (external_product_buffer, stack)
+        = stack.make_aligned_with::<Scalar>(polynomial_size.0, CACHELINE_ALIGN, |_| Scalar::ZERO);
+    let (fourier_digit_list, stack) = stack.make_aligned_raw::<c64>(
+        fourier_polynomial_size * decomp_level_count.0,
+        CACHELINE_ALIGN,
+    );
+
+    {
+        // The digits of sigma(rhs) are the images of the digits of rhs up to rounding ties
+        let (mut decomposition, substack0) = TensorSignedDecompositionLendingIter::new(
+            rhs.as_ref()
+                .iter()
+                .map(|s| decomposer.init_decomposer_state(*s)),
+            decomp_base_log,
+            decomp_level_count,
+            stack,
+        );
+
+        for (i, fourier_digit) in fourier_digit_list.chunks_exact_mut(fourier_polynomial_size).enumerate() {
+            let (level, decomp_poly, substack1)
+                = collect_next_term(&mut decomposition, substack0, CACHELINE_ALIGN);
+            assert_eq!(level, DecompositionLevel(decomp_level_count.0 - i));
+
+            let (fourier, substack2)
+                = substack1.make_aligned_raw::<c64>(fourier_polynomial_size, CACHELINE_ALIGN);
+            let fourier = fft
+                .forward_as_integer(
+                    FourierPolynomialMutView { data: fourier },
+                    Polynomial::from_container(&*decomp_poly),
+                    substack2,
+                ).data;
+
+            for (out, &p) in fourier_digit.iter_mut().zip(fourier_permutation.iter()) {
+                *out = if p < fourier_polynomial_size {
+                    fourier[p]
+                } else {
+                    fourier[p - fourier_polynomial_size].conj()
+                };
+            }
+        }
+    }
+
+    // Horner evaluation from the most significant split as in `add_ntru_external_product_assign`
+    let ngsw = ntru_automorphism_key.as_fourier_ngsw_ciphertext();
+    for (i, ngsw_split) in ngsw.into_splits().rev().enumerate() {
+        let (output_fft_buffer, substack0)
+            = stack.make_aligned_raw::<c64>(fourier_polynomial_size, CACHELINE_ALIGN);
+        let mut is_output_uninit = true;
+
+        for (ngsw_decomp_poly, fourier_digit) in ngsw_split.into_levels()
+            .zip(fourier_digit_list.chunks_exact(fourier_polynomial_size))
+        {
+            update_with_fmadd(
+                output_fft_buffer,
+                ngsw_decomp_poly.data(),
+                fourier_digit,
+                is_output_uninit,
+                fourier_polynomial_size,
+            );
+            is_output_uninit = false;
+        }
+
+        if !is_output_uninit {
+            fft.add_backward_in_place_as_torus(
+                Polynomial::from_container(&mut *external_product_buffer),
+                FourierPolynomialMutView { data: output_fft_buffer },
+                substack0,
+            );
+        }
+
+        if !ciphertext_modulus.is_native_modulus() {
+            let signed_decomposer = SignedDecomposer::new(
+                DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+                DecompositionLevelCount(1),
+            );
+            external_product_buffer.iter_mut()
+                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+        }
+
+        if i + 1 < num_split {
+            slice_wrapping_scalar_mul_assign(
+                external_product_buffer,
+                Scalar::ONE << split_base_log,
+            );
+        }
+    }
+
+    slice_wrapping_add_assign(lhs.as_mut(), external_product_buffer);
 }
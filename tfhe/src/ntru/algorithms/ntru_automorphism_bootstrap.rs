@@ -0,0 +1,326 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::common::modulus_switch;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_div;
+use crate::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_mul_and_subtract;
+use crate::core_crypto::prelude::SignedDecomposer;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+pub fn convert_standard_ntru_automorphism_bootstrap_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_ntru_auto_bsk: &NtruAutomorphismBootstrapKey<InputCont>,
+    fourier_ntru_auto_bsk: &mut FourierNtruAutomorphismBootstrapKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        standard_ntru_auto_bsk.input_lwe_dimension(),
+        fourier_ntru_auto_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_auto_bsk.output_lwe_dimension(),
+        fourier_ntru_auto_bsk.output_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_auto_bsk.window_size(),
+        fourier_ntru_auto_bsk.window_size(),
+    );
+
+    let polynomial_size = standard_ntru_auto_bsk.polynomial_size();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        convert_standard_ntru_automorphism_bootstrap_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    convert_standard_ntru_automorphism_bootstrap_key_to_fourier_mem_optimized(
+        standard_ntru_auto_bsk,
+        fourier_ntru_auto_bsk,
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_automorphism_bootstrap_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    convert_standard_ngsw_ciphertext_to_fourier_mem_optimized_requirement(fft)
+}
+
+pub fn convert_standard_ntru_automorphism_bootstrap_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_ntru_auto_bsk: &NtruAutomorphismBootstrapKey<InputCont>,
+    fourier_ntru_auto_bsk: &mut FourierNtruAutomorphismBootstrapKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    let ngsw_list = standard_ntru_auto_bsk.get_ngsw_list();
+    let mut fourier_ngsw_list = fourier_ntru_auto_bsk.get_mut_fourier_ngsw_list();
+
+    ngsw_list.iter()
+        .zip(fourier_ngsw_list.iter_mut())
+        .for_each(|(ngsw, mut fourier_ngsw)| {
+            convert_standard_ngsw_ciphertext_to_fourier_mem_optimized(
+                &ngsw,
+                &mut fourier_ngsw,
+                fft,
+                stack,
+            )
+        });
+
+    for idx in 0..standard_ntru_auto_bsk.automorphism_key_count().0 {
+        let ntru_auto_key = standard_ntru_auto_bsk.get_automorphism_key(idx);
+        let mut fourier_ntru_auto_key = fourier_ntru_auto_bsk.get_mut_fourier_automorphism_key(idx);
+
+        convert_standard_ntru_automorphism_key_to_fourier_mem_optimized(
+            &ntru_auto_key,
+            &mut fourier_ntru_auto_key,
+            fft,
+            stack,
+        );
+    }
+
+    let ntru_switching_key = standard_ntru_auto_bsk.get_ntru_switching_key();
+    let mut fourier_ntru_switching_key = fourier_ntru_auto_bsk.get_mut_fourier_ntru_switching_key();
+    convert_standard_ntru_switching_key_to_fourier_mem_optimized(
+        &ntru_switching_key,
+        &mut fourier_ntru_switching_key,
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_automorphism_bootstrap_lwe_ciphertext<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    fourier_bsk: &FourierNtruAutomorphismBootstrapKey<KeyCont>,
+) {
+    assert!(
+        input.ciphertext_modulus().is_power_of_two(),
+        "This operation requires the input to have a power of two modulus."
+    );
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension().0,
+        accumulator.plaintext_count().0,
+    );
+
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        ntru_automorphism_bootstrap_scratch::<OutputScalar>(
+            PolynomialSize(accumulator.plaintext_count().0),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_automorphism_bootstrap_mem_optimized(
+        fourier_bsk,
+        input,
+        output,
+        accumulator,
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_automorphism_bootstrap_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    ntru_automorphism_blind_rotate_assign_scratch::<Scalar>(polynomial_size, fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?,
+    )
+}
+
+pub fn ntru_automorphism_blind_rotate_assign_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?,
+        add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)?,
+    ])
+}
+
+pub fn ntru_automorphism_bootstrap_mem_optimized<InputScalar, OutputScalar, KeyCont, InputCont, OutputCont, AccCont>(
+    bsk: &FourierNtruAutomorphismBootstrapKey<KeyCont>,
+    lwe_in: &LweCiphertext<InputCont>,
+    lwe_out: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    KeyCont: Container<Element = c64>,
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputScalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+{
+    let polynomial_size = PolynomialSize(accumulator.plaintext_count().0);
+    let (local_accumulator_data, stack) = stack.make_aligned_raw::<OutputScalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut local_accumulator = NtruCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        polynomial_size,
+        lwe_out.ciphertext_modulus(),
+    );
+    switch_to_ntru_ciphertext(
+        &bsk.get_fourier_ntru_switching_key(),
+        accumulator,
+        &mut local_accumulator,
+    );
+
+    ntru_automorphism_blind_rotate_assign(
+        bsk.as_view(),
+        local_accumulator.as_mut_view(),
+        lwe_in,
+        fft,
+        stack,
+    );
+
+    extract_lwe_sample_from_ntru_ciphertext(
+        &local_accumulator,
+        lwe_out,
+        MonomialDegree(0),
+    );
+}
+
+/// Blind rotate the NTRU encryption `lut` of a LUT polynomial by `X^-(b - <a, s>)` following the
+/// automorphism-based blind rotation of Lee et al. (LMKCDEY).
+///
+/// Each mask element is switched to the nearest odd integer modulo 2N, written as `+-g^l` with the
+/// group generator `g = 5`. The mask elements are processed by decreasing `l`, first for `-g^l`
+/// and then for `g^l`, by multiplying the accumulator by `X^s_i` and applying the automorphism
+/// `X -> X^g` in between, merging consecutive automorphisms up to the window size of the key.
+/// One more automorphism `X -> X^-g` at the beginning makes the whole sequence of automorphisms
+/// the identity.
+pub fn ntru_automorphism_blind_rotate_assign<InputScalar, OutputScalar, InputCont>(
+    bsk: FourierNtruAutomorphismBootstrapKeyView,
+    mut lut: NtruCiphertextMutView<'_, OutputScalar>,
+    lwe_in: &LweCiphertext<InputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputScalar: UnsignedTorus,
+{
+    let polynomial_size = lut.polynomial_size();
+    let ciphertext_modulus = lut.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    assert_eq!(
+        lwe_in.lwe_size().to_lwe_dimension(),
+        bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(polynomial_size, bsk.polynomial_size());
+
+    let auto_group = AutomorphismGroup::new(polynomial_size);
+    let (lwe_index_set_pos, lwe_index_set_neg) = auto_group.get_lwe_index_set(lwe_in);
+
+    let log_modulus = polynomial_size.to_blind_rotation_input_modulus_log();
+    let body: usize = modulus_switch(*lwe_in.get_body().data, log_modulus).cast_into();
+
+    let (ct1, stack) = stack.make_aligned_raw(polynomial_size.0, CACHELINE_ALIGN);
+    let mut ct1 = NtruCiphertextMutView::from_container(&mut *ct1, polynomial_size, ciphertext_modulus);
+
+    polynomial_wrapping_monic_monomial_div(
+        &mut ct1.as_mut_polynomial(),
+        &lut.as_polynomial(),
+        MonomialDegree(body),
+    );
+
+    let window_size = bsk.window_size().0;
+    let fourier_ngsw_list = bsk.get_fourier_ngsw_list();
+    let neg_generator_key = bsk.get_fourier_automorphism_key(window_size);
+
+    automorphism_ntru_ciphertext(&neg_generator_key, &ct1, &mut lut);
+
+    let mut ct0 = lut;
+    for (is_neg, lwe_index_set) in [(true, &lwe_index_set_neg), (false, &lwe_index_set_pos)] {
+        let mut auto_count = 0;
+        for l in (0..polynomial_size.0 / 2).rev() {
+            if auto_count > 0 && (auto_count == window_size || l == 0 || !lwe_index_set[l].is_empty()) {
+                // Apply X -> X^(g^auto_count)
+                let auto_key = bsk.get_fourier_automorphism_key(auto_count - 1);
+                ct1.as_mut().copy_from_slice(ct0.as_ref());
+                automorphism_ntru_ciphertext(&auto_key, &ct1, &mut ct0);
+                auto_count = 0;
+            }
+
+            // Multiply by X^s_i
+            for &i in lwe_index_set[l].iter() {
+                let mut ct1_poly = ct1.as_mut_polynomial();
+                let ct0_poly = ct0.as_polynomial();
+                polynomial_wrapping_monic_monomial_mul_and_subtract(
+                    &mut ct1_poly,
+                    &ct0_poly,
+                    MonomialDegree(1),
+                );
+
+                add_ntru_external_product_assign(
+                    &mut ct0.as_mut_view(),
+                    fourier_ngsw_list.get(i),
+                    ct1.as_view(),
+                    fft,
+                    stack,
+                );
+            }
+
+            if l > 0 {
+                auto_count += 1;
+            }
+        }
+
+        if is_neg {
+            // Apply X -> X^-g
+            ct1.as_mut().copy_from_slice(ct0.as_ref());
+            automorphism_ntru_ciphertext(&neg_generator_key, &ct1, &mut ct0);
+        }
+    }
+
+    if !ciphertext_modulus.is_native_modulus() {
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        ct0.as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+}
@@ -0,0 +1,279 @@
+//! Module containing primitives pertaining to the generation of NtruAutomorphismBootstrapKey
+
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{
+    Distribution, Uniform,
+};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+use rayon::prelude::*;
+
+pub fn generate_ntru_automorphism_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output: &mut NtruAutomorphismBootstrapKey<OutputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
+        Input LWE secret key LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.polynomial_size() == output_ntru_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output NTRU secret key and LWE bootstrap key. \
+        Output NTRU secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_ntru_secret_key.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    let gen_iter = generator
+        .try_fork_from_config(output.get_ngsw_list().encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    for ((mut ngsw, &input_key_element), mut generator) in output
+        .get_mut_ngsw_list().iter_mut()
+        .zip(input_lwe_secret_key.as_ref().iter())
+        .zip(gen_iter) {
+            encrypt_constant_ngsw_ciphertext(
+                output_ntru_secret_key,
+                &mut ngsw,
+                Cleartext(input_key_element.cast_into()),
+                noise_distribution,
+                &mut generator,
+            );
+        }
+
+    let gen_iter = generator
+        .try_fork_from_config(
+            output
+                .get_ntru_keyswitch_key_list()
+                .as_ngsw_ciphertext_list()
+                .encryption_fork_config(noise_distribution),
+        )
+        .unwrap();
+
+    for (idx, mut generator) in (0..output.automorphism_key_count().0).zip(gen_iter) {
+        let mut ntru_auto_key = output.get_mut_automorphism_key(idx);
+        let auto_index = ntru_auto_key.automorphism_index();
+
+        generate_ntru_automorphism_key(
+            output_ntru_secret_key,
+            auto_index,
+            &mut ntru_auto_key,
+            noise_distribution,
+            &mut generator,
+        );
+    }
+
+    generate_ntru_switching_key(
+        output_ntru_secret_key,
+        &mut output.get_mut_ntru_switching_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+pub fn allocate_and_generate_new_ntru_automorphism_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    auto_decomp_base_log: DecompositionBaseLog,
+    auto_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    window_size: AutomorphismWindowSize,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruAutomorphismBootstrapKeyOwned<OutputScalar> {
+    let mut bsk = NtruAutomorphismBootstrapKey::new(
+        OutputScalar::ZERO,
+        output_ntru_secret_key.polynomial_size(),
+        br_decomp_base_log,
+        br_decomp_level_count,
+        auto_decomp_base_log,
+        auto_decomp_level_count,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        input_lwe_secret_key.lwe_dimension(),
+        window_size,
+        ciphertext_modulus,
+    );
+
+    generate_ntru_automorphism_bootstrap_key(
+        input_lwe_secret_key,
+        output_ntru_secret_key,
+        &mut bsk,
+        noise_distribution,
+        generator,
+    );
+
+    bsk
+}
+
+pub fn par_generate_ntru_automorphism_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar> + Sync,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar> + Sync,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output: &mut NtruAutomorphismBootstrapKey<OutputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
+        Input LWE secret key LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.polynomial_size() == output_ntru_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output NTRU secret key and LWE bootstrap key. \
+        Output NTRU secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_ntru_secret_key.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    let gen_iter = generator
+        .par_try_fork_from_config(output.get_ngsw_list().encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    output
+        .get_mut_ngsw_list()
+        .par_iter_mut()
+        .zip(input_lwe_secret_key.as_ref().par_iter())
+        .zip(gen_iter)
+        .for_each(|((mut ngsw, &input_key_element), mut generator)| {
+            encrypt_constant_ngsw_ciphertext(
+                output_ntru_secret_key,
+                &mut ngsw,
+                Cleartext(input_key_element.cast_into()),
+                noise_distribution,
+                &mut generator,
+            );
+        });
+
+    let polynomial_size = output.polynomial_size();
+    let window_size = output.window_size();
+    let ciphertext_modulus = output.ciphertext_modulus();
+
+    let gen_iter = generator
+        .par_try_fork_from_config(
+            output
+                .get_ntru_keyswitch_key_list()
+                .as_ngsw_ciphertext_list()
+                .encryption_fork_config(noise_distribution),
+        )
+        .unwrap();
+
+    let mut ntru_auto_keys = output.get_mut_ntru_keyswitch_key_list();
+    let decomp_base_log = ntru_auto_keys.decomposition_base_log();
+
+    ntru_auto_keys
+        .par_iter_mut()
+        .enumerate()
+        .zip(gen_iter)
+        .for_each(|((idx, ntru_ksk), mut generator)| {
+            let auto_index = automorphism_bootstrap_key_automorphism_index(polynomial_size, window_size, idx);
+            let mut ntru_auto_key = NtruAutomorphismKey::from_container(
+                ntru_ksk.into_container(),
+                auto_index,
+                polynomial_size,
+                decomp_base_log,
+                ciphertext_modulus,
+            );
+
+            generate_ntru_automorphism_key(
+                output_ntru_secret_key,
+                auto_index,
+                &mut ntru_auto_key,
+                noise_distribution,
+                &mut generator,
+            );
+        });
+
+    generate_ntru_switching_key(
+        output_ntru_secret_key,
+        &mut output.get_mut_ntru_switching_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+pub fn par_allocate_and_generate_new_ntru_automorphism_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar> + Sync,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    auto_decomp_base_log: DecompositionBaseLog,
+    auto_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    window_size: AutomorphismWindowSize,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruAutomorphismBootstrapKeyOwned<OutputScalar> {
+    let mut bsk = NtruAutomorphismBootstrapKey::new(
+        OutputScalar::ZERO,
+        output_ntru_secret_key.polynomial_size(),
+        br_decomp_base_log,
+        br_decomp_level_count,
+        auto_decomp_base_log,
+        auto_decomp_level_count,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        input_lwe_secret_key.lwe_dimension(),
+        window_size,
+        ciphertext_modulus,
+    );
+
+    par_generate_ntru_automorphism_bootstrap_key(
+        input_lwe_secret_key,
+        output_ntru_secret_key,
+        &mut bsk,
+        noise_distribution,
+        generator,
+    );
+
+    bsk
+}
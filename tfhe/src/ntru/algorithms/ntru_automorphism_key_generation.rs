@@ -28,8 +28,8 @@ pub fn generate_ntru_automorphism_key<
     assert!(
         input_ntru_secret_key
             .ciphertext_modulus()
-            .is_power_of_two(),
-        "Only support power-of-two modulus currently.",
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently, automorphism keys cannot be generated for a prime modulus.",
     );
 
     assert_eq!(
@@ -137,4 +137,4 @@ pub(crate) fn frobenius_map_poly<Scalar, InputCont, OutputCont>(
         { Scalar::ONE } else { Scalar::MAX };
         output.as_mut()[j] = input.as_ref()[i].wrapping_mul(sign);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,254 @@
+//! Module containing the CMux, CMux tree and blind rotation primitives driven by Fourier NGSW
+//! ciphertexts, e.g., those produced by [`encrypt_constant_ngsw_ciphertext`] or by
+//! [`ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext`].
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::FftView;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+pub fn ntru_cmux_assign_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)
+}
+
+/// Compute ct0 + NGSW(b) * (ct1 - ct0) in `ct0`, i.e., select ct0 if b = 0 and ct1 if b = 1.
+///
+/// `ct1` is used as a buffer for the difference and is overwritten.
+pub fn ntru_cmux_assign<Scalar: UnsignedTorus>(
+    ct0: &mut NtruCiphertextMutView<'_, Scalar>,
+    ct1: &mut NtruCiphertextMutView<'_, Scalar>,
+    ngsw: FourierNgswCiphertextView<'_>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        ct0.polynomial_size(),
+        ngsw.polynomial_size(),
+    );
+
+    assert_eq!(
+        ct1.polynomial_size(),
+        ngsw.polynomial_size(),
+    );
+
+    ntru_ciphertext_sub_assign(ct1, ct0);
+    add_ntru_external_product_assign(
+        ct0,
+        ngsw,
+        ct1.as_view(),
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_cmux_tree_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    leaf_count: NtruCiphertextCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(polynomial_size.0 * leaf_count.0, CACHELINE_ALIGN)?
+        .try_and(ntru_cmux_assign_scratch::<Scalar>(polynomial_size, fft)?)
+}
+
+/// Select one of the `2^n` NTRU ciphertexts of `leaves` with the `n` NGSW ciphertexts of
+/// `ngsw_list`, whose bits are ordered from the msb (`ngsw_list[0]`) to the lsb, and write it to
+/// `output`.
+///
+/// The leaves are NTRU ciphertexts rather than plaintexts: the external product of an NGSW
+/// ciphertext with a plaintext does not yield an NTRU encryption of the product. LUT polynomials
+/// can be evaluated directly with [`ntru_cmux_tree_lut_mem_optimized`].
+pub fn ntru_cmux_tree_mem_optimized<Scalar, OutputCont, LeafCont>(
+    output: &mut NtruCiphertext<OutputCont>,
+    leaves: &NtruCiphertextList<LeafCont>,
+    ngsw_list: FourierNgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+    LeafCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        leaves.ntru_ciphertext_count().0,
+        1 << ngsw_list.ciphertext_count().0,
+        "Mismatched number of leaves and NGSW ciphertexts in the CMux tree",
+    );
+
+    assert_eq!(
+        output.polynomial_size(),
+        leaves.polynomial_size(),
+    );
+
+    let (tree_buffer, stack) = stack.make_aligned_raw::<Scalar>(leaves.as_ref().len(), CACHELINE_ALIGN);
+    tree_buffer.copy_from_slice(leaves.as_ref());
+
+    let mut tree_buffer = NtruCiphertextList::from_container(
+        &mut *tree_buffer,
+        leaves.polynomial_size(),
+        leaves.ciphertext_modulus(),
+    );
+
+    ntru_cmux_tree_assign(&mut tree_buffer, ngsw_list, fft, stack);
+
+    output.as_mut().copy_from_slice(tree_buffer.get(0).as_ref());
+}
+
+pub fn ntru_cmux_tree_lut_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    lut_count: PolynomialCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(polynomial_size.0 * lut_count.0, CACHELINE_ALIGN)?
+        .try_and(StackReq::try_any_of([
+            switch_to_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)?,
+            ntru_cmux_assign_scratch::<Scalar>(polynomial_size, fft)?,
+        ])?)
+}
+
+/// Select one of the `2^n` LUT polynomials of `lut_list` with the `n` NGSW ciphertexts of
+/// `ngsw_list`, ordered from the msb (`ngsw_list[0]`) to the lsb, and write its NTRU encryption
+/// to `output`.
+///
+/// The coefficients of the LUT polynomials are plaintexts modulo the ciphertext modulus of
+/// `output`; each of them is switched to an NTRU ciphertext with `fourier_ntru_switching_key` as
+/// for the accumulator of [`ntru_cmux_bootstrap_mem_optimized`].
+pub fn ntru_cmux_tree_lut_mem_optimized<Scalar, OutputCont, LutCont, SwkCont>(
+    output: &mut NtruCiphertext<OutputCont>,
+    lut_list: &PolynomialList<LutCont>,
+    fourier_ntru_switching_key: &FourierNtruSwitchingKey<SwkCont>,
+    ngsw_list: FourierNgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+    LutCont: Container<Element = Scalar>,
+    SwkCont: Container<Element = c64>,
+{
+    assert_eq!(
+        lut_list.polynomial_count().0,
+        1 << ngsw_list.ciphertext_count().0,
+        "Mismatched number of LUT polynomials and NGSW ciphertexts in the CMux tree",
+    );
+
+    assert_eq!(
+        output.polynomial_size(),
+        lut_list.polynomial_size(),
+    );
+
+    let polynomial_size = output.polynomial_size();
+    let ciphertext_modulus = output.ciphertext_modulus();
+
+    let (tree_buffer, stack) = stack.make_aligned_raw::<Scalar>(
+        polynomial_size.0 * lut_list.polynomial_count().0,
+        CACHELINE_ALIGN,
+    );
+    let mut tree_buffer = NtruCiphertextList::from_container(
+        &mut *tree_buffer,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    for (lut, mut leaf) in lut_list.iter().zip(tree_buffer.iter_mut()) {
+        switch_to_ntru_ciphertext_mem_optimized(
+            fourier_ntru_switching_key,
+            &PlaintextList::from_container(lut.as_ref()),
+            &mut leaf,
+            fft,
+            stack,
+        );
+    }
+
+    ntru_cmux_tree_assign(&mut tree_buffer, ngsw_list, fft, stack);
+
+    output.as_mut().copy_from_slice(tree_buffer.get(0).as_ref());
+}
+
+/// Evaluate the CMux tree in place: the j-th level merges the ciphertexts 2^(j+1) * i and
+/// 2^(j+1) * i + 2^j into the former, starting from the lsb, so that the selected leaf ends up in
+/// the first ciphertext of `tree`.
+fn ntru_cmux_tree_assign<Scalar: UnsignedTorus>(
+    tree: &mut NtruCiphertextListMutView<'_, Scalar>,
+    ngsw_list: FourierNgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        tree.polynomial_size(),
+        ngsw_list.polynomial_size(),
+    );
+
+    assert!(
+        tree.ciphertext_modulus().is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = tree.polynomial_size();
+    let ciphertext_modulus = tree.ciphertext_modulus();
+
+    for (j, ngsw) in ngsw_list.iter().rev().enumerate() {
+        let stride = polynomial_size.0 << j;
+
+        for pair in tree.as_mut().chunks_exact_mut(2 * stride) {
+            let (ct0, ct1) = pair.split_at_mut(stride);
+            let mut ct0 = NtruCiphertextMutView::from_container(
+                &mut ct0[..polynomial_size.0],
+                polynomial_size,
+                ciphertext_modulus,
+            );
+            let mut ct1 = NtruCiphertextMutView::from_container(
+                &mut ct1[..polynomial_size.0],
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            ntru_cmux_assign(&mut ct0, &mut ct1, ngsw, fft, stack);
+        }
+    }
+}
+
+pub fn ntru_blind_rotate_assign_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(ntru_cmux_assign_scratch::<Scalar>(polynomial_size, fft)?)
+}
+
+/// Multiply `lut` by X^(-k), where k = sum_i b_i 2^(n - 1 - i) is given by the bits b_i of the
+/// `n` NGSW ciphertexts of `ngsw_list`, ordered from the msb (`ngsw_list[0]`) to the lsb. The
+/// constant coefficient of the result then encrypts the k-th coefficient of the LUT for k < N.
+pub fn ntru_blind_rotate_assign<Scalar: UnsignedTorus>(
+    lut: &mut NtruCiphertextMutView<'_, Scalar>,
+    ngsw_list: FourierNgswCiphertextListView<'_>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        lut.polynomial_size(),
+        ngsw_list.polynomial_size(),
+    );
+
+    let polynomial_size = lut.polynomial_size();
+    let ciphertext_modulus = lut.ciphertext_modulus();
+
+    let mut monomial_degree = MonomialDegree(1);
+    for ngsw in ngsw_list.iter().rev() {
+        let (ct1, stack) = stack.collect_aligned(CACHELINE_ALIGN, lut.as_ref().iter().copied());
+        let mut ct1 = NtruCiphertextMutView::from_container(&mut *ct1, polynomial_size, ciphertext_modulus);
+        polynomial_wrapping_monic_monomial_div_assign(&mut ct1.as_mut_polynomial(), monomial_degree);
+        monomial_degree.0 <<= 1;
+
+        ntru_cmux_assign(lut, &mut ct1, ngsw, fft, stack);
+    }
+}
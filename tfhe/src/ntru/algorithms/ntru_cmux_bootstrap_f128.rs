@@ -0,0 +1,227 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
+use crate::core_crypto::prelude::lwe_ciphertext_modulus_switch;
+use crate::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_div;
+use crate::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_mul_and_subtract;
+use crate::core_crypto::prelude::ModulusSwitchedLweCiphertext;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+pub fn convert_standard_ntru_cmux_bootstrap_key_to_fourier128<Scalar, InputCont, OutputCont>(
+    standard_ntru_cmux_bsk: &NtruCMuxBootstrapKey<InputCont>,
+    fourier128_ntru_cmux_bsk: &mut Fourier128NtruCMuxBootstrapKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    assert_eq!(
+        standard_ntru_cmux_bsk.input_lwe_dimension(),
+        fourier128_ntru_cmux_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_cmux_bsk.output_lwe_dimension(),
+        fourier128_ntru_cmux_bsk.output_lwe_dimension(),
+    );
+
+    let fft = Fft128::new(fourier128_ntru_cmux_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    let ngsw_list = standard_ntru_cmux_bsk.get_ngsw_list();
+    let fourier128_ngsw_list = fourier128_ntru_cmux_bsk.get_mut_fourier128_ngsw_list();
+
+    ngsw_list.iter()
+        .zip(fourier128_ngsw_list.into_ngsw_iter())
+        .for_each(|(ngsw, mut fourier128_ngsw)| {
+            convert_standard_ngsw_ciphertext_to_fourier128_mem_optimized(
+                &ngsw,
+                &mut fourier128_ngsw,
+                fft,
+            )
+        });
+
+    convert_standard_ntru_switching_key_to_fourier128_mem_optimized(
+        &standard_ntru_cmux_bsk.get_ntru_switching_key(),
+        &mut fourier128_ntru_cmux_bsk.get_mut_fourier128_ntru_switching_key(),
+        fft,
+    );
+}
+
+pub fn ntru_cmux_bootstrap_f128_lwe_ciphertext<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = f64>,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    fourier128_bsk: &Fourier128NtruCMuxBootstrapKey<KeyCont>,
+) {
+    assert!(
+        input.ciphertext_modulus().is_power_of_two(),
+        "This operation requires the input to have a power of two modulus."
+    );
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension().0,
+        accumulator.plaintext_count().0,
+    );
+
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft128::new(fourier128_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        ntru_cmux_bootstrap_f128_scratch::<OutputScalar>(
+            fourier128_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_cmux_bootstrap_f128_mem_optimized(
+        fourier128_bsk,
+        input,
+        output,
+        accumulator,
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_cmux_bootstrap_f128_scratch<OutputScalar>(
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_any_of([
+        switch_to_ntru_ciphertext_f128_mem_optimized_requirement::<OutputScalar>(polynomial_size, fft)?,
+        ntru_cmux_blind_rotate_f128_assign_scratch::<OutputScalar>(polynomial_size, fft)?,
+    ])?
+    .try_and(StackReq::try_new_aligned::<OutputScalar>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn ntru_cmux_blind_rotate_f128_assign_scratch<OutputScalar>(
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<OutputScalar>(polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(add_ntru_external_product_f128_assign_scratch::<OutputScalar>(polynomial_size, fft)?)
+}
+
+pub fn ntru_cmux_bootstrap_f128_mem_optimized<InputScalar, OutputScalar, KeyCont, InputCont, OutputCont, AccCont>(
+    bsk: &Fourier128NtruCMuxBootstrapKey<KeyCont>,
+    lwe_in: &LweCiphertext<InputCont>,
+    lwe_out: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    fft: Fft128View<'_>,
+    stack: &mut PodStack,
+) where
+    KeyCont: Container<Element = f64>,
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+{
+    let polynomial_size = PolynomialSize(accumulator.plaintext_count().0);
+    let (local_accumulator_data, stack)
+        = stack.make_aligned_raw::<OutputScalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut local_accumulator = NtruCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        polynomial_size,
+        lwe_out.ciphertext_modulus(),
+    );
+    switch_to_ntru_ciphertext_f128_mem_optimized(
+        &bsk.get_fourier128_ntru_switching_key(),
+        accumulator,
+        &mut local_accumulator,
+        fft,
+        stack,
+    );
+
+    let log_modulus = polynomial_size.to_blind_rotation_input_modulus_log();
+
+    let msed = lwe_ciphertext_modulus_switch(lwe_in.as_view(), log_modulus);
+
+    ntru_cmux_blind_rotate_f128_assign(
+        bsk.as_view(),
+        local_accumulator.as_mut_view(),
+        &msed,
+        fft,
+        stack,
+    );
+
+    extract_lwe_sample_from_ntru_ciphertext(
+        &local_accumulator,
+        lwe_out,
+        MonomialDegree(0),
+    );
+}
+
+pub fn ntru_cmux_blind_rotate_f128_assign<OutputScalar: UnsignedTorus>(
+    bsk: Fourier128NtruCMuxBootstrapKeyView<'_>,
+    mut lut: NtruCiphertextMutView<'_, OutputScalar>,
+    msed_lwe: &impl ModulusSwitchedLweCiphertext<usize>,
+    fft: Fft128View<'_>,
+    stack: &mut PodStack,
+) {
+    let lut_poly_size = lut.polynomial_size();
+    let ciphertext_modulus = lut.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    assert_eq!(
+        msed_lwe.log_modulus(),
+        lut_poly_size.to_blind_rotation_input_modulus_log(),
+    );
+
+    let msed_lwe_mask = msed_lwe.mask();
+    let msed_lwe_body = msed_lwe.body();
+    let monomial_degree = MonomialDegree(msed_lwe_body);
+
+    let mut lut_poly = lut.as_mut_polynomial();
+    let (tmp_poly, _) = stack.make_aligned_raw(lut_poly.as_ref().len(), CACHELINE_ALIGN);
+    let mut tmp_poly = Polynomial::from_container(&mut *tmp_poly);
+    tmp_poly.as_mut().copy_from_slice(lut_poly.as_ref());
+    polynomial_wrapping_monic_monomial_div(&mut lut_poly, &tmp_poly, monomial_degree);
+
+    let mut ct0 = lut;
+    let (ct1, stack) = stack.make_aligned_raw(ct0.as_ref().len(), CACHELINE_ALIGN);
+    let mut ct1 = NtruCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+    // The 128-bit external product already rounds its output for non-native moduli
+    for (lwe_mask_element, bsk_ngsw) in msed_lwe_mask
+        .zip(bsk.get_fourier128_ngsw_list().into_ngsw_iter()) {
+            if lwe_mask_element != 0 {
+                let monomial_degree = MonomialDegree(lwe_mask_element);
+
+                let mut ct1_poly = ct1.as_mut_polynomial();
+                let ct0_poly = ct0.as_polynomial();
+                polynomial_wrapping_monic_monomial_mul_and_subtract(
+                    &mut ct1_poly,
+                    &ct0_poly,
+                    monomial_degree,
+                );
+
+                add_ntru_external_product_f128_assign(
+                    &mut ct0.as_mut_view(),
+                    bsk_ngsw,
+                    ct1.as_view(),
+                    fft,
+                    stack,
+                );
+            }
+        }
+}
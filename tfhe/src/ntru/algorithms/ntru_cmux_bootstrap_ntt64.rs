@@ -0,0 +1,229 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::ntt::ntt64::{Ntt64, Ntt64View};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::prelude::lwe_ciphertext_modulus_switch;
+use crate::core_crypto::prelude::polynomial_algorithms::{
+    polynomial_wrapping_monic_monomial_div_assign_custom_mod,
+    polynomial_wrapping_monic_monomial_mul_assign_custom_mod,
+};
+use crate::core_crypto::prelude::ModulusSwitchedLweCiphertext;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+pub fn convert_standard_ntru_cmux_bootstrap_key_to_ntt64<InputCont, OutputCont>(
+    standard_ntru_cmux_bsk: &NtruCMuxBootstrapKey<InputCont>,
+    ntt_ntru_cmux_bsk: &mut NttNtruCMuxBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        standard_ntru_cmux_bsk.input_lwe_dimension(),
+        ntt_ntru_cmux_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_cmux_bsk.output_lwe_dimension(),
+        ntt_ntru_cmux_bsk.output_lwe_dimension(),
+    );
+
+    let ntt = Ntt64::new(ntt_ntru_cmux_bsk.ciphertext_modulus(), ntt_ntru_cmux_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    let ngsw_list = standard_ntru_cmux_bsk.get_ngsw_list();
+    let mut ntt_ngsw_list = ntt_ntru_cmux_bsk.get_mut_ntt_ngsw_list();
+
+    ngsw_list.iter()
+        .zip(ntt_ngsw_list.iter_mut())
+        .for_each(|(ngsw, mut ntt_ngsw)| {
+            convert_standard_ngsw_ciphertext_to_ntt64_mem_optimized(
+                &ngsw,
+                &mut ntt_ngsw,
+                ntt,
+            )
+        });
+
+    convert_standard_ntru_keyswitch_key_to_ntt64_mem_optimized(
+        &standard_ntru_cmux_bsk.get_ntru_switching_key().as_ntru_keyswitch_key(),
+        &mut ntt_ntru_cmux_bsk.get_mut_ntt_ntru_switching_key(),
+        ntt,
+    );
+}
+
+pub fn ntru_cmux_bootstrap_ntt64_lwe_ciphertext<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    ntt_bsk: &NttNtruCMuxBootstrapKey<KeyCont>,
+) {
+    assert!(
+        input.ciphertext_modulus().is_power_of_two(),
+        "This operation requires the input to have a power of two modulus."
+    );
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension().0,
+        accumulator.plaintext_count().0,
+    );
+    assert_eq!(
+        output.ciphertext_modulus(),
+        ntt_bsk.ciphertext_modulus(),
+    );
+
+    let mut buffers = ComputationBuffers::new();
+
+    let ntt = Ntt64::new(ntt_bsk.ciphertext_modulus(), ntt_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    buffers.resize(
+        ntru_cmux_bootstrap_ntt64_scratch(
+            ntt_bsk.polynomial_size(),
+            ntt,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_cmux_bootstrap_ntt64_mem_optimized(
+        ntt_bsk,
+        input,
+        output,
+        accumulator,
+        ntt,
+        stack,
+    );
+}
+
+pub fn ntru_cmux_bootstrap_ntt64_scratch(
+    polynomial_size: PolynomialSize,
+    ntt: Ntt64View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_any_of([
+        switch_to_ntru_ciphertext_ntt64_mem_optimized_requirement(polynomial_size, ntt)?,
+        ntru_cmux_blind_rotate_ntt64_assign_scratch(polynomial_size, ntt)?,
+    ])?
+    .try_and(StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn ntru_cmux_blind_rotate_ntt64_assign_scratch(
+    polynomial_size: PolynomialSize,
+    ntt: Ntt64View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(add_ntru_external_product_ntt64_assign_scratch(polynomial_size, ntt)?)
+}
+
+pub fn ntru_cmux_bootstrap_ntt64_mem_optimized<InputScalar, KeyCont, InputCont, OutputCont, AccCont>(
+    bsk: &NttNtruCMuxBootstrapKey<KeyCont>,
+    lwe_in: &LweCiphertext<InputCont>,
+    lwe_out: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    ntt: Ntt64View<'_>,
+    stack: &mut PodStack,
+) where
+    KeyCont: Container<Element = u64>,
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+{
+    let polynomial_size = PolynomialSize(accumulator.plaintext_count().0);
+    let (local_accumulator_data, stack) = stack.make_aligned_raw::<u64>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut local_accumulator = NtruCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        polynomial_size,
+        lwe_out.ciphertext_modulus(),
+    );
+    switch_to_ntru_ciphertext_ntt64_mem_optimized(
+        &bsk.get_ntt_ntru_switching_key(),
+        accumulator,
+        &mut local_accumulator,
+        ntt,
+        stack,
+    );
+
+    let log_modulus = polynomial_size.to_blind_rotation_input_modulus_log();
+
+    let msed = lwe_ciphertext_modulus_switch(lwe_in.as_view(), log_modulus);
+
+    ntru_cmux_blind_rotate_ntt64_assign(
+        bsk.as_view(),
+        local_accumulator.as_mut_view(),
+        &msed,
+        ntt,
+        stack,
+    );
+
+    extract_lwe_sample_from_ntru_ciphertext(
+        &local_accumulator,
+        lwe_out,
+        MonomialDegree(0),
+    );
+}
+
+pub fn ntru_cmux_blind_rotate_ntt64_assign(
+    bsk: NttNtruCMuxBootstrapKeyView<'_, u64>,
+    mut lut: NtruCiphertextMutView<'_, u64>,
+    msed_lwe: &impl ModulusSwitchedLweCiphertext<usize>,
+    ntt: Ntt64View<'_>,
+    stack: &mut PodStack,
+) {
+    let lut_poly_size = lut.polynomial_size();
+    let ciphertext_modulus = lut.ciphertext_modulus();
+    let custom_modulus = ntt.custom_modulus();
+    assert_eq!(ciphertext_modulus, bsk.ciphertext_modulus());
+
+    assert_eq!(
+        msed_lwe.log_modulus(),
+        lut_poly_size.to_blind_rotation_input_modulus_log(),
+    );
+
+    let msed_lwe_mask = msed_lwe.mask();
+    let msed_lwe_body = msed_lwe.body();
+
+    polynomial_wrapping_monic_monomial_div_assign_custom_mod(
+        &mut lut.as_mut_polynomial(),
+        MonomialDegree(msed_lwe_body),
+        custom_modulus,
+    );
+
+    let mut ct0 = lut;
+    let (ct1, stack) = stack.make_aligned_raw(ct0.as_ref().len(), CACHELINE_ALIGN);
+    let mut ct1 = NtruCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+    for (lwe_mask_element, bsk_ngsw) in msed_lwe_mask
+        .zip(bsk.get_ntt_ngsw_list().iter()) {
+            if lwe_mask_element != 0 {
+                // ct1 = X^{a_i} * ct0 - ct0
+                ct1.as_mut().copy_from_slice(ct0.as_ref());
+                polynomial_wrapping_monic_monomial_mul_assign_custom_mod(
+                    &mut ct1.as_mut_polynomial(),
+                    MonomialDegree(lwe_mask_element),
+                    custom_modulus,
+                );
+                izip!(ct1.as_mut().iter_mut(), ct0.as_ref().iter())
+                    .for_each(|(c1, &c0)| *c1 = c1.wrapping_sub_custom_mod(c0, custom_modulus));
+
+                add_ntru_external_product_ntt64_assign(
+                    &mut ct0.as_mut_view(),
+                    bsk_ngsw,
+                    ct1.as_view(),
+                    ntt,
+                    stack,
+                );
+            }
+        }
+}
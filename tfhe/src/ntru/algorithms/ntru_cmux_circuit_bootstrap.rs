@@ -8,6 +8,7 @@ use crate::ntru::entities::*;
 use crate::ntru::algorithms::*;
 
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use rayon::prelude::*;
 use tfhe_fft::c64;
 
 pub fn convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier<Scalar, InputCont, OutputCont>(
@@ -82,20 +83,20 @@ pub fn convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier_mem_optimized
         stack,
     );
 
-    let ntru_to_rlwe_ksk = standard_ntru_cmux_cbs_key.get_ntru_to_rlwe_keyswitch_key();
-    let mut fourier_ntru_to_rlwe_ksk = fourier_ntru_cmux_cbs_key.get_mut_fourier_ntru_to_rlwe_keyswitch_key();
-    convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier_mem_optimized(
-        &ntru_to_rlwe_ksk,
-        &mut fourier_ntru_to_rlwe_ksk,
+    let ntru_to_glwe_ksk = standard_ntru_cmux_cbs_key.get_ntru_to_glwe_keyswitch_key();
+    let mut fourier_ntru_to_glwe_ksk = fourier_ntru_cmux_cbs_key.get_mut_fourier_ntru_to_glwe_keyswitch_key();
+    convert_standard_ntru_to_glwe_keyswitch_key_to_fourier_mem_optimized(
+        &ntru_to_glwe_ksk,
+        &mut fourier_ntru_to_glwe_ksk,
         fft,
         stack,
     );
 
-    let rlwe_ss_key = standard_ntru_cmux_cbs_key.get_rlwe_scheme_switch_key();
-    let mut fourier_rlwe_ss_key = fourier_ntru_cmux_cbs_key.get_mut_fourier_rlwe_scheme_switch_key();
-    convert_standard_rlwe_scheme_switch_key_to_fourier_mem_optimized(
-        &rlwe_ss_key,
-        &mut fourier_rlwe_ss_key,
+    let glwe_ss_key = standard_ntru_cmux_cbs_key.get_glwe_scheme_switch_key();
+    let mut fourier_glwe_ss_key = fourier_ntru_cmux_cbs_key.get_mut_fourier_glwe_scheme_switch_key();
+    convert_standard_glwe_scheme_switch_key_to_fourier_mem_optimized(
+        &glwe_ss_key,
+        &mut fourier_glwe_ss_key,
         fft,
         stack,
     );
@@ -121,8 +122,10 @@ pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext<
 
     buffers.resize(
         ntru_cmux_circuit_bootstrap_lwe_ciphertext_scratch::<OutputScalar>(
+            output.glwe_size(),
             polynomial_size,
             output.decomposition_level_count(),
+            fourier_ntru_cmux_cbs_key.get_fourier_ntru_trace_key().decomposition_level_count(),
             fft,
         )
         .unwrap()
@@ -142,20 +145,22 @@ pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext<
 }
 
 pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_scratch<Scalar>(
+    glwe_size: GlweSize,
     polynomial_size: PolynomialSize,
     decomp_level_count: DecompositionLevelCount,
+    tr_decomp_level_count: DecompositionLevelCount,
     fft: FftView<'_>,
 ) -> Result<StackReq, SizeOverflow> {
-    StackReq::try_all_of([
-        StackReq::try_new::<Scalar>(polynomial_size.0 * decomp_level_count.0)?,
-        StackReq::try_new::<Scalar>(2 * polynomial_size.0)?,
-        StackReq::try_new::<Scalar>(polynomial_size.0)?,
-        StackReq::try_any_of([
-            ntru_cmux_blind_rotate_assign_scratch::<Scalar>(polynomial_size, fft)?,
-            // trace
-            // scheme_switch
-        ])?,
-    ])
+    StackReq::try_new::<Scalar>(polynomial_size.0 * decomp_level_count.0)?
+        .try_and(StackReq::try_any_of([
+            ntru_cmux_circuit_bootstrap_to_ntru_ciphertext_list_scratch::<Scalar>(
+                polynomial_size,
+                tr_decomp_level_count,
+                fft,
+            )?,
+            keyswitch_ntru_to_glwe_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+            scheme_switch_glwe_ciphertext_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+        ])?)
 }
 
 pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized<
@@ -182,30 +187,116 @@ pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized<
         fourier_ntru_cmux_cbs_key.polynomial_size(),
     );
 
+    assert_eq!(
+        output.glwe_size(),
+        fourier_ntru_cmux_cbs_key.glwe_size(),
+    );
+
     let polynomial_size = output.polynomial_size();
-    let half_box_size = polynomial_size.0 / 2;
     let ciphertext_modulus = output.ciphertext_modulus();
-    let log_ciphertext_modulus = ciphertext_modulus.into_modulus_log().0;
-
-    let lut_count = 1 << log_lut_count.0;
     let decomp_base_log = output.decomposition_base_log();
     let decomp_level_count = output.decomposition_level_count();
 
-    let fourier_ntru_cmux_bsk = fourier_ntru_cmux_cbs_key.get_fourier_ntru_cmux_bootstrap_key();
-    let fourier_ntru_trace_key = fourier_ntru_cmux_cbs_key.get_fourier_ntru_trace_key();
-    let fourier_ntru_to_rlwe_ksk = fourier_ntru_cmux_cbs_key.get_fourier_ntru_to_rlwe_keyswitch_key();
-    let fourier_rlwe_ss_key = fourier_ntru_cmux_cbs_key.get_fourier_rlwe_scheme_switch_key();
+    let fourier_ntru_to_glwe_ksk = fourier_ntru_cmux_cbs_key.get_fourier_ntru_to_glwe_keyswitch_key();
+    let fourier_glwe_ss_key = fourier_ntru_cmux_cbs_key.get_fourier_glwe_scheme_switch_key();
 
-    // TODO: add it to stack memory
-    // let mut ntru_buffer = NtruCiphertextList::new(OutputScalar::ZERO, polynomial_size, NtruCiphertextCount(decomp_level_count.0), ciphertext_modulus);
-    let (ntru_buffer, stack) = stack.make_raw::<OutputScalar>(polynomial_size.0 * decomp_base_log.0);
+    let (ntru_buffer, stack) = stack.make_raw::<OutputScalar>(polynomial_size.0 * decomp_level_count.0);
     let mut ntru_buffer = NtruCiphertextList::from_container(
         ntru_buffer,
         polynomial_size,
         ciphertext_modulus,
     );
 
-    for (acc_idx, mut ntru_chunk) in ntru_buffer.chunks_mut(lut_count).enumerate()
+    ntru_cmux_circuit_bootstrap_to_ntru_ciphertext_list_mem_optimized(
+        input,
+        &mut ntru_buffer,
+        fourier_ntru_cmux_cbs_key.get_fourier_ntru_cmux_bootstrap_key(),
+        fourier_ntru_cmux_cbs_key.get_fourier_ntru_trace_key(),
+        decomp_base_log,
+        log_lut_count,
+        fft,
+        stack,
+    );
+
+    let glwe_dimension = output.glwe_size().to_glwe_dimension().0;
+    for (i, (ntru, mut ggsw_level_mat)) in ntru_buffer.iter().zip(output.iter_mut()).enumerate() {
+        let log_scale = OutputScalar::BITS - decomp_base_log.0 * (decomp_level_count.0 - i);
+
+        // The last row encrypts m * q / B^j, the first k rows are obtained by scheme switching it
+        let mut glwe_list = ggsw_level_mat.as_mut_glwe_list();
+        let (mut glwe_mask_rows, mut glwe_body_row) = glwe_list.split_at_mut(glwe_dimension);
+        let mut glwe_body_row = glwe_body_row.get_mut(0);
+
+        keyswitch_ntru_to_glwe_mem_optimized(
+            fourier_ntru_to_glwe_ksk,
+            ntru,
+            &mut glwe_body_row,
+            fft,
+            stack,
+        );
+
+        let mut glwe_body = glwe_body_row.get_mut_body();
+        let mut glwe_body = glwe_body.as_mut_polynomial();
+        glwe_body.as_mut()[0] = glwe_body.as_ref()[0].wrapping_add(OutputScalar::ONE << (log_scale - 1));
+
+        scheme_switch_glwe_ciphertext_mem_optimized(
+            &fourier_glwe_ss_key,
+            &glwe_body_row,
+            &mut glwe_mask_rows,
+            fft,
+            stack,
+        );
+    }
+}
+
+pub(crate) fn ntru_cmux_circuit_bootstrap_to_ntru_ciphertext_list_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    tr_decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let ntru_scratch = StackReq::try_new::<Scalar>(polynomial_size.0)?;
+    // accumulator plaintext list and NTRU ciphertext
+    StackReq::try_all_of([
+        ntru_scratch,
+        ntru_scratch,
+        StackReq::try_any_of([
+            switch_to_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)?,
+            ntru_cmux_blind_rotate_assign_scratch::<Scalar>(polynomial_size, fft)?,
+            rev_trace_ntru_ciphertext_assign_mem_optimized_requirement::<Scalar>(
+                polynomial_size,
+                tr_decomp_level_count,
+                fft,
+            )?,
+        ])?,
+    ])
+}
+
+/// Blind rotation and trace steps shared by the GGSW and NGSW outputs of the CBS: the i-th NTRU
+/// ciphertext of `ntru_list` encrypts the constant (2m - 1) * q / 2B^j with j = l - i, where l is
+/// the number of ciphertexts in the list and m the message bit of `input`.
+pub(crate) fn ntru_cmux_circuit_bootstrap_to_ntru_ciphertext_list_mem_optimized<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+>(
+    input: &LweCiphertext<InputCont>,
+    ntru_list: &mut NtruCiphertextListMutView<'_, OutputScalar>,
+    fourier_ntru_cmux_bsk: FourierNtruCMuxBootstrapKeyView<'_>,
+    fourier_ntru_trace_key: FourierNtruTraceKeyView<'_>,
+    decomp_base_log: DecompositionBaseLog,
+    log_lut_count: LutCountLog,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    let polynomial_size = ntru_list.polynomial_size();
+    let half_box_size = polynomial_size.0 / 2;
+    let ciphertext_modulus = ntru_list.ciphertext_modulus();
+    let log_ciphertext_modulus = ciphertext_modulus.into_modulus_log().0;
+
+    let lut_count = 1 << log_lut_count.0;
+    let decomp_level_count = ntru_list.ntru_ciphertext_count().0;
+
+    for (acc_idx, mut ntru_chunk) in ntru_list.chunks_mut(lut_count).enumerate()
     {
         let (accumulator_plaintext_list, stack1) = stack.make_raw::<OutputScalar>(polynomial_size.0);
         let (accumulator_ntru_ciphertext, stack2) = stack1.make_raw::<OutputScalar>(polynomial_size.0);
@@ -213,8 +304,8 @@ pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized<
         let mut accumulator = PlaintextList::from_container(accumulator_plaintext_list.as_mut());
         for (i, elt) in accumulator.as_mut().iter_mut().enumerate() {
             let k = i % lut_count;
-            let level = if decomp_level_count.0 > acc_idx * lut_count + k {
-                decomp_level_count.0 - (acc_idx * lut_count + k)
+            let level = if decomp_level_count > acc_idx * lut_count + k {
+                decomp_level_count - (acc_idx * lut_count + k)
             } else {
                 1
             };
@@ -228,10 +319,12 @@ pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized<
         accumulator.as_mut().rotate_left(half_box_size);
 
         let mut accumulator_ntru_ciphertext = NtruCiphertext::from_container(accumulator_ntru_ciphertext, polynomial_size, ciphertext_modulus);
-        switch_to_ntru_ciphertext(
+        switch_to_ntru_ciphertext_mem_optimized(
             &fourier_ntru_cmux_bsk.get_fourier_ntru_switching_key(),
             &accumulator,
             &mut accumulator_ntru_ciphertext,
+            fft,
+            stack2,
         );
 
         let log_br_modulus = polynomial_size.to_blind_rotation_input_modulus_log();
@@ -253,40 +346,144 @@ pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized<
                 MonomialDegree(k),
             );
 
-            rev_trace_ntru_ciphertext_assign(
+            rev_trace_ntru_ciphertext_assign_mem_optimized(
                 &fourier_ntru_trace_key,
                 &mut ntru_ciphertext,
+                fft,
+                stack2,
             );
         }
     }
+}
 
-    // TODO: add it to stack memory
-    for (i, (ntru, mut rgsw_level_mat)) in ntru_buffer.iter().zip(output.iter_mut()).enumerate() {
-        let log_scale = OutputScalar::BITS - decomp_base_log.0 * (decomp_level_count.0 - i);
+pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_list<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input_list: &LweCiphertextList<InputCont>,
+    output_list: &mut GgswCiphertextList<OutputCont>,
+    fourier_ntru_cmux_cbs_key: &FourierNtruCMuxCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+) {
+    let polynomial_size = output_list.polynomial_size();
 
-        let mut rlwe_list = rgsw_level_mat.as_mut_glwe_list();
-        let (mut rlwe0, mut rlwe1) = rlwe_list.split_at_mut(1);
-        let mut rlwe0 = rlwe0.get_mut(0);
-        let mut rlwe1 = rlwe1.get_mut(0);
+    let mut buffers = ComputationBuffers::new();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
 
-        keyswitch_ntru_to_rlwe_mem_optimized(
-            fourier_ntru_to_rlwe_ksk,
-            ntru,
-            &mut rlwe1,
+    buffers.resize(
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext_scratch::<OutputScalar>(
+            output_list.glwe_size(),
+            polynomial_size,
+            output_list.decomposition_level_count(),
+            fourier_ntru_cmux_cbs_key.get_fourier_ntru_trace_key().decomposition_level_count(),
             fft,
-            stack,
-        );
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_cmux_circuit_bootstrap_lwe_ciphertext_list_mem_optimized(
+        input_list,
+        output_list,
+        fourier_ntru_cmux_cbs_key,
+        log_lut_count,
+        fft,
+        stack,
+    );
+}
 
-        let mut rlwe1_body = rlwe1.get_mut_body();
-        let mut rlwe1_body = rlwe1_body.as_mut_polynomial();
-        rlwe1_body.as_mut()[0] = rlwe1_body.as_ref()[0].wrapping_add(OutputScalar::ONE << (log_scale - 1));
+pub fn ntru_cmux_circuit_bootstrap_lwe_ciphertext_list_mem_optimized<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input_list: &LweCiphertextList<InputCont>,
+    output_list: &mut GgswCiphertextList<OutputCont>,
+    fourier_ntru_cmux_cbs_key: &FourierNtruCMuxCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        input_list.lwe_ciphertext_count().0,
+        output_list.ggsw_ciphertext_count().0,
+        "Mismatched number of input LWE ciphertexts and output GGSW ciphertexts",
+    );
 
-        scheme_switch_rlwe_ciphertext_mem_optimized(
-            &fourier_rlwe_ss_key,
-            &rlwe1,
-            &mut rlwe0,
+    for (input, mut output) in input_list.iter().zip(output_list.iter_mut()) {
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized(
+            &input,
+            &mut output,
+            fourier_ntru_cmux_cbs_key,
+            log_lut_count,
             fft,
             stack,
         );
     }
 }
+
+/// Parallel variant of [`ntru_cmux_circuit_bootstrap_lwe_ciphertext_list`]. The Fourier CBS key
+/// and the FFT plan are shared between threads. Scratch memory is allocated once per rayon job
+/// (see [`rayon::iter::ParallelIterator::for_each_init`]), and reused by all the ciphertexts that
+/// job processes.
+pub fn par_ntru_cmux_circuit_bootstrap_lwe_ciphertext_list<
+    InputScalar: UnsignedTorus + CastInto<usize> + Sync,
+    OutputScalar: UnsignedTorus + Send + Sync,
+    InputCont: Container<Element = InputScalar> + Sync,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64> + Sync,
+>(
+    input_list: &LweCiphertextList<InputCont>,
+    output_list: &mut GgswCiphertextList<OutputCont>,
+    fourier_ntru_cmux_cbs_key: &FourierNtruCMuxCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+) {
+    assert_eq!(
+        input_list.lwe_ciphertext_count().0,
+        output_list.ggsw_ciphertext_count().0,
+        "Mismatched number of input LWE ciphertexts and output GGSW ciphertexts",
+    );
+
+    let polynomial_size = output_list.polynomial_size();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let scratch_bytes = ntru_cmux_circuit_bootstrap_lwe_ciphertext_scratch::<OutputScalar>(
+        output_list.glwe_size(),
+        polynomial_size,
+        output_list.decomposition_level_count(),
+        fourier_ntru_cmux_cbs_key.get_fourier_ntru_trace_key().decomposition_level_count(),
+        fft,
+    )
+    .unwrap()
+    .unaligned_bytes_required();
+
+    input_list
+        .par_iter()
+        .zip(output_list.par_iter_mut())
+        .for_each_init(
+            || {
+                let mut buffers = ComputationBuffers::new();
+                buffers.resize(scratch_bytes);
+                buffers
+            },
+            |buffers, (input, mut output)| {
+                ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized(
+                    &input,
+                    &mut output,
+                    fourier_ntru_cmux_cbs_key,
+                    log_lut_count,
+                    fft,
+                    buffers.stack(),
+                );
+            },
+        );
+}
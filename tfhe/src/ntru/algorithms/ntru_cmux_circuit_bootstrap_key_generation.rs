@@ -2,7 +2,7 @@
 
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::random::{
-    Distribution, Uniform,
+    Distribution, Gaussian, Seeder, Uniform,
 };
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -21,7 +21,7 @@ pub fn generate_ntru_cmux_circuit_bootstrap_key<
 >(
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
-    output_rlwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut NtruCMuxCircuitBootstrapKey<OutputCont>,
     ntru_noise_distribution: NoiseDistribution,
     rlwe_noise_distribution: NoiseDistribution,
@@ -36,24 +36,27 @@ pub fn generate_ntru_cmux_circuit_bootstrap_key<
     );
 
     assert!(
-        output.polynomial_size() == output_rlwe_secret_key.polynomial_size(),
-        "Mismatched PolynomialSize between output RLWE secret key and LWE bootstrap key. \
-        Output RLWE secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
-        output_rlwe_secret_key.polynomial_size(),
+        output.polynomial_size() == output_glwe_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output GLWE secret key and LWE bootstrap key. \
+        Output GLWE secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_glwe_secret_key.polynomial_size(),
         output.polynomial_size()
     );
 
     assert!(
-        ntru_secret_key.polynomial_size() == output_rlwe_secret_key.polynomial_size(),
-        "Mismatched PolynomialSize between NTRU secret key and output RLWE secret key. \
-        NTRU secret key PolynomialSize: {:?}, Output RLWE secret key PolynomialSize: {:?}.",
+        ntru_secret_key.polynomial_size() == output_glwe_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between NTRU secret key and output GLWE secret key. \
+        NTRU secret key PolynomialSize: {:?}, Output GLWE secret key PolynomialSize: {:?}.",
         ntru_secret_key.polynomial_size(),
-        output_rlwe_secret_key.polynomial_size(),
+        output_glwe_secret_key.polynomial_size(),
     );
 
     assert!(
-        output_rlwe_secret_key.glwe_dimension() == GlweDimension(1),
-        "Only support RLWE output",
+        output.glwe_size() == output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        "Mismatched GlweSize between output GLWE secret key and NTRU CMux CBS key. \
+        Output GLWE secret key GlweSize: {:?}, NTRU CMux CBS key GlweSize: {:?}.",
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        output.glwe_size(),
     );
 
 
@@ -72,17 +75,17 @@ pub fn generate_ntru_cmux_circuit_bootstrap_key<
         generator,
     );
 
-    generate_ntru_to_rlwe_keyswitch_key(
+    generate_ntru_to_glwe_keyswitch_key(
         ntru_secret_key,
-        output_rlwe_secret_key,
-        &mut output.get_mut_ntru_to_rlwe_keyswitch_key(),
+        output_glwe_secret_key,
+        &mut output.get_mut_ntru_to_glwe_keyswitch_key(),
         rlwe_noise_distribution,
         generator,
     );
 
-    generate_rlwe_scheme_switch_key(
-        output_rlwe_secret_key,
-        &mut output.get_mut_rlwe_scheme_switch_key(),
+    generate_glwe_scheme_switch_key(
+        output_glwe_secret_key,
+        &mut output.get_mut_glwe_scheme_switch_key(),
         rlwe_noise_distribution,
         generator,
     );
@@ -98,7 +101,7 @@ pub fn allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key<
 >(
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
-    output_rlwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     br_decomp_base_log: DecompositionBaseLog,
     br_decomp_level_count: DecompositionLevelCount,
     swk_decomp_base_log: DecompositionBaseLog,
@@ -116,6 +119,7 @@ pub fn allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key<
 ) -> NtruCMuxCircuitBootstrapKeyOwned<OutputScalar> {
     let mut cbs_key = NtruCMuxCircuitBootstrapKey::new(
         OutputScalar::ZERO,
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
         ntru_secret_key.polynomial_size(),
         input_lwe_secret_key.lwe_dimension(),
         br_decomp_base_log,
@@ -134,7 +138,7 @@ pub fn allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key<
     generate_ntru_cmux_circuit_bootstrap_key(
         input_lwe_secret_key,
         ntru_secret_key,
-        output_rlwe_secret_key,
+        output_glwe_secret_key,
         &mut cbs_key,
         ntru_noise_distribution,
         rlwe_noise_distribution,
@@ -143,3 +147,356 @@ pub fn allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key<
 
     cbs_key
 }
+
+/// Check `param` with [`NtruCMuxParameters::validate_security`] and, if it is consistent and
+/// reaches `target_security_bits`, generate the CBS key with its decomposition parameters and
+/// noise distributions. Otherwise, the error is returned before any key material is generated.
+pub fn allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key_from_parameters<
+    InputScalar: Copy + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, Gaussian<f64>>,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    param: &NtruCMuxParameters,
+    target_security_bits: f64,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Result<NtruCMuxCircuitBootstrapKeyOwned<OutputScalar>, NtruCMuxParametersError> {
+    param.validate_security(target_security_bits)?;
+
+    assert_eq!(
+        input_lwe_secret_key.lwe_dimension(),
+        param.input_lwe_dimension(),
+        "Mismatched LweDimension between the input LWE secret key and the parameters",
+    );
+
+    assert_eq!(
+        ntru_secret_key.polynomial_size(),
+        param.polynomial_size(),
+        "Mismatched PolynomialSize between the NTRU secret key and the parameters",
+    );
+
+    assert_eq!(
+        output_glwe_secret_key.polynomial_size(),
+        param.polynomial_size(),
+        "Mismatched PolynomialSize between the output GLWE secret key and the parameters",
+    );
+
+    let ciphertext_modulus = ntru_secret_key.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            && ciphertext_modulus.into_modulus_log() == param.log_output_modulus(),
+        "The NTRU secret key modulus {ciphertext_modulus:?} is not 2^{}",
+        param.log_output_modulus().0,
+    );
+
+    Ok(allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        input_lwe_secret_key,
+        ntru_secret_key,
+        output_glwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0),
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0),
+        ciphertext_modulus,
+        generator,
+    ))
+}
+
+pub fn par_generate_ntru_cmux_circuit_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar> + Sync,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar> + Sync,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    output: &mut NtruCMuxCircuitBootstrapKey<OutputCont>,
+    ntru_noise_distribution: NoiseDistribution,
+    rlwe_noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
+        Input LWE secret key LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.polynomial_size() == output_glwe_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output GLWE secret key and LWE bootstrap key. \
+        Output GLWE secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_glwe_secret_key.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    assert!(
+        ntru_secret_key.polynomial_size() == output_glwe_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between NTRU secret key and output GLWE secret key. \
+        NTRU secret key PolynomialSize: {:?}, Output GLWE secret key PolynomialSize: {:?}.",
+        ntru_secret_key.polynomial_size(),
+        output_glwe_secret_key.polynomial_size(),
+    );
+
+    assert!(
+        output.glwe_size() == output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        "Mismatched GlweSize between output GLWE secret key and NTRU CMux CBS key. \
+        Output GLWE secret key GlweSize: {:?}, NTRU CMux CBS key GlweSize: {:?}.",
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        output.glwe_size(),
+    );
+
+    par_generate_ntru_cmux_bootstrap_key(
+        input_lwe_secret_key,
+        ntru_secret_key,
+        &mut output.get_mut_ntru_cmux_bootstrap_key(),
+        ntru_noise_distribution,
+        generator,
+    );
+
+    par_generate_ntru_trace_key(
+        ntru_secret_key,
+        &mut output.get_mut_ntru_trace_key(),
+        ntru_noise_distribution,
+        generator,
+    );
+
+    generate_ntru_to_glwe_keyswitch_key(
+        ntru_secret_key,
+        output_glwe_secret_key,
+        &mut output.get_mut_ntru_to_glwe_keyswitch_key(),
+        rlwe_noise_distribution,
+        generator,
+    );
+
+    generate_glwe_scheme_switch_key(
+        output_glwe_secret_key,
+        &mut output.get_mut_glwe_scheme_switch_key(),
+        rlwe_noise_distribution,
+        generator,
+    );
+}
+
+pub fn par_allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar> + Sync,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    tr_decomp_base_log: DecompositionBaseLog,
+    tr_decomp_level_count: DecompositionLevelCount,
+    ksk_decomp_base_log: DecompositionBaseLog,
+    ksk_decomp_level_count: DecompositionLevelCount,
+    ss_decomp_base_log: DecompositionBaseLog,
+    ss_decomp_level_count: DecompositionLevelCount,
+    ntru_noise_distribution: NoiseDistribution,
+    rlwe_noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruCMuxCircuitBootstrapKeyOwned<OutputScalar> {
+    let mut cbs_key = NtruCMuxCircuitBootstrapKey::new(
+        OutputScalar::ZERO,
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        ntru_secret_key.polynomial_size(),
+        input_lwe_secret_key.lwe_dimension(),
+        br_decomp_base_log,
+        br_decomp_level_count,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        tr_decomp_base_log,
+        tr_decomp_level_count,
+        ksk_decomp_base_log,
+        ksk_decomp_level_count,
+        ss_decomp_base_log,
+        ss_decomp_level_count,
+        ciphertext_modulus,
+    );
+
+    par_generate_ntru_cmux_circuit_bootstrap_key(
+        input_lwe_secret_key,
+        ntru_secret_key,
+        output_glwe_secret_key,
+        &mut cbs_key,
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        generator,
+    );
+
+    cbs_key
+}
+
+pub fn generate_seeded_ntru_cmux_circuit_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+    NoiseSeeder: Seeder + ?Sized,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    output: &mut SeededNtruCMuxCircuitBootstrapKey<OutputCont>,
+    ntru_noise_distribution: NoiseDistribution,
+    rlwe_noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    noise_seeder: &mut NoiseSeeder,
+) {
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
+        Input LWE secret key LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.polynomial_size() == output_glwe_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output GLWE secret key and LWE bootstrap key. \
+        Output GLWE secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_glwe_secret_key.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    assert!(
+        ntru_secret_key.polynomial_size() == output_glwe_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between NTRU secret key and output GLWE secret key. \
+        NTRU secret key PolynomialSize: {:?}, Output GLWE secret key PolynomialSize: {:?}.",
+        ntru_secret_key.polynomial_size(),
+        output_glwe_secret_key.polynomial_size(),
+    );
+
+    assert!(
+        output.glwe_size() == output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        "Mismatched GlweSize between output GLWE secret key and NTRU CMux CBS key. \
+        Output GLWE secret key GlweSize: {:?}, NTRU CMux CBS key GlweSize: {:?}.",
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        output.glwe_size(),
+    );
+
+    assert!(
+        output.get_seeded_ntru_to_glwe_keyswitch_key().compression_seed().seed
+            != output.get_seeded_glwe_scheme_switch_key().compression_seed().seed,
+        "The seeded keyswitching key and scheme switching key must use different compression seeds",
+    );
+
+    generate_ntru_cmux_bootstrap_key(
+        input_lwe_secret_key,
+        ntru_secret_key,
+        &mut output.get_mut_ntru_cmux_bootstrap_key(),
+        ntru_noise_distribution,
+        generator,
+    );
+
+    generate_ntru_trace_key(
+        ntru_secret_key,
+        &mut output.get_mut_ntru_trace_key(),
+        ntru_noise_distribution,
+        generator,
+    );
+
+    generate_seeded_ntru_to_glwe_keyswitch_key(
+        ntru_secret_key,
+        output_glwe_secret_key,
+        &mut output.get_mut_seeded_ntru_to_glwe_keyswitch_key(),
+        rlwe_noise_distribution,
+        noise_seeder,
+    );
+
+    generate_seeded_glwe_scheme_switch_key(
+        output_glwe_secret_key,
+        &mut output.get_mut_seeded_glwe_scheme_switch_key(),
+        rlwe_noise_distribution,
+        noise_seeder,
+    );
+}
+
+pub fn allocate_and_generate_new_seeded_ntru_cmux_circuit_bootstrap_key<
+    InputScalar: Copy + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+    NoiseSeeder: Seeder + ?Sized,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    tr_decomp_base_log: DecompositionBaseLog,
+    tr_decomp_level_count: DecompositionLevelCount,
+    ksk_decomp_base_log: DecompositionBaseLog,
+    ksk_decomp_level_count: DecompositionLevelCount,
+    ss_decomp_base_log: DecompositionBaseLog,
+    ss_decomp_level_count: DecompositionLevelCount,
+    ntru_noise_distribution: NoiseDistribution,
+    rlwe_noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    noise_seeder: &mut NoiseSeeder,
+) -> SeededNtruCMuxCircuitBootstrapKeyOwned<OutputScalar> {
+    let mut seeded_cbs_key = SeededNtruCMuxCircuitBootstrapKey::new(
+        OutputScalar::ZERO,
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        ntru_secret_key.polynomial_size(),
+        input_lwe_secret_key.lwe_dimension(),
+        br_decomp_base_log,
+        br_decomp_level_count,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        tr_decomp_base_log,
+        tr_decomp_level_count,
+        ksk_decomp_base_log,
+        ksk_decomp_level_count,
+        noise_seeder.seed().into(),
+        ss_decomp_base_log,
+        ss_decomp_level_count,
+        noise_seeder.seed().into(),
+        ciphertext_modulus,
+    );
+
+    generate_seeded_ntru_cmux_circuit_bootstrap_key(
+        input_lwe_secret_key,
+        ntru_secret_key,
+        output_glwe_secret_key,
+        &mut seeded_cbs_key,
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        generator,
+        noise_seeder,
+    );
+
+    seeded_cbs_key
+}
@@ -0,0 +1,284 @@
+//! Module containing the vertical packing LUT evaluation driven by the NTRU CMux circuit bootstrapping
+
+use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
+    fill_with_forward_fourier_scratch,
+    FourierGgswCiphertextListMutView,
+};
+use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::{
+    blind_rotate_assign,
+    cmux_tree_memory_optimized,
+    vertical_packing_scratch,
+};
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::prelude::slice_algorithms::slice_wrapping_scalar_mul_assign;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+/// Circuit bootstrap each boolean LWE ciphertext of `lwe_list_in` into a GGSW ciphertext with
+/// the NTRU CMux CBS, then evaluate the LUTs of `big_lut_as_polynomial_list` by vertical packing.
+///
+/// The input bits are ordered from the msb (`lwe_list_in[0]`) to the lsb. The LUT polynomials are
+/// split evenly between the output ciphertexts and their coefficients are plaintexts modulo the
+/// ciphertext modulus of the CBS key, i.e., they are encoded as for [`encrypt_glwe_ciphertext`].
+///
+/// [`encrypt_glwe_ciphertext`]: crate::core_crypto::algorithms::encrypt_glwe_ciphertext
+pub fn ntru_cmux_circuit_bootstrap_vertical_packing_lwe_ciphertext_list<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    LutCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    lwe_list_in: &LweCiphertextList<InputCont>,
+    lwe_list_out: &mut LweCiphertextList<OutputCont>,
+    big_lut_as_polynomial_list: &PolynomialList<LutCont>,
+    fourier_ntru_cmux_cbs_key: &FourierNtruCMuxCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+    base_log_cbs: DecompositionBaseLog,
+    level_cbs: DecompositionLevelCount,
+) {
+    let polynomial_size = fourier_ntru_cmux_cbs_key.polynomial_size();
+
+    let mut buffers = ComputationBuffers::new();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    buffers.resize(
+        ntru_cmux_circuit_bootstrap_vertical_packing_lwe_ciphertext_list_scratch::<OutputScalar>(
+            lwe_list_in.lwe_ciphertext_count(),
+            lwe_list_out.lwe_ciphertext_count(),
+            big_lut_as_polynomial_list.polynomial_count(),
+            fourier_ntru_cmux_cbs_key.glwe_size(),
+            polynomial_size,
+            level_cbs,
+            fourier_ntru_cmux_cbs_key.get_fourier_ntru_trace_key().decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_cmux_circuit_bootstrap_vertical_packing_lwe_ciphertext_list_mem_optimized(
+        lwe_list_in,
+        lwe_list_out,
+        big_lut_as_polynomial_list,
+        fourier_ntru_cmux_cbs_key,
+        log_lut_count,
+        base_log_cbs,
+        level_cbs,
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_cmux_circuit_bootstrap_vertical_packing_lwe_ciphertext_list_scratch<Scalar>(
+    lwe_list_in_count: LweCiphertextCount,
+    lwe_list_out_count: LweCiphertextCount,
+    big_lut_polynomial_count: PolynomialCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    level_cbs: DecompositionLevelCount,
+    tr_decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let small_lut_size = PolynomialCount(big_lut_polynomial_count.0 / lwe_list_out_count.0);
+
+    StackReq::try_all_of([
+        // fourier rgsw list
+        StackReq::try_new_aligned::<c64>(
+            lwe_list_in_count.0 * polynomial_size.to_fourier_polynomial_size().0
+                * glwe_size.0
+                * glwe_size.0
+                * level_cbs.0,
+            CACHELINE_ALIGN,
+        )?,
+        // standard rgsw
+        StackReq::try_new_aligned::<Scalar>(
+            polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_cbs.0,
+            CACHELINE_ALIGN,
+        )?,
+        // scaled lut
+        StackReq::try_new_aligned::<Scalar>(
+            polynomial_size.0 * small_lut_size.0,
+            CACHELINE_ALIGN,
+        )?,
+        StackReq::try_any_of([
+            ntru_cmux_circuit_bootstrap_lwe_ciphertext_scratch::<Scalar>(
+                glwe_size,
+                polynomial_size,
+                level_cbs,
+                tr_decomp_level_count,
+                fft,
+            )?,
+            fill_with_forward_fourier_scratch(fft)?,
+            vertical_packing_scratch::<Scalar>(
+                glwe_size,
+                polynomial_size,
+                small_lut_size,
+                lwe_list_in_count.0,
+                fft,
+            )?,
+        ])?,
+    ])
+}
+
+pub fn ntru_cmux_circuit_bootstrap_vertical_packing_lwe_ciphertext_list_mem_optimized<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    LutCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    lwe_list_in: &LweCiphertextList<InputCont>,
+    lwe_list_out: &mut LweCiphertextList<OutputCont>,
+    big_lut_as_polynomial_list: &PolynomialList<LutCont>,
+    fourier_ntru_cmux_cbs_key: &FourierNtruCMuxCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+    base_log_cbs: DecompositionBaseLog,
+    level_cbs: DecompositionLevelCount,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        lwe_list_in.lwe_size().to_lwe_dimension(),
+        fourier_ntru_cmux_cbs_key.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        lwe_list_out.lwe_size().to_lwe_dimension(),
+        fourier_ntru_cmux_cbs_key
+            .glwe_size()
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(fourier_ntru_cmux_cbs_key.polynomial_size()),
+    );
+
+    assert_eq!(
+        big_lut_as_polynomial_list.polynomial_size(),
+        fourier_ntru_cmux_cbs_key.polynomial_size(),
+    );
+
+    let ciphertext_modulus = lwe_list_out.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus, currently.",
+    );
+
+    let number_of_luts = lwe_list_out.lwe_ciphertext_count().0;
+    assert!(
+        big_lut_as_polynomial_list.polynomial_count().0 % number_of_luts == 0,
+        "The number of LUT polynomials {} should be a multiple of the number of outputs {}.",
+        big_lut_as_polynomial_list.polynomial_count().0,
+        number_of_luts,
+    );
+
+    let polynomial_size = fourier_ntru_cmux_cbs_key.polynomial_size();
+    let glwe_size = fourier_ntru_cmux_cbs_key.glwe_size();
+    let ggsw_count = lwe_list_in.lwe_ciphertext_count().0;
+
+    let (ggsw_list_data, stack) = stack.make_aligned_with(
+        ggsw_count * polynomial_size.to_fourier_polynomial_size().0
+            * glwe_size.0
+            * glwe_size.0
+            * level_cbs.0,
+        CACHELINE_ALIGN,
+        |_| c64::default(),
+    );
+    let (ggsw_res_data, stack) = stack.make_aligned_with(
+        polynomial_size.0 * glwe_size.0 * glwe_size.0 * level_cbs.0,
+        CACHELINE_ALIGN,
+        |_| OutputScalar::ZERO,
+    );
+
+    let mut ggsw_list = FourierGgswCiphertextListMutView::new(
+        ggsw_list_data,
+        ggsw_count,
+        glwe_size,
+        polynomial_size,
+        base_log_cbs,
+        level_cbs,
+    );
+
+    let mut ggsw_res = GgswCiphertext::from_container(
+        ggsw_res_data,
+        glwe_size,
+        polynomial_size,
+        base_log_cbs,
+        ciphertext_modulus,
+    );
+
+    for (lwe_in, ggsw) in lwe_list_in.iter().zip(ggsw_list.as_mut_view().into_ggsw_iter()) {
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized(
+            &lwe_in,
+            &mut ggsw_res,
+            fourier_ntru_cmux_cbs_key,
+            log_lut_count,
+            fft,
+            stack,
+        );
+
+        ggsw.fill_with_forward_fourier(ggsw_res.as_view(), fft, stack);
+    }
+
+    let small_lut_size = big_lut_as_polynomial_list.polynomial_count().0 / number_of_luts;
+
+    // Get the base 2 logarithm (rounded down) of the number of polynomials in a LUT
+    let log_lut_number = small_lut_size.ilog2() as usize;
+    let log_number_of_luts_for_cmux_tree = if log_lut_number > ggsw_count {
+        0
+    } else {
+        log_lut_number
+    };
+
+    let (scaled_lut_data, stack) = stack.make_aligned_with(
+        polynomial_size.0 * small_lut_size,
+        CACHELINE_ALIGN,
+        |_| OutputScalar::ZERO,
+    );
+
+    for (lut, mut lwe_out) in big_lut_as_polynomial_list
+        .chunks_exact(small_lut_size)
+        .zip(lwe_list_out.iter_mut())
+    {
+        // LUT coefficients are moved to the MSBs for non-native power-of-two moduli
+        scaled_lut_data.copy_from_slice(lut.as_ref());
+        if !ciphertext_modulus.is_native_modulus() {
+            slice_wrapping_scalar_mul_assign(
+                scaled_lut_data,
+                ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+            );
+        }
+        let scaled_lut = PolynomialList::from_container(&*scaled_lut_data, polynomial_size);
+
+        // The msb GGSWs drive the CMux tree and the lsb GGSWs the final blind rotation
+        let (cmux_ggsw, br_ggsw) = ggsw_list.as_view().split_at(log_number_of_luts_for_cmux_tree);
+
+        let (cmux_tree_lut_res_data, stack) = stack.make_aligned_with(
+            polynomial_size.0 * glwe_size.0,
+            CACHELINE_ALIGN,
+            |_| OutputScalar::ZERO,
+        );
+        let mut cmux_tree_lut_res = GlweCiphertext::from_container(
+            cmux_tree_lut_res_data,
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        cmux_tree_memory_optimized(cmux_tree_lut_res.as_mut_view(), scaled_lut, cmux_ggsw, fft, stack);
+        blind_rotate_assign(cmux_tree_lut_res.as_mut_view(), br_ggsw, fft, stack);
+
+        extract_lwe_sample_from_glwe_ciphertext(&cmux_tree_lut_res, &mut lwe_out, MonomialDegree(0));
+    }
+}
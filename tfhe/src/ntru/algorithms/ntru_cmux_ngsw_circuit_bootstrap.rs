@@ -0,0 +1,295 @@
+//! Module containing the NTRU CMux circuit bootstrapping whose output is an NGSW ciphertext
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+pub fn convert_standard_ntru_cmux_ngsw_circuit_bootstrap_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_ntru_cmux_cbs_key: &NtruCMuxNgswCircuitBootstrapKey<InputCont>,
+    fourier_ntru_cmux_cbs_key: &mut FourierNtruCMuxNgswCircuitBootstrapKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        standard_ntru_cmux_cbs_key.input_lwe_dimension(),
+        fourier_ntru_cmux_cbs_key.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_cmux_cbs_key.output_lwe_dimension(),
+        fourier_ntru_cmux_cbs_key.output_lwe_dimension(),
+    );
+
+    let polynomial_size = standard_ntru_cmux_cbs_key.polynomial_size();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        convert_standard_ntru_cmux_ngsw_circuit_bootstrap_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    convert_standard_ntru_cmux_ngsw_circuit_bootstrap_key_to_fourier_mem_optimized(
+        standard_ntru_cmux_cbs_key,
+        fourier_ntru_cmux_cbs_key,
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_cmux_ngsw_circuit_bootstrap_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    convert_standard_ngsw_ciphertext_to_fourier_mem_optimized_requirement(fft)
+}
+
+pub fn convert_standard_ntru_cmux_ngsw_circuit_bootstrap_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_ntru_cmux_cbs_key: &NtruCMuxNgswCircuitBootstrapKey<InputCont>,
+    fourier_ntru_cmux_cbs_key: &mut FourierNtruCMuxNgswCircuitBootstrapKey<OutputCont>,
+    fft: FftView,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    let ntru_cmux_bsk = standard_ntru_cmux_cbs_key.get_ntru_cmux_bootstrap_key();
+    let mut fourier_ntru_cmux_bsk = fourier_ntru_cmux_cbs_key.get_mut_fourier_ntru_cmux_bootstrap_key();
+    convert_standard_ntru_cmux_bootstrap_key_to_fourier_mem_optimized(
+        &ntru_cmux_bsk,
+        &mut fourier_ntru_cmux_bsk,
+        fft,
+        stack,
+    );
+
+    let ntru_trace_key = standard_ntru_cmux_cbs_key.get_ntru_trace_key();
+    let mut fourier_ntru_trace_key = fourier_ntru_cmux_cbs_key.get_mut_fourier_ntru_trace_key();
+    convert_standard_ntru_trace_key_to_fourier_mem_optimized(
+        &ntru_trace_key,
+        &mut fourier_ntru_trace_key,
+        fft,
+        stack,
+    );
+
+    let ntru_ss_key = standard_ntru_cmux_cbs_key.get_ntru_scheme_switch_key();
+    let mut fourier_ntru_ss_key = fourier_ntru_cmux_cbs_key.get_mut_fourier_ntru_scheme_switch_key();
+    convert_standard_ntru_scheme_switch_key_to_fourier_mem_optimized(
+        &ntru_ss_key,
+        &mut fourier_ntru_ss_key,
+        fft,
+        stack,
+    );
+}
+
+/// Circuit bootstrap a boolean LWE ciphertext into an NGSW ciphertext under the NTRU secret key of
+/// `fourier_ntru_cmux_ngsw_cbs_key`, which can be used directly in
+/// [`add_ntru_external_product_assign`] once converted to the Fourier domain.
+pub fn ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut NgswCiphertext<OutputCont>,
+    fourier_ntru_cmux_ngsw_cbs_key: &FourierNtruCMuxNgswCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+) {
+    let polynomial_size = output.polynomial_size();
+
+    let mut buffers = ComputationBuffers::new();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    buffers.resize(
+        ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_scratch::<OutputScalar>(
+            polynomial_size,
+            output.decomposition_level_count(),
+            fourier_ntru_cmux_ngsw_cbs_key.get_fourier_ntru_trace_key().decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        fourier_ntru_cmux_ngsw_cbs_key,
+        log_lut_count,
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_scratch<Scalar>(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    tr_decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new::<Scalar>(polynomial_size.0 * decomp_level_count.0)?
+        .try_and(StackReq::try_any_of([
+            ntru_cmux_circuit_bootstrap_to_ntru_ciphertext_list_scratch::<Scalar>(
+                polynomial_size,
+                tr_decomp_level_count,
+                fft,
+            )?,
+            scheme_switch_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)?,
+        ])?)
+}
+
+pub fn ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_mem_optimized<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut NgswCiphertext<OutputCont>,
+    fourier_ntru_cmux_ngsw_cbs_key: &FourierNtruCMuxNgswCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        input.lwe_size().to_lwe_dimension(),
+        fourier_ntru_cmux_ngsw_cbs_key.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        output.polynomial_size(),
+        fourier_ntru_cmux_ngsw_cbs_key.polynomial_size(),
+    );
+
+    let polynomial_size = output.polynomial_size();
+    let ciphertext_modulus = output.ciphertext_modulus();
+    let decomp_base_log = output.decomposition_base_log();
+    let decomp_level_count = output.decomposition_level_count();
+
+    let fourier_ntru_ss_key = fourier_ntru_cmux_ngsw_cbs_key.get_fourier_ntru_scheme_switch_key();
+
+    let (ntru_buffer, stack) = stack.make_raw::<OutputScalar>(polynomial_size.0 * decomp_level_count.0);
+    let mut ntru_buffer = NtruCiphertextList::from_container(
+        ntru_buffer,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    ntru_cmux_circuit_bootstrap_to_ntru_ciphertext_list_mem_optimized(
+        input,
+        &mut ntru_buffer,
+        fourier_ntru_cmux_ngsw_cbs_key.get_fourier_ntru_cmux_bootstrap_key(),
+        fourier_ntru_cmux_ngsw_cbs_key.get_fourier_ntru_trace_key(),
+        decomp_base_log,
+        log_lut_count,
+        fft,
+        stack,
+    );
+
+    for (i, (ntru, mut ngsw_row)) in ntru_buffer.iter().zip(output.iter_mut()).enumerate() {
+        let log_scale = OutputScalar::BITS - decomp_base_log.0 * (decomp_level_count.0 - i);
+
+        // NTRU_f((2m - 1) * q / 2B^j) is switched to g / f + (2m - 1) * q / 2B^j, to which
+        // q / 2B^j is added so that the row encrypts m * q / B^j as in an NGSW ciphertext
+        scheme_switch_ntru_ciphertext_mem_optimized(
+            &fourier_ntru_ss_key,
+            &ntru,
+            &mut ngsw_row,
+            fft,
+            stack,
+        );
+
+        let mut ngsw_row_poly = ngsw_row.as_mut_polynomial();
+        ngsw_row_poly.as_mut()[0] = ngsw_row_poly.as_ref()[0].wrapping_add(OutputScalar::ONE << (log_scale - 1));
+    }
+}
+
+pub fn ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_list<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input_list: &LweCiphertextList<InputCont>,
+    output_list: &mut NgswCiphertextList<OutputCont>,
+    fourier_ntru_cmux_ngsw_cbs_key: &FourierNtruCMuxNgswCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+) {
+    let polynomial_size = output_list.polynomial_size();
+
+    let mut buffers = ComputationBuffers::new();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    buffers.resize(
+        ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_scratch::<OutputScalar>(
+            polynomial_size,
+            output_list.decomposition_level_count(),
+            fourier_ntru_cmux_ngsw_cbs_key.get_fourier_ntru_trace_key().decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_list_mem_optimized(
+        input_list,
+        output_list,
+        fourier_ntru_cmux_ngsw_cbs_key,
+        log_lut_count,
+        fft,
+        stack,
+    );
+}
+
+pub fn ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_list_mem_optimized<
+    InputScalar: UnsignedTorus + CastInto<usize>,
+    OutputScalar: UnsignedTorus,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    KeyCont: Container<Element = c64>,
+>(
+    input_list: &LweCiphertextList<InputCont>,
+    output_list: &mut NgswCiphertextList<OutputCont>,
+    fourier_ntru_cmux_ngsw_cbs_key: &FourierNtruCMuxNgswCircuitBootstrapKey<KeyCont>,
+    log_lut_count: LutCountLog,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) {
+    assert_eq!(
+        input_list.lwe_ciphertext_count().0,
+        output_list.ngsw_ciphertext_count().0,
+        "Mismatched number of input LWE ciphertexts and output NGSW ciphertexts",
+    );
+
+    for (input, mut output) in input_list.iter().zip(output_list.iter_mut()) {
+        ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_mem_optimized(
+            &input,
+            &mut output,
+            fourier_ntru_cmux_ngsw_cbs_key,
+            log_lut_count,
+            fft,
+            stack,
+        );
+    }
+}
@@ -13,6 +13,7 @@ use crate::core_crypto::entities::*;
 
 // use crate::ntru::algorithms::*;
 use crate::ntru::entities::*;
+use rayon::prelude::*;
 
 pub fn encrypt_ntru_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, OutputCont, Gen>(
     ntru_secret_key: &NtruSecretKey<KeyCont>,
@@ -54,8 +55,9 @@ pub fn encrypt_ntru_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
     let ciphertext_modulus = ntru_secret_key.ciphertext_modulus();
 
     assert!(
-        ciphertext_modulus.is_compatible_with_native_modulus(),
-        "Only support power-of-two modulus currently."
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            || ciphertext_modulus.get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus currently."
     );
 
     let mut buf = Polynomial::new(Scalar::ZERO, polynomial_size);
@@ -66,13 +68,32 @@ pub fn encrypt_ntru_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Ou
         ciphertext_modulus,
     );
 
+    let sk_inv_poly = ntru_secret_key.get_secret_key_inverse_polynomial();
+
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        let custom_modulus = Scalar::cast_from(ciphertext_modulus.get_custom_modulus());
+
+        polynomial_wrapping_add_assign_custom_mod(
+            &mut buf,
+            &input_plaintext_list.as_polynomial(),
+            custom_modulus,
+        );
+
+        polynomial_karatsuba_wrapping_mul_custom_mod(
+            &mut output_ntru_ciphertext.as_mut_polynomial(),
+            &buf,
+            &sk_inv_poly,
+            custom_modulus,
+        );
+
+        return;
+    }
+
     polynomial_wrapping_add_assign(
         &mut buf,
         &input_plaintext_list.as_polynomial(),
     );
 
-    let sk_inv_poly = ntru_secret_key.get_secret_key_inverse_polynomial();
-
     polynomial_wrapping_mul(
         &mut output_ntru_ciphertext.as_mut_polynomial(),
         &buf,
@@ -124,12 +145,24 @@ pub fn decrypt_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
     let ciphertext_modulus = ntru_secret_key.ciphertext_modulus();
 
     assert!(
-        ciphertext_modulus.is_compatible_with_native_modulus(),
-        "Only support power-of-two modulus currently."
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            || ciphertext_modulus.get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus currently."
     );
 
     let sk_poly = ntru_secret_key.get_secret_key_polynomial();
 
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_karatsuba_wrapping_mul_custom_mod(
+            &mut output_plaintext_list.as_mut_polynomial(),
+            &input_ntru_ciphertext.as_polynomial(),
+            &sk_poly,
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+
+        return;
+    }
+
     polynomial_wrapping_mul(
         &mut output_plaintext_list.as_mut_polynomial(),
         &input_ntru_ciphertext.as_polynomial(),
@@ -144,3 +177,136 @@ pub fn decrypt_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
         );
     }
 }
+
+pub fn encrypt_ntru_ciphertext_list<Scalar, NoiseDistribution, KeyCont, InputCont, OutputCont, Gen>(
+    ntru_secret_key: &NtruSecretKey<KeyCont>,
+    output_ntru_ciphertext_list: &mut NtruCiphertextList<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        output_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * output_ntru_ciphertext_list.polynomial_size().0
+            == input_plaintext_list.plaintext_count().0,
+        "Mismatch between required number of plaintexts: {} ({:?} * {:?}) and input \
+        PlaintextCount: {:?}",
+        output_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * output_ntru_ciphertext_list.polynomial_size().0,
+        output_ntru_ciphertext_list.ntru_ciphertext_count(),
+        output_ntru_ciphertext_list.polynomial_size(),
+        input_plaintext_list.plaintext_count(),
+    );
+
+    let polynomial_size = output_ntru_ciphertext_list.polynomial_size();
+
+    let gen_iter = generator
+        .try_fork_from_config(output_ntru_ciphertext_list.encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    for ((mut ntru_ciphertext, plaintext_list), mut generator) in output_ntru_ciphertext_list
+        .iter_mut()
+        .zip(input_plaintext_list.chunks_exact(polynomial_size.0))
+        .zip(gen_iter)
+    {
+        encrypt_ntru_ciphertext(
+            ntru_secret_key,
+            &mut ntru_ciphertext,
+            &plaintext_list,
+            noise_distribution,
+            &mut generator,
+        );
+    }
+}
+
+pub fn par_encrypt_ntru_ciphertext_list<Scalar, NoiseDistribution, KeyCont, InputCont, OutputCont, Gen>(
+    ntru_secret_key: &NtruSecretKey<KeyCont>,
+    output_ntru_ciphertext_list: &mut NtruCiphertextList<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    KeyCont: Container<Element = Scalar> + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+{
+    assert!(
+        output_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * output_ntru_ciphertext_list.polynomial_size().0
+            == input_plaintext_list.plaintext_count().0,
+        "Mismatch between required number of plaintexts: {} ({:?} * {:?}) and input \
+        PlaintextCount: {:?}",
+        output_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * output_ntru_ciphertext_list.polynomial_size().0,
+        output_ntru_ciphertext_list.ntru_ciphertext_count(),
+        output_ntru_ciphertext_list.polynomial_size(),
+        input_plaintext_list.plaintext_count(),
+    );
+
+    let polynomial_size = output_ntru_ciphertext_list.polynomial_size();
+
+    let gen_iter = generator
+        .par_try_fork_from_config(output_ntru_ciphertext_list.encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    output_ntru_ciphertext_list
+        .par_iter_mut()
+        .zip(input_plaintext_list.par_chunks_exact(polynomial_size.0))
+        .zip(gen_iter)
+        .for_each(|((mut ntru_ciphertext, plaintext_list), mut generator)| {
+            encrypt_ntru_ciphertext(
+                ntru_secret_key,
+                &mut ntru_ciphertext,
+                &plaintext_list,
+                noise_distribution,
+                &mut generator,
+            );
+        });
+}
+
+pub fn decrypt_ntru_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_secret_key: &NtruSecretKey<KeyCont>,
+    input_ntru_ciphertext_list: &NtruCiphertextList<InputCont>,
+    output_plaintext_list: &mut PlaintextList<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * input_ntru_ciphertext_list.polynomial_size().0
+            == output_plaintext_list.plaintext_count().0,
+        "Mismatch between required number of plaintexts: {} ({:?} * {:?}) and output \
+        PlaintextCount: {:?}",
+        input_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * input_ntru_ciphertext_list.polynomial_size().0,
+        input_ntru_ciphertext_list.ntru_ciphertext_count(),
+        input_ntru_ciphertext_list.polynomial_size(),
+        output_plaintext_list.plaintext_count(),
+    );
+
+    let polynomial_size = input_ntru_ciphertext_list.polynomial_size();
+
+    for (ntru_ciphertext, mut plaintext_list) in input_ntru_ciphertext_list
+        .iter()
+        .zip(output_plaintext_list.chunks_exact_mut(polynomial_size.0))
+    {
+        decrypt_ntru_ciphertext(
+            ntru_secret_key,
+            &ntru_ciphertext,
+            &mut plaintext_list,
+        );
+    }
+}
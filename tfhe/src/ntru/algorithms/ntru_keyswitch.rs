@@ -1,6 +1,9 @@
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::ntt::ntt64::{Ntt64, Ntt64View};
+use crate::core_crypto::commons::parameters::PolynomialSize;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
 use crate::ntru::entities::*;
 use crate::ntru::algorithms::*;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
@@ -81,6 +84,50 @@ pub fn keyswitch_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
     KskCont: Container<Element = c64>,
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_keyswitch_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        keyswitch_ntru_ciphertext_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    keyswitch_ntru_ciphertext_mem_optimized(
+        ntru_keyswitch_key,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn keyswitch_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)
+}
+
+pub fn keyswitch_ntru_ciphertext_mem_optimized<Scalar, KskCont, InputCont, OutputCont>(
+    ntru_keyswitch_key: &FourierNtruKeyswitchKey<KskCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
 {
     assert_eq!(
         input_ntru_ciphertext.polynomial_size(),
@@ -104,14 +151,182 @@ pub fn keyswitch_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
         "Only support power-of-two modulus currently.",
     );
 
+    output_ntru_ciphertext.as_mut().fill(Scalar::ZERO);
+    add_ntru_external_product_assign(
+        &mut output_ntru_ciphertext.as_mut_view(),
+        ntru_keyswitch_key.as_fourier_ngsw_ciphertext(),
+        input_ntru_ciphertext.as_view(),
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_keyswitch_key_to_ntt64<InputCont, OutputCont>(
+    standard_ntru_ksk: &NtruKeyswitchKey<InputCont>,
+    ntt_ntru_ksk: &mut NttNtruKeyswitchKey<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    let ntt = Ntt64::new(ntt_ntru_ksk.ciphertext_modulus(), ntt_ntru_ksk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    convert_standard_ntru_keyswitch_key_to_ntt64_mem_optimized(
+        standard_ntru_ksk,
+        ntt_ntru_ksk,
+        ntt,
+    );
+}
+
+pub fn convert_standard_ntru_keyswitch_key_to_ntt64_mem_optimized<InputCont, OutputCont>(
+    standard_ntru_ksk: &NtruKeyswitchKey<InputCont>,
+    ntt_ntru_ksk: &mut NttNtruKeyswitchKey<OutputCont>,
+    ntt: Ntt64View<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    convert_standard_ngsw_ciphertext_to_ntt64_mem_optimized(
+        &standard_ntru_ksk.as_ngsw_ciphertext(),
+        &mut ntt_ntru_ksk.as_mut_ntt_ngsw_ciphertext(),
+        ntt,
+    );
+}
+
+pub fn keyswitch_ntru_ciphertext_ntt64<KskCont, InputCont, OutputCont>(
+    ntru_keyswitch_key: &NttNtruKeyswitchKey<KskCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    KskCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
     let polynomial_size = ntru_keyswitch_key.polynomial_size();
 
-    let fft = Fft::new(polynomial_size);
+    let ntt = Ntt64::new(ntru_keyswitch_key.ciphertext_modulus(), polynomial_size);
+    let ntt = ntt.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        add_ntru_external_product_ntt64_assign_scratch(
+            polynomial_size,
+            ntt,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    keyswitch_ntru_ciphertext_ntt64_mem_optimized(
+        ntru_keyswitch_key,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        ntt,
+        stack,
+    );
+}
+
+pub fn keyswitch_ntru_ciphertext_ntt64_mem_optimized_requirement(
+    polynomial_size: PolynomialSize,
+    ntt: Ntt64View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ntru_external_product_ntt64_assign_scratch(polynomial_size, ntt)
+}
+
+pub fn keyswitch_ntru_ciphertext_ntt64_mem_optimized<KskCont, InputCont, OutputCont>(
+    ntru_keyswitch_key: &NttNtruKeyswitchKey<KskCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    ntt: Ntt64View<'_>,
+    stack: &mut PodStack,
+) where
+    KskCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        input_ntru_ciphertext.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext.polynomial_size(),
+        ntru_keyswitch_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext.ciphertext_modulus(),
+        ntru_keyswitch_key.ciphertext_modulus(),
+    );
+
+    output_ntru_ciphertext.as_mut().fill(0u64);
+    add_ntru_external_product_ntt64_assign(
+        &mut output_ntru_ciphertext.as_mut_view(),
+        ntru_keyswitch_key.as_ntt_ngsw_ciphertext(),
+        input_ntru_ciphertext.as_view(),
+        ntt,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_keyswitch_key_to_fourier128<Scalar, InputCont, OutputCont>(
+    standard_ntru_ksk: &NtruKeyswitchKey<InputCont>,
+    fourier128_ntru_ksk: &mut Fourier128NtruKeyswitchKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    let fft = Fft128::new(fourier128_ntru_ksk.polynomial_size());
+    let fft = fft.as_view();
+
+    convert_standard_ntru_keyswitch_key_to_fourier128_mem_optimized(
+        standard_ntru_ksk,
+        fourier128_ntru_ksk,
+        fft,
+    );
+}
+
+pub fn convert_standard_ntru_keyswitch_key_to_fourier128_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_ntru_ksk: &NtruKeyswitchKey<InputCont>,
+    fourier128_ntru_ksk: &mut Fourier128NtruKeyswitchKey<OutputCont>,
+    fft: Fft128View<'_>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    convert_standard_ngsw_ciphertext_to_fourier128_mem_optimized(
+        &standard_ntru_ksk.as_ngsw_ciphertext(),
+        &mut fourier128_ntru_ksk.as_mut_fourier128_ngsw_ciphertext(),
+        fft,
+    );
+}
+
+pub fn keyswitch_ntru_ciphertext_f128<Scalar, KskCont, InputCont, OutputCont>(
+    ntru_keyswitch_key: &Fourier128NtruKeyswitchKey<KskCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = f64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_keyswitch_key.polynomial_size();
+
+    let fft = Fft128::new(polynomial_size);
     let fft = fft.as_view();
 
     let mut buffers = ComputationBuffers::new();
     buffers.resize(
-        add_ntru_external_product_assign_scratch::<Scalar>(
+        keyswitch_ntru_ciphertext_f128_mem_optimized_requirement::<Scalar>(
             polynomial_size,
             fft,
         )
@@ -120,10 +335,53 @@ pub fn keyswitch_ntru_ciphertext<Scalar, KskCont, InputCont, OutputCont>(
     );
     let stack = buffers.stack();
 
+    keyswitch_ntru_ciphertext_f128_mem_optimized(
+        ntru_keyswitch_key,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn keyswitch_ntru_ciphertext_f128_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ntru_external_product_f128_assign_scratch::<Scalar>(polynomial_size, fft)
+}
+
+pub fn keyswitch_ntru_ciphertext_f128_mem_optimized<Scalar, KskCont, InputCont, OutputCont>(
+    ntru_keyswitch_key: &Fourier128NtruKeyswitchKey<KskCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: Fft128View<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = f64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_ntru_ciphertext.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext.polynomial_size(),
+        ntru_keyswitch_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
     output_ntru_ciphertext.as_mut().fill(Scalar::ZERO);
-    add_ntru_external_product_assign(
+    add_ntru_external_product_f128_assign(
         &mut output_ntru_ciphertext.as_mut_view(),
-        ntru_keyswitch_key.as_fourier_ngsw_ciphertext(),
+        ntru_keyswitch_key.as_fourier128_ngsw_ciphertext(),
         input_ntru_ciphertext.as_view(),
         fft,
         stack,
@@ -1,4 +1,7 @@
-use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_scalar_div_assign;
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_scalar_div_assign,
+    slice_wrapping_scalar_mul_assign_custom_mod,
+};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
@@ -30,10 +33,9 @@ pub fn generate_ntru_keyswitch_key<
     Gen: ByteRandomGenerator,
 {
     assert!(
-        input_ntru_secret_key
-            .ciphertext_modulus()
-            .is_power_of_two(),
-        "Only support power-of-two modulus currently.",
+        input_ntru_secret_key.ciphertext_modulus().is_power_of_two()
+            || input_ntru_secret_key.ciphertext_modulus().get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus currently.",
     );
 
     assert_eq!(
@@ -71,13 +73,27 @@ pub fn generate_ntru_keyswitch_key<
         .map(DecompositionLevel)
         .zip(ntru_keyswitch_key.as_mut_ntru_ciphertext_list().iter_mut())
     {
-        DecompositionTermSlice::new(level, decomp_base_log, input_sk_poly.as_ref())
-            .fill_slice_with_recomposition_summand(decomp_poly_buffer.as_mut());
+        if ciphertext_modulus.is_power_of_two() {
+            DecompositionTermSlice::new(level, decomp_base_log, input_sk_poly.as_ref())
+                .fill_slice_with_recomposition_summand(decomp_poly_buffer.as_mut());
 
-        slice_wrapping_scalar_div_assign(
-            decomp_poly_buffer.as_mut(),
-            ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
-        );
+            slice_wrapping_scalar_div_assign(
+                decomp_poly_buffer.as_mut(),
+                ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+            );
+        } else {
+            // For odd q, q/B^j is approximated by 2^(ceil(log2 q) - j * log2 B)
+            let custom_modulus = Scalar::cast_from(ciphertext_modulus.get_custom_modulus());
+            let modulus_bit_count = custom_modulus.ceil_ilog2() as usize;
+            let shift = modulus_bit_count - decomp_base_log.0 * level.0;
+
+            decomp_poly_buffer.as_mut().clone_from_slice(input_sk_poly.as_ref());
+            slice_wrapping_scalar_mul_assign_custom_mod(
+                decomp_poly_buffer.as_mut(),
+                Scalar::ONE << shift,
+                custom_modulus,
+            );
+        }
 
         let decomp_plaintext_buffer = PlaintextList::from_container(
             decomp_poly_buffer.as_ref(),
@@ -1,5 +1,9 @@
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::ntru::algorithms::*;
 use crate::ntru::entities::*;
 
 pub fn ntru_ciphertext_add_assign<Scalar, LhsCont, RhsCont>(
@@ -15,7 +19,207 @@ pub fn ntru_ciphertext_add_assign<Scalar, LhsCont, RhsCont>(
         rhs.ciphertext_modulus(),
     );
 
-    slice_wrapping_add_assign(lhs.as_mut(), rhs.as_ref());
+    let ciphertext_modulus = lhs.ciphertext_modulus();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        slice_wrapping_add_assign(lhs.as_mut(), rhs.as_ref());
+    } else {
+        slice_wrapping_add_assign_custom_mod(
+            lhs.as_mut(),
+            rhs.as_ref(),
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
+}
+
+pub fn ntru_ciphertext_add<Scalar, OutputCont, LhsCont, RhsCont>(
+    output: &mut NtruCiphertext<OutputCont>,
+    lhs: &NtruCiphertext<LhsCont>,
+    rhs: &NtruCiphertext<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        output.ciphertext_modulus(),
+        lhs.ciphertext_modulus(),
+    );
+
+    output.as_mut().copy_from_slice(lhs.as_ref());
+    ntru_ciphertext_add_assign(output, rhs);
+}
+
+pub fn ntru_ciphertext_sub_assign<Scalar, LhsCont, RhsCont>(
+    lhs: &mut NtruCiphertext<LhsCont>,
+    rhs: &NtruCiphertext<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    LhsCont: ContainerMut<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus(),
+    );
+
+    let ciphertext_modulus = lhs.ciphertext_modulus();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        slice_wrapping_sub_assign(lhs.as_mut(), rhs.as_ref());
+    } else {
+        slice_wrapping_sub_assign_custom_mod(
+            lhs.as_mut(),
+            rhs.as_ref(),
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
+}
+
+pub fn ntru_ciphertext_sub<Scalar, OutputCont, LhsCont, RhsCont>(
+    output: &mut NtruCiphertext<OutputCont>,
+    lhs: &NtruCiphertext<LhsCont>,
+    rhs: &NtruCiphertext<RhsCont>,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+{
+    assert_eq!(
+        output.ciphertext_modulus(),
+        lhs.ciphertext_modulus(),
+    );
+
+    output.as_mut().copy_from_slice(lhs.as_ref());
+    ntru_ciphertext_sub_assign(output, rhs);
+}
+
+pub fn ntru_ciphertext_opposite_assign<Scalar, InCont>(ct: &mut NtruCiphertext<InCont>)
+where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+{
+    let ciphertext_modulus = ct.ciphertext_modulus();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        slice_wrapping_opposite_assign(ct.as_mut());
+    } else {
+        slice_wrapping_opposite_assign_custom_mod(
+            ct.as_mut(),
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
+}
+
+pub fn ntru_ciphertext_cleartext_mul_assign<Scalar, InCont>(
+    lhs: &mut NtruCiphertext<InCont>,
+    rhs: Cleartext<Scalar>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+{
+    let ciphertext_modulus = lhs.ciphertext_modulus();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        slice_wrapping_scalar_mul_assign(lhs.as_mut(), rhs.0);
+    } else {
+        slice_wrapping_scalar_mul_assign_custom_mod(
+            lhs.as_mut(),
+            rhs.0,
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
+}
+
+pub fn ntru_ciphertext_cleartext_mul<Scalar, InputCont, OutputCont>(
+    output: &mut NtruCiphertext<OutputCont>,
+    lhs: &NtruCiphertext<InputCont>,
+    rhs: Cleartext<Scalar>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        output.ciphertext_modulus(),
+        lhs.ciphertext_modulus(),
+    );
+
+    output.as_mut().copy_from_slice(lhs.as_ref());
+    ntru_ciphertext_cleartext_mul_assign(output, rhs);
+}
+
+/// Add the encoding of the plaintext list under the given [`NtruPublicKey`] to lhs, see
+/// [`encode_plaintext_list_with_ntru_public_key`]. Unlike GLWE, the NTRU encoding of a plaintext
+/// depends on the key, and it adds the noise of the gadget encryptions of the public key.
+pub fn ntru_ciphertext_plaintext_list_add_assign<Scalar, InCont, PlainCont, KeyCont>(
+    lhs: &mut NtruCiphertext<InCont>,
+    rhs: &PlaintextList<PlainCont>,
+    ntru_public_key: &NtruPublicKey<KeyCont>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    PlainCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    let mut encoding = NtruCiphertext::new(Scalar::ZERO, lhs.polynomial_size(), lhs.ciphertext_modulus());
+    encode_plaintext_list_with_ntru_public_key(ntru_public_key, &mut encoding, rhs);
+    ntru_ciphertext_add_assign(lhs, &encoding);
+}
+
+/// Subtract the encoding of the plaintext list under the given [`NtruPublicKey`] from lhs, see
+/// [`ntru_ciphertext_plaintext_list_add_assign`].
+pub fn ntru_ciphertext_plaintext_list_sub_assign<Scalar, InCont, PlainCont, KeyCont>(
+    lhs: &mut NtruCiphertext<InCont>,
+    rhs: &PlaintextList<PlainCont>,
+    ntru_public_key: &NtruPublicKey<KeyCont>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    PlainCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = Scalar>,
+{
+    let mut encoding = NtruCiphertext::new(Scalar::ZERO, lhs.polynomial_size(), lhs.ciphertext_modulus());
+    encode_plaintext_list_with_ntru_public_key(ntru_public_key, &mut encoding, rhs);
+    ntru_ciphertext_sub_assign(lhs, &encoding);
+}
+
+/// Multiply the NTRU ciphertext by X^monomial_degree in place.
+pub fn ntru_ciphertext_monic_monomial_mul_assign<Scalar, InCont>(
+    ct: &mut NtruCiphertext<InCont>,
+    monomial_degree: MonomialDegree,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+{
+    let ciphertext_modulus = ct.ciphertext_modulus();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_wrapping_monic_monomial_mul_assign(&mut ct.as_mut_polynomial(), monomial_degree);
+    } else {
+        polynomial_wrapping_monic_monomial_mul_assign_custom_mod(
+            &mut ct.as_mut_polynomial(),
+            monomial_degree,
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
+}
+
+/// Divide the NTRU ciphertext by X^monomial_degree in place.
+pub fn ntru_ciphertext_monic_monomial_div_assign<Scalar, InCont>(
+    ct: &mut NtruCiphertext<InCont>,
+    monomial_degree: MonomialDegree,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+{
+    let ciphertext_modulus = ct.ciphertext_modulus();
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_wrapping_monic_monomial_div_assign(&mut ct.as_mut_polynomial(), monomial_degree);
+    } else {
+        polynomial_wrapping_monic_monomial_div_assign_custom_mod(
+            &mut ct.as_mut_polynomial(),
+            monomial_degree,
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
 }
 
 pub fn ntru_ciphertext_round_by_2_assign<C: ContainerMut>(
@@ -26,10 +230,24 @@ pub fn ntru_ciphertext_round_by_2_assign<C: ContainerMut>(
     let ciphertext_modulus = input.ciphertext_modulus();
 
     assert!(
-        ciphertext_modulus.is_compatible_with_native_modulus(),
-        "Only support power-of-two modulus, currently.",
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            || ciphertext_modulus.get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus, currently.",
     );
 
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        // Over an odd modulus q, division by 2 is exact: x / 2 = x * (q + 1) / 2 (mod q)
+        let custom_modulus = C::Element::cast_from(ciphertext_modulus.get_custom_modulus());
+        for elt in input.as_mut().iter_mut() {
+            *elt = if *elt & C::Element::ONE == C::Element::ZERO {
+                *elt / C::Element::TWO
+            } else {
+                *elt / C::Element::TWO + custom_modulus / C::Element::TWO + C::Element::ONE
+            };
+        }
+        return;
+    }
+
     let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
     for elt in input.as_mut().iter_mut() {
         let rounding = *elt & torus_scaling;
@@ -0,0 +1,383 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::ggsw::update_with_fmadd_factor;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomial;
+use crate::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_div_assign;
+use crate::core_crypto::prelude::{MultiBitModulusSwitchedLweCiphertext, StandardMultiBitModulusSwitchedCt};
+use crate::core_crypto::prelude::SignedDecomposer;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+use aligned_vec::ABox;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use itertools::Itertools;
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use tfhe_fft::c64;
+
+pub fn convert_standard_ntru_multi_bit_bootstrap_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_ntru_multi_bit_bsk: &NtruMultiBitBootstrapKey<InputCont>,
+    fourier_ntru_multi_bit_bsk: &mut FourierNtruMultiBitBootstrapKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    let fft = Fft::new(standard_ntru_multi_bit_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        convert_standard_ntru_multi_bit_bootstrap_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    convert_standard_ntru_multi_bit_bootstrap_key_to_fourier_mem_optimized(
+        standard_ntru_multi_bit_bsk,
+        fourier_ntru_multi_bit_bsk,
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_multi_bit_bootstrap_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    convert_standard_ngsw_ciphertext_to_fourier_mem_optimized_requirement(fft)
+}
+
+pub fn convert_standard_ntru_multi_bit_bootstrap_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_ntru_multi_bit_bsk: &NtruMultiBitBootstrapKey<InputCont>,
+    fourier_ntru_multi_bit_bsk: &mut FourierNtruMultiBitBootstrapKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        standard_ntru_multi_bit_bsk.input_lwe_dimension(),
+        fourier_ntru_multi_bit_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_multi_bit_bsk.output_lwe_dimension(),
+        fourier_ntru_multi_bit_bsk.output_lwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_ntru_multi_bit_bsk.grouping_factor(),
+        fourier_ntru_multi_bit_bsk.grouping_factor(),
+    );
+
+    let ngsw_list = standard_ntru_multi_bit_bsk.get_ngsw_list();
+    let mut fourier_ngsw_list = fourier_ntru_multi_bit_bsk.get_mut_fourier_ngsw_list();
+
+    ngsw_list.iter()
+        .zip(fourier_ngsw_list.iter_mut())
+        .for_each(|(ngsw, mut fourier_ngsw)| {
+            convert_standard_ngsw_ciphertext_to_fourier_mem_optimized(
+                &ngsw,
+                &mut fourier_ngsw,
+                fft,
+                stack,
+            )
+        });
+
+    let ntru_switching_key = standard_ntru_multi_bit_bsk.get_ntru_switching_key();
+    let mut fourier_ntru_switching_key = fourier_ntru_multi_bit_bsk.get_mut_fourier_ntru_switching_key();
+    convert_standard_ntru_switching_key_to_fourier_mem_optimized(
+        &ntru_switching_key,
+        &mut fourier_ntru_switching_key,
+        fft,
+        stack,
+    );
+}
+
+/// Compute in the Fourier domain the NGSW encrypting X^{<a, s>} for one group of mask elements,
+/// as the sum of the group NGSWs each multiplied by its monomial.
+pub fn prepare_multi_bit_ngsw_mem_optimized<BufferCont, FourierPolyCont>(
+    fourier_ngsw_buffer: &mut FourierNgswCiphertext<BufferCont>,
+    ngsw_group: &[FourierNgswCiphertextView<'_>],
+    switched_degrees: impl Iterator<Item = usize>,
+    fourier_a_monomial: &mut FourierPolynomial<FourierPolyCont>,
+    fft: FftView<'_>,
+) where
+    BufferCont: ContainerMut<Element = c64>,
+    FourierPolyCont: ContainerMut<Element = c64>,
+{
+    let polynomial_size = fft.polynomial_size();
+    let mut ngsw_group_iter = ngsw_group.iter();
+
+    // Keygen guarantees the first NGSW of the group encrypts a constant, it is not rotated
+    let ngsw_a_none = ngsw_group_iter.next().unwrap();
+
+    let multi_bit_fourier_ngsw = fourier_ngsw_buffer.as_mut_view().data();
+    multi_bit_fourier_ngsw.copy_from_slice(ngsw_a_none.as_view().data());
+
+    // The monomial multiplication is linear, so it applies to every split and level polynomial
+    for (fourier_ngsw, switched_degree) in ngsw_group_iter.zip_eq(switched_degrees) {
+        let factor = fft.incomplete_monomial_forward_as_integer(
+            fourier_a_monomial.as_mut_view(),
+            switched_degree,
+        );
+        update_with_fmadd_factor(
+            multi_bit_fourier_ngsw,
+            fourier_ngsw.as_view().data(),
+            fourier_a_monomial.as_view().data,
+            factor,
+            false,
+            polynomial_size.to_fourier_polynomial_size().0,
+        );
+    }
+}
+
+/// Blind rotate the accumulator by the modulus switched input. The multi-bit NGSW of each group is
+/// prepared by `thread_count` producer threads, in a round-robin order so that the result does not
+/// depend on thread scheduling.
+pub fn ntru_multi_bit_blind_rotate_assign<Scalar, OutputCont, KeyCont>(
+    switched_modulus_input: &impl MultiBitModulusSwitchedLweCiphertext,
+    accumulator: &mut NtruCiphertext<OutputCont>,
+    multi_bit_bsk: &FourierNtruMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+) where
+    Scalar: UnsignedTorus + Sync,
+    OutputCont: ContainerMut<Element = Scalar>,
+    KeyCont: Container<Element = c64> + Sync,
+{
+    assert_eq!(
+        switched_modulus_input.lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension. LweCiphertext input LweDimension {:?}. \
+        FourierNtruMultiBitBootstrapKey input LweDimension {:?}.",
+        switched_modulus_input.lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        switched_modulus_input.grouping_factor(),
+        multi_bit_bsk.grouping_factor(),
+    );
+
+    assert_eq!(
+        accumulator.polynomial_size(),
+        multi_bit_bsk.polynomial_size(),
+        "Mismatched PolynomialSize. Accumulator PolynomialSize {:?}. \
+        FourierNtruMultiBitBootstrapKey PolynomialSize {:?}.",
+        accumulator.polynomial_size(),
+        multi_bit_bsk.polynomial_size(),
+    );
+
+    assert!(
+        thread_count.0 != 0,
+        "Got thread_count == 0, this is not supported"
+    );
+
+    let ciphertext_modulus = accumulator.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let polynomial_size = multi_bit_bsk.polynomial_size();
+    let fourier_ngsw_list = multi_bit_bsk.get_fourier_ngsw_list();
+    let ngsw_vec: Vec<_> = fourier_ngsw_list.iter().collect();
+    let ngsw_per_multi_bit_element = multi_bit_bsk.grouping_factor().ggsw_per_multi_bit_element();
+    let max_work_index = multi_bit_bsk.multi_bit_input_lwe_dimension().0;
+
+    polynomial_wrapping_monic_monomial_div_assign(
+        &mut accumulator.as_mut_polynomial(),
+        MonomialDegree(switched_modulus_input.switched_modulus_input_lwe_body()),
+    );
+
+    let fourier_multi_bit_ngsw_buffers: Vec<_> = (0..thread_count.0)
+        .map(|_| {
+            (
+                Mutex::new(false),
+                Condvar::new(),
+                Mutex::new(FourierNgswCiphertext::new(
+                    polynomial_size,
+                    multi_bit_bsk.decomposition_base_log(),
+                    multi_bit_bsk.decomposition_level_count(),
+                    multi_bit_bsk.br_fft_type(),
+                )),
+            )
+        })
+        .collect();
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    thread::scope(|s| {
+        let produce_multi_bit_fourier_ngsw = |thread_id| {
+            let mut fourier_a_monomial = FourierPolynomial::new(polynomial_size);
+
+            #[allow(clippy::type_complexity)]
+            let (ready_for_consumer_lock, condvar, fourier_ngsw_buffer): &(
+                Mutex<bool>,
+                Condvar,
+                Mutex<FourierNgswCiphertext<ABox<[c64]>>>,
+            ) = &fourier_multi_bit_ngsw_buffers[thread_id];
+
+            for work_index in (0..max_work_index).skip(thread_id).step_by(thread_count.0) {
+                let switched_degrees =
+                    switched_modulus_input.switched_modulus_input_mask_per_group(work_index);
+
+                let ngsw_group = &ngsw_vec[work_index * ngsw_per_multi_bit_element.0
+                    ..(work_index + 1) * ngsw_per_multi_bit_element.0];
+
+                let mut ready_for_consumer = ready_for_consumer_lock.lock().unwrap();
+
+                // Wait until the consumer is done with the previous NGSW of this buffer
+                while *ready_for_consumer {
+                    ready_for_consumer = condvar.wait(ready_for_consumer).unwrap();
+                }
+
+                let mut fourier_ngsw_buffer = fourier_ngsw_buffer.lock().unwrap();
+
+                prepare_multi_bit_ngsw_mem_optimized(
+                    &mut fourier_ngsw_buffer,
+                    ngsw_group,
+                    switched_degrees,
+                    &mut fourier_a_monomial,
+                    fft,
+                );
+
+                drop(fourier_ngsw_buffer);
+
+                *ready_for_consumer = true;
+
+                condvar.notify_all();
+            }
+        };
+
+        // false positive as the mapping function has side effects (thread spawning)
+        #[allow(clippy::needless_collect)]
+        let threads: Vec<_> = (0..thread_count.0)
+            .map(|idx| s.spawn(move || produce_multi_bit_fourier_ngsw(idx)))
+            .collect();
+
+        let ct0 = accumulator;
+        let mut ct1 = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+
+        let mut buffers = ComputationBuffers::new();
+        buffers.resize(
+            add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+
+        let mut src_idx = 1usize;
+
+        for (ready_lock, condvar, multi_bit_fourier_ngsw) in fourier_multi_bit_ngsw_buffers
+            .iter()
+            .cycle()
+            .take(max_work_index)
+        {
+            src_idx ^= 1;
+
+            let (src_ct, mut dst_ct) = if src_idx == 0 {
+                (ct0.as_view(), ct1.as_mut_view())
+            } else {
+                (ct1.as_view(), ct0.as_mut_view())
+            };
+
+            dst_ct.as_mut().fill(Scalar::ZERO);
+
+            let mut ready = ready_lock.lock().unwrap();
+
+            while !*ready {
+                ready = condvar.wait(ready).unwrap();
+            }
+
+            let multi_bit_fourier_ngsw = multi_bit_fourier_ngsw.lock().unwrap();
+
+            add_ntru_external_product_assign(
+                &mut dst_ct,
+                multi_bit_fourier_ngsw.as_view(),
+                src_ct,
+                fft,
+                buffers.stack(),
+            );
+
+            *ready = false;
+
+            condvar.notify_one();
+        }
+
+        if src_idx == 0 {
+            ct0.as_mut().copy_from_slice(ct1.as_ref());
+        }
+
+        if !ciphertext_modulus.is_native_modulus() {
+            let signed_decomposer = SignedDecomposer::new(
+                DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+                DecompositionLevelCount(1),
+            );
+            ct0.as_mut()
+                .iter_mut()
+                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+        }
+
+        for t in threads {
+            t.join().unwrap();
+        }
+    });
+}
+
+pub fn ntru_multi_bit_bootstrap_lwe_ciphertext<
+    InputScalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    OutputScalar: UnsignedTorus + Sync,
+    InputCont: Container<Element = InputScalar> + Sync,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    AccCont: Container<Element = OutputScalar>,
+    KeyCont: Container<Element = c64> + Sync,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &PlaintextList<AccCont>,
+    multi_bit_bsk: &FourierNtruMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+) {
+    assert!(
+        input.ciphertext_modulus().is_power_of_two(),
+        "This operation requires the input to have a power of two modulus."
+    );
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension().0,
+        accumulator.plaintext_count().0,
+    );
+
+    let polynomial_size = PolynomialSize(accumulator.plaintext_count().0);
+    let mut local_accumulator = NtruCiphertext::new(
+        OutputScalar::ZERO,
+        polynomial_size,
+        output.ciphertext_modulus(),
+    );
+    switch_to_ntru_ciphertext(
+        &multi_bit_bsk.get_fourier_ntru_switching_key(),
+        accumulator,
+        &mut local_accumulator,
+    );
+
+    let multi_bit_modulus_switched_input = StandardMultiBitModulusSwitchedCt {
+        input: input.as_view(),
+        grouping_factor: multi_bit_bsk.grouping_factor(),
+        log_modulus: polynomial_size.to_blind_rotation_input_modulus_log(),
+    };
+
+    ntru_multi_bit_blind_rotate_assign(
+        &multi_bit_modulus_switched_input,
+        &mut local_accumulator,
+        multi_bit_bsk,
+        thread_count,
+    );
+
+    extract_lwe_sample_from_ntru_ciphertext(
+        &local_accumulator,
+        output,
+        MonomialDegree(0),
+    );
+}
@@ -0,0 +1,234 @@
+//! Module containing primitives pertaining to the generation of NtruMultiBitBootstrapKey
+
+use crate::core_crypto::algorithms::lwe_multi_bit_bootstrap_key_generation::combine_key_bits;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{
+    Distribution, Uniform,
+};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+use rayon::prelude::*;
+
+pub fn generate_ntru_multi_bit_bootstrap_key<
+    InputScalar: UnsignedInteger + CastFrom<usize> + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output: &mut NtruMultiBitBootstrapKey<OutputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
+        Input LWE secret key LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.polynomial_size() == output_ntru_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output NTRU secret key and LWE bootstrap key. \
+        Output NTRU secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_ntru_secret_key.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    let grouping_factor = output.grouping_factor();
+    let ngsw_per_multi_bit_element = grouping_factor.ggsw_per_multi_bit_element();
+
+    let gen_iter = generator
+        .try_fork_from_config(output.get_ngsw_list().encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    // The power set index of an NGSW inside its group selects which key bits are inverted in the
+    // encrypted product, see combine_key_bits
+    for ((ngsw_idx, mut ngsw), mut generator) in output
+        .get_mut_ngsw_list().iter_mut()
+        .enumerate()
+        .zip(gen_iter) {
+            let group_idx = ngsw_idx / ngsw_per_multi_bit_element.0;
+            let input_key_elements = &input_lwe_secret_key.as_ref()
+                [group_idx * grouping_factor.0..(group_idx + 1) * grouping_factor.0];
+            let key_bits = combine_key_bits(ngsw_idx % ngsw_per_multi_bit_element.0, input_key_elements);
+
+            encrypt_constant_ngsw_ciphertext(
+                output_ntru_secret_key,
+                &mut ngsw,
+                Cleartext(key_bits.cast_into()),
+                noise_distribution,
+                &mut generator,
+            );
+        }
+
+    generate_ntru_switching_key(
+        output_ntru_secret_key,
+        &mut output.get_mut_ntru_switching_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn allocate_and_generate_new_ntru_multi_bit_bootstrap_key<
+    InputScalar: UnsignedInteger + CastFrom<usize> + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruMultiBitBootstrapKeyOwned<OutputScalar> {
+    let mut bsk = NtruMultiBitBootstrapKey::new(
+        OutputScalar::ZERO,
+        output_ntru_secret_key.polynomial_size(),
+        br_decomp_base_log,
+        br_decomp_level_count,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        input_lwe_secret_key.lwe_dimension(),
+        grouping_factor,
+        ciphertext_modulus,
+    );
+
+    generate_ntru_multi_bit_bootstrap_key(
+        input_lwe_secret_key,
+        output_ntru_secret_key,
+        &mut bsk,
+        noise_distribution,
+        generator,
+    );
+
+    bsk
+}
+
+
+pub fn par_generate_ntru_multi_bit_bootstrap_key<
+    InputScalar: UnsignedInteger + CastFrom<usize> + CastInto<OutputScalar> + Sync,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar> + Sync,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    output: &mut NtruMultiBitBootstrapKey<OutputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
+        Input LWE secret key LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.polynomial_size() == output_ntru_secret_key.polynomial_size(),
+        "Mismatched PolynomialSize between output NTRU secret key and LWE bootstrap key. \
+        Output NTRU secret key PolynomialSize: {:?}, LWE bootstrap key PolynomialSize {:?}.",
+        output_ntru_secret_key.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    let grouping_factor = output.grouping_factor();
+    let ngsw_per_multi_bit_element = grouping_factor.ggsw_per_multi_bit_element();
+
+    let input_key_elements = input_lwe_secret_key.as_ref();
+
+    let gen_iter = generator
+        .par_try_fork_from_config(output.get_ngsw_list().encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    output
+        .get_mut_ngsw_list()
+        .par_iter_mut()
+        .enumerate()
+        .zip(gen_iter)
+        .for_each(|((ngsw_idx, mut ngsw), mut generator)| {
+            let group_idx = ngsw_idx / ngsw_per_multi_bit_element.0;
+            let group_key_elements = &input_key_elements
+                [group_idx * grouping_factor.0..(group_idx + 1) * grouping_factor.0];
+            let key_bits = combine_key_bits(ngsw_idx % ngsw_per_multi_bit_element.0, group_key_elements);
+
+            encrypt_constant_ngsw_ciphertext(
+                output_ntru_secret_key,
+                &mut ngsw,
+                Cleartext(key_bits.cast_into()),
+                noise_distribution,
+                &mut generator,
+            );
+        });
+
+    generate_ntru_switching_key(
+        output_ntru_secret_key,
+        &mut output.get_mut_ntru_switching_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn par_allocate_and_generate_new_ntru_multi_bit_bootstrap_key<
+    InputScalar: UnsignedInteger + CastFrom<usize> + CastInto<OutputScalar> + Sync,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruMultiBitBootstrapKeyOwned<OutputScalar> {
+    let mut bsk = NtruMultiBitBootstrapKey::new(
+        OutputScalar::ZERO,
+        output_ntru_secret_key.polynomial_size(),
+        br_decomp_base_log,
+        br_decomp_level_count,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        input_lwe_secret_key.lwe_dimension(),
+        grouping_factor,
+        ciphertext_modulus,
+    );
+
+    par_generate_ntru_multi_bit_bootstrap_key(
+        input_lwe_secret_key,
+        output_ntru_secret_key,
+        &mut bsk,
+        noise_distribution,
+        generator,
+    );
+
+    bsk
+}
@@ -0,0 +1,109 @@
+//! Module containing the generation of accumulators for the NTRU programmable bootstrapping
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Helper function to generate an accumulator for [`ntru_cmux_bootstrap_lwe_ciphertext`] and
+/// [`ntru_automorphism_bootstrap_lwe_ciphertext`].
+///
+/// The input is assumed to encode x in [0, message_modulus * carry_modulus) with one padding bit,
+/// i.e., with delta = q / (2 * message_modulus * carry_modulus), and the output encodes
+/// f(x) mod (message_modulus * carry_modulus) with the same layout under the output modulus.
+/// Both moduli must be powers of two.
+///
+/// [`ntru_cmux_bootstrap_lwe_ciphertext`]: crate::ntru::algorithms::ntru_cmux_bootstrap_lwe_ciphertext
+/// [`ntru_automorphism_bootstrap_lwe_ciphertext`]: crate::ntru::algorithms::ntru_automorphism_bootstrap_lwe_ciphertext
+pub fn generate_programmable_bootstrap_ntru_lut<F, Scalar>(
+    polynomial_size: PolynomialSize,
+    message_modulus: usize,
+    carry_modulus: usize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    f: F,
+) -> PlaintextListOwned<Scalar>
+where
+    F: Fn(u64) -> u64,
+    Scalar: UnsignedTorus + CastFrom<u64>,
+{
+    generate_programmable_bootstrap_ntru_lut_many(
+        polynomial_size,
+        message_modulus,
+        carry_modulus,
+        ciphertext_modulus,
+        LutCountLog(0),
+        &[&f],
+    )
+}
+
+/// Helper function to generate an accumulator evaluating several functions at once with
+/// [`ntru_cmux_bootstrap_lwe_ciphertext_lut_many`].
+///
+/// The k-th output of the bootstrapping encodes functions[k](x), with the same layout as in
+/// [`generate_programmable_bootstrap_ntru_lut`]. At most 2^log_lut_count functions can be given.
+///
+/// [`ntru_cmux_bootstrap_lwe_ciphertext_lut_many`]: crate::ntru::algorithms::ntru_cmux_bootstrap_lwe_ciphertext_lut_many
+pub fn generate_programmable_bootstrap_ntru_lut_many<Scalar>(
+    polynomial_size: PolynomialSize,
+    message_modulus: usize,
+    carry_modulus: usize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    log_lut_count: LutCountLog,
+    functions: &[&dyn Fn(u64) -> u64],
+) -> PlaintextListOwned<Scalar>
+where
+    Scalar: UnsignedTorus + CastFrom<u64>,
+{
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let lut_count = 1 << log_lut_count.0;
+    assert!(
+        !functions.is_empty() && functions.len() <= lut_count,
+        "The number of functions must be between 1 and 2^log_lut_count = {lut_count}. Got {}.",
+        functions.len(),
+    );
+
+    let full_message_modulus = message_modulus * carry_modulus;
+    assert!(
+        full_message_modulus.is_power_of_two(),
+        "message_modulus * carry_modulus must be a power of two. Got {full_message_modulus}.",
+    );
+
+    // N/(p/2) = size of each box, where p/2 = message_modulus * carry_modulus
+    let box_size = polynomial_size.0 / full_message_modulus;
+    let half_box_size = box_size / 2;
+    assert!(
+        half_box_size > 0 && half_box_size % lut_count == 0,
+        "The half box size {half_box_size} must be a positive multiple of the LUT count {lut_count}.",
+    );
+
+    let log_modulus = ciphertext_modulus.into_modulus_log().0;
+    let delta = Scalar::ONE << (log_modulus - 1 - full_message_modulus.ilog2() as usize);
+
+    let mut accumulator = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    // Fill each box with the encoded denoised values, the k-th function on the slots k mod lut_count
+    for (x, acc_box) in accumulator.as_mut().chunks_exact_mut(box_size).enumerate() {
+        for (i, elt) in acc_box.iter_mut().enumerate() {
+            *elt = functions.get(i % lut_count).map_or(Scalar::ZERO, |f| {
+                Scalar::cast_from(f(x as u64) % full_message_modulus as u64).wrapping_mul(delta)
+            });
+        }
+    }
+
+    // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+    for a_i in accumulator.as_mut()[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+    accumulator.as_mut().rotate_left(half_box_size);
+
+    // Keep the values in [0, q)
+    if !ciphertext_modulus.is_native_modulus() {
+        let mask = (Scalar::ONE << log_modulus).wrapping_sub(Scalar::ONE);
+        accumulator.as_mut().iter_mut().for_each(|a| *a &= mask);
+    }
+
+    accumulator
+}
@@ -0,0 +1,225 @@
+//! Module containing ntru public key encryption
+
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_scalar_mul_assign;
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform, UniformBinary};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::*;
+
+/// Write in the output the noiseless part of a public key encryption of the plaintext polynomial,
+/// i.e., sum_j d_j * c_j where m = sum_j d_j * q/B^j is the signed decomposition of the plaintext
+/// and c_j are the gadget encryptions of the public key. Its decryption noise is
+/// sum_j d_j * e_j.
+pub fn encode_plaintext_list_with_ntru_public_key<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_public_key: &NtruPublicKey<KeyCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        ntru_public_key.polynomial_size().0 == output_ntru_ciphertext.polynomial_size().0,
+        "Mismatch between PolynomialSize of input public key and output ciphertext. \
+        Got {:?} in public key, and {:?} in output.",
+        ntru_public_key.polynomial_size().0,
+        output_ntru_ciphertext.polynomial_size().0,
+    );
+    assert!(
+        output_ntru_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext and PlaintextCount of input. \
+        Got {:?} in output, and {:} in input.",
+        output_ntru_ciphertext.polynomial_size().0,
+        input_plaintext_list.plaintext_count().0,
+    );
+    assert!(
+        ntru_public_key.ciphertext_modulus() == output_ntru_ciphertext.ciphertext_modulus(),
+        "Mismatch between CiphertextModulus of input public key and output. \
+        Got {:?} in public key, and {:?} in output.",
+        ntru_public_key.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
+    let polynomial_size = ntru_public_key.polynomial_size();
+    let decomp_base_log = ntru_public_key.decomposition_base_log().0;
+
+    // Signed decomposition of m from the least significant digit, i.e., from level l
+    let mut state = input_plaintext_list.as_polynomial().as_ref().to_vec();
+    let mut digit_poly = Polynomial::new(Scalar::ZERO, polynomial_size);
+
+    output_ntru_ciphertext.as_mut().fill(Scalar::ZERO);
+
+    let base = Scalar::ONE << decomp_base_log;
+    let half_base = base >> 1;
+    let mask = base - Scalar::ONE;
+
+    for gadget_ntru_ciphertext in ntru_public_key.get_gadget_encryption_list().iter().rev() {
+        for (digit, state) in digit_poly.as_mut().iter_mut().zip(state.iter_mut()) {
+            let mut d = *state & mask;
+            *state >>= decomp_base_log;
+            if d >= half_base {
+                d = d.wrapping_sub(base);
+                *state = state.wrapping_add(Scalar::ONE);
+            }
+            *digit = d;
+        }
+
+        polynomial_wrapping_add_mul_assign(
+            &mut output_ntru_ciphertext.as_mut_polynomial(),
+            &digit_poly,
+            &gadget_ntru_ciphertext.as_polynomial(),
+        );
+    }
+}
+
+/// Encrypt a plaintext polynomial with an [`NtruPublicKey`].
+///
+/// The plaintext is decomposed in base B as m = sum_j d_j * q/B^j with signed digits, and the
+/// output is c = sum_j d_j * c_j + r * h + e, where c_j are the gadget encryptions of the public
+/// key, r is a uniform binary polynomial and e is sampled from the noise distribution. The
+/// decryption noise is then
+/// sum_j d_j * e_j + r * g_0 + e * f.
+pub fn encrypt_ntru_ciphertext_with_public_key<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    ntru_public_key: &NtruPublicKey<KeyCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution> + RandomGenerable<UniformBinary>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert!(
+        ntru_public_key.polynomial_size().0 == output_ntru_ciphertext.polynomial_size().0,
+        "Mismatch between PolynomialSize of input public key and output ciphertext. \
+        Got {:?} in public key, and {:?} in output.",
+        ntru_public_key.polynomial_size().0,
+        output_ntru_ciphertext.polynomial_size().0,
+    );
+    assert!(
+        output_ntru_ciphertext.polynomial_size().0 == input_plaintext_list.plaintext_count().0,
+        "Mismatch between PolynomialSize of output ciphertext and PlaintextCount of input. \
+        Got {:?} in output, and {:} in input.",
+        output_ntru_ciphertext.polynomial_size().0,
+        input_plaintext_list.plaintext_count().0,
+    );
+    assert!(
+        ntru_public_key.ciphertext_modulus() == output_ntru_ciphertext.ciphertext_modulus(),
+        "Mismatch between CiphertextModulus of input public key and output. \
+        Got {:?} in public key, and {:?} in output.",
+        ntru_public_key.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
+    let polynomial_size = ntru_public_key.polynomial_size();
+    let ciphertext_modulus = ntru_public_key.ciphertext_modulus();
+    let log_modulus = ciphertext_modulus.into_modulus_log().0;
+
+    encode_plaintext_list_with_ntru_public_key(
+        ntru_public_key,
+        output_ntru_ciphertext,
+        input_plaintext_list,
+    );
+
+    // r * h
+    let mut noise_poly = Polynomial::new(Scalar::ZERO, polynomial_size);
+    secret_generator.fill_slice_with_random_uniform_binary(noise_poly.as_mut());
+
+    polynomial_wrapping_add_mul_assign(
+        &mut output_ntru_ciphertext.as_mut_polynomial(),
+        &noise_poly,
+        &ntru_public_key.get_encryption_of_zero().as_polynomial(),
+    );
+
+    // e
+    noise_poly.as_mut().fill(Scalar::ZERO);
+    encryption_generator.unsigned_integer_slice_wrapping_add_random_noise_from_distribution_custom_mod_assign(
+        noise_poly.as_mut(),
+        noise_distribution,
+        ciphertext_modulus,
+    );
+
+    if log_modulus < Scalar::BITS {
+        slice_wrapping_scalar_mul_assign(
+            noise_poly.as_mut(),
+            ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+        );
+    }
+
+    polynomial_wrapping_add_assign(
+        &mut output_ntru_ciphertext.as_mut_polynomial(),
+        &noise_poly,
+    );
+}
+
+pub fn encrypt_ntru_ciphertext_list_with_public_key<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    InputCont,
+    OutputCont,
+    SecretGen,
+    EncryptionGen,
+>(
+    ntru_public_key: &NtruPublicKey<KeyCont>,
+    output_ntru_ciphertext_list: &mut NtruCiphertextList<OutputCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    noise_distribution: NoiseDistribution,
+    secret_generator: &mut SecretRandomGenerator<SecretGen>,
+    encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution> + RandomGenerable<UniformBinary>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    SecretGen: ByteRandomGenerator,
+    EncryptionGen: ByteRandomGenerator,
+{
+    assert!(
+        output_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * output_ntru_ciphertext_list.polynomial_size().0
+            == input_plaintext_list.plaintext_count().0,
+        "Mismatch between required number of plaintexts: {} ({:?} * {:?}) and input \
+        PlaintextCount: {:?}",
+        output_ntru_ciphertext_list.ntru_ciphertext_count().0
+            * output_ntru_ciphertext_list.polynomial_size().0,
+        output_ntru_ciphertext_list.ntru_ciphertext_count(),
+        output_ntru_ciphertext_list.polynomial_size(),
+        input_plaintext_list.plaintext_count(),
+    );
+
+    let polynomial_size = output_ntru_ciphertext_list.polynomial_size();
+
+    for (mut ntru_ciphertext, plaintext_list) in output_ntru_ciphertext_list
+        .iter_mut()
+        .zip(input_plaintext_list.chunks_exact(polynomial_size.0))
+    {
+        encrypt_ntru_ciphertext_with_public_key(
+            ntru_public_key,
+            &mut ntru_ciphertext,
+            &plaintext_list,
+            noise_distribution,
+            secret_generator,
+            encryption_generator,
+        );
+    }
+}
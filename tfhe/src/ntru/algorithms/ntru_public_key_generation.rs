@@ -0,0 +1,94 @@
+//! Module containing the generation of the ntru public key
+
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+
+pub fn generate_ntru_public_key<Scalar, NoiseDistribution, KeyCont, PkCont, Gen>(
+    ntru_secret_key: &NtruSecretKey<KeyCont>,
+    ntru_public_key: &mut NtruPublicKey<PkCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    PkCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        ntru_secret_key.polynomial_size(),
+        ntru_public_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        ntru_secret_key.ciphertext_modulus(),
+        ntru_public_key.ciphertext_modulus(),
+    );
+
+    let polynomial_size = ntru_public_key.polynomial_size();
+    let decomp_base_log = ntru_public_key.decomposition_base_log();
+    let log_modulus = ntru_public_key.ciphertext_modulus().into_modulus_log().0;
+
+    let mut plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    // h = g_0 / f
+    encrypt_ntru_ciphertext(
+        ntru_secret_key,
+        &mut ntru_public_key.get_mut_encryption_of_zero(),
+        &plaintext_list,
+        noise_distribution,
+        generator,
+    );
+
+    // NTRU_f(q/B^j) for j = 1, ..., l
+    for (level, mut gadget_ntru_ciphertext) in ntru_public_key
+        .get_mut_gadget_encryption_list()
+        .iter_mut()
+        .enumerate()
+        .map(|(i, ct)| (i + 1, ct))
+    {
+        plaintext_list.as_mut()[0] = Scalar::ONE << (log_modulus - level * decomp_base_log.0);
+
+        encrypt_ntru_ciphertext(
+            ntru_secret_key,
+            &mut gadget_ntru_ciphertext,
+            &plaintext_list,
+            noise_distribution,
+            generator,
+        );
+    }
+}
+
+pub fn allocate_and_generate_new_ntru_public_key<Scalar, NoiseDistribution, KeyCont, Gen>(
+    ntru_secret_key: &NtruSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruPublicKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_ntru_public_key = NtruPublicKeyOwned::new(
+        Scalar::ZERO,
+        ntru_secret_key.polynomial_size(),
+        decomp_base_log,
+        ntru_secret_key.ciphertext_modulus(),
+    );
+
+    generate_ntru_public_key(
+        ntru_secret_key,
+        &mut new_ntru_public_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_ntru_public_key
+}
@@ -0,0 +1,345 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::{DecompositionLevelCount, MonomialDegree, PolynomialSize};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::ntru::algorithms::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+/// Pack 2^d NTRU ciphertexts ct_0, ..., ct_{2^d - 1} encrypting m_0, ..., m_{2^d - 1} into a single
+/// NTRU ciphertext encrypting sum_j X^j * pi_d(m_j), where pi_d keeps the coefficients of degree
+/// divisible by 2^d (i.e. the N/2^d slots of the subring Z[X^{2^d}]) and zeroes the others.
+///
+/// This is sum_j X^j * Tr_d(ct_j) computed with 2^d - 1 automorphisms instead of d * 2^d, using that
+/// the level-l automorphism X -> X^{2N/2^l + 1} maps X^{2^{l-1}} to -X^{2^{l-1}}.
+pub fn pack_ntru_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    input_ntru_ciphertext_list: &NtruCiphertextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_trace_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        pack_ntru_ciphertext_list_mem_optimized_requirement::<Scalar>(
+            input_ntru_ciphertext_list.ntru_ciphertext_count(),
+            polynomial_size,
+            ntru_trace_key.decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    pack_ntru_ciphertext_list_mem_optimized(
+        ntru_trace_key,
+        input_ntru_ciphertext_list,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn pack_ntru_ciphertext_list_mem_optimized_requirement<Scalar>(
+    ntru_ciphertext_count: NtruCiphertextCount,
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(ntru_ciphertext_count.0 * polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)?
+        .try_and(fourier_trace_automorphism_permutations_requirement(fft)?)?
+        .try_and(ntru_ciphertext_add_automorphism_assign_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            decomp_level_count,
+            fft,
+        )?)
+}
+
+pub fn pack_ntru_ciphertext_list_mem_optimized<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    input_ntru_ciphertext_list: &NtruCiphertextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        ntru_trace_key.polynomial_size(),
+        input_ntru_ciphertext_list.polynomial_size(),
+    );
+
+    assert_eq!(
+        ntru_trace_key.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext_list.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
+    let ntru_ciphertext_count = input_ntru_ciphertext_list.ntru_ciphertext_count().0;
+    assert!(
+        ntru_ciphertext_count.is_power_of_two(),
+        "The number of packed ciphertexts {ntru_ciphertext_count} should be a power of two",
+    );
+
+    let trace_level_count = ntru_ciphertext_count.ilog2() as usize;
+    let key_level_count = ntru_trace_key.trace_level_count().0;
+    assert!(
+        trace_level_count <= key_level_count,
+        "Packing {ntru_ciphertext_count} ciphertexts needs {trace_level_count} trace levels, \
+        but the trace key only has {key_level_count}",
+    );
+
+    assert!(
+        output_ntru_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = output_ntru_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_ntru_ciphertext.ciphertext_modulus();
+
+    let (work, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        input_ntru_ciphertext_list.as_ref().iter().copied(),
+    );
+    let mut work = NtruCiphertextListMutView::from_container(
+        &mut *work,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    let (diff, stack) = stack.make_aligned_raw::<Scalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut diff = NtruCiphertextMutView::from_container(&mut *diff, polynomial_size, ciphertext_modulus);
+
+    let (fourier_trace_permutations, stack) = fourier_trace_automorphism_permutations(fft, stack);
+
+    // At level l, the ciphertexts j and j + 2^{l-1} with j < 2^{l-1} are merged as
+    // (E + X^{2^{l-1}} O) + auto_l(E - X^{2^{l-1}} O), after halving E and O
+    for level in (1..=trace_level_count).rev() {
+        let half = 1 << (level - 1);
+        let fourier_ntru_auto_key = ntru_trace_key.get_automorphism_key(key_level_count - level);
+        let permutation = get_fourier_trace_automorphism_permutation(
+            fourier_trace_permutations,
+            fourier_ntru_auto_key.automorphism_index(),
+            polynomial_size,
+        );
+
+        let (mut even_list, mut odd_list) = work.split_at_mut(half);
+        for (mut even, mut odd) in even_list.iter_mut().zip(odd_list.iter_mut()) {
+            ntru_ciphertext_round_by_2_assign(&mut even);
+            ntru_ciphertext_round_by_2_assign(&mut odd);
+            ntru_ciphertext_monic_monomial_mul_assign(&mut odd, MonomialDegree(half));
+
+            ntru_ciphertext_sub(&mut diff, &even, &odd);
+            ntru_ciphertext_add_assign(&mut even, &odd);
+
+            ntru_ciphertext_add_automorphism_assign_mem_optimized(
+                &fourier_ntru_auto_key,
+                permutation,
+                &mut even,
+                &diff,
+                fft,
+                stack,
+            );
+        }
+    }
+
+    output_ntru_ciphertext.as_mut()
+        .clone_from_slice(work.get(0).as_ref());
+}
+
+/// Unpack an NTRU ciphertext encrypting m into 2^d NTRU ciphertexts, the j-th one encrypting
+/// pi_d(X^{-j} * m), i.e. the coefficients of m of degree j mod 2^d moved to the subring Z[X^{2^d}].
+/// This inverts [`pack_ntru_ciphertext_list`].
+///
+/// The j-th output is Tr_d(X^{-j} * C) / 2^d = sum_h X^{-j * h} * auto_h(C / 2^d) over the 2^d automorphisms
+/// auto_h of the trace, so the images auto_h(C / 2^d) are computed once with 2^d - 1 automorphisms.
+pub fn unpack_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext_list: &mut NtruCiphertextList<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_trace_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        unpack_ntru_ciphertext_mem_optimized_requirement::<Scalar>(
+            output_ntru_ciphertext_list.ntru_ciphertext_count(),
+            polynomial_size,
+            ntru_trace_key.decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    unpack_ntru_ciphertext_mem_optimized(
+        ntru_trace_key,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext_list,
+        fft,
+        stack,
+    );
+}
+
+pub fn unpack_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    ntru_ciphertext_count: NtruCiphertextCount,
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(ntru_ciphertext_count.0 * polynomial_size.0, CACHELINE_ALIGN)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)?
+        .try_and(fourier_trace_automorphism_permutations_requirement(fft)?)?
+        .try_and(ntru_ciphertext_add_automorphism_assign_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            decomp_level_count,
+            fft,
+        )?)
+}
+
+
+pub fn unpack_ntru_ciphertext_mem_optimized<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext_list: &mut NtruCiphertextList<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        ntru_trace_key.polynomial_size(),
+        input_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        ntru_trace_key.polynomial_size(),
+        output_ntru_ciphertext_list.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ntru_ciphertext.ciphertext_modulus(),
+        output_ntru_ciphertext_list.ciphertext_modulus(),
+    );
+
+    let ntru_ciphertext_count = output_ntru_ciphertext_list.ntru_ciphertext_count().0;
+    assert!(
+        ntru_ciphertext_count.is_power_of_two(),
+        "The number of unpacked ciphertexts {ntru_ciphertext_count} should be a power of two",
+    );
+
+    let trace_level_count = ntru_ciphertext_count.ilog2() as usize;
+    let key_level_count = ntru_trace_key.trace_level_count().0;
+    assert!(
+        trace_level_count <= key_level_count,
+        "Unpacking {ntru_ciphertext_count} ciphertexts needs {trace_level_count} trace levels, \
+        but the trace key only has {key_level_count}",
+    );
+
+    assert!(
+        input_ntru_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = input_ntru_ciphertext.polynomial_size();
+    let ciphertext_modulus = input_ntru_ciphertext.ciphertext_modulus();
+
+    let (images, stack) = stack.make_aligned_raw::<Scalar>(ntru_ciphertext_count * polynomial_size.0, CACHELINE_ALIGN);
+    let mut images = NtruCiphertextListMutView::from_container(
+        &mut *images,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    let (buf, stack) = stack.make_aligned_raw::<Scalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut buf = NtruCiphertextMutView::from_container(&mut *buf, polynomial_size, ciphertext_modulus);
+
+    let (fourier_trace_permutations, stack) = fourier_trace_automorphism_permutations(fft, stack);
+
+    let mut input_image = images.get_mut(0);
+    input_image.as_mut().clone_from_slice(input_ntru_ciphertext.as_ref());
+    for _ in 0..trace_level_count {
+        ntru_ciphertext_round_by_2_assign(&mut input_image);
+    }
+
+    // The image i is auto_h(C / 2^d), h being the product of the level-l automorphism indices
+    // over the bits l - 1 set in i
+    for level in 1..=trace_level_count {
+        let half = 1 << (level - 1);
+        let fourier_ntru_auto_key = ntru_trace_key.get_automorphism_key(key_level_count - level);
+        let permutation = get_fourier_trace_automorphism_permutation(
+            fourier_trace_permutations,
+            fourier_ntru_auto_key.automorphism_index(),
+            polynomial_size,
+        );
+
+        let (src_list, mut dst_list) = images.split_at_mut(half);
+        for (src, mut dst) in src_list.iter().zip(dst_list.iter_mut()) {
+            dst.as_mut().fill(Scalar::ZERO);
+            ntru_ciphertext_add_automorphism_assign_mem_optimized(
+                &fourier_ntru_auto_key,
+                permutation,
+                &mut dst,
+                &src,
+                fft,
+                stack,
+            );
+        }
+    }
+
+    let double_polynomial_size = 2 * polynomial_size.0;
+    for (j, mut output_ntru_ciphertext) in output_ntru_ciphertext_list.iter_mut().enumerate() {
+        output_ntru_ciphertext.as_mut().fill(Scalar::ZERO);
+
+        for (i, image) in images.iter().enumerate() {
+            let auto_index = (1..=trace_level_count)
+                .filter(|level| (i >> (level - 1)) & 1 == 1)
+                .fold(1, |acc, level| {
+                    acc * ntru_trace_key.automorphism_index(key_level_count - level).0 % double_polynomial_size
+                });
+
+            buf.as_mut().clone_from_slice(image.as_ref());
+            ntru_ciphertext_monic_monomial_div_assign(
+                &mut buf,
+                MonomialDegree(j * auto_index % double_polynomial_size),
+            );
+            ntru_ciphertext_add_assign(&mut output_ntru_ciphertext, &buf);
+        }
+    }
+}
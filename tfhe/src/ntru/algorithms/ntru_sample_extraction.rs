@@ -1,7 +1,10 @@
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
-use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_opposite_assign;
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_opposite_assign,
+    slice_wrapping_opposite_assign_custom_mod,
+};
 use crate::ntru::entities::*;
 
 pub fn extract_lwe_sample_from_ntru_ciphertext<Scalar, InputCont, OutputCont>(
@@ -33,10 +36,13 @@ pub fn extract_lwe_sample_from_ntru_ciphertext<Scalar, InputCont, OutputCont>(
     assert!(
         input_ntru
             .ciphertext_modulus()
-            .is_compatible_with_native_modulus(),
-        "Only support power-of-two modulus, currently.",
+            .is_compatible_with_native_modulus()
+            || input_ntru.ciphertext_modulus().get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus, currently.",
     );
 
+    let ciphertext_modulus = input_ntru.ciphertext_modulus();
+
     let (mut lwe_mask, lwe_body) = output_lwe.get_mut_mask_and_body();
 
     // LWE body part
@@ -50,6 +56,13 @@ pub fn extract_lwe_sample_from_ntru_ciphertext<Scalar, InputCont, OutputCont>(
     lwe_mask.reverse();
     // Unlike GLWE decryption, NTRU decryption multiplies ntru ciphertext polynomial
     // by secret key polynomial, and do not subtract by the result.
-    slice_wrapping_opposite_assign(&mut lwe_mask[opposite_count..]);
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        slice_wrapping_opposite_assign(&mut lwe_mask[opposite_count..]);
+    } else {
+        slice_wrapping_opposite_assign_custom_mod(
+            &mut lwe_mask[opposite_count..],
+            Scalar::cast_from(ciphertext_modulus.get_custom_modulus()),
+        );
+    }
     lwe_mask.rotate_left(opposite_count);
 }
\ No newline at end of file
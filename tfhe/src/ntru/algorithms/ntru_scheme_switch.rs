@@ -1,4 +1,5 @@
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::PolynomialSize;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
 use crate::ntru::entities::*;
@@ -105,3 +106,48 @@ pub fn scheme_switch_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
         output_ntru_ciphertext,
     );
 }
+
+pub fn scheme_switch_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)
+}
+
+pub fn scheme_switch_ntru_ciphertext_mem_optimized<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_scheme_switch_key: &FourierNtruSchemeSwitchKey<KeyCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        ntru_scheme_switch_key.polynomial_size(),
+        input_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        ntru_scheme_switch_key.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert!(
+        output_ntru_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    keyswitch_ntru_ciphertext_mem_optimized(
+        &ntru_scheme_switch_key.as_fourier_ntru_keyswitch_key(),
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
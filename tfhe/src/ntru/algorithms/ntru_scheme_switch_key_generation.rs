@@ -22,7 +22,7 @@ pub fn generate_ntru_scheme_switch_key<
         input_ntru_secret_key
             .ciphertext_modulus()
             .is_compatible_with_native_modulus(),
-        "Only support poewr-of-two modulus currently.",
+        "Only support power-of-two modulus currently, scheme switch keys cannot be generated for a prime modulus.",
     );
 
     assert_eq!(
@@ -3,7 +3,7 @@ use crate::core_crypto::commons::math::random::{RandomGenerable, Distribution, U
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
-use crate::ntru::algorithms::polynomial_inverse_mod_power_of_two;
+use crate::ntru::algorithms::{is_odd_prime_modulus, polynomial_inverse_mod_power_of_two, polynomial_inverse_mod_prime};
 use crate::ntru::entities::*;
 
 pub fn allocate_and_generate_new_binary_ntru_secret_key<Scalar, Gen>(
@@ -12,7 +12,7 @@ pub fn allocate_and_generate_new_binary_ntru_secret_key<Scalar, Gen>(
     generator: &mut SecretRandomGenerator<Gen>,
 ) -> NtruSecretKeyOwned<Scalar>
 where
-    Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary> + CastInto<i128> + CastFrom<i128>,
     Gen: ByteRandomGenerator,
 {
     let mut ntru_secret_key =
@@ -28,16 +28,16 @@ pub fn generate_binary_ntru_secret_key<Scalar, KeyCont, Gen>(
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut SecretRandomGenerator<Gen>,
 ) where
-    Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary> + CastInto<i128> + CastFrom<i128>,
     KeyCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
-    // Currently only suuports power-of-two modulus
+    // Currently only suuports power-of-two or prime modulus
     assert!(
-        ciphertext_modulus.is_compatible_with_native_modulus(),
-        "Only supports power-of-two modulus currently"
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            || is_odd_prime_modulus(ciphertext_modulus.get_custom_modulus()),
+        "Only supports power-of-two or odd prime modulus currently, got {ciphertext_modulus:?}"
     );
-    let power = ciphertext_modulus.into_modulus_log().0;
 
     let polynomial_size = ntru_secret_key.polynomial_size();
     let mut is_invertible = false;
@@ -48,7 +48,7 @@ pub fn generate_binary_ntru_secret_key<Scalar, KeyCont, Gen>(
 
         generator.fill_slice_with_random_uniform_binary(f.as_mut());
 
-        is_invertible = polynomial_inverse_mod_power_of_two(&f, &mut f_inv, power);
+        is_invertible = ntru_secret_key_polynomial_inverse(&f, &mut f_inv, ciphertext_modulus);
 
         for i in 0..polynomial_size.0 {
             ntru_secret_key.as_mut()[i] = f.as_ref()[i];
@@ -64,7 +64,8 @@ pub fn allocate_and_generate_new_gaussian_ntru_secret_key<Scalar, NoiseDistribut
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> NtruSecretKeyOwned<Scalar>
 where
-    Scalar: UnsignedInteger + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    Scalar: UnsignedInteger + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>
+        + CastInto<i128> + CastFrom<i128>,
     NoiseDistribution: Distribution,
     Gen: ByteRandomGenerator,
 {
@@ -82,17 +83,18 @@ pub fn generate_gaussian_ntru_secret_key<Scalar, KeyCont, NoiseDistribution, Gen
     noise_distribution: NoiseDistribution,
     generator: &mut EncryptionRandomGenerator<Gen>, // Currently use EncryptionRandomGenerator for Gaussian error sampling
 ) where
-    Scalar: UnsignedInteger + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    Scalar: UnsignedInteger + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>
+        + CastInto<i128> + CastFrom<i128>,
     KeyCont: ContainerMut<Element = Scalar>,
     NoiseDistribution: Distribution,
     Gen: ByteRandomGenerator,
 {
-    // Currently only suuports power-of-two modulus
+    // Currently only suuports power-of-two or prime modulus
     assert!(
-        ciphertext_modulus.is_compatible_with_native_modulus(),
-        "Only supports power-of-two modulus currently"
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            || is_odd_prime_modulus(ciphertext_modulus.get_custom_modulus()),
+        "Only supports power-of-two or odd prime modulus currently, got {ciphertext_modulus:?}"
     );
-    let power = ciphertext_modulus.into_modulus_log().0;
 
     let polynomial_size = ntru_secret_key.polynomial_size();
     let mut is_invertible = false;
@@ -107,7 +109,7 @@ pub fn generate_gaussian_ntru_secret_key<Scalar, KeyCont, NoiseDistribution, Gen
             ciphertext_modulus,
         );
 
-        is_invertible = polynomial_inverse_mod_power_of_two(&f, &mut f_inv, power);
+        is_invertible = ntru_secret_key_polynomial_inverse(&f, &mut f_inv, ciphertext_modulus);
 
         for i in 0..polynomial_size.0 {
             ntru_secret_key.as_mut()[i] = f.as_ref()[i];
@@ -115,3 +117,22 @@ pub fn generate_gaussian_ntru_secret_key<Scalar, KeyCont, NoiseDistribution, Gen
         }
     }
 }
+
+fn ntru_secret_key_polynomial_inverse<Scalar, InputCont, OutputCont>(
+    input: &Polynomial<InputCont>,
+    output: &mut Polynomial<OutputCont>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> bool
+where
+    Scalar: UnsignedInteger + CastInto<i128> + CastFrom<i128>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    if ciphertext_modulus.is_compatible_with_native_modulus() {
+        let power = ciphertext_modulus.into_modulus_log().0;
+        polynomial_inverse_mod_power_of_two(input, output, power)
+    } else {
+        let modulus = Scalar::cast_from(ciphertext_modulus.get_custom_modulus());
+        polynomial_inverse_mod_prime(input, output, modulus)
+    }
+}
@@ -1,10 +1,13 @@
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::ntt::ntt64::{Ntt64, Ntt64View};
+use crate::core_crypto::commons::parameters::PolynomialSize;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::PlaintextList;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
-use crate::core_crypto::prelude::slice_algorithms::slice_wrapping_scalar_mul_assign;
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
 use crate::ntru::entities::*;
 use crate::ntru::algorithms::*;
+use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use tfhe_fft::c64;
 
@@ -83,6 +86,48 @@ pub fn switch_to_ntru_ciphertext<Scalar, SwkCont, InputCont, OutputCont>(
     SwkCont: Container<Element = c64>,
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_switching_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        switch_to_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    switch_to_ntru_ciphertext_mem_optimized(
+        ntru_switching_key,
+        input_plaintext_list,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn switch_to_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn switch_to_ntru_ciphertext_mem_optimized<Scalar, SwkCont, InputCont, OutputCont>(
+    ntru_switching_key: &FourierNtruSwitchingKey<SwkCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    SwkCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
 {
     assert_eq!(
         ntru_switching_key.polynomial_size().0,
@@ -105,20 +150,239 @@ pub fn switch_to_ntru_ciphertext<Scalar, SwkCont, InputCont, OutputCont>(
     let ciphertext_modulus = output_ntru_ciphertext.ciphertext_modulus();
     let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
 
-    let mut input_ntru_ciphertext = NtruCiphertext::new(
-        Scalar::ZERO,
+    let (input_ntru_ciphertext, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        input_plaintext_list.as_ref().iter().map(|&x| x.wrapping_mul(torus_scaling)),
+    );
+    let input_ntru_ciphertext = NtruCiphertext::from_container(
+        &*input_ntru_ciphertext,
         polynomial_size,
         ciphertext_modulus,
     );
-    input_ntru_ciphertext.as_mut().clone_from_slice(input_plaintext_list.as_ref());
-    slice_wrapping_scalar_mul_assign(
-        &mut input_ntru_ciphertext.as_mut(),
-        torus_scaling,
-    );
 
-    keyswitch_ntru_ciphertext(
+    keyswitch_ntru_ciphertext_mem_optimized(
         &ntru_switching_key.as_fourier_ntru_keyswitch_key(),
         &input_ntru_ciphertext,
         output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_switching_key_to_ntt64<InputCont, OutputCont>(
+    standard_ntru_swk: &NtruSwitchingKey<InputCont>,
+    ntt_ntru_swk: &mut NttNtruKeyswitchKey<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    convert_standard_ntru_keyswitch_key_to_ntt64(
+        &standard_ntru_swk.as_ntru_keyswitch_key(),
+        ntt_ntru_swk,
+    );
+}
+
+pub fn switch_to_ntru_ciphertext_ntt64<SwkCont, InputCont, OutputCont>(
+    ntru_switching_key: &NttNtruKeyswitchKey<SwkCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    SwkCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    let polynomial_size = ntru_switching_key.polynomial_size();
+
+    let ntt = Ntt64::new(ntru_switching_key.ciphertext_modulus(), polynomial_size);
+    let ntt = ntt.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        switch_to_ntru_ciphertext_ntt64_mem_optimized_requirement(polynomial_size, ntt)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    switch_to_ntru_ciphertext_ntt64_mem_optimized(
+        ntru_switching_key,
+        input_plaintext_list,
+        output_ntru_ciphertext,
+        ntt,
+        stack,
+    );
+}
+
+pub fn switch_to_ntru_ciphertext_ntt64_mem_optimized_requirement(
+    polynomial_size: PolynomialSize,
+    ntt: Ntt64View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_ntru_ciphertext_ntt64_mem_optimized_requirement(polynomial_size, ntt)?
+        .try_and(StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn switch_to_ntru_ciphertext_ntt64_mem_optimized<SwkCont, InputCont, OutputCont>(
+    ntru_switching_key: &NttNtruKeyswitchKey<SwkCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    ntt: Ntt64View<'_>,
+    stack: &mut PodStack,
+) where
+    SwkCont: Container<Element = u64>,
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        ntru_switching_key.polynomial_size().0,
+        input_plaintext_list.plaintext_count().0,
+    );
+
+    assert_eq!(
+        ntru_switching_key.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    let polynomial_size = output_ntru_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_ntru_ciphertext.ciphertext_modulus();
+
+    // Plaintexts modulo a prime are not scaled to the native torus
+    let (input_ntru_ciphertext, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        input_plaintext_list.as_ref().iter().copied(),
+    );
+    let input_ntru_ciphertext = NtruCiphertext::from_container(
+        &*input_ntru_ciphertext,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    keyswitch_ntru_ciphertext_ntt64_mem_optimized(
+        ntru_switching_key,
+        &input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        ntt,
+        stack,
+    );
+}
+
+pub fn convert_standard_ntru_switching_key_to_fourier128<Scalar, InputCont, OutputCont>(
+    standard_ntru_swk: &NtruSwitchingKey<InputCont>,
+    fourier128_ntru_swk: &mut Fourier128NtruKeyswitchKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    convert_standard_ntru_keyswitch_key_to_fourier128(
+        &standard_ntru_swk.as_ntru_keyswitch_key(),
+        fourier128_ntru_swk,
+    );
+}
+
+pub fn convert_standard_ntru_switching_key_to_fourier128_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_ntru_swk: &NtruSwitchingKey<InputCont>,
+    fourier128_ntru_swk: &mut Fourier128NtruKeyswitchKey<OutputCont>,
+    fft: Fft128View<'_>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = f64>,
+{
+    convert_standard_ntru_keyswitch_key_to_fourier128_mem_optimized(
+        &standard_ntru_swk.as_ntru_keyswitch_key(),
+        fourier128_ntru_swk,
+        fft,
+    );
+}
+
+pub fn switch_to_ntru_ciphertext_f128<Scalar, SwkCont, InputCont, OutputCont>(
+    ntru_switching_key: &Fourier128NtruKeyswitchKey<SwkCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    SwkCont: Container<Element = f64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_switching_key.polynomial_size();
+
+    let fft = Fft128::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        switch_to_ntru_ciphertext_f128_mem_optimized_requirement::<Scalar>(polynomial_size, fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    switch_to_ntru_ciphertext_f128_mem_optimized(
+        ntru_switching_key,
+        input_plaintext_list,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn switch_to_ntru_ciphertext_f128_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    keyswitch_ntru_ciphertext_f128_mem_optimized_requirement::<Scalar>(polynomial_size, fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn switch_to_ntru_ciphertext_f128_mem_optimized<Scalar, SwkCont, InputCont, OutputCont>(
+    ntru_switching_key: &Fourier128NtruKeyswitchKey<SwkCont>,
+    input_plaintext_list: &PlaintextList<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: Fft128View<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    SwkCont: Container<Element = f64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        ntru_switching_key.polynomial_size().0,
+        input_plaintext_list.plaintext_count().0,
+    );
+
+    assert_eq!(
+        ntru_switching_key.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert!(
+        output_ntru_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = output_ntru_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_ntru_ciphertext.ciphertext_modulus();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let (input_ntru_ciphertext, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        input_plaintext_list.as_ref().iter().map(|&x| x.wrapping_mul(torus_scaling)),
+    );
+    let input_ntru_ciphertext = NtruCiphertext::from_container(
+        &*input_ntru_ciphertext,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    keyswitch_ntru_ciphertext_f128_mem_optimized(
+        ntru_switching_key,
+        &input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
     );
 }
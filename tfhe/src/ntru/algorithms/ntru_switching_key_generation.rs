@@ -21,8 +21,9 @@ pub fn generate_ntru_switching_key<
     assert!(
         input_ntru_secret_key
             .ciphertext_modulus()
-            .is_compatible_with_native_modulus(),
-        "Only support poewr-of-two modulus currently.",
+            .is_compatible_with_native_modulus()
+            || input_ntru_secret_key.ciphertext_modulus().get_custom_modulus() % 2 == 1,
+        "Only support power-of-two or odd modulus currently.",
     );
 
     assert_eq!(
@@ -14,57 +14,62 @@ use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use tfhe_fft::c64;
 
-pub fn convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier<Scalar, InputCont, OutputCont>(
-    standard_ntru_to_rlwe_ksk: &NtruToRlweKeyswitchKey<InputCont>,
-    fourier_ntru_to_rlwe_ksk: &mut FourierNtruToRlweKeyswitchKey<OutputCont>,
+pub fn convert_standard_ntru_to_glwe_keyswitch_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_ntru_to_glwe_ksk: &NtruToGlweKeyswitchKey<InputCont>,
+    fourier_ntru_to_glwe_ksk: &mut FourierNtruToGlweKeyswitchKey<OutputCont>,
 ) where
     Scalar: UnsignedTorus,
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = c64>,
 {
     assert_eq!(
-        standard_ntru_to_rlwe_ksk.polynomial_size(),
-        fourier_ntru_to_rlwe_ksk.polynomial_size(),
+        standard_ntru_to_glwe_ksk.glwe_size(),
+        fourier_ntru_to_glwe_ksk.glwe_size(),
     );
 
     assert_eq!(
-        standard_ntru_to_rlwe_ksk.decomposition_base_log(),
-        fourier_ntru_to_rlwe_ksk.decomposition_base_log(),
+        standard_ntru_to_glwe_ksk.polynomial_size(),
+        fourier_ntru_to_glwe_ksk.polynomial_size(),
     );
 
     assert_eq!(
-        standard_ntru_to_rlwe_ksk.decomposition_level_count(),
-        fourier_ntru_to_rlwe_ksk.decomposition_level_count(),
+        standard_ntru_to_glwe_ksk.decomposition_base_log(),
+        fourier_ntru_to_glwe_ksk.decomposition_base_log(),
     );
 
-    let fft = Fft::new(fourier_ntru_to_rlwe_ksk.polynomial_size());
+    assert_eq!(
+        standard_ntru_to_glwe_ksk.decomposition_level_count(),
+        fourier_ntru_to_glwe_ksk.decomposition_level_count(),
+    );
+
+    let fft = Fft::new(fourier_ntru_to_glwe_ksk.polynomial_size());
     let fft = fft.as_view();
 
     let mut buffers = ComputationBuffers::new();
     buffers.resize(
-        convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier_mem_optimized_requirement(fft)
+        convert_standard_ntru_to_glwe_keyswitch_key_to_fourier_mem_optimized_requirement(fft)
             .unwrap()
             .unaligned_bytes_required(),
     );
     let stack = buffers.stack();
 
-    convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier_mem_optimized(
-        standard_ntru_to_rlwe_ksk,
-        fourier_ntru_to_rlwe_ksk,
+    convert_standard_ntru_to_glwe_keyswitch_key_to_fourier_mem_optimized(
+        standard_ntru_to_glwe_ksk,
+        fourier_ntru_to_glwe_ksk,
         fft,
         stack,
     );
 }
 
-pub fn convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier_mem_optimized_requirement(
+pub fn convert_standard_ntru_to_glwe_keyswitch_key_to_fourier_mem_optimized_requirement(
     fft: FftView<'_>,
 ) -> Result<StackReq, SizeOverflow> {
     fft.forward_scratch()
 }
 
-pub fn convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
-    standard_ntru_to_rlwe_ksk: &NtruToRlweKeyswitchKey<InputCont>,
-    fourier_ntru_to_rlwe_ksk: &mut FourierNtruToRlweKeyswitchKey<OutputCont>,
+pub fn convert_standard_ntru_to_glwe_keyswitch_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_ntru_to_glwe_ksk: &NtruToGlweKeyswitchKey<InputCont>,
+    fourier_ntru_to_glwe_ksk: &mut FourierNtruToGlweKeyswitchKey<OutputCont>,
     fft: FftView<'_>,
     stack: &mut PodStack,
 ) where
@@ -72,19 +77,20 @@ pub fn convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier_mem_optimized<Scal
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = c64>,
 {
-    fourier_ntru_to_rlwe_ksk
+    fourier_ntru_to_glwe_ksk
         .as_mut_view()
-        .fill_with_forward_fourier(standard_ntru_to_rlwe_ksk.as_view(), fft, stack);
+        .fill_with_forward_fourier(standard_ntru_to_glwe_ksk.as_view(), fft, stack);
 }
 
-pub fn keyswitch_ntru_to_rlwe_scratch<Scalar>(
+pub fn keyswitch_ntru_to_glwe_scratch<Scalar>(
+    glwe_size: GlweSize,
     polynomial_size: PolynomialSize,
     fft: FftView<'_>,
 ) -> Result<StackReq, SizeOverflow> {
     let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
     let standard_scratch = StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?;
     let fourier_scratch
-        = StackReq::try_new_aligned::<c64>(2 * fourier_polynomial_size, CACHELINE_ALIGN)?;
+        = StackReq::try_new_aligned::<c64>(glwe_size.0 * fourier_polynomial_size, CACHELINE_ALIGN)?;
     let fourier_scratch_single = StackReq::try_new_aligned::<c64>(fourier_polynomial_size, CACHELINE_ALIGN)?;
 
     let substack3 = fft.forward_scratch()?;
@@ -97,24 +103,25 @@ pub fn keyswitch_ntru_to_rlwe_scratch<Scalar>(
     substack0.try_and(fourier_scratch)
 }
 
-pub fn keyswitch_ntru_to_rlwe<Scalar, KskCont, InputCont, OutputCont>(
-    ntru_to_rlwe_keyswitch_key: &FourierNtruToRlweKeyswitchKey<KskCont>,
+pub fn keyswitch_ntru_to_glwe<Scalar, KskCont, InputCont, OutputCont>(
+    ntru_to_glwe_keyswitch_key: &FourierNtruToGlweKeyswitchKey<KskCont>,
     input_ntru_ciphertext: &NtruCiphertext<InputCont>,
-    output_rlwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
 ) where
     Scalar: UnsignedTorus,
     KskCont: Container<Element = c64>,
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
 {
-    let polynomial_size = ntru_to_rlwe_keyswitch_key.polynomial_size();
+    let polynomial_size = ntru_to_glwe_keyswitch_key.polynomial_size();
 
     let fft = Fft::new(polynomial_size);
     let fft = fft.as_view();
 
     let mut buffers = ComputationBuffers::new();
     buffers.resize(
-        keyswitch_ntru_to_rlwe_scratch::<Scalar>(
+        keyswitch_ntru_to_glwe_scratch::<Scalar>(
+            ntru_to_glwe_keyswitch_key.glwe_size(),
             polynomial_size,
             fft,
         )
@@ -123,19 +130,19 @@ pub fn keyswitch_ntru_to_rlwe<Scalar, KskCont, InputCont, OutputCont>(
     );
     let stack = buffers.stack();
 
-    keyswitch_ntru_to_rlwe_mem_optimized(
-        ntru_to_rlwe_keyswitch_key.as_view(),
+    keyswitch_ntru_to_glwe_mem_optimized(
+        ntru_to_glwe_keyswitch_key.as_view(),
         input_ntru_ciphertext.as_view(),
-        &mut output_rlwe_ciphertext.as_mut_view(),
+        &mut output_glwe_ciphertext.as_mut_view(),
         fft,
         stack,
     );
 }
 
-pub fn keyswitch_ntru_to_rlwe_mem_optimized<Scalar>(
-    ntru_to_rlwe_keyswitch_key: FourierNtruToRlweKeyswitchKeyView<'_>,
+pub fn keyswitch_ntru_to_glwe_mem_optimized<Scalar>(
+    ntru_to_glwe_keyswitch_key: FourierNtruToGlweKeyswitchKeyView<'_>,
     input_ntru_ciphertext: NtruCiphertextView<'_, Scalar>,
-    output_rlwe_ciphertext: &mut GlweCiphertextMutView<'_, Scalar>,
+    output_glwe_ciphertext: &mut GlweCiphertextMutView<'_, Scalar>,
     fft: FftView<'_>,
     stack: &mut PodStack,
 ) where
@@ -143,22 +150,22 @@ pub fn keyswitch_ntru_to_rlwe_mem_optimized<Scalar>(
 {
     assert_eq!(
         input_ntru_ciphertext.polynomial_size(),
-        output_rlwe_ciphertext.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size(),
     );
 
     assert_eq!(
         input_ntru_ciphertext.polynomial_size(),
-        ntru_to_rlwe_keyswitch_key.polynomial_size(),
+        ntru_to_glwe_keyswitch_key.polynomial_size(),
     );
 
     assert_eq!(
-        output_rlwe_ciphertext.glwe_size(),
-        GlweSize(2),
+        output_glwe_ciphertext.glwe_size(),
+        ntru_to_glwe_keyswitch_key.glwe_size(),
     );
 
     assert_eq!(
         input_ntru_ciphertext.ciphertext_modulus(),
-        output_rlwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus(),
     );
 
     assert!(
@@ -168,30 +175,60 @@ pub fn keyswitch_ntru_to_rlwe_mem_optimized<Scalar>(
         "Only support power-of-two modulus currently.",
     );
 
-    let fft_type = ntru_to_rlwe_keyswitch_key.fft_type();
+    keyswitch_ntru_sum_to_glwe_mem_optimized(
+        &std::iter::once((ntru_to_glwe_keyswitch_key, input_ntru_ciphertext)),
+        output_glwe_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+/// Keyswitch the sum of the input NTRU ciphertexts, each under its own keyswitch key, to a single
+/// GLWE ciphertext. The scratch requirement is the same as [`keyswitch_ntru_to_glwe_scratch`].
+pub(crate) fn keyswitch_ntru_sum_to_glwe_mem_optimized<'k, 'c, Scalar, I>(
+    keyswitch_key_and_inputs: &I,
+    output_glwe_ciphertext: &mut GlweCiphertextMutView<'_, Scalar>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    I: Iterator<Item = (FourierNtruToGlweKeyswitchKeyView<'k>, NtruCiphertextView<'c, Scalar>)> + Clone,
+{
+    let Some((first_ksk, _)) = keyswitch_key_and_inputs.clone().next() else {
+        output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+        return;
+    };
+    let fft_type = first_ksk.fft_type();
     let split_base_log = fft_type.split_base_log();
 
-    output_rlwe_ciphertext.as_mut().fill(Scalar::ZERO);
-    ntru_to_rlwe_keyswitch_key.into_splits().rev().enumerate()
-        .for_each(|(i, ksk_split)| {
+    for (ksk, _) in keyswitch_key_and_inputs.clone() {
+        assert_eq!(ksk.fft_type(), fft_type);
+        assert_eq!(ksk.glwe_size(), first_ksk.glwe_size());
+    }
+
+    output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+    for i in 0..fft_type.num_split() {
+        for (ksk, input) in keyswitch_key_and_inputs.clone() {
+            let ksk_split = ksk.into_splits().rev().nth(i).unwrap();
             add_keyswitch_split_external_product_assign(
-                ksk_split.as_view(),
-                input_ntru_ciphertext.as_view(),
-                &mut output_rlwe_ciphertext.as_mut_view(),
+                ksk_split,
+                input.as_view(),
+                &mut output_glwe_ciphertext.as_mut_view(),
                 fft,
                 stack,
             );
-            if i == 0 {
-                slice_wrapping_scalar_mul_assign(
-                    output_rlwe_ciphertext.as_mut(),
-                    Scalar::ONE << split_base_log,
-                );
-            }
-        });
+        }
+        if i + 1 < fft_type.num_split() {
+            slice_wrapping_scalar_mul_assign(
+                output_glwe_ciphertext.as_mut(),
+                Scalar::ONE << split_base_log,
+            );
+        }
+    }
 }
 
 fn add_keyswitch_split_external_product_assign<Scalar>(
-    ksk_split: FourierNtruToRlweKeyswitchKeySplitView<'_>,
+    ksk_split: FourierNtruToGlweKeyswitchKeySplitView<'_>,
     input: NtruCiphertextView<'_, Scalar>,
     output: &mut GlweCiphertextMutView<'_, Scalar>,
     fft: FftView<'_>,
@@ -199,6 +236,7 @@ fn add_keyswitch_split_external_product_assign<Scalar>(
 ) where
     Scalar: UnsignedTorus,
 {
+    let glwe_size = ksk_split.glwe_size();
     let fourier_poly_size = ksk_split.polynomial_size().to_fourier_polynomial_size().0;
 
     let decomposer = SignedDecomposer::<Scalar>::new(
@@ -207,7 +245,7 @@ fn add_keyswitch_split_external_product_assign<Scalar>(
     );
 
     let (output_fft_buffer, substack0)
-        = stack.make_aligned_raw::<c64>(2 * fourier_poly_size, CACHELINE_ALIGN);
+        = stack.make_aligned_raw::<c64>(glwe_size.0 * fourier_poly_size, CACHELINE_ALIGN);
     // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
     // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
     // it has been fully initialized for the first time.
@@ -0,0 +1,62 @@
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Keyswitch an LWE ciphertext extracted from an NTRU ciphertext, e.g., the output of
+/// [`ntru_cmux_bootstrap_lwe_ciphertext`], to the small LWE secret key of the keyswitch key, and
+/// switch it to the modulus of the output so that it can be bootstrapped again.
+///
+/// The keyswitch is computed modulo the keyswitch key modulus, which has to be a power of two at
+/// least as large as the output modulus.
+///
+/// [`ntru_cmux_bootstrap_lwe_ciphertext`]: crate::ntru::algorithms::ntru_cmux_bootstrap_lwe_ciphertext
+pub fn keyswitch_ntru_to_lwe<InputScalar, OutputScalar, KskCont, InputCont, OutputCont>(
+    ntru_to_lwe_keyswitch_key: &LweKeyswitchKey<KskCont>,
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    InputScalar: UnsignedInteger,
+    OutputScalar: UnsignedInteger + CastFrom<InputScalar>,
+    KskCont: Container<Element = OutputScalar>,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
+{
+    let ksk_ciphertext_modulus = ntru_to_lwe_keyswitch_key.ciphertext_modulus();
+    let output_ciphertext_modulus = output_lwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        output_ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+    assert!(
+        output_ciphertext_modulus.into_modulus_log().0 <= ksk_ciphertext_modulus.into_modulus_log().0,
+        "The output modulus {output_ciphertext_modulus} cannot be larger than the keyswitch key \
+        modulus {ksk_ciphertext_modulus}.",
+    );
+
+    // Power-of-two moduli are stored in the MSBs, so that the keyswitch can be done in place
+    let mut keyswitched_lwe_ciphertext = LweCiphertext::from_container(
+        output_lwe_ciphertext.as_mut(),
+        ksk_ciphertext_modulus,
+    );
+
+    keyswitch_lwe_ciphertext_with_scalar_change(
+        ntru_to_lwe_keyswitch_key,
+        input_lwe_ciphertext,
+        &mut keyswitched_lwe_ciphertext,
+    );
+
+    if output_ciphertext_modulus != ksk_ciphertext_modulus {
+        let decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(output_ciphertext_modulus.into_modulus_log().0),
+            DecompositionLevelCount(1),
+        );
+
+        output_lwe_ciphertext
+            .as_mut()
+            .iter_mut()
+            .for_each(|x| *x = decomposer.closest_representable(*x));
+    }
+}
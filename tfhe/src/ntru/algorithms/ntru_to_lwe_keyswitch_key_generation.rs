@@ -0,0 +1,99 @@
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::*;
+
+/// Fill an [`LweKeyswitchKey`] switching the LWE ciphertexts extracted from NTRU ciphertexts,
+/// i.e., the LWE ciphertexts under the coefficients of f, to a small LWE secret key.
+///
+/// The output key may use a smaller scalar type, e.g., u32 for an input NTRU key over u64.
+pub fn generate_ntru_to_lwe_keyswitch_key<
+    InputScalar,
+    OutputScalar,
+    NoiseDistribution,
+    InputKeyCont,
+    OutputKeyCont,
+    KSKeyCont,
+    Gen,
+>(
+    input_ntru_sk: &NtruSecretKey<InputKeyCont>,
+    output_lwe_sk: &LweSecretKey<OutputKeyCont>,
+    ntru_to_lwe_keyswitch_key: &mut LweKeyswitchKey<KSKeyCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    InputScalar: UnsignedInteger + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    KSKeyCont: ContainerMut<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        input_ntru_sk.ciphertext_modulus().is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    assert!(
+        ntru_to_lwe_keyswitch_key.ciphertext_modulus().is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    // The coefficients of f are stored modulo the power-of-two q, so that their casts to a smaller
+    // scalar type are correct modulo the keyswitch key modulus
+    generate_lwe_keyswitch_key(
+        &input_ntru_sk.as_lwe_secret_key(),
+        output_lwe_sk,
+        ntru_to_lwe_keyswitch_key,
+        noise_distribution,
+        generator,
+    );
+}
+
+pub fn allocate_and_generate_new_ntru_to_lwe_keyswitch_key<
+    InputScalar,
+    OutputScalar,
+    NoiseDistribution,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+>(
+    input_ntru_sk: &NtruSecretKey<InputKeyCont>,
+    output_lwe_sk: &LweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<OutputScalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LweKeyswitchKeyOwned<OutputScalar>
+where
+    InputScalar: UnsignedInteger + CastInto<OutputScalar>,
+    OutputScalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = InputScalar>,
+    OutputKeyCont: Container<Element = OutputScalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_ntru_to_lwe_keyswitch_key = LweKeyswitchKeyOwned::new(
+        OutputScalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        LweDimension(input_ntru_sk.polynomial_size().0),
+        output_lwe_sk.lwe_dimension(),
+        ciphertext_modulus,
+    );
+
+    generate_ntru_to_lwe_keyswitch_key(
+        input_ntru_sk,
+        output_lwe_sk,
+        &mut new_ntru_to_lwe_keyswitch_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_ntru_to_lwe_keyswitch_key
+}
@@ -1,9 +1,11 @@
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::{DecompositionLevelCount, PolynomialSize};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
 use crate::ntru::algorithms::*;
 use crate::ntru::entities::*;
 
+use aligned_vec::CACHELINE_ALIGN;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 use tfhe_fft::c64;
 
@@ -38,7 +40,7 @@ pub fn convert_standard_ntru_trace_key_to_fourier<Scalar, InputCont, OutputCont>
     buffers.resize(
         convert_standard_ntru_trace_key_to_fourier_mem_optimized_requirement(fft)
             .unwrap()
-            .unaligned_bytes_required(),
+        .unaligned_bytes_required(),
     );
     let stack = buffers.stack();
 
@@ -66,11 +68,14 @@ pub fn convert_standard_ntru_trace_key_to_fourier_mem_optimized<Scalar, InputCon
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = c64>,
 {
-    let polynomial_size = standard_ntru_trace_key.polynomial_size();
+    assert_eq!(
+        standard_ntru_trace_key.trace_level_count(),
+        fourier_ntru_trace_key.trace_level_count(),
+    );
 
-    for k in 1..=polynomial_size.0.ilog2() {
-        let ntru_auto_key = standard_ntru_trace_key.get_automorphism_key(k as usize - 1);
-        let mut fourier_ntru_auto_key = fourier_ntru_trace_key.get_mut_automorphism_key(k as usize - 1);
+    for idx in 0..standard_ntru_trace_key.automorphism_key_count().0 {
+        let ntru_auto_key = standard_ntru_trace_key.get_automorphism_key(idx);
+        let mut fourier_ntru_auto_key = fourier_ntru_trace_key.get_mut_automorphism_key(idx);
 
         convert_standard_ntru_automorphism_key_to_fourier_mem_optimized(
             &ntru_auto_key,
@@ -79,6 +84,7 @@ pub fn convert_standard_ntru_trace_key_to_fourier_mem_optimized<Scalar, InputCon
             stack,
         );
     }
+
 }
 
 pub fn rev_trace_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
@@ -90,6 +96,129 @@ pub fn rev_trace_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
     KeyCont: Container<Element = c64>,
     InputCont: Container<Element = Scalar>,
     OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_trace_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        rev_trace_ntru_ciphertext_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            ntru_trace_key.decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    rev_trace_ntru_ciphertext_mem_optimized(
+        ntru_trace_key,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn rev_trace_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    fourier_trace_automorphism_permutations_requirement(fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)?
+        .try_and(ntru_ciphertext_add_automorphism_assign_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            decomp_level_count,
+            fft,
+        )?)
+}
+
+pub fn rev_trace_ntru_ciphertext_mem_optimized<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    partial_rev_trace_ntru_ciphertext_mem_optimized(
+        ntru_trace_key,
+        TraceLevelCount(ntru_trace_key.polynomial_size().0.ilog2() as usize),
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+/// Trace down to the subring Z[X^{2^d}] with d = trace_level_count: the output encrypts the input
+/// message with the coefficients of degree not divisible by 2^d set to zero.
+pub fn partial_rev_trace_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    trace_level_count: TraceLevelCount,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = ntru_trace_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        partial_rev_trace_ntru_ciphertext_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            ntru_trace_key.decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    partial_rev_trace_ntru_ciphertext_mem_optimized(
+        ntru_trace_key,
+        trace_level_count,
+        input_ntru_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn partial_rev_trace_ntru_ciphertext_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    rev_trace_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, decomp_level_count, fft)
+}
+
+pub fn partial_rev_trace_ntru_ciphertext_mem_optimized<Scalar, KeyCont, InputCont, OutputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    trace_level_count: TraceLevelCount,
+    input_ntru_ciphertext: &NtruCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
 {
     assert_eq!(
         ntru_trace_key.polynomial_size(),
@@ -106,6 +235,13 @@ pub fn rev_trace_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
         output_ntru_ciphertext.ciphertext_modulus(),
     );
 
+    assert!(
+        trace_level_count.0 <= ntru_trace_key.trace_level_count().0,
+        "Trace level count {} exceeds the number of levels {} of the trace key",
+        trace_level_count.0,
+        ntru_trace_key.trace_level_count().0,
+    );
+
     assert!(
         input_ntru_ciphertext
             .ciphertext_modulus()
@@ -116,28 +252,42 @@ pub fn rev_trace_ntru_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
     let polynomial_size = input_ntru_ciphertext.polynomial_size();
     let ciphertext_modulus = input_ntru_ciphertext.ciphertext_modulus();
 
-    let mut buf = NtruCiphertext::new(
-        Scalar::ZERO,
+    let (fourier_trace_permutations, stack) = fourier_trace_automorphism_permutations(fft, stack);
+
+    let (buf, stack) = stack.make_aligned_raw::<Scalar>(polynomial_size.0, CACHELINE_ALIGN);
+    let mut buf = NtruCiphertextMutView::from_container(
+        &mut *buf,
         polynomial_size,
         ciphertext_modulus,
     );
     output_ntru_ciphertext.as_mut()
         .clone_from_slice(input_ntru_ciphertext.as_ref());
 
-    for k in 1..=polynomial_size.0.ilog2() {
-        let fourier_ntru_auto_key = ntru_trace_key.get_automorphism_key(k as usize - 1);
+    // Level l uses the automorphism X -> X^{2N/2^l + 1}, stored at index (key level count - l).
+    // Each level applies the automorphism as a permutation of the Fourier coefficients of the
+    // decomposed accumulator and adds the keyswitched image back in place. With L decomposition
+    // levels and s splits, a level takes L forward and s backward FFTs instead of s * L forward and
+    // s backward FFTs for `automorphism_ntru_ciphertext`, so the d levels of the trace save
+    // (s - 1) * L * d forward FFTs, and nothing for unsplit keys.
+    let key_level_count = ntru_trace_key.trace_level_count().0;
+    for level in (1..=trace_level_count.0).rev() {
+        let fourier_ntru_auto_key = ntru_trace_key.get_automorphism_key(key_level_count - level);
+        let permutation = get_fourier_trace_automorphism_permutation(
+            fourier_trace_permutations,
+            fourier_ntru_auto_key.automorphism_index(),
+            polynomial_size,
+        );
 
         ntru_ciphertext_round_by_2_assign(output_ntru_ciphertext);
+        buf.as_mut().clone_from_slice(output_ntru_ciphertext.as_ref());
 
-        automorphism_ntru_ciphertext(
+        ntru_ciphertext_add_automorphism_assign_mem_optimized(
             &fourier_ntru_auto_key,
-            &output_ntru_ciphertext,
-            &mut buf,
-        );
-
-        ntru_ciphertext_add_assign(
+            permutation,
             output_ntru_ciphertext,
             &buf,
+            fft,
+            stack,
         );
     }
 }
@@ -150,16 +300,65 @@ pub fn rev_trace_ntru_ciphertext_assign<Scalar, KeyCont, InputCont>(
     KeyCont: Container<Element = c64>,
     InputCont: ContainerMut<Element = Scalar>,
 {
-    let mut buf = NtruCiphertext::new(
-        Scalar::ZERO,
+    let polynomial_size = ntru_trace_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        rev_trace_ntru_ciphertext_assign_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            ntru_trace_key.decomposition_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    rev_trace_ntru_ciphertext_assign_mem_optimized(
+        ntru_trace_key,
+        input_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn rev_trace_ntru_ciphertext_assign_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    rev_trace_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, decomp_level_count, fft)?
+        .try_and(StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?)
+}
+
+pub fn rev_trace_ntru_ciphertext_assign_mem_optimized<Scalar, KeyCont, InputCont>(
+    ntru_trace_key: &FourierNtruTraceKey<KeyCont>,
+    input_ntru_ciphertext: &mut NtruCiphertext<InputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: ContainerMut<Element = Scalar>,
+{
+    let (buf, stack) = stack.collect_aligned(
+        CACHELINE_ALIGN,
+        input_ntru_ciphertext.as_ref().iter().copied(),
+    );
+    let buf = NtruCiphertextView::from_container(
+        &*buf,
         input_ntru_ciphertext.polynomial_size(),
         input_ntru_ciphertext.ciphertext_modulus(),
     );
-    buf.as_mut().clone_from_slice(input_ntru_ciphertext.as_ref());
 
-    rev_trace_ntru_ciphertext(
+    rev_trace_ntru_ciphertext_mem_optimized(
         ntru_trace_key,
         &buf,
         input_ntru_ciphertext,
+        fft,
+        stack,
     );
 }
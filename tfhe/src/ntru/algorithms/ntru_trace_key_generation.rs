@@ -4,6 +4,7 @@ use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::random::{Distribution, Uniform};
 use crate::ntru::entities::*;
 use crate::ntru::algorithms::*;
+use rayon::prelude::*;
 
 pub fn generate_ntru_trace_key<
     Scalar: Encryptable<Uniform, NoiseDistribution>,
@@ -21,7 +22,7 @@ pub fn generate_ntru_trace_key<
         input_ntru_secret_key
             .ciphertext_modulus()
             .is_compatible_with_native_modulus(),
-        "Only support power-of-two modulus currently.",
+        "Only support power-of-two modulus currently, trace keys cannot be generated for a prime modulus.",
     );
 
     assert_eq!(
@@ -34,18 +35,20 @@ pub fn generate_ntru_trace_key<
         output_ntru_trace_key.polynomial_size(),
     );
 
-    let polynomial_size = input_ntru_secret_key.polynomial_size();
+    let gen_iter = generator
+        .try_fork_from_config(output_ntru_trace_key.encryption_fork_config(noise_distribution))
+        .unwrap();
 
-    for k in 1..=polynomial_size.0.ilog2() {
-        let auto_index = AutomorphismIndex((1 << k) + 1);
-        let mut ntru_auto_key = output_ntru_trace_key.get_mut_automorphism_key(k as usize - 1);
+    for (idx, mut generator) in (0..output_ntru_trace_key.automorphism_key_count().0).zip(gen_iter) {
+        let auto_index = output_ntru_trace_key.automorphism_index(idx);
+        let mut ntru_auto_key = output_ntru_trace_key.get_mut_automorphism_key(idx);
 
         generate_ntru_automorphism_key(
             input_ntru_secret_key,
             auto_index,
             &mut ntru_auto_key,
             noise_distribution,
-            generator,
+            &mut generator,
         );
     }
 }
@@ -78,3 +81,159 @@ pub fn allocate_and_generate_new_ntru_trace_key<
 
     new_ntru_trace_key
 }
+
+pub fn allocate_and_generate_new_partial_ntru_trace_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution> + UnsignedTorus,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_ntru_secret_key: &NtruSecretKey<InputKeyCont>,
+    trace_level_count: TraceLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruTraceKeyOwned<Scalar> {
+    let mut new_ntru_trace_key = NtruTraceKeyOwned::new_partial(
+        input_ntru_secret_key.polynomial_size(),
+        trace_level_count,
+        decomp_base_log,
+        decomp_level_count,
+        input_ntru_secret_key.ciphertext_modulus(),
+    );
+
+    generate_ntru_trace_key(
+        input_ntru_secret_key,
+        &mut new_ntru_trace_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_ntru_trace_key
+}
+
+pub fn par_generate_ntru_trace_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar> + Sync,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_ntru_secret_key: &NtruSecretKey<InputKeyCont>,
+    output_ntru_trace_key: &mut NtruTraceKey<OutputCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        input_ntru_secret_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently, trace keys cannot be generated for a prime modulus.",
+    );
+
+    assert_eq!(
+        input_ntru_secret_key.ciphertext_modulus(),
+        output_ntru_trace_key.ciphertext_modulus(),
+    );
+
+    assert_eq!(
+        input_ntru_secret_key.polynomial_size(),
+        output_ntru_trace_key.polynomial_size(),
+    );
+
+    let polynomial_size = output_ntru_trace_key.polynomial_size();
+    let decomp_base_log = output_ntru_trace_key.decomposition_base_log();
+    let ciphertext_modulus = output_ntru_trace_key.ciphertext_modulus();
+    let auto_index_list = (0..output_ntru_trace_key.automorphism_key_count().0)
+        .map(|idx| output_ntru_trace_key.automorphism_index(idx))
+        .collect::<Vec<_>>();
+
+    let gen_iter = generator
+        .par_try_fork_from_config(output_ntru_trace_key.encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    output_ntru_trace_key
+        .get_mut_ntru_keyswitch_key_list()
+        .par_iter_mut()
+        .enumerate()
+        .zip(gen_iter)
+        .for_each(|((idx, ntru_ksk), mut generator)| {
+            let auto_index = auto_index_list[idx];
+            let mut ntru_auto_key = NtruAutomorphismKey::from_container(
+                ntru_ksk.into_container(),
+                auto_index,
+                polynomial_size,
+                decomp_base_log,
+                ciphertext_modulus,
+            );
+
+            generate_ntru_automorphism_key(
+                input_ntru_secret_key,
+                auto_index,
+                &mut ntru_auto_key,
+                noise_distribution,
+                &mut generator,
+            );
+        });
+}
+
+pub fn par_allocate_and_generate_new_ntru_trace_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution> + UnsignedTorus + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_ntru_secret_key: &NtruSecretKey<InputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruTraceKeyOwned<Scalar> {
+    let mut new_ntru_trace_key = NtruTraceKeyOwned::new(
+        input_ntru_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        input_ntru_secret_key.ciphertext_modulus(),
+    );
+
+    par_generate_ntru_trace_key(
+        input_ntru_secret_key,
+        &mut new_ntru_trace_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_ntru_trace_key
+}
+
+pub fn par_allocate_and_generate_new_partial_ntru_trace_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution> + UnsignedTorus + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_ntru_secret_key: &NtruSecretKey<InputKeyCont>,
+    trace_level_count: TraceLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> NtruTraceKeyOwned<Scalar> {
+    let mut new_ntru_trace_key = NtruTraceKeyOwned::new_partial(
+        input_ntru_secret_key.polynomial_size(),
+        trace_level_count,
+        decomp_base_log,
+        decomp_level_count,
+        input_ntru_secret_key.ciphertext_modulus(),
+    );
+
+    par_generate_ntru_trace_key(
+        input_ntru_secret_key,
+        &mut new_ntru_trace_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_ntru_trace_key
+}
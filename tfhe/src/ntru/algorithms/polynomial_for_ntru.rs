@@ -497,3 +497,165 @@ where
 
     return true;
 }
+
+// Return x such that a * x == 1 (mod modulus), assuming gcd(a, modulus) == 1
+fn scalar_inverse_custom_mod<Scalar>(a: Scalar, modulus: Scalar) -> Scalar
+where
+    Scalar: UnsignedInteger + CastInto<i128> + CastFrom<i128>,
+{
+    let (g, a_inv, _) = egcd(a % modulus, modulus);
+    assert!(
+        g == Scalar::ONE,
+        "{a:?} is not invertible modulo {modulus:?}",
+    );
+
+    let signed_mod: i128 = modulus.cast_into();
+    let a_inv = a_inv % signed_mod;
+    if a_inv >= 0 {a_inv.cast_into()} else {(signed_mod + a_inv).cast_into()}
+}
+
+fn mul_mod_u64(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+fn pow_mod_u64(base: u64, exp: u64, modulus: u64) -> u64 {
+    let mut base = base % modulus;
+    let mut exp = exp;
+    let mut res = 1u64;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod_u64(res, base, modulus);
+        }
+        base = mul_mod_u64(base, base, modulus);
+        exp >>= 1;
+    }
+    res
+}
+
+// Miller-Rabin test with the first 12 primes as witnesses, which is deterministic for any input
+// fitting in 64 bits
+pub fn is_odd_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 3 || n % 2 == 0 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n == p {
+            return true;
+        }
+        if n % p == 0 {
+            return false;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for a in WITNESSES {
+        let mut x = pow_mod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod_u64(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+// The primality test is only deterministic for 64-bit inputs, so larger odd moduli are rejected
+pub fn is_odd_prime_modulus(modulus: u128) -> bool {
+    let modulus = u64::try_from(modulus).unwrap_or_else(|_| {
+        panic!("Odd moduli larger than 2^64 are not supported, got {modulus}")
+    });
+    is_odd_prime(modulus)
+}
+
+// Compute the inverse of the input polynomial in Z_p[X] / (X^N + 1) for a prime p,
+// by running the extended Euclidean algorithm against X^N + 1
+pub fn polynomial_inverse_mod_prime<Scalar, InputCont, OutputCont>(
+    input: &Polynomial<InputCont>,
+    output: &mut Polynomial<OutputCont>,
+    modulus: Scalar,
+) -> bool
+where
+    Scalar: UnsignedInteger + CastInto<i128> + CastFrom<i128>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input.polynomial_size().0 == output.polynomial_size().0,
+        "The input polynomial size {:?} is different from the output polynomial size {:?}",
+        input.polynomial_size().0,
+        output.polynomial_size().0,
+    );
+    assert!(
+        is_odd_prime_modulus(CastInto::<i128>::cast_into(modulus) as u128),
+        "The modulus {modulus:?} should be an odd prime",
+    );
+
+    let polynomial_size = input.polynomial_size().0;
+    let inc_polynomial_size = PolynomialSize(polynomial_size + 1);
+
+    // Invariant: t0 * input == r0 and t1 * input == r1 (mod X^N + 1)
+    let mut r0 = Polynomial::new(Scalar::ZERO, inc_polynomial_size);
+    let mut r1 = Polynomial::new(Scalar::ZERO, inc_polynomial_size);
+    let mut t0 = Polynomial::new(Scalar::ZERO, input.polynomial_size());
+    let mut t1 = Polynomial::new(Scalar::ZERO, input.polynomial_size());
+
+    // r0 <- X^N + 1, r1 <- input, t0 <- 0, t1 <- 1
+    r0.as_mut()[0] = Scalar::ONE;
+    r0.as_mut()[polynomial_size] = Scalar::ONE;
+    for i in 0..polynomial_size {
+        r1.as_mut()[i] = input.as_ref()[i] % modulus;
+    }
+    t1.as_mut()[0] = Scalar::ONE;
+
+    loop {
+        if is_polynomial_zero(&r1) {
+            return false;
+        }
+
+        let degree_r1 = polynomial_nonzero_coeff_idx(&r1, inc_polynomial_size.0);
+        if degree_r1 == 0 {
+            let lead_inv = scalar_inverse_custom_mod(r1.as_ref()[0], modulus);
+            polynomial_wrapping_mul_scalar_custom_mod(&t1, lead_inv, output, modulus);
+            return true;
+        }
+
+        let degree_r0 = polynomial_nonzero_coeff_idx(&r0, inc_polynomial_size.0);
+        if degree_r0 < degree_r1 {
+            polynomial_swap(&mut r0, &mut r1);
+            polynomial_swap(&mut t0, &mut t1);
+            continue;
+        }
+
+        // Cancel the leading term of r0 by coeff * X^shift * r1
+        let shift = degree_r0 - degree_r1;
+        let lead_inv = scalar_inverse_custom_mod(r1.as_ref()[degree_r1], modulus);
+        let coeff = r0.as_ref()[degree_r0].wrapping_mul_custom_mod(lead_inv, modulus);
+
+        for i in 0..=degree_r1 {
+            let elt = r1.as_ref()[i].wrapping_mul_custom_mod(coeff, modulus);
+            r0.as_mut()[i + shift] = r0.as_ref()[i + shift].wrapping_sub_custom_mod(elt, modulus);
+        }
+
+        // t0 <- t0 - coeff * X^shift * t1 (mod X^N + 1)
+        for i in 0..polynomial_size {
+            let elt = t1.as_ref()[i].wrapping_mul_custom_mod(coeff, modulus);
+            let j = i + shift;
+            if j < polynomial_size {
+                t0.as_mut()[j] = t0.as_ref()[j].wrapping_sub_custom_mod(elt, modulus);
+            } else {
+                let j = j - polynomial_size;
+                t0.as_mut()[j] = t0.as_ref()[j].wrapping_add_custom_mod(elt, modulus);
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::ntru::algorithms::*;
+use crate::ntru::entities::*;
+
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use tfhe_fft::c64;
+
+pub fn convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier<Scalar, InputCont, OutputCont>(
+    standard_rlwe_to_ntru_ksk: &RlweToNtruKeyswitchKey<InputCont>,
+    fourier_rlwe_to_ntru_ksk: &mut FourierRlweToNtruKeyswitchKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    let fft = Fft::new(fourier_rlwe_to_ntru_ksk.polynomial_size());
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier_mem_optimized(
+        standard_rlwe_to_ntru_ksk,
+        fourier_rlwe_to_ntru_ksk,
+        fft,
+        stack,
+    );
+}
+
+pub fn convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    convert_standard_ntru_keyswitch_key_to_fourier_mem_optimized_requirement(fft)
+}
+
+pub fn convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    standard_rlwe_to_ntru_ksk: &RlweToNtruKeyswitchKey<InputCont>,
+    fourier_rlwe_to_ntru_ksk: &mut FourierRlweToNtruKeyswitchKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        standard_rlwe_to_ntru_ksk.input_glwe_dimension(),
+        fourier_rlwe_to_ntru_ksk.input_glwe_dimension(),
+    );
+
+    assert_eq!(
+        standard_rlwe_to_ntru_ksk.polynomial_size(),
+        fourier_rlwe_to_ntru_ksk.polynomial_size(),
+    );
+
+    assert_eq!(
+        standard_rlwe_to_ntru_ksk.decomposition_base_log(),
+        fourier_rlwe_to_ntru_ksk.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        standard_rlwe_to_ntru_ksk.decomposition_level_count(),
+        fourier_rlwe_to_ntru_ksk.decomposition_level_count(),
+    );
+
+    for idx in 0..standard_rlwe_to_ntru_ksk.input_glwe_dimension().0 {
+        convert_standard_ntru_keyswitch_key_to_fourier_mem_optimized(
+            &standard_rlwe_to_ntru_ksk.get_ntru_keyswitch_key(idx),
+            &mut fourier_rlwe_to_ntru_ksk.get_mut_ntru_keyswitch_key(idx),
+            fft,
+            stack,
+        );
+    }
+
+    convert_standard_ntru_switching_key_to_fourier_mem_optimized(
+        &standard_rlwe_to_ntru_ksk.get_ntru_switching_key(),
+        &mut fourier_rlwe_to_ntru_ksk.get_mut_ntru_switching_key(),
+        fft,
+        stack,
+    );
+}
+
+/// Keyswitch a GLWE ciphertext (A_1, ..., A_k, B) under S to an NTRU ciphertext under f,
+/// computed as KS_{1->f}(B) - sum_i KS_{S_i->f}(A_i).
+pub fn keyswitch_rlwe_to_ntru<Scalar, KskCont, InputCont, OutputCont>(
+    rlwe_to_ntru_keyswitch_key: &FourierRlweToNtruKeyswitchKey<KskCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = rlwe_to_ntru_keyswitch_key.polynomial_size();
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        keyswitch_rlwe_to_ntru_mem_optimized_requirement::<Scalar>(
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
+    keyswitch_rlwe_to_ntru_mem_optimized(
+        rlwe_to_ntru_keyswitch_key,
+        input_glwe_ciphertext,
+        output_ntru_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+pub fn keyswitch_rlwe_to_ntru_mem_optimized_requirement<Scalar>(
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)
+}
+
+pub fn keyswitch_rlwe_to_ntru_mem_optimized<Scalar, KskCont, InputCont, OutputCont>(
+    rlwe_to_ntru_keyswitch_key: &FourierRlweToNtruKeyswitchKey<KskCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_ntru_ciphertext: &mut NtruCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: &mut PodStack,
+) where
+    Scalar: UnsignedTorus,
+    KskCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        rlwe_to_ntru_keyswitch_key.input_glwe_dimension(),
+    );
+
+    assert_eq!(
+        input_glwe_ciphertext.polynomial_size(),
+        rlwe_to_ntru_keyswitch_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_glwe_ciphertext.polynomial_size(),
+        output_ntru_ciphertext.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_ntru_ciphertext.ciphertext_modulus(),
+    );
+
+    assert!(
+        output_ntru_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    let polynomial_size = output_ntru_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_ntru_ciphertext.ciphertext_modulus();
+
+    output_ntru_ciphertext.as_mut().fill(Scalar::ZERO);
+
+    for (idx, mask_poly) in input_glwe_ciphertext.get_mask().as_polynomial_list().iter().enumerate() {
+        add_ntru_external_product_assign(
+            &mut output_ntru_ciphertext.as_mut_view(),
+            rlwe_to_ntru_keyswitch_key
+                .get_ntru_keyswitch_key(idx)
+                .as_fourier_ngsw_ciphertext(),
+            NtruCiphertextView::from_container(mask_poly.as_ref(), polynomial_size, ciphertext_modulus),
+            fft,
+            stack,
+        );
+    }
+    ntru_ciphertext_opposite_assign(output_ntru_ciphertext);
+
+    add_ntru_external_product_assign(
+        &mut output_ntru_ciphertext.as_mut_view(),
+        rlwe_to_ntru_keyswitch_key
+            .get_ntru_switching_key()
+            .as_fourier_ngsw_ciphertext(),
+        NtruCiphertextView::from_container(
+            input_glwe_ciphertext.get_body().as_polynomial().as_ref(),
+            polynomial_size,
+            ciphertext_modulus,
+        ),
+        fft,
+        stack,
+    );
+}
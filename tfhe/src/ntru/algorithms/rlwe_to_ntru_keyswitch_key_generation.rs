@@ -0,0 +1,244 @@
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::*;
+use crate::ntru::algorithms::*;
+use rayon::prelude::*;
+
+pub fn generate_rlwe_to_ntru_keyswitch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    KskCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    rlwe_to_ntru_keyswitch_key: &mut RlweToNtruKeyswitchKey<KskCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output_ntru_secret_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    assert_eq!(
+        input_glwe_secret_key.glwe_dimension(),
+        rlwe_to_ntru_keyswitch_key.input_glwe_dimension(),
+    );
+
+    assert_eq!(
+        input_glwe_secret_key.polynomial_size(),
+        output_ntru_secret_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        output_ntru_secret_key.polynomial_size(),
+        rlwe_to_ntru_keyswitch_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        output_ntru_secret_key.ciphertext_modulus(),
+        rlwe_to_ntru_keyswitch_key.ciphertext_modulus(),
+    );
+
+    let polynomial_size = output_ntru_secret_key.polynomial_size();
+    let ciphertext_modulus = output_ntru_secret_key.ciphertext_modulus();
+
+    let mut gen_iter = generator
+        .try_fork_from_config(rlwe_to_ntru_keyswitch_key.encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    // Only the key polynomial of S_i is used to generate the keyswitch key, its inverse is left empty
+    let mut ntru_secret_key_poly = NtruSecretKey::new_empty_key(
+        Scalar::ZERO,
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    for (idx, (glwe_sk_poly, mut generator)) in input_glwe_secret_key.as_polynomial_list().iter()
+        .zip(gen_iter.by_ref())
+        .enumerate()
+    {
+        ntru_secret_key_poly.as_mut()[..polynomial_size.0].copy_from_slice(glwe_sk_poly.as_ref());
+
+        generate_ntru_keyswitch_key(
+            &ntru_secret_key_poly,
+            output_ntru_secret_key,
+            &mut rlwe_to_ntru_keyswitch_key.get_mut_ntru_keyswitch_key(idx),
+            noise_distribution,
+            &mut generator,
+        );
+    }
+
+    let mut generator = gen_iter.next().unwrap();
+    generate_ntru_switching_key(
+        output_ntru_secret_key,
+        &mut rlwe_to_ntru_keyswitch_key.get_mut_ntru_switching_key(),
+        noise_distribution,
+        &mut generator,
+    );
+}
+
+pub fn allocate_and_generate_new_rlwe_to_ntru_keyswitch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+>(
+    input_glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> RlweToNtruKeyswitchKeyOwned<Scalar> {
+    let mut new_rlwe_to_ntru_keyswitch_key = RlweToNtruKeyswitchKeyOwned::new(
+        input_glwe_secret_key.glwe_dimension(),
+        output_ntru_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        output_ntru_secret_key.ciphertext_modulus(),
+    );
+
+    generate_rlwe_to_ntru_keyswitch_key(
+        input_glwe_secret_key,
+        output_ntru_secret_key,
+        &mut new_rlwe_to_ntru_keyswitch_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_rlwe_to_ntru_keyswitch_key
+}
+
+pub fn par_generate_rlwe_to_ntru_keyswitch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar> + Sync,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    KskCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    rlwe_to_ntru_keyswitch_key: &mut RlweToNtruKeyswitchKey<KskCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) {
+    assert!(
+        output_ntru_secret_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Only support power-of-two modulus currently.",
+    );
+
+    assert_eq!(
+        input_glwe_secret_key.glwe_dimension(),
+        rlwe_to_ntru_keyswitch_key.input_glwe_dimension(),
+    );
+
+    assert_eq!(
+        input_glwe_secret_key.polynomial_size(),
+        output_ntru_secret_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        output_ntru_secret_key.polynomial_size(),
+        rlwe_to_ntru_keyswitch_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        output_ntru_secret_key.ciphertext_modulus(),
+        rlwe_to_ntru_keyswitch_key.ciphertext_modulus(),
+    );
+
+    let input_glwe_dimension = input_glwe_secret_key.glwe_dimension();
+    let polynomial_size = rlwe_to_ntru_keyswitch_key.polynomial_size();
+    let decomp_base_log = rlwe_to_ntru_keyswitch_key.decomposition_base_log();
+    let ciphertext_modulus = rlwe_to_ntru_keyswitch_key.ciphertext_modulus();
+
+    let gen_iter = generator
+        .par_try_fork_from_config(rlwe_to_ntru_keyswitch_key.encryption_fork_config(noise_distribution))
+        .unwrap();
+
+    rlwe_to_ntru_keyswitch_key
+        .get_mut_ntru_keyswitch_key_list()
+        .par_iter_mut()
+        .enumerate()
+        .zip(gen_iter)
+        .for_each(|((idx, mut ntru_ksk), mut generator)| {
+            if idx < input_glwe_dimension.0 {
+                let mut ntru_secret_key_poly = NtruSecretKey::new_empty_key(
+                    Scalar::ZERO,
+                    polynomial_size,
+                    ciphertext_modulus,
+                );
+                ntru_secret_key_poly.as_mut()[..polynomial_size.0].copy_from_slice(
+                    input_glwe_secret_key.as_polynomial_list().get(idx).as_ref(),
+                );
+
+                generate_ntru_keyswitch_key(
+                    &ntru_secret_key_poly,
+                    output_ntru_secret_key,
+                    &mut ntru_ksk,
+                    noise_distribution,
+                    &mut generator,
+                );
+            } else {
+                let mut ntru_swk = NtruSwitchingKey::from_container(
+                    ntru_ksk.into_container(),
+                    polynomial_size,
+                    decomp_base_log,
+                    ciphertext_modulus,
+                );
+
+                generate_ntru_switching_key(
+                    output_ntru_secret_key,
+                    &mut ntru_swk,
+                    noise_distribution,
+                    &mut generator,
+                );
+            }
+        });
+}
+
+pub fn par_allocate_and_generate_new_rlwe_to_ntru_keyswitch_key<
+    Scalar: Encryptable<Uniform, NoiseDistribution> + Sync + Send,
+    NoiseDistribution: Distribution + Sync,
+    InputKeyCont: Container<Element = Scalar> + Sync,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+>(
+    input_glwe_secret_key: &GlweSecretKey<InputKeyCont>,
+    output_ntru_secret_key: &NtruSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> RlweToNtruKeyswitchKeyOwned<Scalar> {
+    let mut new_rlwe_to_ntru_keyswitch_key = RlweToNtruKeyswitchKeyOwned::new(
+        input_glwe_secret_key.glwe_dimension(),
+        output_ntru_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        output_ntru_secret_key.ciphertext_modulus(),
+    );
+
+    par_generate_rlwe_to_ntru_keyswitch_key(
+        input_glwe_secret_key,
+        output_ntru_secret_key,
+        &mut new_rlwe_to_ntru_keyswitch_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_rlwe_to_ntru_keyswitch_key
+}
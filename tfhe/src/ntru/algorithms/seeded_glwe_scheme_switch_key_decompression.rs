@@ -0,0 +1,42 @@
+//! Module with primitives pertaining to [`SeededGlweSchemeSwitchKey`] decompression.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::traits::*;
+use crate::ntru::entities::*;
+
+/// Decompress a [`SeededGlweSchemeSwitchKey`], without consuming it, into a standard
+/// [`GlweSchemeSwitchKey`].
+pub fn decompress_seeded_glwe_scheme_switch_key<Scalar, InputCont, OutputCont, Gen>(
+    output_ss_key: &mut GlweSchemeSwitchKey<OutputCont>,
+    input_seeded_ss_key: &SeededGlweSchemeSwitchKey<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_ss_key.glwe_size(),
+        input_seeded_ss_key.glwe_size(),
+    );
+    assert_eq!(
+        output_ss_key.polynomial_size(),
+        input_seeded_ss_key.polynomial_size(),
+    );
+    assert_eq!(
+        output_ss_key.decomposition_base_log(),
+        input_seeded_ss_key.decomposition_base_log(),
+    );
+    assert_eq!(
+        output_ss_key.decomposition_level_count(),
+        input_seeded_ss_key.decomposition_level_count(),
+    );
+
+    let mut generator = MaskRandomGenerator::<Gen>::new(input_seeded_ss_key.compression_seed().seed);
+    decompress_seeded_glwe_ciphertext_list_with_pre_seeded_generator::<_, _, _, Gen>(
+        &mut output_ss_key.as_mut_glwe_ciphertext_list(),
+        &input_seeded_ss_key.as_seeded_glwe_ciphertext_list(),
+        &mut generator,
+    );
+}
@@ -0,0 +1,56 @@
+//! Module with primitives pertaining to [`SeededNtruCMuxCircuitBootstrapKey`] decompression.
+
+use crate::core_crypto::commons::traits::*;
+use crate::ntru::algorithms::*;
+use crate::ntru::entities::*;
+
+/// Decompress a [`SeededNtruCMuxCircuitBootstrapKey`], without consuming it, into a standard
+/// [`NtruCMuxCircuitBootstrapKey`].
+pub fn decompress_seeded_ntru_cmux_circuit_bootstrap_key<Scalar, InputCont, OutputCont, Gen>(
+    output_cbs_key: &mut NtruCMuxCircuitBootstrapKey<OutputCont>,
+    input_seeded_cbs_key: &SeededNtruCMuxCircuitBootstrapKey<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_cbs_key.input_lwe_dimension(),
+        input_seeded_cbs_key.input_lwe_dimension(),
+    );
+    assert_eq!(
+        output_cbs_key.polynomial_size(),
+        input_seeded_cbs_key.polynomial_size(),
+    );
+    assert_eq!(
+        output_cbs_key.ciphertext_modulus(),
+        input_seeded_cbs_key.ciphertext_modulus(),
+    );
+
+    let input_bsk = input_seeded_cbs_key.get_ntru_cmux_bootstrap_key();
+    let mut output_bsk = output_cbs_key.get_mut_ntru_cmux_bootstrap_key();
+    output_bsk.get_mut_ngsw_list().as_mut().copy_from_slice(input_bsk.get_ngsw_list().as_ref());
+    output_bsk.get_mut_ntru_switching_key().as_mut().copy_from_slice(input_bsk.get_ntru_switching_key().as_ref());
+
+    let input_trace_key = input_seeded_cbs_key.get_ntru_trace_key();
+    let mut output_trace_key = output_cbs_key.get_mut_ntru_trace_key();
+    assert_eq!(
+        output_trace_key.automorphism_key_count(),
+        input_trace_key.automorphism_key_count(),
+    );
+    for idx in 0..input_trace_key.automorphism_key_count().0 {
+        output_trace_key.get_mut_automorphism_key(idx).as_mut()
+            .copy_from_slice(input_trace_key.get_automorphism_key(idx).as_ref());
+    }
+
+    decompress_seeded_ntru_to_glwe_keyswitch_key::<_, _, _, Gen>(
+        &mut output_cbs_key.get_mut_ntru_to_glwe_keyswitch_key(),
+        &input_seeded_cbs_key.get_seeded_ntru_to_glwe_keyswitch_key(),
+    );
+
+    decompress_seeded_glwe_scheme_switch_key::<_, _, _, Gen>(
+        &mut output_cbs_key.get_mut_glwe_scheme_switch_key(),
+        &input_seeded_cbs_key.get_seeded_glwe_scheme_switch_key(),
+    );
+}
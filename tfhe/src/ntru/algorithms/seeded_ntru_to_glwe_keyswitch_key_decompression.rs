@@ -0,0 +1,42 @@
+//! Module with primitives pertaining to [`SeededNtruToGlweKeyswitchKey`] decompression.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::traits::*;
+use crate::ntru::entities::*;
+
+/// Decompress a [`SeededNtruToGlweKeyswitchKey`], without consuming it, into a standard
+/// [`NtruToGlweKeyswitchKey`].
+pub fn decompress_seeded_ntru_to_glwe_keyswitch_key<Scalar, InputCont, OutputCont, Gen>(
+    output_ksk: &mut NtruToGlweKeyswitchKey<OutputCont>,
+    input_seeded_ksk: &SeededNtruToGlweKeyswitchKey<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_ksk.glwe_size(),
+        input_seeded_ksk.glwe_size(),
+    );
+    assert_eq!(
+        output_ksk.polynomial_size(),
+        input_seeded_ksk.polynomial_size(),
+    );
+    assert_eq!(
+        output_ksk.decomposition_base_log(),
+        input_seeded_ksk.decomposition_base_log(),
+    );
+    assert_eq!(
+        output_ksk.decomposition_level_count(),
+        input_seeded_ksk.decomposition_level_count(),
+    );
+
+    let mut generator = MaskRandomGenerator::<Gen>::new(input_seeded_ksk.compression_seed().seed);
+    decompress_seeded_glwe_ciphertext_list_with_pre_seeded_generator::<_, _, _, Gen>(
+        &mut output_ksk.as_mut_glwe_ciphertext_list(),
+        &input_seeded_ksk.as_seeded_glwe_ciphertext_list(),
+        &mut generator,
+    );
+}
@@ -0,0 +1,9 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::Fourier128NgswCiphertextList;
+
+#[derive(VersionsDispatch)]
+pub enum Fourier128NgswCiphertextListVersions<C: Container<Element = f64>> {
+    V0(Fourier128NgswCiphertextList<C>),
+}
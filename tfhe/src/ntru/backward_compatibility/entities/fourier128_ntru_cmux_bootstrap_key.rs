@@ -0,0 +1,9 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::Fourier128NtruCMuxBootstrapKey;
+
+#[derive(VersionsDispatch)]
+pub enum Fourier128NtruCMuxBootstrapKeyVersions<C: Container<Element = f64>> {
+    V0(Fourier128NtruCMuxBootstrapKey<C>),
+}
@@ -0,0 +1,9 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::Fourier128NtruKeyswitchKey;
+
+#[derive(VersionsDispatch)]
+pub enum Fourier128NtruKeyswitchKeyVersions<C: Container<Element = f64>> {
+    V0(Fourier128NtruKeyswitchKey<C>),
+}
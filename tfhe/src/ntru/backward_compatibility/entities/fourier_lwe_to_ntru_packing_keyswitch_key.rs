@@ -0,0 +1,11 @@
+use tfhe_versionable::VersionsDispatch;
+
+use tfhe_fft::c64;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::FourierLweToNtruPackingKeyswitchKey;
+
+#[derive(VersionsDispatch)]
+pub enum FourierLweToNtruPackingKeyswitchKeyVersions<C: Container<Element = c64>> {
+    V0(FourierLweToNtruPackingKeyswitchKey<C>),
+}
@@ -0,0 +1,16 @@
+use tfhe_versionable::VersionsDispatch;
+
+use tfhe_fft::c64;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::{FftType, FourierNgswCiphertext};
+
+#[derive(VersionsDispatch)]
+pub enum FourierNgswCiphertextVersions<C: Container<Element = c64>> {
+    V0(FourierNgswCiphertext<C>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum FftTypeVersions {
+    V0(FftType),
+}
@@ -0,0 +1,11 @@
+use tfhe_versionable::VersionsDispatch;
+
+use tfhe_fft::c64;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::FourierNtruAutomorphismBootstrapKey;
+
+#[derive(VersionsDispatch)]
+pub enum FourierNtruAutomorphismBootstrapKeyVersions<C: Container<Element = c64>> {
+    V0(FourierNtruAutomorphismBootstrapKey<C>),
+}
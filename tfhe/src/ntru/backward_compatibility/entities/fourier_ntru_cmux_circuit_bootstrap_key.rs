@@ -0,0 +1,11 @@
+use tfhe_versionable::VersionsDispatch;
+
+use tfhe_fft::c64;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::FourierNtruCMuxCircuitBootstrapKey;
+
+#[derive(VersionsDispatch)]
+pub enum FourierNtruCMuxCircuitBootstrapKeyVersions<C: Container<Element = c64>> {
+    V0(FourierNtruCMuxCircuitBootstrapKey<C>),
+}
@@ -0,0 +1,11 @@
+use tfhe_versionable::VersionsDispatch;
+
+use tfhe_fft::c64;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::FourierNtruKeyswitchKeyList;
+
+#[derive(VersionsDispatch)]
+pub enum FourierNtruKeyswitchKeyListVersions<C: Container<Element = c64>> {
+    V0(FourierNtruKeyswitchKeyList<C>),
+}
@@ -0,0 +1,11 @@
+use tfhe_versionable::VersionsDispatch;
+
+use tfhe_fft::c64;
+
+use crate::core_crypto::prelude::Container;
+use crate::ntru::entities::FourierNtruSwitchingKey;
+
+#[derive(VersionsDispatch)]
+pub enum FourierNtruSwitchingKeyVersions<C: Container<Element = c64>> {
+    V0(FourierNtruSwitchingKey<C>),
+}
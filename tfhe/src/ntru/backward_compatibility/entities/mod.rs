@@ -0,0 +1,49 @@
+pub mod fourier128_ngsw_ciphertext;
+pub mod fourier128_ngsw_ciphertext_list;
+pub mod fourier128_ntru_cmux_bootstrap_key;
+pub mod fourier128_ntru_keyswitch_key;
+pub mod fourier_lwe_to_ntru_packing_keyswitch_key;
+pub mod fourier_ngsw_ciphertext;
+pub mod fourier_ngsw_ciphertext_list;
+pub mod fourier_ntru_automorphism_bootstrap_key;
+pub mod fourier_ntru_automorphism_key;
+pub mod fourier_ntru_cmux_bootstrap_key;
+pub mod fourier_ntru_multi_bit_bootstrap_key;
+pub mod fourier_ntru_cmux_circuit_bootstrap_key;
+pub mod fourier_ntru_cmux_ngsw_circuit_bootstrap_key;
+pub mod fourier_ntru_keyswitch_key;
+pub mod fourier_ntru_keyswitch_key_list;
+pub mod fourier_ntru_scheme_switch_key;
+pub mod fourier_ntru_switching_key;
+pub mod fourier_ntru_to_glwe_keyswitch_key;
+pub mod fourier_ntru_trace_key;
+pub mod fourier_rlwe_to_ntru_keyswitch_key;
+pub mod fourier_glwe_scheme_switch_key;
+pub mod ngsw_ciphertext;
+pub mod ngsw_ciphertext_list;
+pub mod ntru_automorphism_bootstrap_key;
+pub mod ntru_automorphism_key;
+pub mod ntru_ciphertext;
+pub mod ntru_ciphertext_list;
+pub mod ntru_cmux_bootstrap_key;
+pub mod ntru_multi_bit_bootstrap_key;
+pub mod ntru_cmux_circuit_bootstrap_key;
+pub mod ntru_cmux_ngsw_circuit_bootstrap_key;
+pub mod ntru_keyswitch_key;
+pub mod ntru_keyswitch_key_list;
+pub mod ntru_public_key;
+pub mod ntru_scheme_switch_key;
+pub mod ntru_secret_key;
+pub mod ntru_switching_key;
+pub mod ntru_to_glwe_keyswitch_key;
+pub mod ntru_trace_key;
+pub mod ntt_ngsw_ciphertext;
+pub mod ntt_ngsw_ciphertext_list;
+pub mod ntt_ntru_cmux_bootstrap_key;
+pub mod ntt_ntru_keyswitch_key;
+pub mod rlwe_to_ntru_keyswitch_key;
+pub mod glwe_scheme_switch_key;
+pub mod lwe_to_ntru_packing_keyswitch_key;
+pub mod seeded_ntru_cmux_circuit_bootstrap_key;
+pub mod seeded_ntru_to_glwe_keyswitch_key;
+pub mod seeded_glwe_scheme_switch_key;
@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::NtruAutomorphismBootstrapKey;
+
+#[derive(VersionsDispatch)]
+pub enum NtruAutomorphismBootstrapKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(NtruAutomorphismBootstrapKey<C>),
+}
@@ -0,0 +1,17 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::{AutomorphismIndex, NtruAutomorphismKey};
+
+#[derive(VersionsDispatch)]
+pub enum NtruAutomorphismKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(NtruAutomorphismKey<C>),
+}
+
+#[derive(VersionsDispatch)]
+pub enum AutomorphismIndexVersions {
+    V0(AutomorphismIndex),
+}
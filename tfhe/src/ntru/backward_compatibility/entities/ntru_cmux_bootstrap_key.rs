@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::NtruCMuxBootstrapKey;
+
+#[derive(VersionsDispatch)]
+pub enum NtruCMuxBootstrapKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(NtruCMuxBootstrapKey<C>),
+}
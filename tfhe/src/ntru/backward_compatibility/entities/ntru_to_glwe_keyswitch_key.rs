@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::NtruToGlweKeyswitchKey;
+
+#[derive(VersionsDispatch)]
+pub enum NtruToGlweKeyswitchKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(NtruToGlweKeyswitchKey<C>),
+}
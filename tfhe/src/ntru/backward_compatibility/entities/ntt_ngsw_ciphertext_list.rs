@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::NttNgswCiphertextList;
+
+#[derive(VersionsDispatch)]
+pub enum NttNgswCiphertextListVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(NttNgswCiphertextList<C>),
+}
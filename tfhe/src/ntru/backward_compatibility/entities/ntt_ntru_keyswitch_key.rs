@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::NttNtruKeyswitchKey;
+
+#[derive(VersionsDispatch)]
+pub enum NttNtruKeyswitchKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(NttNtruKeyswitchKey<C>),
+}
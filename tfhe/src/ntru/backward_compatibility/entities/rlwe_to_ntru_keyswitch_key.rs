@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::RlweToNtruKeyswitchKey;
+
+#[derive(VersionsDispatch)]
+pub enum RlweToNtruKeyswitchKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(RlweToNtruKeyswitchKey<C>),
+}
@@ -0,0 +1,12 @@
+use tfhe_versionable::VersionsDispatch;
+
+use crate::core_crypto::prelude::{Container, UnsignedInteger};
+use crate::ntru::entities::SeededGlweSchemeSwitchKey;
+
+#[derive(VersionsDispatch)]
+pub enum SeededGlweSchemeSwitchKeyVersions<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    V0(SeededGlweSchemeSwitchKey<C>),
+}
@@ -0,0 +1,4 @@
+// Backward compatibility types should not be themselves versioned
+#![cfg_attr(dylint_lib = "tfhe_lints", allow(serialize_without_versionize))]
+
+pub mod entities;
@@ -67,19 +67,27 @@ impl AutomorphismGroup {
 
         let lwe_mask = input_lwe.get_mask();
 
-        let log_delta_ms = C::Element::BITS - self.polynomial_size.0.ilog2() as usize;
-        let delta_ms = 1usize << log_delta_ms;
-        // let delta_ms = C::Element::ONE << log_delta_ms;
+        let modulus = 2 * self.polynomial_size.0;
+        let log_modulus = modulus.ilog2() as usize;
 
         for (i, elem) in lwe_mask.as_ref().iter().enumerate() {
-            // Modulus switch to N
+            // Modulus switch to the nearest odd integer mod 2N
             let elem:usize = (*elem).cast_into();
-            let rounding = (elem & (delta_ms >> 1)) << 1;
-            let elem = (elem.wrapping_add(rounding) << (usize::BITS as usize - C::Element::BITS)) >> (usize::BITS - self.polynomial_size.0.ilog2()) as usize;
-
+            let elem = elem << (usize::BITS as usize - C::Element::BITS);
+            let elem_msb = elem >> (usize::BITS as usize - log_modulus);
+            let elem_lsb = elem << log_modulus;
+
+            // Ties, i.e., even integers, are rounded up and down alternately to avoid any bias
+            let elem = if elem_msb % 2 == 1 || elem_lsb != 0 {
+                elem_msb | 1
+            } else if i % 2 == 0 {
+                elem_msb + 1
+            } else {
+                (elem_msb + modulus - 1) % modulus
+            };
 
             // Check with odd input mod 2N
-            let (index, is_pos) = self.get_index(2 * elem + 1);
+            let (index, is_pos) = self.get_index(elem);
             if is_pos {
                 lwe_index_set_pos[index].push(i);
             } else {
@@ -0,0 +1,186 @@
+//! Module containing the definition of the Fourier128NgswCiphertext.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier128_ngsw_ciphertext::Fourier128NgswCiphertextVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::fft_impl::fft128::crypto::ggsw::Fourier128GgswCiphertext;
+use crate::core_crypto::fft_impl::fft128::math::fft::Fft128View;
+use crate::ntru::entities::*;
+
+use aligned_vec::{avec, ABox};
+
+/// An [`NGSW ciphertext`](`crate::ntru::entities::NgswCiphertext`) in the 128-bit Fourier domain,
+/// for use with 128-bit ciphertext moduli.
+///
+/// Each polynomial is stored as four `f64` halves (real/imaginary, high/low), following the
+/// layout of [`Fourier128GgswCiphertext`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(Fourier128NgswCiphertextVersions)]
+pub struct Fourier128NgswCiphertext<C: Container<Element = f64>> {
+    data_re0: C,
+    data_re1: C,
+    data_im0: C,
+    data_im1: C,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+}
+
+impl<C: Container<Element = f64>> Named for Fourier128NgswCiphertext<C> {
+    const NAME: &'static str = "ntru::Fourier128NgswCiphertext";
+}
+
+pub type Fourier128NgswCiphertextOwned = Fourier128NgswCiphertext<ABox<[f64]>>;
+pub type Fourier128NgswCiphertextView<'a> = Fourier128NgswCiphertext<&'a [f64]>;
+pub type Fourier128NgswCiphertextMutView<'a> = Fourier128NgswCiphertext<&'a mut [f64]>;
+
+pub fn fourier128_ngsw_ciphertext_size(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+) -> usize {
+    polynomial_size.to_fourier_polynomial_size().0 * decomp_level_count.0
+}
+
+impl<C: Container<Element = f64>> Fourier128NgswCiphertext<C> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_container(
+        data_re0: C,
+        data_re1: C,
+        data_im0: C,
+        data_im1: C,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        let container_len = fourier128_ngsw_ciphertext_size(polynomial_size, decomp_level_count);
+        assert_eq!(data_re0.container_len(), container_len);
+        assert_eq!(data_re1.container_len(), container_len);
+        assert_eq!(data_im0.container_len(), container_len);
+        assert_eq!(data_im1.container_len(), container_len);
+
+        Self {
+            data_re0,
+            data_re1,
+            data_im0,
+            data_im1,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    pub fn data(self) -> (C, C, C, C) {
+        (self.data_re0, self.data_re1, self.data_im0, self.data_im1)
+    }
+
+    pub fn as_view(&self) -> Fourier128NgswCiphertextView<'_> {
+        Fourier128NgswCiphertext {
+            data_re0: self.data_re0.as_ref(),
+            data_re1: self.data_re1.as_ref(),
+            data_im0: self.data_im0.as_ref(),
+            data_im1: self.data_im1.as_ref(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+        }
+    }
+
+    /// Return a view of the NGSW ciphertext as a [`Fourier128GgswCiphertext`] with a GLWE size of
+    /// one, which has exactly the same layout.
+    pub fn as_fourier128_ggsw_ciphertext(&self) -> Fourier128GgswCiphertext<&[f64]> {
+        Fourier128GgswCiphertext::from_container(
+            self.data_re0.as_ref(),
+            self.data_re1.as_ref(),
+            self.data_im0.as_ref(),
+            self.data_im1.as_ref(),
+            self.polynomial_size,
+            GlweSize(1),
+            self.decomp_base_log,
+            self.decomp_level_count,
+        )
+    }
+}
+
+impl<C: ContainerMut<Element = f64>> Fourier128NgswCiphertext<C> {
+    pub fn as_mut_view(&mut self) -> Fourier128NgswCiphertextMutView<'_> {
+        Fourier128NgswCiphertext {
+            data_re0: self.data_re0.as_mut(),
+            data_re1: self.data_re1.as_mut(),
+            data_im0: self.data_im0.as_mut(),
+            data_im1: self.data_im1.as_mut(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+        }
+    }
+
+    pub fn fill_with_forward_fourier<Scalar, InputCont>(
+        &mut self,
+        coef_ngsw: &NgswCiphertext<InputCont>,
+        fft: Fft128View<'_>,
+    ) where
+        Scalar: UnsignedTorus,
+        InputCont: Container<Element = Scalar>,
+    {
+        assert_eq!(coef_ngsw.polynomial_size(), self.polynomial_size);
+        assert_eq!(coef_ngsw.decomposition_base_log(), self.decomp_base_log);
+        assert_eq!(coef_ngsw.decomposition_level_count(), self.decomp_level_count);
+
+        let fourier_poly_size = self.polynomial_size.to_fourier_polynomial_size().0;
+        let (data_re0, data_re1, data_im0, data_im1) = self.as_mut_view().data();
+
+        for (fourier_re0, fourier_re1, fourier_im0, fourier_im1, coef_poly) in izip!(
+            data_re0.chunks_exact_mut(fourier_poly_size),
+            data_re1.chunks_exact_mut(fourier_poly_size),
+            data_im0.chunks_exact_mut(fourier_poly_size),
+            data_im1.chunks_exact_mut(fourier_poly_size),
+            coef_ngsw.as_polynomial_list().iter()
+        ) {
+            fft.forward_as_torus(
+                fourier_re0,
+                fourier_re1,
+                fourier_im0,
+                fourier_im1,
+                coef_poly.as_ref(),
+            );
+        }
+    }
+}
+
+impl Fourier128NgswCiphertextOwned {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+    ) -> Self {
+        let container_len = fourier128_ngsw_ciphertext_size(polynomial_size, decomp_level_count);
+
+        Self::from_container(
+            avec![0.0f64; container_len].into_boxed_slice(),
+            avec![0.0f64; container_len].into_boxed_slice(),
+            avec![0.0f64; container_len].into_boxed_slice(),
+            avec![0.0f64; container_len].into_boxed_slice(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+        )
+    }
+}
@@ -0,0 +1,166 @@
+//! Module containing the definition of the Fourier128NgswCiphertextList.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier128_ngsw_ciphertext_list::Fourier128NgswCiphertextListVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::ntru::entities::*;
+
+use aligned_vec::{avec, ABox};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Fourier128NgswCiphertextCount(pub usize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(Fourier128NgswCiphertextListVersions)]
+pub struct Fourier128NgswCiphertextList<C: Container<Element = f64>> {
+    data_re0: C,
+    data_re1: C,
+    data_im0: C,
+    data_im1: C,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    count: usize,
+}
+
+impl<C: Container<Element = f64>> Named for Fourier128NgswCiphertextList<C> {
+    const NAME: &'static str = "ntru::Fourier128NgswCiphertextList";
+}
+
+pub type Fourier128NgswCiphertextListOwned = Fourier128NgswCiphertextList<ABox<[f64]>>;
+pub type Fourier128NgswCiphertextListView<'a> = Fourier128NgswCiphertextList<&'a [f64]>;
+pub type Fourier128NgswCiphertextListMutView<'a> = Fourier128NgswCiphertextList<&'a mut [f64]>;
+
+impl<C: Container<Element = f64>> Fourier128NgswCiphertextList<C> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_container(
+        data_re0: C,
+        data_re1: C,
+        data_im0: C,
+        data_im1: C,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        count: Fourier128NgswCiphertextCount,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        let container_len = count.0 * fourier128_ngsw_ciphertext_size(polynomial_size, decomp_level_count);
+        assert_eq!(data_re0.container_len(), container_len);
+        assert_eq!(data_re1.container_len(), container_len);
+        assert_eq!(data_im0.container_len(), container_len);
+        assert_eq!(data_im1.container_len(), container_len);
+
+        Self {
+            data_re0,
+            data_re1,
+            data_im0,
+            data_im1,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            count: count.0,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    pub fn ciphertext_count(&self) -> Fourier128NgswCiphertextCount {
+        Fourier128NgswCiphertextCount(self.count)
+    }
+
+    pub fn data(self) -> (C, C, C, C) {
+        (self.data_re0, self.data_re1, self.data_im0, self.data_im1)
+    }
+
+    pub fn as_view(&self) -> Fourier128NgswCiphertextListView<'_> {
+        Fourier128NgswCiphertextList {
+            data_re0: self.data_re0.as_ref(),
+            data_re1: self.data_re1.as_ref(),
+            data_im0: self.data_im0.as_ref(),
+            data_im1: self.data_im1.as_ref(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            count: self.count,
+        }
+    }
+
+    pub fn into_ngsw_iter(self) -> impl DoubleEndedIterator<Item = Fourier128NgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let count = self.count;
+
+        izip!(
+            self.data_re0.split_into(count),
+            self.data_re1.split_into(count),
+            self.data_im0.split_into(count),
+            self.data_im1.split_into(count),
+        )
+        .map(move |(data_re0, data_re1, data_im0, data_im1)| {
+            Fourier128NgswCiphertext::from_container(
+                data_re0,
+                data_re1,
+                data_im0,
+                data_im1,
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+            )
+        })
+    }
+}
+
+impl<C: ContainerMut<Element = f64>> Fourier128NgswCiphertextList<C> {
+    pub fn as_mut_view(&mut self) -> Fourier128NgswCiphertextListMutView<'_> {
+        Fourier128NgswCiphertextList {
+            data_re0: self.data_re0.as_mut(),
+            data_re1: self.data_re1.as_mut(),
+            data_im0: self.data_im0.as_mut(),
+            data_im1: self.data_im1.as_mut(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            count: self.count,
+        }
+    }
+}
+
+impl Fourier128NgswCiphertextListOwned {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        count: Fourier128NgswCiphertextCount,
+    ) -> Self {
+        let container_len = count.0 * fourier128_ngsw_ciphertext_size(polynomial_size, decomp_level_count);
+
+        Self::from_container(
+            avec![0.0f64; container_len].into_boxed_slice(),
+            avec![0.0f64; container_len].into_boxed_slice(),
+            avec![0.0f64; container_len].into_boxed_slice(),
+            avec![0.0f64; container_len].into_boxed_slice(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            count,
+        )
+    }
+}
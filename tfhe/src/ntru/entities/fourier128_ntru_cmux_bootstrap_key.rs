@@ -0,0 +1,99 @@
+//! Module containing the definition of the Fourier128NtruCMuxBootstrapKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier128_ntru_cmux_bootstrap_key::Fourier128NtruCMuxBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+
+/// An [`NtruCMuxBootstrapKey`] in the 128-bit Fourier domain, used to bootstrap into 128-bit
+/// ciphertext moduli.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(Fourier128NtruCMuxBootstrapKeyVersions)]
+pub struct Fourier128NtruCMuxBootstrapKey<C: Container<Element = f64>> {
+    fourier128_ngsw_list: Fourier128NgswCiphertextList<C>,
+    fourier128_ntru_switching_key: Fourier128NtruKeyswitchKey<C>,
+}
+
+impl<C: Container<Element = f64>> Named for Fourier128NtruCMuxBootstrapKey<C> {
+    const NAME: &'static str = "ntru::Fourier128NtruCMuxBootstrapKey";
+}
+
+pub type Fourier128NtruCMuxBootstrapKeyView<'a> = Fourier128NtruCMuxBootstrapKey<&'a [f64]>;
+pub type Fourier128NtruCMuxBootstrapKeyMutView<'a> = Fourier128NtruCMuxBootstrapKey<&'a mut [f64]>;
+pub type Fourier128NtruCMuxBootstrapKeyOwned = Fourier128NtruCMuxBootstrapKey<ABox<[f64]>>;
+
+impl<C: Container<Element = f64>> Fourier128NtruCMuxBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.fourier128_ngsw_list.ciphertext_count().0)
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.fourier128_ngsw_list.polynomial_size().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier128_ngsw_list.polynomial_size()
+    }
+
+    pub fn get_fourier128_ngsw_list(&self) -> Fourier128NgswCiphertextListView<'_> {
+        self.fourier128_ngsw_list.as_view()
+    }
+
+    pub fn get_fourier128_ntru_switching_key(&self) -> Fourier128NtruKeyswitchKeyView<'_> {
+        self.fourier128_ntru_switching_key.as_view()
+    }
+
+    pub fn as_view(&self) -> Fourier128NtruCMuxBootstrapKeyView<'_> {
+        Fourier128NtruCMuxBootstrapKeyView::<'_> {
+            fourier128_ngsw_list: self.fourier128_ngsw_list.as_view(),
+            fourier128_ntru_switching_key: self.fourier128_ntru_switching_key.as_view(),
+        }
+    }
+}
+
+impl<C: ContainerMut<Element = f64>> Fourier128NtruCMuxBootstrapKey<C> {
+    pub fn get_mut_fourier128_ngsw_list(&mut self) -> Fourier128NgswCiphertextListMutView<'_> {
+        self.fourier128_ngsw_list.as_mut_view()
+    }
+
+    pub fn get_mut_fourier128_ntru_switching_key(&mut self) -> Fourier128NtruKeyswitchKeyMutView<'_> {
+        self.fourier128_ntru_switching_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> Fourier128NtruCMuxBootstrapKeyMutView<'_> {
+        Fourier128NtruCMuxBootstrapKeyMutView::<'_> {
+            fourier128_ngsw_list: self.fourier128_ngsw_list.as_mut_view(),
+            fourier128_ntru_switching_key: self.fourier128_ntru_switching_key.as_mut_view(),
+        }
+    }
+}
+
+impl Fourier128NtruCMuxBootstrapKeyOwned {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        input_lwe_dimension: LweDimension,
+    ) -> Self {
+        Self {
+            fourier128_ngsw_list: Fourier128NgswCiphertextList::new(
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                Fourier128NgswCiphertextCount(input_lwe_dimension.0),
+            ),
+            fourier128_ntru_switching_key: Fourier128NtruKeyswitchKey::new(
+                polynomial_size,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+            ),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Module containing the definition of the Fourier128NtruKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier128_ntru_keyswitch_key::Fourier128NtruKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+
+/// An [`NtruKeyswitchKey`] in the 128-bit Fourier domain. It is also used as the switching key of
+/// 128-bit NTRU bootstrap keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(Fourier128NtruKeyswitchKeyVersions)]
+pub struct Fourier128NtruKeyswitchKey<C: Container<Element = f64>> {
+    fourier128_ngsw: Fourier128NgswCiphertext<C>,
+}
+
+impl<C: Container<Element = f64>> Named for Fourier128NtruKeyswitchKey<C> {
+    const NAME: &'static str = "ntru::Fourier128NtruKeyswitchKey";
+}
+
+pub type Fourier128NtruKeyswitchKeyOwned = Fourier128NtruKeyswitchKey<ABox<[f64]>>;
+pub type Fourier128NtruKeyswitchKeyView<'a> = Fourier128NtruKeyswitchKey<&'a [f64]>;
+pub type Fourier128NtruKeyswitchKeyMutView<'a> = Fourier128NtruKeyswitchKey<&'a mut [f64]>;
+
+impl<C: Container<Element = f64>> Fourier128NtruKeyswitchKey<C> {
+    pub fn from_fourier128_ngsw_ciphertext(fourier128_ngsw: Fourier128NgswCiphertext<C>) -> Self {
+        Self { fourier128_ngsw }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier128_ngsw.polynomial_size()
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.fourier128_ngsw.decomposition_base_log()
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.fourier128_ngsw.decomposition_level_count()
+    }
+
+    pub fn as_view(&self) -> Fourier128NtruKeyswitchKeyView<'_> {
+        Fourier128NtruKeyswitchKey {
+            fourier128_ngsw: self.fourier128_ngsw.as_view(),
+        }
+    }
+
+    pub fn as_fourier128_ngsw_ciphertext(&self) -> Fourier128NgswCiphertextView<'_> {
+        self.fourier128_ngsw.as_view()
+    }
+
+    pub fn into_fourier128_ngsw_ciphertext(self) -> Fourier128NgswCiphertext<C> {
+        self.fourier128_ngsw
+    }
+}
+
+impl<C: ContainerMut<Element = f64>> Fourier128NtruKeyswitchKey<C> {
+    pub fn as_mut_view(&mut self) -> Fourier128NtruKeyswitchKeyMutView<'_> {
+        Fourier128NtruKeyswitchKey {
+            fourier128_ngsw: self.fourier128_ngsw.as_mut_view(),
+        }
+    }
+
+    pub fn as_mut_fourier128_ngsw_ciphertext(&mut self) -> Fourier128NgswCiphertextMutView<'_> {
+        self.fourier128_ngsw.as_mut_view()
+    }
+}
+
+impl Fourier128NtruKeyswitchKeyOwned {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+    ) -> Self {
+        Self {
+            fourier128_ngsw: Fourier128NgswCiphertext::new(
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+            ),
+        }
+    }
+}
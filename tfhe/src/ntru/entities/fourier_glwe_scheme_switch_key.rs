@@ -0,0 +1,200 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_glwe_scheme_switch_key::FourierGlweSchemeSwitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::FourierPolynomialList;
+use crate::ntru::entities::*;
+
+use aligned_vec::{avec, ABox};
+use tfhe_fft::c64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierGlweSchemeSwitchKeyVersions)]
+pub struct FourierGlweSchemeSwitchKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
+    decomp_base_log: DecompositionBaseLog,
+    fft_type: FftType,
+}
+
+impl<C: Container<Element = c64>> Named for FourierGlweSchemeSwitchKey<C> {
+    const NAME: &'static str = "ntru::FourierGlweSchemeSwitchKey";
+}
+
+pub type FourierGlweSchemeSwitchKeyOwned = FourierGlweSchemeSwitchKey<ABox<[c64]>>;
+pub type FourierGlweSchemeSwitchKeyView<'a> = FourierGlweSchemeSwitchKey<&'a [c64]>;
+pub type FourierGlweSchemeSwitchKeyMutView<'a> = FourierGlweSchemeSwitchKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierGlweSchemeSwitchKey<C> {
+    pub fn from_container(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        fft_type: FftType,
+    ) -> Self {
+        assert!(
+            data.container_len() % (
+                glwe_scheme_switch_key_keyswitch_key_count(glwe_size)
+                    * polynomial_size.to_fourier_polynomial_size().0
+                    * fft_type.num_split() * glwe_size.0
+            ) == 0,
+            "The provided container length is not valid. \
+            It needs to be divisible by (glwe_size - 1)^2 * glwe_size * polynomial size / 2 * fft_type.num_split(). \
+            Got container length: {}, glwe size {:?}, polynomial size {:?}, fft_type: {:?}.",
+            data.container_len(),
+            glwe_size,
+            polynomial_size,
+            fft_type,
+        );
+
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            glwe_size,
+            decomp_base_log,
+            fft_type,
+        }
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(
+            self.fourier.data.container_len() / (
+                glwe_scheme_switch_key_keyswitch_key_count(self.glwe_size)
+                    * self.fourier.polynomial_size.to_fourier_polynomial_size().0
+                    * self.fft_type.num_split() * self.glwe_size.0
+            )
+        )
+    }
+
+    pub fn fft_type(&self) -> FftType {
+        self.fft_type
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    fn keyswitch_key_size(&self) -> usize {
+        self.fourier.data.container_len() / glwe_scheme_switch_key_keyswitch_key_count(self.glwe_size)
+    }
+
+    pub fn as_view(&self) -> FourierGlweSchemeSwitchKeyView<'_>
+    where
+        C: AsRef<[c64]>
+    {
+        FourierGlweSchemeSwitchKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomp_base_log: self.decomp_base_log,
+            fft_type: self.fft_type,
+        }
+    }
+
+    /// Return the keyswitch key of -S_i * S_j used to compute the i-th output row from the j-th
+    /// mask polynomial of the input.
+    pub fn get_fourier_ntru_to_glwe_keyswitch_key(
+        &self,
+        output_index: usize,
+        input_index: usize,
+    ) -> FourierNtruToGlweKeyswitchKeyView<'_> {
+        let glwe_dimension = self.glwe_size.to_glwe_dimension().0;
+        assert!(output_index < glwe_dimension && input_index < glwe_dimension);
+
+        let ksk_size = self.keyswitch_key_size();
+        let start = (output_index * glwe_dimension + input_index) * ksk_size;
+
+        FourierNtruToGlweKeyswitchKey::from_container(
+            &self.fourier.data.as_ref()[start..start + ksk_size],
+            self.glwe_size,
+            self.fourier.polynomial_size,
+            self.decomp_base_log,
+            self.fft_type,
+        )
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweSchemeSwitchKeyMutView<'_>
+    where
+        C: AsMut<[c64]>
+    {
+        FourierGlweSchemeSwitchKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            glwe_size: self.glwe_size,
+            decomp_base_log: self.decomp_base_log,
+            fft_type: self.fft_type,
+        }
+    }
+
+    pub fn get_mut_fourier_ntru_to_glwe_keyswitch_key(
+        &mut self,
+        output_index: usize,
+        input_index: usize,
+    ) -> FourierNtruToGlweKeyswitchKeyMutView<'_>
+    where
+        C: AsMut<[c64]>
+    {
+        let glwe_dimension = self.glwe_size.to_glwe_dimension().0;
+        assert!(output_index < glwe_dimension && input_index < glwe_dimension);
+
+        let ksk_size = self.keyswitch_key_size();
+        let start = (output_index * glwe_dimension + input_index) * ksk_size;
+
+        FourierNtruToGlweKeyswitchKey::from_container(
+            &mut self.fourier.data.as_mut()[start..start + ksk_size],
+            self.glwe_size,
+            self.fourier.polynomial_size,
+            self.decomp_base_log,
+            self.fft_type,
+        )
+    }
+}
+
+impl FourierGlweSchemeSwitchKeyOwned {
+    pub fn new(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        fft_type: FftType,
+    ) -> Self {
+        let boxed = avec![
+            c64::default();
+            glwe_scheme_switch_key_keyswitch_key_count(glwe_size)
+                * polynomial_size.to_fourier_polynomial_size().0
+                * decomp_level_count.0
+                * fft_type.num_split() * glwe_size.0
+        ]
+        .into_boxed_slice();
+
+        FourierGlweSchemeSwitchKey::from_container(
+            boxed,
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            fft_type,
+        )
+    }
+}
@@ -0,0 +1,120 @@
+//! Module containing the definition of the FourierLweToNtruPackingKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_lwe_to_ntru_packing_keyswitch_key::FourierLweToNtruPackingKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+use tfhe_fft::c64;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierLweToNtruPackingKeyswitchKeyVersions)]
+pub struct FourierLweToNtruPackingKeyswitchKey<C: Container<Element = c64>> {
+    fourier_rlwe_to_ntru_ksk: FourierRlweToNtruKeyswitchKey<C>,
+}
+
+impl<C: Container<Element = c64>> Named for FourierLweToNtruPackingKeyswitchKey<C> {
+    const NAME: &'static str = "ntru::FourierLweToNtruPackingKeyswitchKey";
+}
+
+pub type FourierLweToNtruPackingKeyswitchKeyView<'a> = FourierLweToNtruPackingKeyswitchKey<&'a [c64]>;
+pub type FourierLweToNtruPackingKeyswitchKeyMutView<'a> = FourierLweToNtruPackingKeyswitchKey<&'a mut [c64]>;
+pub type FourierLweToNtruPackingKeyswitchKeyOwned = FourierLweToNtruPackingKeyswitchKey<ABox<[c64]>>;
+
+impl<C: Container<Element = c64>> FourierLweToNtruPackingKeyswitchKey<C> {
+    pub fn get_ntru_keyswitch_key(&self, index: usize) -> FourierNtruKeyswitchKeyView<'_> {
+        let input_lwe_dimension = self.input_lwe_dimension().0;
+        assert!(
+            index < input_lwe_dimension,
+            "Input index {index} should be smaller than the input LWE dimension {input_lwe_dimension}",
+        );
+
+        self.fourier_rlwe_to_ntru_ksk.get_ntru_keyswitch_key(index)
+    }
+
+    pub fn get_ntru_switching_key(&self) -> FourierNtruSwitchingKeyView<'_> {
+        self.fourier_rlwe_to_ntru_ksk.get_ntru_switching_key()
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.fourier_rlwe_to_ntru_ksk.input_glwe_dimension().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier_rlwe_to_ntru_ksk.polynomial_size()
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.fourier_rlwe_to_ntru_ksk.decomposition_base_log()
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.fourier_rlwe_to_ntru_ksk.decomposition_level_count()
+    }
+
+    pub fn fft_type(&self) -> FftType {
+        self.fourier_rlwe_to_ntru_ksk.fft_type()
+    }
+
+    pub fn as_rlwe_to_ntru_keyswitch_key(&self) -> FourierRlweToNtruKeyswitchKeyView<'_> {
+        self.fourier_rlwe_to_ntru_ksk.as_view()
+    }
+
+    pub fn as_view(&self) -> FourierLweToNtruPackingKeyswitchKeyView<'_> {
+        FourierLweToNtruPackingKeyswitchKeyView::<'_> {
+            fourier_rlwe_to_ntru_ksk: self.fourier_rlwe_to_ntru_ksk.as_view(),
+        }
+    }
+}
+
+impl<C: ContainerMut<Element = c64>> FourierLweToNtruPackingKeyswitchKey<C> {
+    pub fn get_mut_ntru_keyswitch_key(&mut self, index: usize) -> FourierNtruKeyswitchKeyMutView<'_> {
+        let input_lwe_dimension = self.input_lwe_dimension().0;
+        assert!(
+            index < input_lwe_dimension,
+            "Input index {index} should be smaller than the input LWE dimension {input_lwe_dimension}",
+        );
+
+        self.fourier_rlwe_to_ntru_ksk.get_mut_ntru_keyswitch_key(index)
+    }
+
+    pub fn get_mut_ntru_switching_key(&mut self) -> FourierNtruSwitchingKeyMutView<'_> {
+        self.fourier_rlwe_to_ntru_ksk.get_mut_ntru_switching_key()
+    }
+
+    pub fn as_mut_rlwe_to_ntru_keyswitch_key(&mut self) -> FourierRlweToNtruKeyswitchKeyMutView<'_> {
+        self.fourier_rlwe_to_ntru_ksk.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierLweToNtruPackingKeyswitchKeyMutView<'_> {
+        FourierLweToNtruPackingKeyswitchKeyMutView::<'_> {
+            fourier_rlwe_to_ntru_ksk: self.fourier_rlwe_to_ntru_ksk.as_mut_view(),
+        }
+    }
+}
+
+impl FourierLweToNtruPackingKeyswitchKeyOwned {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        fft_type: FftType,
+    ) -> Self {
+        Self {
+            fourier_rlwe_to_ntru_ksk: FourierRlweToNtruKeyswitchKey::new(
+                GlweDimension(input_lwe_dimension.0),
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+                fft_type,
+            ),
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ngsw_ciphertext::{FftTypeVersions, FourierNgswCiphertextVersions};
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::commons::parameters::*;
@@ -11,36 +15,107 @@ use dyn_stack::PodStack;
 use aligned_vec::{avec, ABox};
 use tfhe_fft::c64;
 
-#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[derive(Clone, Debug, Copy, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(FftTypeVersions)]
 pub enum FftType {
     Vanilla,
+    /// Two-way split with the given split base log, same as `SplitN(split_base_log, 2)`.
+    #[deprecated(note = "use `FftType::SplitN(split_base_log, 2)` instead")]
     Split(usize),
+    /// Split of the key given as (split_base_log, num_split): every part but the most significant
+    /// one holds split_base_log bits of the key.
+    SplitN(usize, usize),
+}
+
+// Split(b) and SplitN(b, 2) describe the same FFT type
+impl PartialEq for FftType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Vanilla, Self::Vanilla) => true,
+            (Self::Vanilla, _) | (_, Self::Vanilla) => false,
+            _ => {
+                self.split_base_log() == other.split_base_log()
+                    && self.num_split() == other.num_split()
+            }
+        }
+    }
 }
 
 impl FftType {
+    /// Two-way split with the given split base log, i.e., `SplitN(split_base_log, 2)`.
+    pub const fn split(split_base_log: usize) -> Self {
+        Self::SplitN(split_base_log, 2)
+    }
+
+    #[allow(deprecated)]
     pub fn num_split(&self) -> usize {
         match self {
-            FftType::Vanilla => 1,
-            FftType::Split(_) => 2,
+            Self::Vanilla => 1,
+            Self::Split(_) => 2,
+            Self::SplitN(_, num_split) => *num_split,
         }
     }
 
+    #[allow(deprecated)]
     pub fn split_base_log(&self) -> usize {
         match self {
-            FftType::Vanilla => 0,
-            FftType::Split(b) => *b,
+            Self::Vanilla => 0,
+            Self::Split(b) => *b,
+            Self::SplitN(b, _) => *b,
         }
     }
-}
 
+    /// Writes in `output` the part of index `split_idx` of `input`, starting from the least
+    /// significant one, for a power-of-two modulus 2^log_modulus. The parts are kept in the MSBs
+    /// like the input so that the sum of part i times 2^(i * split_base_log) gives back the input.
+    pub fn fill_with_split_part<Scalar: UnsignedInteger>(
+        &self,
+        input: &[Scalar],
+        output: &mut [Scalar],
+        split_idx: usize,
+        log_modulus: usize,
+    ) {
+        assert_eq!(input.len(), output.len());
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        let num_split = self.num_split();
+        let b = self.split_base_log();
+        assert!(split_idx < num_split);
+        assert!(
+            num_split == 1 || (b > 0 && log_modulus > b * (num_split - 1)),
+            "Log of ciphertext modulus should be greater than split_base_log * (num_split - 1). \
+            Log of ciphertext modulus is {log_modulus}, and FFT type is {self:?}.",
+        );
+
+        let log_torus_scaling = Scalar::BITS - log_modulus;
+        let rsh_bit = log_torus_scaling + b * split_idx;
+        if split_idx == num_split - 1 {
+            let lsh_bit = log_torus_scaling;
+            for (&input_coeff, output_coeff) in input.iter().zip(output.iter_mut()) {
+                *output_coeff = (input_coeff >> rsh_bit) << lsh_bit;
+            }
+        } else {
+            let mask_bit = Scalar::BITS - b;
+            let lsh_bit = log_modulus - b;
+            for (&input_coeff, output_coeff) in input.iter().zip(output.iter_mut()) {
+                *output_coeff = ((input_coeff >> rsh_bit) << mask_bit) >> lsh_bit;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNgswCiphertextVersions)]
 pub struct FourierNgswCiphertext<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     decomp_base_log: DecompositionBaseLog,
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNgswCiphertext<C> {
+    const NAME: &'static str = "ntru::FourierNgswCiphertext";
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FourierNgswSplitBlock<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
@@ -288,8 +363,6 @@ impl FourierNgswCiphertextMutView<'_> {
         let polynomial_size = self.polynomial_size();
         let fourier_poly_size = polynomial_size.to_fourier_polynomial_size().0;
         let log_modulus = standard_ngsw.ciphertext_modulus().into_modulus_log().0;
-        let log_torus_scaling = Scalar::BITS - log_modulus;
-
         let fft_type = self.fft_type;
         let mut poly_buffer = Polynomial::new(Scalar::ZERO, polynomial_size);
 
@@ -306,7 +379,7 @@ impl FourierNgswCiphertextMutView<'_> {
                     );
                 }
             },
-            FftType::Split(b) => {
+            _ => {
                 self.data().split_into(fft_type.num_split())
                     .enumerate()
                     .for_each(|(split_idx, split_fourier)| {
@@ -314,34 +387,18 @@ impl FourierNgswCiphertextMutView<'_> {
                             split_fourier.into_chunks(fourier_poly_size),
                             standard_ngsw.as_polynomial_list().iter(),
                         ) {
-                            if split_idx == 0 {
-                                let shift_bit = log_modulus - b;
-                                for (standard_coeff, split_coeff)
-                                    in standard_poly.iter().zip(poly_buffer.iter_mut())
-                                {
-                                    *split_coeff = ((*standard_coeff) << shift_bit) >> shift_bit;
-                                }
-
-                                fft.forward_as_torus(
-                                    FourierPolynomialMutView { data: fourier_poly },
-                                    poly_buffer.as_view(),
-                                    stack,
-                                );
-                            } else { // split_idx == 1
-                                let rsh_bit = log_torus_scaling + b;
-                                let lhs_bit = log_torus_scaling;
-                                for (standard_coeff, split_coeff)
-                                    in standard_poly.iter().zip(poly_buffer.iter_mut())
-                                {
-                                    *split_coeff = ((*standard_coeff) >> rsh_bit) << lhs_bit;
-                                }
-
-                                fft.forward_as_torus(
-                                    FourierPolynomialMutView { data: fourier_poly },
-                                    poly_buffer.as_view(),
-                                    stack,
-                                );
-                            }
+                            fft_type.fill_with_split_part(
+                                standard_poly.as_ref(),
+                                poly_buffer.as_mut(),
+                                split_idx,
+                                log_modulus,
+                            );
+
+                            fft.forward_as_torus(
+                                FourierPolynomialMutView { data: fourier_poly },
+                                poly_buffer.as_view(),
+                                stack,
+                            );
                         }
                     });
             },
@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ngsw_ciphertext_list::FourierNgswCiphertextListVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::FourierPolynomialList;
@@ -9,7 +13,9 @@ use tfhe_fft::c64;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FourierNgswCiphertextCount(pub usize);
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNgswCiphertextListVersions)]
 pub struct FourierNgswCiphertextList<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     decomp_base_log: DecompositionBaseLog,
@@ -17,6 +23,10 @@ pub struct FourierNgswCiphertextList<C: Container<Element = c64>> {
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNgswCiphertextList<C> {
+    const NAME: &'static str = "ntru::FourierNgswCiphertextList";
+}
+
 impl<C: Container<Element = c64>> AsRef<[c64]> for FourierNgswCiphertextList<C> {
     fn as_ref(&self) -> &[c64] {
         self.fourier.data.as_ref()
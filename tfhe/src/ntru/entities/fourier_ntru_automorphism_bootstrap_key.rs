@@ -0,0 +1,189 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_automorphism_bootstrap_key::FourierNtruAutomorphismBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+use tfhe_fft::c64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruAutomorphismBootstrapKeyVersions)]
+pub struct FourierNtruAutomorphismBootstrapKey<C: Container<Element = c64>> {
+    fourier_ngsw_list: FourierNgswCiphertextList<C>,
+    fourier_ntru_auto_keys: FourierNtruKeyswitchKeyList<C>,
+    fourier_ntru_switching_key: FourierNtruSwitchingKey<C>,
+}
+
+impl<C: Container<Element = c64>> Named for FourierNtruAutomorphismBootstrapKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruAutomorphismBootstrapKey";
+}
+
+pub type FourierNtruAutomorphismBootstrapKeyView<'a> = FourierNtruAutomorphismBootstrapKey<&'a [c64]>;
+pub type FourierNtruAutomorphismBootstrapKeyMutView<'a> = FourierNtruAutomorphismBootstrapKey<&'a mut [c64]>;
+pub type FourierNtruAutomorphismBootstrapKeyOwned = FourierNtruAutomorphismBootstrapKey<ABox<[c64]>>;
+
+impl<C: Container<Element = c64>> FourierNtruAutomorphismBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.fourier_ngsw_list.ciphertext_count().0)
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.fourier_ngsw_list.polynomial_size().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier_ngsw_list.polynomial_size()
+    }
+
+    pub fn window_size(&self) -> AutomorphismWindowSize {
+        AutomorphismWindowSize(self.automorphism_key_count().0 - 1)
+    }
+
+    pub fn automorphism_key_count(&self) -> FourierNtruKeyswitchKeyCount {
+        self.fourier_ntru_auto_keys.fourier_ntru_keyswitch_key_count()
+    }
+
+    pub fn br_fft_type(&self) -> FftType {
+        self.fourier_ngsw_list.fft_type()
+    }
+
+    pub fn auto_fft_type(&self) -> FftType {
+        self.fourier_ntru_auto_keys.fft_type()
+    }
+
+    pub fn swk_fft_type(&self) -> FftType {
+        self.fourier_ntru_switching_key.fft_type()
+    }
+
+    pub fn get_fourier_ngsw_list(&self) -> FourierNgswCiphertextListView<'_> {
+        self.fourier_ngsw_list.as_view()
+    }
+
+    pub fn get_fourier_automorphism_key(&self, index: usize) -> FourierNtruAutomorphismKeyView<'_> {
+        let automorphism_key_count = self.automorphism_key_count().0;
+        assert!(
+            index < automorphism_key_count,
+            "Input index {} should be smaller than the number of automorphism keys {}",
+            index,
+            automorphism_key_count,
+        );
+
+        let auto_index = automorphism_bootstrap_key_automorphism_index(
+            self.polynomial_size(),
+            self.window_size(),
+            index,
+        );
+        FourierNtruAutomorphismKey::from_container(
+            self.fourier_ntru_auto_keys.get(index).data(),
+            auto_index,
+            self.fourier_ntru_auto_keys.polynomial_size(),
+            self.fourier_ntru_auto_keys.decomposition_base_log(),
+            self.fourier_ntru_auto_keys.fft_type(),
+        )
+    }
+
+    pub fn get_fourier_ntru_switching_key(&self) -> FourierNtruSwitchingKeyView<'_> {
+        self.fourier_ntru_switching_key.as_view()
+    }
+
+    pub fn as_view(&self) -> FourierNtruAutomorphismBootstrapKeyView<'_> {
+        FourierNtruAutomorphismBootstrapKeyView::<'_> {
+            fourier_ngsw_list: self.fourier_ngsw_list.as_view(),
+            fourier_ntru_auto_keys: self.fourier_ntru_auto_keys.as_view(),
+            fourier_ntru_switching_key: self.fourier_ntru_switching_key.as_view(),
+        }
+    }
+}
+
+impl<C: ContainerMut<Element = c64>> FourierNtruAutomorphismBootstrapKey<C> {
+    pub fn get_mut_fourier_ngsw_list(&mut self) -> FourierNgswCiphertextListMutView<'_> {
+        self.fourier_ngsw_list.as_mut_view()
+    }
+
+    pub fn get_mut_fourier_automorphism_key(&mut self, index: usize) -> FourierNtruAutomorphismKeyMutView<'_> {
+        let automorphism_key_count = self.automorphism_key_count().0;
+        assert!(
+            index < automorphism_key_count,
+            "Input index {} should be smaller than the number of automorphism keys {}",
+            index,
+            automorphism_key_count,
+        );
+
+        let auto_index = automorphism_bootstrap_key_automorphism_index(
+            self.polynomial_size(),
+            self.window_size(),
+            index,
+        );
+        let polynomial_size = self.fourier_ntru_auto_keys.polynomial_size();
+        let decomp_base_log = self.fourier_ntru_auto_keys.decomposition_base_log();
+        let fft_type = self.fourier_ntru_auto_keys.fft_type();
+        FourierNtruAutomorphismKey::from_container(
+            self.fourier_ntru_auto_keys.get_mut(index).data(),
+            auto_index,
+            polynomial_size,
+            decomp_base_log,
+            fft_type,
+        )
+    }
+
+    pub fn get_mut_fourier_ntru_switching_key(&mut self) -> FourierNtruSwitchingKeyMutView<'_> {
+        self.fourier_ntru_switching_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierNtruAutomorphismBootstrapKeyMutView<'_> {
+        FourierNtruAutomorphismBootstrapKeyMutView::<'_> {
+            fourier_ngsw_list: self.fourier_ngsw_list.as_mut_view(),
+            fourier_ntru_auto_keys: self.fourier_ntru_auto_keys.as_mut_view(),
+            fourier_ntru_switching_key: self.fourier_ntru_switching_key.as_mut_view(),
+        }
+    }
+}
+
+impl FourierNtruAutomorphismBootstrapKeyOwned {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        auto_decomp_base_log: DecompositionBaseLog,
+        auto_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        input_lwe_dimension: LweDimension,
+        window_size: AutomorphismWindowSize,
+        br_fft_type: FftType,
+        auto_fft_type: FftType,
+        swk_fft_type: FftType,
+    ) -> Self {
+        assert!(
+            window_size.0 > 0,
+            "The automorphism window size should be positive.",
+        );
+
+        Self {
+            fourier_ngsw_list: FourierNgswCiphertextList::new(
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                FourierNgswCiphertextCount(input_lwe_dimension.0),
+                br_fft_type,
+            ),
+            fourier_ntru_auto_keys: FourierNtruKeyswitchKeyList::new(
+                polynomial_size,
+                auto_decomp_base_log,
+                auto_decomp_level_count,
+                FourierNtruKeyswitchKeyCount(window_size.0 + 1),
+                auto_fft_type,
+            ),
+            fourier_ntru_switching_key: FourierNtruSwitchingKey::new(
+                polynomial_size,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                swk_fft_type,
+            ),
+        }
+    }
+}
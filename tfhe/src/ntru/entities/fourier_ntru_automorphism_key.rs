@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_automorphism_key::FourierNtruAutomorphismKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::FourierPolynomialList;
@@ -6,7 +10,9 @@ use crate::ntru::entities::*;
 use aligned_vec::{avec, ABox};
 use tfhe_fft::c64;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruAutomorphismKeyVersions)]
 pub struct FourierNtruAutomorphismKey<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     automorphism_index: AutomorphismIndex,
@@ -14,6 +20,10 @@ pub struct FourierNtruAutomorphismKey<C: Container<Element = c64>> {
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNtruAutomorphismKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruAutomorphismKey";
+}
+
 pub type FourierNtruAutomorphismKeyView<'a> = FourierNtruAutomorphismKey<&'a [c64]>;
 pub type FourierNtruAutomorphismKeyMutView<'a> = FourierNtruAutomorphismKey<&'a mut [c64]>;
 
@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_cmux_bootstrap_key::FourierNtruCMuxBootstrapKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::ntru::entities::*;
@@ -5,12 +9,18 @@ use crate::ntru::entities::*;
 use aligned_vec::ABox;
 use tfhe_fft::c64;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruCMuxBootstrapKeyVersions)]
 pub struct FourierNtruCMuxBootstrapKey<C: Container<Element = c64>> {
     fourier_ngsw_list: FourierNgswCiphertextList<C>,
     fourier_ntru_switching_key: FourierNtruSwitchingKey<C>,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNtruCMuxBootstrapKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruCMuxBootstrapKey";
+}
+
 pub type FourierNtruCMuxBootstrapKeyView<'a> = FourierNtruCMuxBootstrapKey<&'a [c64]>;
 pub type FourierNtruCMuxBootstrapKeyMutView<'a> = FourierNtruCMuxBootstrapKey<&'a mut [c64]>;
 pub type FourierNtruCMuxBootstrapKeyOwned = FourierNtruCMuxBootstrapKey<ABox<[c64]>>;
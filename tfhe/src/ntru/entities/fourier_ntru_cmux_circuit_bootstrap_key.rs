@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_cmux_circuit_bootstrap_key::FourierNtruCMuxCircuitBootstrapKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::ntru::entities::*;
@@ -5,12 +9,18 @@ use crate::ntru::entities::*;
 use aligned_vec::ABox;
 use tfhe_fft::c64;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruCMuxCircuitBootstrapKeyVersions)]
 pub struct FourierNtruCMuxCircuitBootstrapKey<C: Container<Element = c64>> {
     fourier_ntru_cmux_bsk: FourierNtruCMuxBootstrapKey<C>,
     fourier_ntru_trace_key: FourierNtruTraceKey<C>,
-    fourier_ntru_to_rlwe_ksk: FourierNtruToRlweKeyswitchKey<C>,
-    fourier_rlwe_ss_key: FourierRlweSchemeSwitchKey<C>,
+    fourier_ntru_to_glwe_ksk: FourierNtruToGlweKeyswitchKey<C>,
+    fourier_glwe_ss_key: FourierGlweSchemeSwitchKey<C>,
+}
+
+impl<C: Container<Element = c64>> Named for FourierNtruCMuxCircuitBootstrapKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruCMuxCircuitBootstrapKey";
 }
 
 pub type FourierNtruCMuxCircuitBootstrapKeyView<'a> = FourierNtruCMuxCircuitBootstrapKey<&'a [c64]>;
@@ -30,6 +40,10 @@ impl<C: Container<Element = c64>> FourierNtruCMuxCircuitBootstrapKey<C> {
         self.fourier_ntru_cmux_bsk.polynomial_size()
     }
 
+    pub fn glwe_size(&self) -> GlweSize {
+        self.fourier_glwe_ss_key.glwe_size()
+    }
+
     pub fn br_fft_type(&self) -> FftType {
         self.fourier_ntru_cmux_bsk.br_fft_type()
     }
@@ -43,11 +57,11 @@ impl<C: Container<Element = c64>> FourierNtruCMuxCircuitBootstrapKey<C> {
     }
 
     pub fn ksk_fft_type(&self) -> FftType {
-        self.fourier_ntru_to_rlwe_ksk.fft_type()
+        self.fourier_ntru_to_glwe_ksk.fft_type()
     }
 
     pub fn ss_fft_type(&self) -> FftType {
-        self.fourier_rlwe_ss_key.fft_type()
+        self.fourier_glwe_ss_key.fft_type()
     }
 
     pub fn get_fourier_ntru_cmux_bootstrap_key(&self) -> FourierNtruCMuxBootstrapKeyView<'_> {
@@ -58,20 +72,20 @@ impl<C: Container<Element = c64>> FourierNtruCMuxCircuitBootstrapKey<C> {
         self.fourier_ntru_trace_key.as_view()
     }
 
-    pub fn get_fourier_ntru_to_rlwe_keyswitch_key(&self) -> FourierNtruToRlweKeyswitchKeyView<'_> {
-        self.fourier_ntru_to_rlwe_ksk.as_view()
+    pub fn get_fourier_ntru_to_glwe_keyswitch_key(&self) -> FourierNtruToGlweKeyswitchKeyView<'_> {
+        self.fourier_ntru_to_glwe_ksk.as_view()
     }
 
-    pub fn get_fourier_rlwe_scheme_switch_key(&self) -> FourierRlweSchemeSwitchKeyView<'_> {
-        self.fourier_rlwe_ss_key.as_view()
+    pub fn get_fourier_glwe_scheme_switch_key(&self) -> FourierGlweSchemeSwitchKeyView<'_> {
+        self.fourier_glwe_ss_key.as_view()
     }
 
     pub fn as_view(&self) -> FourierNtruCMuxCircuitBootstrapKeyView<'_> {
         FourierNtruCMuxCircuitBootstrapKeyView::<'_> {
             fourier_ntru_cmux_bsk: self.fourier_ntru_cmux_bsk.as_view(),
             fourier_ntru_trace_key: self.fourier_ntru_trace_key.as_view(),
-            fourier_ntru_to_rlwe_ksk: self.fourier_ntru_to_rlwe_ksk.as_view(),
-            fourier_rlwe_ss_key: self.fourier_rlwe_ss_key.as_view(),
+            fourier_ntru_to_glwe_ksk: self.fourier_ntru_to_glwe_ksk.as_view(),
+            fourier_glwe_ss_key: self.fourier_glwe_ss_key.as_view(),
         }
     }
 }
@@ -85,26 +99,27 @@ impl<C: ContainerMut<Element = c64>> FourierNtruCMuxCircuitBootstrapKey<C> {
         self.fourier_ntru_trace_key.as_mut_view()
     }
 
-    pub fn get_mut_fourier_ntru_to_rlwe_keyswitch_key(&mut self) -> FourierNtruToRlweKeyswitchKeyMutView<'_> {
-        self.fourier_ntru_to_rlwe_ksk.as_mut_view()
+    pub fn get_mut_fourier_ntru_to_glwe_keyswitch_key(&mut self) -> FourierNtruToGlweKeyswitchKeyMutView<'_> {
+        self.fourier_ntru_to_glwe_ksk.as_mut_view()
     }
 
-    pub fn get_mut_fourier_rlwe_scheme_switch_key(&mut self) -> FourierRlweSchemeSwitchKeyMutView<'_> {
-        self.fourier_rlwe_ss_key.as_mut_view()
+    pub fn get_mut_fourier_glwe_scheme_switch_key(&mut self) -> FourierGlweSchemeSwitchKeyMutView<'_> {
+        self.fourier_glwe_ss_key.as_mut_view()
     }
 
     pub fn as_mut_view(&mut self) -> FourierNtruCMuxCircuitBootstrapKeyMutView<'_> {
         FourierNtruCMuxCircuitBootstrapKeyMutView::<'_> {
             fourier_ntru_cmux_bsk: self.fourier_ntru_cmux_bsk.as_mut_view(),
             fourier_ntru_trace_key: self.fourier_ntru_trace_key.as_mut_view(),
-            fourier_ntru_to_rlwe_ksk: self.fourier_ntru_to_rlwe_ksk.as_mut_view(),
-            fourier_rlwe_ss_key: self.fourier_rlwe_ss_key.as_mut_view(),
+            fourier_ntru_to_glwe_ksk: self.fourier_ntru_to_glwe_ksk.as_mut_view(),
+            fourier_glwe_ss_key: self.fourier_glwe_ss_key.as_mut_view(),
         }
     }
 }
 
 impl FourierNtruCMuxCircuitBootstrapKeyOwned {
     pub fn new(
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         input_lwe_dimension: LweDimension,
         br_decomp_base_log: DecompositionBaseLog,
@@ -140,13 +155,15 @@ impl FourierNtruCMuxCircuitBootstrapKeyOwned {
                 tr_decomp_level_count,
                 tr_fft_type,
             ),
-            fourier_ntru_to_rlwe_ksk: FourierNtruToRlweKeyswitchKey::new(
+            fourier_ntru_to_glwe_ksk: FourierNtruToGlweKeyswitchKey::new(
+                glwe_size,
                 polynomial_size,
                 ksk_decomp_base_log,
                 ksk_decomp_level_count,
                 ksk_fft_type,
             ),
-            fourier_rlwe_ss_key: FourierRlweSchemeSwitchKey::new(
+            fourier_glwe_ss_key: FourierGlweSchemeSwitchKey::new(
+                glwe_size,
                 polynomial_size,
                 ss_decomp_base_log,
                 ss_decomp_level_count,
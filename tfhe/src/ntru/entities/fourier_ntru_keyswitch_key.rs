@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_keyswitch_key::FourierNtruKeyswitchKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::FourierPolynomialList;
@@ -6,13 +10,19 @@ use crate::ntru::entities::*;
 use aligned_vec::{avec, ABox};
 use tfhe_fft::c64;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruKeyswitchKeyVersions)]
 pub struct FourierNtruKeyswitchKey<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     decomp_base_log: DecompositionBaseLog,
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNtruKeyswitchKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruKeyswitchKey";
+}
+
 pub type FourierNtruKeyswitchKeyView<'a> = FourierNtruKeyswitchKey<&'a [c64]>;
 pub type FourierNtruKeyswitchKeyMutView<'a> = FourierNtruKeyswitchKey<&'a mut [c64]>;
 
@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_keyswitch_key_list::FourierNtruKeyswitchKeyListVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::FourierPolynomialList;
@@ -9,7 +13,9 @@ use tfhe_fft::c64;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FourierNtruKeyswitchKeyCount(pub usize);
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruKeyswitchKeyListVersions)]
 pub struct FourierNtruKeyswitchKeyList<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
     decomp_base_log: DecompositionBaseLog,
@@ -17,6 +23,10 @@ pub struct FourierNtruKeyswitchKeyList<C: Container<Element = c64>> {
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNtruKeyswitchKeyList<C> {
+    const NAME: &'static str = "ntru::FourierNtruKeyswitchKeyList";
+}
+
 impl<C: Container<Element = c64>> AsRef<[c64]> for FourierNtruKeyswitchKeyList<C> {
     fn as_ref(&self) -> &[c64] {
         self.fourier.data.as_ref()
@@ -83,12 +93,17 @@ impl<C: Container<Element = c64>> FourierNtruKeyswitchKeyList<C> {
         self.decomp_level_count
     }
 
+    pub fn fft_type(&self) -> FftType {
+        self.fft_type
+    }
+
     pub fn fourier_ntru_keyswitch_key_count(&self) -> FourierNtruKeyswitchKeyCount {
         FourierNtruKeyswitchKeyCount(
             self.fourier.data.container_len() / (
                 self.fourier.polynomial_size
                     .to_fourier_polynomial_size().0
                     * self.decomp_level_count.0
+                    * self.fft_type.num_split()
             )
         )
     }
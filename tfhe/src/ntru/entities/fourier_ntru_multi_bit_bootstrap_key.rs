@@ -0,0 +1,144 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_multi_bit_bootstrap_key::FourierNtruMultiBitBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+use tfhe_fft::c64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruMultiBitBootstrapKeyVersions)]
+pub struct FourierNtruMultiBitBootstrapKey<C: Container<Element = c64>> {
+    fourier_ngsw_list: FourierNgswCiphertextList<C>,
+    fourier_ntru_switching_key: FourierNtruSwitchingKey<C>,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+impl<C: Container<Element = c64>> Named for FourierNtruMultiBitBootstrapKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruMultiBitBootstrapKey";
+}
+
+pub type FourierNtruMultiBitBootstrapKeyView<'a> = FourierNtruMultiBitBootstrapKey<&'a [c64]>;
+pub type FourierNtruMultiBitBootstrapKeyMutView<'a> = FourierNtruMultiBitBootstrapKey<&'a mut [c64]>;
+pub type FourierNtruMultiBitBootstrapKeyOwned = FourierNtruMultiBitBootstrapKey<ABox<[c64]>>;
+
+impl<C: Container<Element = c64>> FourierNtruMultiBitBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.multi_bit_input_lwe_dimension().0 * self.grouping_factor.0)
+    }
+
+    pub fn multi_bit_input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(
+            self.fourier_ngsw_list.ciphertext_count().0
+                / self.grouping_factor.ggsw_per_multi_bit_element().0,
+        )
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.fourier_ngsw_list.polynomial_size().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier_ngsw_list.polynomial_size()
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.fourier_ngsw_list.decomposition_base_log()
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.fourier_ngsw_list.decomposition_level_count()
+    }
+
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    pub fn br_fft_type(&self) -> FftType {
+        self.fourier_ngsw_list.fft_type()
+    }
+
+    pub fn swk_fft_type(&self) -> FftType {
+        self.fourier_ntru_switching_key.fft_type()
+    }
+
+    pub fn get_fourier_ngsw_list(&self) -> FourierNgswCiphertextListView<'_> {
+        self.fourier_ngsw_list.as_view()
+    }
+
+    pub fn get_fourier_ntru_switching_key(&self) -> FourierNtruSwitchingKeyView<'_> {
+        self.fourier_ntru_switching_key.as_view()
+    }
+
+    pub fn as_view(&self) -> FourierNtruMultiBitBootstrapKeyView<'_> {
+        FourierNtruMultiBitBootstrapKeyView::<'_> {
+            fourier_ngsw_list: self.fourier_ngsw_list.as_view(),
+            fourier_ntru_switching_key: self.fourier_ntru_switching_key.as_view(),
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+impl<C: ContainerMut<Element = c64>> FourierNtruMultiBitBootstrapKey<C> {
+    pub fn get_mut_fourier_ngsw_list(&mut self) -> FourierNgswCiphertextListMutView<'_> {
+        self.fourier_ngsw_list.as_mut_view()
+    }
+
+    pub fn get_mut_fourier_ntru_switching_key(&mut self) -> FourierNtruSwitchingKeyMutView<'_> {
+        self.fourier_ntru_switching_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierNtruMultiBitBootstrapKeyMutView<'_> {
+        FourierNtruMultiBitBootstrapKeyMutView::<'_> {
+            fourier_ngsw_list: self.fourier_ngsw_list.as_mut_view(),
+            fourier_ntru_switching_key: self.fourier_ntru_switching_key.as_mut_view(),
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+impl FourierNtruMultiBitBootstrapKeyOwned {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        input_lwe_dimension: LweDimension,
+        grouping_factor: LweBskGroupingFactor,
+        br_fft_type: FftType,
+        swk_fft_type: FftType,
+    ) -> Self {
+        assert!(
+            input_lwe_dimension.0 % grouping_factor.0 == 0,
+            "Multi Bit BSK requires input LWE dimension ({}) to be a multiple of {}",
+            input_lwe_dimension.0,
+            grouping_factor.0
+        );
+        let equivalent_multi_bit_dimension = input_lwe_dimension.0 / grouping_factor.0;
+
+        Self {
+            fourier_ngsw_list: FourierNgswCiphertextList::new(
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                FourierNgswCiphertextCount(
+                    equivalent_multi_bit_dimension * grouping_factor.ggsw_per_multi_bit_element().0,
+                ),
+                br_fft_type,
+            ),
+            fourier_ntru_switching_key: FourierNtruSwitchingKey::new(
+                polynomial_size,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                swk_fft_type,
+            ),
+            grouping_factor,
+        }
+    }
+}
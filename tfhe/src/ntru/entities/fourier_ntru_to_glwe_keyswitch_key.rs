@@ -1,3 +1,7 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_to_glwe_keyswitch_key::FourierNtruToGlweKeyswitchKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::math::decomposition::DecompositionLevel;
@@ -11,36 +15,46 @@ use aligned_vec::{avec, ABox};
 use dyn_stack::PodStack;
 use tfhe_fft::c64;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct FourierNtruToRlweKeyswitchKey<C: Container<Element = c64>> {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruToGlweKeyswitchKeyVersions)]
+pub struct FourierNtruToGlweKeyswitchKey<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
     decomp_base_log: DecompositionBaseLog,
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNtruToGlweKeyswitchKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruToGlweKeyswitchKey";
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct FourierNtruToRlweKeyswitchKeySplit<C: Container<Element = c64>> {
+pub struct FourierNtruToGlweKeyswitchKeySplit<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
     decomp_base_log: DecompositionBaseLog,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct FourierNtruToRlweKeyswitchKeyLevel<C: Container<Element = c64>> {
+pub struct FourierNtruToGlweKeyswitchKeyLevel<C: Container<Element = c64>> {
     fourier: FourierPolynomialList<C>,
+    glwe_size: GlweSize,
     decomp_level: DecompositionLevel,
 }
 
-pub type FourierNtruToRlweKeyswitchKeyOwned = FourierNtruToRlweKeyswitchKey<ABox<[c64]>>;
-pub type FourierNtruToRlweKeyswitchKeyView<'a> = FourierNtruToRlweKeyswitchKey<&'a [c64]>;
-pub type FourierNtruToRlweKeyswitchKeyMutView<'a> = FourierNtruToRlweKeyswitchKey<&'a mut [c64]>;
-pub type FourierNtruToRlweKeyswitchKeySplitView<'a> = FourierNtruToRlweKeyswitchKeySplit<&'a [c64]>;
-pub type FourierNtruToRlweKeyswitchKeySplitMutView<'a> = FourierNtruToRlweKeyswitchKeySplit<&'a mut [c64]>;
-pub type FourierNtruToRlweKeyswitchKeyLevelView<'a> = FourierNtruToRlweKeyswitchKeyLevel<&'a [c64]>;
-pub type FourierNtruToRlweKeyswitchKeyLevelMutView<'a> = FourierNtruToRlweKeyswitchKeyLevel<&'a mut [c64]>;
+pub type FourierNtruToGlweKeyswitchKeyOwned = FourierNtruToGlweKeyswitchKey<ABox<[c64]>>;
+pub type FourierNtruToGlweKeyswitchKeyView<'a> = FourierNtruToGlweKeyswitchKey<&'a [c64]>;
+pub type FourierNtruToGlweKeyswitchKeyMutView<'a> = FourierNtruToGlweKeyswitchKey<&'a mut [c64]>;
+pub type FourierNtruToGlweKeyswitchKeySplitView<'a> = FourierNtruToGlweKeyswitchKeySplit<&'a [c64]>;
+pub type FourierNtruToGlweKeyswitchKeySplitMutView<'a> = FourierNtruToGlweKeyswitchKeySplit<&'a mut [c64]>;
+pub type FourierNtruToGlweKeyswitchKeyLevelView<'a> = FourierNtruToGlweKeyswitchKeyLevel<&'a [c64]>;
+pub type FourierNtruToGlweKeyswitchKeyLevelMutView<'a> = FourierNtruToGlweKeyswitchKeyLevel<&'a mut [c64]>;
 
-impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKey<C> {
+impl<C: Container<Element = c64>> FourierNtruToGlweKeyswitchKey<C> {
     pub fn from_container(
         data: C,
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         decomp_base_log: DecompositionBaseLog,
         fft_type: FftType,
@@ -48,12 +62,13 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKey<C> {
         assert!(
             data.container_len() % (
                 polynomial_size.to_fourier_polynomial_size().0
-                    * fft_type.num_split() * 2
+                    * fft_type.num_split() * glwe_size.0
             ) == 0,
             "The provided container length is not valid. \
-            It needs to be divisible by 2 * polynomial size * fft_type.num_split(). \
-            Got container length: {}, polynomial size {:?}, fft_type: {:?}.",
+            It needs to be divisible by glwe_size * polynomial size / 2 * fft_type.num_split(). \
+            Got container length: {}, glwe size {:?}, polynomial size {:?}, fft_type: {:?}.",
             data.container_len(),
+            glwe_size,
             polynomial_size,
             fft_type,
         );
@@ -63,11 +78,16 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKey<C> {
                 data,
                 polynomial_size,
             },
+            glwe_size,
             decomp_base_log,
             fft_type,
         }
     }
 
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
     pub fn polynomial_size(&self) -> PolynomialSize {
         self.fourier.polynomial_size
     }
@@ -81,7 +101,7 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKey<C> {
             self.fourier.data.container_len() / (
                 self.fourier.polynomial_size
                     .to_fourier_polynomial_size().0
-                    * self.fft_type.num_split() * 2
+                    * self.fft_type.num_split() * self.glwe_size.0
             )
         )
     }
@@ -94,29 +114,31 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKey<C> {
         self.fourier.data
     }
 
-    pub fn as_view(&self) -> FourierNtruToRlweKeyswitchKeyView<'_>
+    pub fn as_view(&self) -> FourierNtruToGlweKeyswitchKeyView<'_>
     where
         C: AsRef<[c64]>
     {
-        FourierNtruToRlweKeyswitchKeyView {
+        FourierNtruToGlweKeyswitchKeyView {
             fourier: FourierPolynomialList {
                 data: self.fourier.data.as_ref(),
                 polynomial_size: self.fourier.polynomial_size,
             },
+            glwe_size: self.glwe_size,
             decomp_base_log: self.decomp_base_log,
             fft_type: self.fft_type,
         }
     }
 
-    pub fn as_mut_view(&mut self) -> FourierNtruToRlweKeyswitchKeyMutView<'_>
+    pub fn as_mut_view(&mut self) -> FourierNtruToGlweKeyswitchKeyMutView<'_>
     where
         C: AsMut<[c64]>
     {
-        FourierNtruToRlweKeyswitchKeyMutView {
+        FourierNtruToGlweKeyswitchKeyMutView {
             fourier: FourierPolynomialList {
                 data: self.fourier.data.as_mut(),
                 polynomial_size: self.fourier.polynomial_size,
             },
+            glwe_size: self.glwe_size,
             decomp_base_log: self.decomp_base_log,
             fft_type: self.fft_type,
         }
@@ -124,15 +146,16 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKey<C> {
 
 }
 
-impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKeySplit<C> {
+impl<C: Container<Element = c64>> FourierNtruToGlweKeyswitchKeySplit<C> {
     pub fn from_container(
         data: C,
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         decomp_base_log: DecompositionBaseLog,
     ) -> Self {
         assert!(
             data.container_len() % (
-                polynomial_size.to_fourier_polynomial_size().0 * 2
+                polynomial_size.to_fourier_polynomial_size().0 * glwe_size.0
             ) == 0,
         );
 
@@ -141,10 +164,15 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKeySplit<C> {
                 data,
                 polynomial_size,
             },
+            glwe_size,
             decomp_base_log,
         }
     }
 
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
     pub fn polynomial_size(&self) -> PolynomialSize {
         self.fourier.polynomial_size
     }
@@ -157,7 +185,7 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKeySplit<C> {
         DecompositionLevelCount(
             self.fourier.data.container_len() / (
                 self.fourier.polynomial_size
-                    .to_fourier_polynomial_size().0 * 2
+                    .to_fourier_polynomial_size().0 * self.glwe_size.0
             )
         )
     }
@@ -166,52 +194,60 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKeySplit<C> {
         self.fourier.data
     }
 
-    pub fn as_view(&self) -> FourierNtruToRlweKeyswitchKeySplitView<'_>
+    pub fn as_view(&self) -> FourierNtruToGlweKeyswitchKeySplitView<'_>
     where
         C: AsRef<[c64]>,
     {
-        FourierNtruToRlweKeyswitchKeySplitView {
+        FourierNtruToGlweKeyswitchKeySplitView {
             fourier: FourierPolynomialList {
                 data: self.fourier.data.as_ref(),
                 polynomial_size: self.fourier.polynomial_size,
             },
+            glwe_size: self.glwe_size,
             decomp_base_log: self.decomp_base_log,
         }
     }
 
-    pub fn as_mut_view(&mut self) -> FourierNtruToRlweKeyswitchKeySplitMutView<'_>
+    pub fn as_mut_view(&mut self) -> FourierNtruToGlweKeyswitchKeySplitMutView<'_>
     where
         C: AsMut<[c64]>,
     {
-        FourierNtruToRlweKeyswitchKeySplitMutView {
+        FourierNtruToGlweKeyswitchKeySplitMutView {
             fourier: FourierPolynomialList {
                 data: self.fourier.data.as_mut(),
                 polynomial_size: self.fourier.polynomial_size,
             },
+            glwe_size: self.glwe_size,
             decomp_base_log: self.decomp_base_log,
         }
     }
 }
 
-impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKeyLevel<C> {
+impl<C: Container<Element = c64>> FourierNtruToGlweKeyswitchKeyLevel<C> {
     pub fn from_container(
         data: C,
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         decomp_level: DecompositionLevel,
     ) -> Self {
         assert_eq!(
             data.container_len(),
-            polynomial_size.to_fourier_polynomial_size().0 * 2,
+            polynomial_size.to_fourier_polynomial_size().0 * glwe_size.0,
         );
         Self {
             fourier: FourierPolynomialList {
                 data,
                 polynomial_size,
             },
+            glwe_size,
             decomp_level,
         }
     }
 
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
     pub fn polynomial_size(&self) -> PolynomialSize {
         self.fourier.polynomial_size
     }
@@ -225,14 +261,15 @@ impl<C: Container<Element = c64>> FourierNtruToRlweKeyswitchKeyLevel<C> {
     }
 }
 
-impl<'a> FourierNtruToRlweKeyswitchKeyView<'a> {
-    pub fn into_splits(self) -> impl DoubleEndedIterator<Item = FourierNtruToRlweKeyswitchKeySplitView<'a>> {
+impl<'a> FourierNtruToGlweKeyswitchKeyView<'a> {
+    pub fn into_splits(self) -> impl DoubleEndedIterator<Item = FourierNtruToGlweKeyswitchKeySplitView<'a>> {
         self.fourier
             .data
             .split_into(self.fft_type.num_split())
             .map(move |slice| {
-                FourierNtruToRlweKeyswitchKeySplitView::from_container(
+                FourierNtruToGlweKeyswitchKeySplitView::from_container(
                     slice,
+                    self.glwe_size,
                     self.fourier.polynomial_size,
                     self.decomp_base_log,
                 )
@@ -240,16 +277,17 @@ impl<'a> FourierNtruToRlweKeyswitchKeyView<'a> {
     }
 }
 
-impl<'a> FourierNtruToRlweKeyswitchKeySplitView<'a> {
-    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = FourierNtruToRlweKeyswitchKeyLevelView<'a>> {
+impl<'a> FourierNtruToGlweKeyswitchKeySplitView<'a> {
+    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = FourierNtruToGlweKeyswitchKeyLevelView<'a>> {
         let decomp_level_count = self.decomposition_level_count().0;
         self.fourier
             .data
             .split_into(decomp_level_count)
             .enumerate()
             .map(move |(i, slice)| {
-                FourierNtruToRlweKeyswitchKeyLevelView::from_container(
+                FourierNtruToGlweKeyswitchKeyLevelView::from_container(
                     slice,
+                    self.glwe_size,
                     self.fourier.polynomial_size,
                     DecompositionLevel(decomp_level_count - i),
                 )
@@ -257,22 +295,24 @@ impl<'a> FourierNtruToRlweKeyswitchKeySplitView<'a> {
     }
 }
 
-impl FourierNtruToRlweKeyswitchKeyMutView<'_> {
+impl FourierNtruToGlweKeyswitchKeyMutView<'_> {
     pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
         self,
-        standard_ntru_to_rlwe_ksk: NtruToRlweKeyswitchKeyView<'_, Scalar>,
+        standard_ntru_to_glwe_ksk: NtruToGlweKeyswitchKeyView<'_, Scalar>,
         fft: FftView<'_>,
         stack: &mut PodStack,
     ) {
         assert_eq!(
-            standard_ntru_to_rlwe_ksk.polynomial_size(),
+            standard_ntru_to_glwe_ksk.glwe_size(),
+            self.glwe_size(),
+        );
+        assert_eq!(
+            standard_ntru_to_glwe_ksk.polynomial_size(),
             self.polynomial_size(),
         );
         let polynomial_size = self.polynomial_size();
         let fourier_poly_size = polynomial_size.to_fourier_polynomial_size().0;
-        let log_modulus = standard_ntru_to_rlwe_ksk.ciphertext_modulus().into_modulus_log().0;
-        let log_torus_scaling = Scalar::BITS - log_modulus;
-
+        let log_modulus = standard_ntru_to_glwe_ksk.ciphertext_modulus().into_modulus_log().0;
         let fft_type = self.fft_type;
         let mut poly_buffer = Polynomial::new(Scalar::ZERO, polynomial_size);
 
@@ -280,7 +320,7 @@ impl FourierNtruToRlweKeyswitchKeyMutView<'_> {
             FftType::Vanilla => {
                 for (fourier_poly, standard_poly) in izip!(
                     self.data().into_chunks(fourier_poly_size),
-                    standard_ntru_to_rlwe_ksk.as_polynomial_list().iter(),
+                    standard_ntru_to_glwe_ksk.as_polynomial_list().iter(),
                 ) {
                     fft.forward_as_torus(
                         FourierPolynomialMutView { data: fourier_poly },
@@ -289,46 +329,28 @@ impl FourierNtruToRlweKeyswitchKeyMutView<'_> {
                     );
                 }
             },
-            FftType::Split(b) => {
+            _ => {
                 self.data().split_into(fft_type.num_split())
                     .enumerate()
                     .for_each(|(split_idx, split_fourier)| {
                         for (fourier_poly, standard_poly) in izip!(
                             split_fourier.into_chunks(fourier_poly_size),
-                            standard_ntru_to_rlwe_ksk.as_polynomial_list().iter(),
+                            standard_ntru_to_glwe_ksk.as_polynomial_list().iter(),
                         ) {
-                            if split_idx == 0 {
-                                let shift_bit = log_modulus - b;
-                                for (&standard_coeff, split_coeff)
-                                    in standard_poly.iter().zip(poly_buffer.iter_mut())
-                                {
-                                    *split_coeff = (standard_coeff << shift_bit) >> shift_bit;
-                                }
-
-                                fft.forward_as_torus(
-                                    FourierPolynomialMutView {
-                                        data: fourier_poly,
-                                    },
-                                    poly_buffer.as_view(),
-                                    stack,
-                                );
-                            } else { // split_idx == 1
-                                let rsh_bit = log_torus_scaling + b;
-                                let lsh_bit = log_torus_scaling;
-                                for (&standard_coeff, split_coeff)
-                                    in standard_poly.iter().zip(poly_buffer.iter_mut())
-                                {
-                                    *split_coeff = (standard_coeff >> rsh_bit) << lsh_bit;
-                                }
-
-                                fft.forward_as_torus(
-                                    FourierPolynomialMutView {
-                                        data: fourier_poly,
-                                    },
-                                    poly_buffer.as_view(),
-                                    stack,
-                                );
-                            }
+                            fft_type.fill_with_split_part(
+                                standard_poly.as_ref(),
+                                poly_buffer.as_mut(),
+                                split_idx,
+                                log_modulus,
+                            );
+
+                            fft.forward_as_torus(
+                                FourierPolynomialMutView {
+                                    data: fourier_poly,
+                                },
+                                poly_buffer.as_view(),
+                                stack,
+                            );
                         }
                     });
             }
@@ -336,8 +358,9 @@ impl FourierNtruToRlweKeyswitchKeyMutView<'_> {
     }
 }
 
-impl FourierNtruToRlweKeyswitchKeyOwned {
+impl FourierNtruToGlweKeyswitchKeyOwned {
     pub fn new(
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         decomp_base_log: DecompositionBaseLog,
         decomp_level_count: DecompositionLevelCount,
@@ -347,12 +370,13 @@ impl FourierNtruToRlweKeyswitchKeyOwned {
             c64::default();
             polynomial_size.to_fourier_polynomial_size().0
                 * decomp_level_count.0
-                * fft_type.num_split() * 2
+                * fft_type.num_split() * glwe_size.0
         ]
         .into_boxed_slice();
 
-        FourierNtruToRlweKeyswitchKey::from_container(
+        FourierNtruToGlweKeyswitchKey::from_container(
             boxed,
+            glwe_size,
             polynomial_size,
             decomp_base_log,
             fft_type,
@@ -1,5 +1,9 @@
 //! Module containing the definition of the FourierNtruTraceKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_ntru_trace_key::FourierNtruTraceKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::ntru::entities::*;
@@ -8,7 +12,9 @@ use aligned_vec::ABox;
 use tfhe_fft::c64;
 
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierNtruTraceKeyVersions)]
 pub struct FourierNtruTraceKey<C: Container<Element = c64>> {
     fourier_ntru_auto_keys: FourierNtruKeyswitchKeyList<C>,
     polynomial_size: PolynomialSize,
@@ -17,6 +23,10 @@ pub struct FourierNtruTraceKey<C: Container<Element = c64>> {
     fft_type: FftType,
 }
 
+impl<C: Container<Element = c64>> Named for FourierNtruTraceKey<C> {
+    const NAME: &'static str = "ntru::FourierNtruTraceKey";
+}
+
 pub type FourierNtruTraceKeyView<'a> = FourierNtruTraceKey<&'a [c64]>;
 pub type FourierNtruTraceKeyMutView<'a> = FourierNtruTraceKey<&'a mut [c64]>;
 pub type FourierNtruTraceKeyOwned = FourierNtruTraceKey<ABox<[c64]>>;
@@ -31,8 +41,8 @@ impl<C: Container<Element = c64>> FourierNtruTraceKey<C> {
             automorphism_key_count,
         );
 
+        let auto_index = self.automorphism_index(index);
         let fourier_ntru_auto_key = self.fourier_ntru_auto_keys.get(index);
-        let auto_index = AutomorphismIndex((1 << (index + 1)) + 1);
         FourierNtruAutomorphismKey::from_container(
             fourier_ntru_auto_key.data(),
             auto_index,
@@ -46,6 +56,16 @@ impl<C: Container<Element = c64>> FourierNtruTraceKey<C> {
         self.fourier_ntru_auto_keys.fourier_ntru_keyswitch_key_count()
     }
 
+    pub fn trace_level_count(&self) -> TraceLevelCount {
+        TraceLevelCount(self.automorphism_key_count().0)
+    }
+
+    pub fn automorphism_index(&self, index: usize) -> AutomorphismIndex {
+        let log_polynomial_size = self.polynomial_size.0.ilog2() as usize;
+        let k = log_polynomial_size - self.trace_level_count().0 + 1 + index;
+        AutomorphismIndex((1 << k) + 1)
+    }
+
     pub fn polynomial_size(&self) -> PolynomialSize {
         self.polynomial_size
     }
@@ -83,8 +103,8 @@ impl<C: ContainerMut<Element = c64>> FourierNtruTraceKey<C> {
             automorphism_key_count,
         );
 
+        let auto_index = self.automorphism_index(index);
         let fourier_ntru_auto_key = self.fourier_ntru_auto_keys.get_mut(index);
-        let auto_index = AutomorphismIndex((1 << (index + 1)) + 1);
         FourierNtruAutomorphismKey::from_container(
             fourier_ntru_auto_key.data(),
             auto_index,
@@ -112,11 +132,34 @@ impl FourierNtruTraceKeyOwned {
         decomp_level_count: DecompositionLevelCount,
         fft_type: FftType,
     ) -> Self {
+        Self::new_partial(
+            polynomial_size,
+            TraceLevelCount(polynomial_size.0.ilog2() as usize),
+            decomp_base_log,
+            decomp_level_count,
+            fft_type,
+        )
+    }
+
+    pub fn new_partial(
+        polynomial_size: PolynomialSize,
+        trace_level_count: TraceLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        fft_type: FftType,
+    ) -> Self {
+        assert!(
+            trace_level_count.0 <= polynomial_size.0.ilog2() as usize,
+            "Trace level count {} should not exceed log2 of the polynomial size {}",
+            trace_level_count.0,
+            polynomial_size.0,
+        );
+
         let fourier_ntru_auto_keys = FourierNtruKeyswitchKeyList::new(
             polynomial_size,
             decomp_base_log,
             decomp_level_count,
-            FourierNtruKeyswitchKeyCount(polynomial_size.0.ilog2() as usize),
+            FourierNtruKeyswitchKeyCount(trace_level_count.0),
             fft_type,
         );
 
@@ -0,0 +1,147 @@
+//! Module containing the definition of the FourierRlweToNtruKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::fourier_rlwe_to_ntru_keyswitch_key::FourierRlweToNtruKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+use tfhe_fft::c64;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+#[versionize(FourierRlweToNtruKeyswitchKeyVersions)]
+pub struct FourierRlweToNtruKeyswitchKey<C: Container<Element = c64>> {
+    fourier_ntru_ksks: FourierNtruKeyswitchKeyList<C>,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+}
+
+impl<C: Container<Element = c64>> Named for FourierRlweToNtruKeyswitchKey<C> {
+    const NAME: &'static str = "ntru::FourierRlweToNtruKeyswitchKey";
+}
+
+pub type FourierRlweToNtruKeyswitchKeyView<'a> = FourierRlweToNtruKeyswitchKey<&'a [c64]>;
+pub type FourierRlweToNtruKeyswitchKeyMutView<'a> = FourierRlweToNtruKeyswitchKey<&'a mut [c64]>;
+pub type FourierRlweToNtruKeyswitchKeyOwned = FourierRlweToNtruKeyswitchKey<ABox<[c64]>>;
+
+impl<C: Container<Element = c64>> FourierRlweToNtruKeyswitchKey<C> {
+    pub fn get_ntru_keyswitch_key(&self, index: usize) -> FourierNtruKeyswitchKeyView<'_> {
+        let input_glwe_dimension = self.input_glwe_dimension().0;
+        assert!(
+            index < input_glwe_dimension,
+            "Input index {index} should be smaller than the input GLWE dimension {input_glwe_dimension}",
+        );
+
+        self.fourier_ntru_ksks.get(index)
+    }
+
+    pub fn get_ntru_switching_key(&self) -> FourierNtruSwitchingKeyView<'_> {
+        let fourier_ntru_swk = self.fourier_ntru_ksks.get(self.input_glwe_dimension().0);
+        FourierNtruSwitchingKey::from_container(
+            fourier_ntru_swk.data(),
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.fft_type,
+        )
+    }
+
+    pub fn input_glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.fourier_ntru_ksks.fourier_ntru_keyswitch_key_count().0 - 1)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    pub fn fft_type(&self) -> FftType {
+        self.fft_type
+    }
+
+    pub fn as_view(&self) -> FourierRlweToNtruKeyswitchKeyView<'_> {
+        FourierRlweToNtruKeyswitchKeyView::<'_> {
+            fourier_ntru_ksks: self.fourier_ntru_ksks.as_view(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            fft_type: self.fft_type,
+        }
+    }
+}
+
+impl<C: ContainerMut<Element = c64>> FourierRlweToNtruKeyswitchKey<C> {
+    pub fn get_mut_ntru_keyswitch_key(&mut self, index: usize) -> FourierNtruKeyswitchKeyMutView<'_> {
+        let input_glwe_dimension = self.input_glwe_dimension().0;
+        assert!(
+            index < input_glwe_dimension,
+            "Input index {index} should be smaller than the input GLWE dimension {input_glwe_dimension}",
+        );
+
+        self.fourier_ntru_ksks.get_mut(index)
+    }
+
+    pub fn get_mut_ntru_switching_key(&mut self) -> FourierNtruSwitchingKeyMutView<'_> {
+        let index = self.input_glwe_dimension().0;
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let fft_type = self.fft_type;
+
+        let fourier_ntru_swk = self.fourier_ntru_ksks.get_mut(index);
+        FourierNtruSwitchingKey::from_container(
+            fourier_ntru_swk.data(),
+            polynomial_size,
+            decomp_base_log,
+            fft_type,
+        )
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierRlweToNtruKeyswitchKeyMutView<'_> {
+        FourierRlweToNtruKeyswitchKeyMutView::<'_> {
+            fourier_ntru_ksks: self.fourier_ntru_ksks.as_mut_view(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            fft_type: self.fft_type,
+        }
+    }
+}
+
+impl FourierRlweToNtruKeyswitchKeyOwned {
+    pub fn new(
+        input_glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        fft_type: FftType,
+    ) -> Self {
+        let fourier_ntru_ksks = FourierNtruKeyswitchKeyList::new(
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            FourierNtruKeyswitchKeyCount(input_glwe_dimension.0 + 1),
+            fft_type,
+        );
+
+        Self {
+            fourier_ntru_ksks,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            fft_type,
+        }
+    }
+}
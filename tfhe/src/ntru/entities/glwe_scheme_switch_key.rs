@@ -0,0 +1,242 @@
+//! Module containing the definition of GlweSchemeSwitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::glwe_scheme_switch_key::GlweSchemeSwitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::ntru::entities::NtruToGlweKeyswitchKey;
+use crate::ntru::entities::NtruToGlweKeyswitchKeyMutView;
+use crate::ntru::entities::NtruToGlweKeyswitchKeyView;
+
+/// A key switching GLWE ciphertexts of m under S = (S_0, ..., S_{k-1}) to the GLWE ciphertexts of
+/// -S_i * m, i.e., to the first k rows of a level of a GGSW ciphertext of m.
+///
+/// It consists of k * k keyswitch keys, the (i, j)-th one encrypting -S_i * S_j.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(GlweSchemeSwitchKeyVersions)]
+pub struct GlweSchemeSwitchKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for GlweSchemeSwitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::GlweSchemeSwitchKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweSchemeSwitchKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweSchemeSwitchKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+pub type GlweSchemeSwitchKeyOwned<Scalar> = GlweSchemeSwitchKey<Vec<Scalar>>;
+pub type GlweSchemeSwitchKeyView<'data, Scalar> = GlweSchemeSwitchKey<&'data [Scalar]>;
+pub type GlweSchemeSwitchKeyMutView<'data, Scalar> = GlweSchemeSwitchKey<&'data mut [Scalar]>;
+
+pub fn glwe_scheme_switch_key_keyswitch_key_count(glwe_size: GlweSize) -> usize {
+    let glwe_dimension = glwe_size.to_glwe_dimension().0;
+    glwe_dimension * glwe_dimension
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweSchemeSwitchKey<C> {
+    pub fn from_container(
+        container: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let ksk_count = glwe_scheme_switch_key_keyswitch_key_count(glwe_size);
+
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a GlweSchemeSwitchKey"
+        );
+        assert!(
+            container.container_len() % (ksk_count * glwe_size.0 * polynomial_size.0) == 0,
+            "The provided container length is not valid. \
+        It needs to be dividable by (glwe_size - 1)^2 * glwe_size * polynomial_size: {}. \
+        Got container length: {} and glwe_size: {glwe_size:?}, polynomial_size: {polynomial_size:?}.",
+            ksk_count * glwe_size.0 * polynomial_size.0,
+            container.container_len()
+        );
+
+        Self {
+            data: container,
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(
+            self.data.container_len() / (
+                glwe_scheme_switch_key_keyswitch_key_count(self.glwe_size)
+                    * self.glwe_size.0
+                    * self.polynomial_size.0
+            )
+        )
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    pub fn keyswitch_key_size(&self) -> usize {
+        self.decomposition_level_count().0 * self.glwe_size.0 * self.polynomial_size.0
+    }
+
+    pub fn as_polynomial_list(&self) -> PolynomialListView<'_, Scalar> {
+        PolynomialListView::from_container(self.as_ref(), self.polynomial_size)
+    }
+
+    pub fn as_glwe_ciphertext_list(&self) -> GlweCiphertextListView<'_, Scalar> {
+        GlweCiphertextListView::from_container(
+            self.data.as_ref(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Return the keyswitch key of -S_i * S_j used to compute the i-th output row from the j-th
+    /// mask polynomial of the input.
+    pub fn get_ntru_to_glwe_keyswitch_key(
+        &self,
+        output_index: usize,
+        input_index: usize,
+    ) -> NtruToGlweKeyswitchKeyView<'_, Scalar> {
+        let glwe_dimension = self.glwe_size.to_glwe_dimension().0;
+        assert!(output_index < glwe_dimension && input_index < glwe_dimension);
+
+        let ksk_size = self.keyswitch_key_size();
+        let start = (output_index * glwe_dimension + input_index) * ksk_size;
+
+        NtruToGlweKeyswitchKey::from_container(
+            &self.data.as_ref()[start..start + ksk_size],
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn as_view(&self) -> GlweSchemeSwitchKeyView<'_, Scalar> {
+        GlweSchemeSwitchKey::from_container(
+            self.data.as_ref(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweSchemeSwitchKey<C> {
+    pub fn as_mut_polynomial_list(&mut self) -> PolynomialListMutView<'_, Scalar> {
+        PolynomialListMutView::from_container(
+            self.data.as_mut(),
+            self.polynomial_size,
+        )
+    }
+
+    pub fn as_mut_glwe_ciphertext_list(&mut self) -> GlweCiphertextListMutView<'_, Scalar> {
+        GlweCiphertextListMutView::from_container(
+            self.data.as_mut(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn get_mut_ntru_to_glwe_keyswitch_key(
+        &mut self,
+        output_index: usize,
+        input_index: usize,
+    ) -> NtruToGlweKeyswitchKeyMutView<'_, Scalar> {
+        let glwe_dimension = self.glwe_size.to_glwe_dimension().0;
+        assert!(output_index < glwe_dimension && input_index < glwe_dimension);
+
+        let ksk_size = self.keyswitch_key_size();
+        let start = (output_index * glwe_dimension + input_index) * ksk_size;
+
+        NtruToGlweKeyswitchKey::from_container(
+            &mut self.data.as_mut()[start..start + ksk_size],
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_view(&mut self) -> GlweSchemeSwitchKeyMutView<'_, Scalar> {
+        GlweSchemeSwitchKeyMutView::from_container(
+            self.data.as_mut(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger> GlweSchemeSwitchKeyOwned<Scalar> {
+    pub fn new(
+        fill_with: Scalar,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_scheme_switch_key_keyswitch_key_count(glwe_size)
+                    * decomp_level_count.0
+                    * glwe_size.0
+                    * polynomial_size.0
+            ],
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+}
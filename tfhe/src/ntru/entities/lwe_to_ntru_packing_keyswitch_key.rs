@@ -0,0 +1,147 @@
+//! Module containing the definition of the LweToNtruPackingKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::lwe_to_ntru_packing_keyswitch_key::LweToNtruPackingKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::generators::EncryptionRandomGeneratorForkConfig;
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+/// NTRU keyswitch keys from each (constant) LWE secret key coefficient s_i to f,
+/// followed by the switching key from the constant 1 to f used for the bodies.
+///
+/// This is the [`RlweToNtruKeyswitchKey`] of the constant polynomials S_i = s_i, indexed by the
+/// input LWE dimension instead of a GLWE dimension.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(LweToNtruPackingKeyswitchKeyVersions)]
+pub struct LweToNtruPackingKeyswitchKey<C: Container>
+    where C::Element: UnsignedInteger,
+{
+    rlwe_to_ntru_ksk: RlweToNtruKeyswitchKey<C>,
+}
+
+impl<C: Container> Named for LweToNtruPackingKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::LweToNtruPackingKeyswitchKey";
+}
+
+pub type LweToNtruPackingKeyswitchKeyView<'data, Scalar> = LweToNtruPackingKeyswitchKey<&'data [Scalar]>;
+pub type LweToNtruPackingKeyswitchKeyMutView<'data, Scalar> = LweToNtruPackingKeyswitchKey<&'data mut [Scalar]>;
+pub type LweToNtruPackingKeyswitchKeyOwned<Scalar> = LweToNtruPackingKeyswitchKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweToNtruPackingKeyswitchKey<C> {
+    pub fn get_ntru_keyswitch_key(&self, index: usize) -> NtruKeyswitchKeyView<'_, Scalar> {
+        let input_lwe_dimension = self.input_lwe_dimension().0;
+        assert!(
+            index < input_lwe_dimension,
+            "Input index {index} should be smaller than the input LWE dimension {input_lwe_dimension}",
+        );
+
+        self.rlwe_to_ntru_ksk.get_ntru_keyswitch_key(index)
+    }
+
+    pub fn get_ntru_switching_key(&self) -> NtruSwitchingKeyView<'_, Scalar> {
+        self.rlwe_to_ntru_ksk.get_ntru_switching_key()
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.rlwe_to_ntru_ksk.input_glwe_dimension().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.rlwe_to_ntru_ksk.polynomial_size()
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.rlwe_to_ntru_ksk.decomposition_base_log()
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.rlwe_to_ntru_ksk.decomposition_level_count()
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.rlwe_to_ntru_ksk.ciphertext_modulus()
+    }
+
+    pub fn get_ntru_keyswitch_key_list(&self) -> NtruKeyswitchKeyListView<'_, Scalar> {
+        self.rlwe_to_ntru_ksk.get_ntru_keyswitch_key_list()
+    }
+
+    pub fn as_rlwe_to_ntru_keyswitch_key(&self) -> RlweToNtruKeyswitchKeyView<'_, Scalar> {
+        self.rlwe_to_ntru_ksk.as_view()
+    }
+
+    pub fn as_view(&self) -> LweToNtruPackingKeyswitchKeyView<'_, Scalar> {
+        LweToNtruPackingKeyswitchKeyView::<'_, Scalar> {
+            rlwe_to_ntru_ksk: self.rlwe_to_ntru_ksk.as_view(),
+        }
+    }
+
+    pub fn encryption_fork_config<NoiseDistribution>(
+        &self,
+        noise_distribution: NoiseDistribution,
+    ) -> EncryptionRandomGeneratorForkConfig
+    where
+        NoiseDistribution: Distribution,
+        Scalar: RandomGenerable<Uniform, CustomModulus = Scalar>
+            + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    {
+        self.rlwe_to_ntru_ksk.encryption_fork_config(noise_distribution)
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> LweToNtruPackingKeyswitchKey<C> {
+    pub fn get_mut_ntru_keyswitch_key(&mut self, index: usize) -> NtruKeyswitchKeyMutView<'_, Scalar> {
+        let input_lwe_dimension = self.input_lwe_dimension().0;
+        assert!(
+            index < input_lwe_dimension,
+            "Input index {index} should be smaller than the input LWE dimension {input_lwe_dimension}",
+        );
+
+        self.rlwe_to_ntru_ksk.get_mut_ntru_keyswitch_key(index)
+    }
+
+    pub fn get_mut_ntru_switching_key(&mut self) -> NtruSwitchingKeyMutView<'_, Scalar> {
+        self.rlwe_to_ntru_ksk.get_mut_ntru_switching_key()
+    }
+
+    pub fn get_mut_ntru_keyswitch_key_list(&mut self) -> NtruKeyswitchKeyListMutView<'_, Scalar> {
+        self.rlwe_to_ntru_ksk.get_mut_ntru_keyswitch_key_list()
+    }
+
+    pub fn as_mut_rlwe_to_ntru_keyswitch_key(&mut self) -> RlweToNtruKeyswitchKeyMutView<'_, Scalar> {
+        self.rlwe_to_ntru_ksk.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> LweToNtruPackingKeyswitchKeyMutView<'_, Scalar> {
+        LweToNtruPackingKeyswitchKeyMutView::<'_, Scalar> {
+            rlwe_to_ntru_ksk: self.rlwe_to_ntru_ksk.as_mut_view(),
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> LweToNtruPackingKeyswitchKeyOwned<Scalar> {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self {
+            rlwe_to_ntru_ksk: RlweToNtruKeyswitchKey::new(
+                GlweDimension(input_lwe_dimension.0),
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+                ciphertext_modulus,
+            ),
+        }
+    }
+}
@@ -1,14 +1,21 @@
 pub mod ntru_secret_key;
+pub mod ntru_public_key;
 pub mod ntru_ciphertext;
 pub mod ntru_ciphertext_list;
 pub mod ngsw_ciphertext;
 pub mod ngsw_ciphertext_list;
 pub mod fourier_ngsw_ciphertext;
 pub mod fourier_ngsw_ciphertext_list;
+pub mod ntt_ngsw_ciphertext;
+pub mod ntt_ngsw_ciphertext_list;
+pub mod fourier128_ngsw_ciphertext;
+pub mod fourier128_ngsw_ciphertext_list;
 pub mod ntru_keyswitch_key;
 pub mod ntru_keyswitch_key_list;
 pub mod fourier_ntru_keyswitch_key;
 pub mod fourier_ntru_keyswitch_key_list;
+pub mod ntt_ntru_keyswitch_key;
+pub mod fourier128_ntru_keyswitch_key;
 pub mod ntru_automorphism_key;
 pub mod fourier_ntru_automorphism_key;
 pub mod ntru_switching_key;
@@ -19,27 +26,49 @@ pub mod ntru_trace_key;
 pub mod fourier_ntru_trace_key;
 pub mod ntru_cmux_bootstrap_key;
 pub mod fourier_ntru_cmux_bootstrap_key;
+pub mod ntt_ntru_cmux_bootstrap_key;
+pub mod fourier128_ntru_cmux_bootstrap_key;
+pub mod ntru_multi_bit_bootstrap_key;
+pub mod fourier_ntru_multi_bit_bootstrap_key;
+pub mod ntru_automorphism_bootstrap_key;
+pub mod fourier_ntru_automorphism_bootstrap_key;
 pub mod ntru_cmux_circuit_bootstrap_key;
 pub mod fourier_ntru_cmux_circuit_bootstrap_key;
-pub mod ntru_to_rlwe_keyswitch_key;
-pub mod fourier_ntru_to_rlwe_keyswitch_key;
-pub mod rlwe_scheme_switch_key;
-pub mod fourier_rlwe_scheme_switch_key;
+pub mod seeded_ntru_cmux_circuit_bootstrap_key;
+pub mod ntru_cmux_ngsw_circuit_bootstrap_key;
+pub mod fourier_ntru_cmux_ngsw_circuit_bootstrap_key;
+pub mod ntru_to_glwe_keyswitch_key;
+pub mod fourier_ntru_to_glwe_keyswitch_key;
+pub mod seeded_ntru_to_glwe_keyswitch_key;
+pub mod lwe_to_ntru_packing_keyswitch_key;
+pub mod fourier_lwe_to_ntru_packing_keyswitch_key;
+pub mod rlwe_to_ntru_keyswitch_key;
+pub mod fourier_rlwe_to_ntru_keyswitch_key;
+pub mod glwe_scheme_switch_key;
+pub mod fourier_glwe_scheme_switch_key;
+pub mod seeded_glwe_scheme_switch_key;
 pub mod modulus_switched_lwe_ciphertext_many_lut;
 pub mod automorphism_group;
 pub mod ntru_parameter_sets;
 
 pub use ntru_secret_key::*;
+pub use ntru_public_key::*;
 pub use ntru_ciphertext::*;
 pub use ntru_ciphertext_list::*;
 pub use ngsw_ciphertext::*;
 pub use ngsw_ciphertext_list::*;
 pub use fourier_ngsw_ciphertext::*;
 pub use fourier_ngsw_ciphertext_list::*;
+pub use ntt_ngsw_ciphertext::*;
+pub use ntt_ngsw_ciphertext_list::*;
+pub use fourier128_ngsw_ciphertext::*;
+pub use fourier128_ngsw_ciphertext_list::*;
 pub use ntru_keyswitch_key::*;
 pub use ntru_keyswitch_key_list::*;
 pub use fourier_ntru_keyswitch_key::*;
 pub use fourier_ntru_keyswitch_key_list::*;
+pub use ntt_ntru_keyswitch_key::*;
+pub use fourier128_ntru_keyswitch_key::*;
 pub use ntru_automorphism_key::*;
 pub use fourier_ntru_automorphism_key::*;
 pub use ntru_switching_key::*;
@@ -50,12 +79,27 @@ pub use ntru_trace_key::*;
 pub use fourier_ntru_trace_key::*;
 pub use ntru_cmux_bootstrap_key::*;
 pub use fourier_ntru_cmux_bootstrap_key::*;
+pub use ntt_ntru_cmux_bootstrap_key::*;
+pub use fourier128_ntru_cmux_bootstrap_key::*;
+pub use ntru_multi_bit_bootstrap_key::*;
+pub use fourier_ntru_multi_bit_bootstrap_key::*;
+pub use ntru_automorphism_bootstrap_key::*;
+pub use fourier_ntru_automorphism_bootstrap_key::*;
 pub use ntru_cmux_circuit_bootstrap_key::*;
 pub use fourier_ntru_cmux_circuit_bootstrap_key::*;
-pub use ntru_to_rlwe_keyswitch_key::*;
-pub use fourier_ntru_to_rlwe_keyswitch_key::*;
-pub use rlwe_scheme_switch_key::*;
-pub use fourier_rlwe_scheme_switch_key::*;
+pub use seeded_ntru_cmux_circuit_bootstrap_key::*;
+pub use ntru_cmux_ngsw_circuit_bootstrap_key::*;
+pub use fourier_ntru_cmux_ngsw_circuit_bootstrap_key::*;
+pub use ntru_to_glwe_keyswitch_key::*;
+pub use fourier_ntru_to_glwe_keyswitch_key::*;
+pub use seeded_ntru_to_glwe_keyswitch_key::*;
+pub use lwe_to_ntru_packing_keyswitch_key::*;
+pub use fourier_lwe_to_ntru_packing_keyswitch_key::*;
+pub use rlwe_to_ntru_keyswitch_key::*;
+pub use fourier_rlwe_to_ntru_keyswitch_key::*;
+pub use glwe_scheme_switch_key::*;
+pub use fourier_glwe_scheme_switch_key::*;
+pub use seeded_glwe_scheme_switch_key::*;
 pub use modulus_switched_lwe_ciphertext_many_lut::*;
 pub use automorphism_group::*;
 pub use ntru_parameter_sets::*;
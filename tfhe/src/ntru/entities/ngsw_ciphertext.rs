@@ -1,11 +1,16 @@
 //! Module containing the definition of the NgswCiphertext
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ngsw_ciphertext::NgswCiphertextVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
 use crate::ntru::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NgswCiphertextVersions)]
 pub struct NgswCiphertext<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -16,6 +21,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NgswCiphertext<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NgswCiphertext";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NgswCiphertext<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -28,6 +40,15 @@ impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NgswCipher
     }
 }
 
+/// Return the number of noise samples used during encryption of an [`NgswCiphertext`], i.e., one
+/// noise polynomial per level. NTRU encryption does not draw any mask sample.
+pub fn ngsw_ciphertext_encryption_noise_sample_count(
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+) -> EncryptionNoiseSampleCount {
+    EncryptionNoiseSampleCount(decomp_level_count.0 * polynomial_size.0)
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NgswCiphertext<C> {
     pub fn from_container(
         container: C,
@@ -1,5 +1,11 @@
 //! Module containing the definition of the NgswCiphertextList.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ngsw_ciphertext_list::NgswCiphertextListVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::generators::EncryptionRandomGeneratorForkConfig;
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::ntru::entities::*;
@@ -7,7 +13,8 @@ use crate::ntru::entities::*;
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct NgswCiphertextCount(pub usize);
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NgswCiphertextListVersions)]
 pub struct NgswCiphertextList<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -19,6 +26,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NgswCiphertextList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NgswCiphertextList";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NgswCiphertextList<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -35,6 +49,38 @@ pub type NgswCiphertextListOwned<Scalar> = NgswCiphertextList<Vec<Scalar>>;
 pub type NgswCiphertextListView<'a, Scalar> = NgswCiphertextList<&'a [Scalar]>;
 pub type NgswCiphertextListMutView<'a, Scalar> = NgswCiphertextList<&'a mut [Scalar]>;
 
+// NTRU ciphertexts have no mask, but the CSPRNG refuses to fork children with zero bytes, so each
+// child mask generator is given a single (unused) uniform sample.
+pub fn ngsw_ciphertext_list_encryption_fork_config<Scalar, NoiseDistribution>(
+    ngsw_ciphertext_count: NgswCiphertextCount,
+    polynomial_size: PolynomialSize,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> EncryptionRandomGeneratorForkConfig
+where
+    Scalar: UnsignedInteger
+        + RandomGenerable<Uniform, CustomModulus = Scalar>
+        + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    NoiseDistribution: Distribution,
+{
+    let ngsw_noise_sample_count = ngsw_ciphertext_encryption_noise_sample_count(
+        polynomial_size,
+        decomp_level_count,
+    );
+
+    let modulus = ciphertext_modulus.get_custom_modulus_as_optional_scalar();
+
+    EncryptionRandomGeneratorForkConfig::new(
+        ngsw_ciphertext_count.0,
+        EncryptionMaskSampleCount(1),
+        Uniform,
+        ngsw_noise_sample_count,
+        noise_distribution,
+        modulus,
+    )
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NgswCiphertextList<C> {
     pub fn from_container(
         container: C,
@@ -100,6 +146,24 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NgswCiphertextList
             ciphertext_modulus: self.ciphertext_modulus,
         }
     }
+
+    pub fn encryption_fork_config<NoiseDistribution>(
+        &self,
+        noise_distribution: NoiseDistribution,
+    ) -> EncryptionRandomGeneratorForkConfig
+    where
+        NoiseDistribution: Distribution,
+        Scalar: RandomGenerable<Uniform, CustomModulus = Scalar>
+            + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    {
+        ngsw_ciphertext_list_encryption_fork_config(
+            self.ngsw_ciphertext_count(),
+            self.polynomial_size(),
+            self.decomposition_level_count(),
+            noise_distribution,
+            self.ciphertext_modulus(),
+        )
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NgswCiphertextList<C> {
@@ -0,0 +1,220 @@
+//! Module containing the definition of the NtruAutomorphismBootstrapKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_automorphism_bootstrap_key::NtruAutomorphismBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+/// Number of automorphism keys for the powers `g, g^2, ..., g^w` of the automorphism group
+/// generator `g`. Consecutive automorphisms are merged up to this window during blind rotation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AutomorphismWindowSize(pub usize);
+
+/// Return the automorphism index of the `index`-th automorphism key of an automorphism bootstrap
+/// key with `window_size` windows, i.e., `g^(index + 1)` for `index < window_size` and `-g`
+/// for the last key.
+pub fn automorphism_bootstrap_key_automorphism_index(
+    polynomial_size: PolynomialSize,
+    window_size: AutomorphismWindowSize,
+    index: usize,
+) -> AutomorphismIndex {
+    let modulus = 2 * polynomial_size.0;
+    if index < window_size.0 {
+        let mut auto_index = 1;
+        for _ in 0..=index {
+            auto_index = (auto_index * AUTO_GROUP_GENERATOR) % modulus;
+        }
+        AutomorphismIndex(auto_index)
+    } else {
+        AutomorphismIndex(modulus - AUTO_GROUP_GENERATOR)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruAutomorphismBootstrapKeyVersions)]
+pub struct NtruAutomorphismBootstrapKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    ngsw_list: NgswCiphertextList<C>,
+    ntru_auto_keys: NtruKeyswitchKeyList<C>,
+    ntru_switching_key: NtruSwitchingKey<C>,
+}
+
+impl<C: Container> Named for NtruAutomorphismBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruAutomorphismBootstrapKey";
+}
+
+pub type NtruAutomorphismBootstrapKeyView<'data, Scalar> = NtruAutomorphismBootstrapKey<&'data [Scalar]>;
+pub type NtruAutomorphismBootstrapKeyMutView<'data, Scalar> = NtruAutomorphismBootstrapKey<&'data mut [Scalar]>;
+pub type NtruAutomorphismBootstrapKeyOwned<Scalar> = NtruAutomorphismBootstrapKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruAutomorphismBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ngsw_list.ngsw_ciphertext_count().0)
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ngsw_list.polynomial_size().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.ngsw_list.polynomial_size()
+    }
+
+    pub fn window_size(&self) -> AutomorphismWindowSize {
+        AutomorphismWindowSize(self.ntru_auto_keys.ntru_keyswitch_key_count().0 - 1)
+    }
+
+    pub fn automorphism_key_count(&self) -> NtruKeyswitchKeyCount {
+        self.ntru_auto_keys.ntru_keyswitch_key_count()
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ngsw_list.ciphertext_modulus()
+    }
+
+    pub fn get_ngsw_list(&self) -> NgswCiphertextListView<'_, Scalar> {
+        self.ngsw_list.as_view()
+    }
+
+    pub fn get_automorphism_key(&self, index: usize) -> NtruAutomorphismKeyView<'_, Scalar> {
+        let automorphism_key_count = self.automorphism_key_count().0;
+        assert!(
+            index < automorphism_key_count,
+            "Input index {} should be smaller than the number of automorphism keys {}",
+            index,
+            automorphism_key_count,
+        );
+
+        let auto_index = automorphism_bootstrap_key_automorphism_index(
+            self.polynomial_size(),
+            self.window_size(),
+            index,
+        );
+        NtruAutomorphismKey::from_container(
+            self.ntru_auto_keys.get(index).into_container(),
+            auto_index,
+            self.ntru_auto_keys.polynomial_size(),
+            self.ntru_auto_keys.decomposition_base_log(),
+            self.ntru_auto_keys.ciphertext_modulus(),
+        )
+    }
+
+    pub fn get_ntru_keyswitch_key_list(&self) -> NtruKeyswitchKeyListView<'_, Scalar> {
+        self.ntru_auto_keys.as_view()
+    }
+
+    pub fn get_ntru_switching_key(&self) -> NtruSwitchingKeyView<'_, Scalar> {
+        self.ntru_switching_key.as_view()
+    }
+
+    pub fn as_view(&self) -> NtruAutomorphismBootstrapKeyView<'_, Scalar> {
+        NtruAutomorphismBootstrapKeyView::<'_, Scalar> {
+            ngsw_list: self.ngsw_list.as_view(),
+            ntru_auto_keys: self.ntru_auto_keys.as_view(),
+            ntru_switching_key: self.ntru_switching_key.as_view(),
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruAutomorphismBootstrapKey<C> {
+    pub fn get_mut_ngsw_list(&mut self) -> NgswCiphertextListMutView<'_, Scalar> {
+        self.ngsw_list.as_mut_view()
+    }
+
+    pub fn get_mut_automorphism_key(&mut self, index: usize) -> NtruAutomorphismKeyMutView<'_, Scalar> {
+        let automorphism_key_count = self.automorphism_key_count().0;
+        assert!(
+            index < automorphism_key_count,
+            "Input index {} should be smaller than the number of automorphism keys {}",
+            index,
+            automorphism_key_count,
+        );
+
+        let auto_index = automorphism_bootstrap_key_automorphism_index(
+            self.polynomial_size(),
+            self.window_size(),
+            index,
+        );
+        let polynomial_size = self.ntru_auto_keys.polynomial_size();
+        let decomp_base_log = self.ntru_auto_keys.decomposition_base_log();
+        let ciphertext_modulus = self.ntru_auto_keys.ciphertext_modulus();
+        NtruAutomorphismKey::from_container(
+            self.ntru_auto_keys.get_mut(index).into_container(),
+            auto_index,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn get_mut_ntru_keyswitch_key_list(&mut self) -> NtruKeyswitchKeyListMutView<'_, Scalar> {
+        self.ntru_auto_keys.as_mut_view()
+    }
+
+    pub fn get_mut_ntru_switching_key(&mut self) -> NtruSwitchingKeyMutView<'_, Scalar> {
+        self.ntru_switching_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> NtruAutomorphismBootstrapKeyMutView<'_, Scalar> {
+        NtruAutomorphismBootstrapKeyMutView::<'_, Scalar> {
+            ngsw_list: self.ngsw_list.as_mut_view(),
+            ntru_auto_keys: self.ntru_auto_keys.as_mut_view(),
+            ntru_switching_key: self.ntru_switching_key.as_mut_view(),
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> NtruAutomorphismBootstrapKeyOwned<Scalar> {
+    pub fn new(
+        fill_with: Scalar,
+        polynomial_size: PolynomialSize,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        auto_decomp_base_log: DecompositionBaseLog,
+        auto_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        input_lwe_dimension: LweDimension,
+        window_size: AutomorphismWindowSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            window_size.0 > 0,
+            "The automorphism window size should be positive.",
+        );
+
+        Self {
+            ngsw_list: NgswCiphertextList::new(
+                fill_with,
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                NgswCiphertextCount(input_lwe_dimension.0),
+                ciphertext_modulus,
+            ),
+            ntru_auto_keys: NtruKeyswitchKeyList::new(
+                fill_with,
+                polynomial_size,
+                auto_decomp_base_log,
+                auto_decomp_level_count,
+                NtruKeyswitchKeyCount(window_size.0 + 1),
+                ciphertext_modulus,
+            ),
+            ntru_switching_key: NtruSwitchingKey::new(
+                fill_with,
+                polynomial_size,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                ciphertext_modulus,
+            ),
+        }
+    }
+}
@@ -1,14 +1,20 @@
 //! Module containing the definition of the NtruAutomorphismKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_automorphism_key::{AutomorphismIndexVersions, NtruAutomorphismKeyVersions};
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
 use crate::ntru::entities::*;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(AutomorphismIndexVersions)]
 pub struct AutomorphismIndex(pub usize);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruAutomorphismKeyVersions)]
 pub struct NtruAutomorphismKey<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -20,6 +26,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruAutomorphismKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruAutomorphismKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruAutomorphismKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -53,7 +66,7 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruAutomorphismKe
         );
         assert!(
             ciphertext_modulus.is_compatible_with_native_modulus(),
-            "Only support power-of-two modulus currently.",
+            "Only support power-of-two modulus currently, automorphism keys cannot be generated for a prime modulus.",
         );
 
         Self {
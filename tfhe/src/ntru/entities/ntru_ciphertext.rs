@@ -1,10 +1,15 @@
 //! Module containing the definition of the NtruCiphertext.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_ciphertext::NtruCiphertextVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruCiphertextVersions)]
 pub struct NtruCiphertext<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -14,6 +19,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruCiphertext<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruCiphertext";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCiphertext<C> {
     pub fn from_container(
         container: C,
@@ -28,8 +40,9 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCiphertext<C>
         );
 
         assert!(
-            ciphertext_modulus.is_power_of_two(),
-            "Only supports power-of-two modulus currently"
+            ciphertext_modulus.is_power_of_two()
+                || ciphertext_modulus.get_custom_modulus() % 2 == 1,
+            "Only supports power-of-two or odd modulus currently"
         );
 
         Self {
@@ -58,6 +71,10 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCiphertext<C>
             ciphertext_modulus: self.ciphertext_modulus,
         }
     }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruCiphertext<C> {
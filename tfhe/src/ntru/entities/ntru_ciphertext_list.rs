@@ -1,3 +1,9 @@
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_ciphertext_list::NtruCiphertextListVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::generators::EncryptionRandomGeneratorForkConfig;
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::ntru::entities::*;
@@ -5,6 +11,8 @@ use crate::ntru::entities::*;
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct NtruCiphertextCount(pub usize);
 
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruCiphertextListVersions)]
 pub struct NtruCiphertextList<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -14,6 +22,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruCiphertextList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruCiphertextList";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruCiphertextList<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -26,6 +41,32 @@ impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NtruCipher
     }
 }
 
+// NTRU ciphertexts have no mask, but the CSPRNG refuses to fork children with zero bytes, so each
+// child mask generator is given a single (unused) uniform sample.
+pub fn ntru_ciphertext_list_encryption_fork_config<Scalar, NoiseDistribution>(
+    ntru_ciphertext_count: NtruCiphertextCount,
+    polynomial_size: PolynomialSize,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> EncryptionRandomGeneratorForkConfig
+where
+    Scalar: UnsignedInteger
+        + RandomGenerable<Uniform, CustomModulus = Scalar>
+        + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    NoiseDistribution: Distribution,
+{
+    let modulus = ciphertext_modulus.get_custom_modulus_as_optional_scalar();
+
+    EncryptionRandomGeneratorForkConfig::new(
+        ntru_ciphertext_count.0,
+        EncryptionMaskSampleCount(1),
+        Uniform,
+        EncryptionNoiseSampleCount(polynomial_size.0),
+        noise_distribution,
+        modulus,
+    )
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCiphertextList<C> {
     pub fn from_container(
         container: C,
@@ -71,6 +112,23 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCiphertextList
             ciphertext_modulus: self.ciphertext_modulus,
         }
     }
+
+    pub fn encryption_fork_config<NoiseDistribution>(
+        &self,
+        noise_distribution: NoiseDistribution,
+    ) -> EncryptionRandomGeneratorForkConfig
+    where
+        NoiseDistribution: Distribution,
+        Scalar: RandomGenerable<Uniform, CustomModulus = Scalar>
+            + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    {
+        ntru_ciphertext_list_encryption_fork_config(
+            self.ntru_ciphertext_count(),
+            self.polynomial_size(),
+            noise_distribution,
+            self.ciphertext_modulus(),
+        )
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruCiphertextList<C> {
@@ -1,10 +1,15 @@
 //! Module containing the definition of the NtruCMuxBootstrapKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_cmux_bootstrap_key::NtruCMuxBootstrapKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::ntru::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruCMuxBootstrapKeyVersions)]
 pub struct NtruCMuxBootstrapKey<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -13,6 +18,13 @@ where
     ntru_switching_key: NtruSwitchingKey<C>,
 }
 
+impl<C: Container> Named for NtruCMuxBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruCMuxBootstrapKey";
+}
+
 pub type NtruCMuxBootstrapKeyView<'data, Scalar> = NtruCMuxBootstrapKey<&'data [Scalar]>;
 pub type NtruCMuxBootstrapKeyMutView<'data, Scalar> = NtruCMuxBootstrapKey<&'data mut [Scalar]>;
 pub type NtruCMuxBootstrapKeyOwned<Scalar> = NtruCMuxBootstrapKey<Vec<Scalar>>;
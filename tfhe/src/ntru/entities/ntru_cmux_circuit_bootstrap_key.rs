@@ -1,21 +1,33 @@
 //! Module containing the definition of the NtruCMuxCircuitBootstrapKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_cmux_circuit_bootstrap_key::NtruCMuxCircuitBootstrapKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::ntru::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruCMuxCircuitBootstrapKeyVersions)]
 pub struct NtruCMuxCircuitBootstrapKey<C: Container>
 where
     C::Element: UnsignedInteger,
 {
     ntru_cmux_bsk: NtruCMuxBootstrapKey<C>,
     ntru_trace_key: NtruTraceKey<C>,
-    ntru_to_rlwe_ksk: NtruToRlweKeyswitchKey<C>,
-    rlwe_ss_key: RlweSchemeSwitchKey<C>,
+    ntru_to_glwe_ksk: NtruToGlweKeyswitchKey<C>,
+    glwe_ss_key: GlweSchemeSwitchKey<C>,
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruCMuxCircuitBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruCMuxCircuitBootstrapKey";
+}
+
 pub type NtruCMuxCircuitBootstrapKeyView<'data, Scalar> = NtruCMuxCircuitBootstrapKey<&'data [Scalar]>;
 pub type NtruCMuxCircuitBootstrapKeyMutView<'data, Scalar> = NtruCMuxCircuitBootstrapKey<&'data mut [Scalar]>;
 pub type NtruCMuxCircuitBootstrapKeyOwned<Scalar> = NtruCMuxCircuitBootstrapKey<Vec<Scalar>>;
@@ -33,6 +45,10 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCMuxCircuitBoo
         self.ntru_cmux_bsk.polynomial_size()
     }
 
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_ss_key.glwe_size()
+    }
+
     pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
         self.ciphertext_modulus
     }
@@ -45,20 +61,20 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCMuxCircuitBoo
         self.ntru_trace_key.as_view()
     }
 
-    pub fn get_ntru_to_rlwe_keyswitch_key(&self) -> NtruToRlweKeyswitchKeyView<'_, Scalar> {
-        self.ntru_to_rlwe_ksk.as_view()
+    pub fn get_ntru_to_glwe_keyswitch_key(&self) -> NtruToGlweKeyswitchKeyView<'_, Scalar> {
+        self.ntru_to_glwe_ksk.as_view()
     }
 
-    pub fn get_rlwe_scheme_switch_key(&self) -> RlweSchemeSwitchKeyView<'_, Scalar> {
-        self.rlwe_ss_key.as_view()
+    pub fn get_glwe_scheme_switch_key(&self) -> GlweSchemeSwitchKeyView<'_, Scalar> {
+        self.glwe_ss_key.as_view()
     }
 
     pub fn as_view(&self) -> NtruCMuxCircuitBootstrapKeyView<'_, Scalar> {
         NtruCMuxCircuitBootstrapKeyView::<'_, Scalar> {
             ntru_cmux_bsk: self.ntru_cmux_bsk.as_view(),
             ntru_trace_key: self.ntru_trace_key.as_view(),
-            ntru_to_rlwe_ksk: self.ntru_to_rlwe_ksk.as_view(),
-            rlwe_ss_key: self.rlwe_ss_key.as_view(),
+            ntru_to_glwe_ksk: self.ntru_to_glwe_ksk.as_view(),
+            glwe_ss_key: self.glwe_ss_key.as_view(),
             ciphertext_modulus: self.ciphertext_modulus,
         }
     }
@@ -73,20 +89,20 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruCMuxCircuit
         self.ntru_trace_key.as_mut_view()
     }
 
-    pub fn get_mut_ntru_to_rlwe_keyswitch_key(&mut self) -> NtruToRlweKeyswitchKeyMutView<'_, Scalar> {
-        self.ntru_to_rlwe_ksk.as_mut_view()
+    pub fn get_mut_ntru_to_glwe_keyswitch_key(&mut self) -> NtruToGlweKeyswitchKeyMutView<'_, Scalar> {
+        self.ntru_to_glwe_ksk.as_mut_view()
     }
 
-    pub fn get_mut_rlwe_scheme_switch_key(&mut self) -> RlweSchemeSwitchKeyMutView<'_, Scalar> {
-        self.rlwe_ss_key.as_mut_view()
+    pub fn get_mut_glwe_scheme_switch_key(&mut self) -> GlweSchemeSwitchKeyMutView<'_, Scalar> {
+        self.glwe_ss_key.as_mut_view()
     }
 
     pub fn as_mut_view(&mut self) -> NtruCMuxCircuitBootstrapKeyMutView<'_, Scalar> {
         NtruCMuxCircuitBootstrapKeyMutView::<'_, Scalar> {
             ntru_cmux_bsk: self.ntru_cmux_bsk.as_mut_view(),
             ntru_trace_key: self.ntru_trace_key.as_mut_view(),
-            ntru_to_rlwe_ksk: self.ntru_to_rlwe_ksk.as_mut_view(),
-            rlwe_ss_key: self.rlwe_ss_key.as_mut_view(),
+            ntru_to_glwe_ksk: self.ntru_to_glwe_ksk.as_mut_view(),
+            glwe_ss_key: self.glwe_ss_key.as_mut_view(),
             ciphertext_modulus: self.ciphertext_modulus,
         }
     }
@@ -95,6 +111,7 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruCMuxCircuit
 impl<Scalar: UnsignedInteger> NtruCMuxCircuitBootstrapKeyOwned<Scalar> {
     pub fn new(
         fill_with: Scalar,
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         input_lwe_dimension: LweDimension,
         br_decomp_base_log: DecompositionBaseLog,
@@ -126,15 +143,17 @@ impl<Scalar: UnsignedInteger> NtruCMuxCircuitBootstrapKeyOwned<Scalar> {
                 tr_decomp_level_count,
                 ciphertext_modulus,
             ),
-            ntru_to_rlwe_ksk: NtruToRlweKeyswitchKey::new(
+            ntru_to_glwe_ksk: NtruToGlweKeyswitchKey::new(
                 fill_with,
+                glwe_size,
                 polynomial_size,
                 ksk_decomp_base_log,
                 ksk_decomp_level_count,
                 ciphertext_modulus,
             ),
-            rlwe_ss_key: RlweSchemeSwitchKey::new(
+            glwe_ss_key: GlweSchemeSwitchKey::new(
                 fill_with,
+                glwe_size,
                 polynomial_size,
                 ss_decomp_base_log,
                 ss_decomp_level_count,
@@ -0,0 +1,141 @@
+//! Module containing the definition of the NtruCMuxNgswCircuitBootstrapKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_cmux_ngsw_circuit_bootstrap_key::NtruCMuxNgswCircuitBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+/// Key bundle of the CBS whose output is an NGSW ciphertext: the NTRU ciphertexts produced by the
+/// blind rotation and the trace are scheme switched under the NTRU secret key itself, so that no
+/// RLWE key is involved.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruCMuxNgswCircuitBootstrapKeyVersions)]
+pub struct NtruCMuxNgswCircuitBootstrapKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    ntru_cmux_bsk: NtruCMuxBootstrapKey<C>,
+    ntru_trace_key: NtruTraceKey<C>,
+    ntru_ss_key: NtruSchemeSwitchKey<C>,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for NtruCMuxNgswCircuitBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruCMuxNgswCircuitBootstrapKey";
+}
+
+pub type NtruCMuxNgswCircuitBootstrapKeyView<'data, Scalar> = NtruCMuxNgswCircuitBootstrapKey<&'data [Scalar]>;
+pub type NtruCMuxNgswCircuitBootstrapKeyMutView<'data, Scalar> = NtruCMuxNgswCircuitBootstrapKey<&'data mut [Scalar]>;
+pub type NtruCMuxNgswCircuitBootstrapKeyOwned<Scalar> = NtruCMuxNgswCircuitBootstrapKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruCMuxNgswCircuitBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.ntru_cmux_bsk.input_lwe_dimension()
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        self.ntru_cmux_bsk.output_lwe_dimension()
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.ntru_cmux_bsk.polynomial_size()
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    pub fn get_ntru_cmux_bootstrap_key(&self) -> NtruCMuxBootstrapKeyView<'_, Scalar> {
+        self.ntru_cmux_bsk.as_view()
+    }
+
+    pub fn get_ntru_trace_key(&self) -> NtruTraceKeyView<'_, Scalar> {
+        self.ntru_trace_key.as_view()
+    }
+
+    pub fn get_ntru_scheme_switch_key(&self) -> NtruSchemeSwitchKeyView<'_, Scalar> {
+        self.ntru_ss_key.as_view()
+    }
+
+    pub fn as_view(&self) -> NtruCMuxNgswCircuitBootstrapKeyView<'_, Scalar> {
+        NtruCMuxNgswCircuitBootstrapKeyView::<'_, Scalar> {
+            ntru_cmux_bsk: self.ntru_cmux_bsk.as_view(),
+            ntru_trace_key: self.ntru_trace_key.as_view(),
+            ntru_ss_key: self.ntru_ss_key.as_view(),
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruCMuxNgswCircuitBootstrapKey<C> {
+    pub fn get_mut_ntru_cmux_bootstrap_key(&mut self) -> NtruCMuxBootstrapKeyMutView<'_, Scalar> {
+        self.ntru_cmux_bsk.as_mut_view()
+    }
+
+    pub fn get_mut_ntru_trace_key(&mut self) -> NtruTraceKeyMutView<'_, Scalar> {
+        self.ntru_trace_key.as_mut_view()
+    }
+
+    pub fn get_mut_ntru_scheme_switch_key(&mut self) -> NtruSchemeSwitchKeyMutView<'_, Scalar> {
+        self.ntru_ss_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> NtruCMuxNgswCircuitBootstrapKeyMutView<'_, Scalar> {
+        NtruCMuxNgswCircuitBootstrapKeyMutView::<'_, Scalar> {
+            ntru_cmux_bsk: self.ntru_cmux_bsk.as_mut_view(),
+            ntru_trace_key: self.ntru_trace_key.as_mut_view(),
+            ntru_ss_key: self.ntru_ss_key.as_mut_view(),
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> NtruCMuxNgswCircuitBootstrapKeyOwned<Scalar> {
+    pub fn new(
+        fill_with: Scalar,
+        polynomial_size: PolynomialSize,
+        input_lwe_dimension: LweDimension,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        tr_decomp_base_log: DecompositionBaseLog,
+        tr_decomp_level_count: DecompositionLevelCount,
+        ss_decomp_base_log: DecompositionBaseLog,
+        ss_decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self {
+            ntru_cmux_bsk: NtruCMuxBootstrapKey::new(
+                fill_with,
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                input_lwe_dimension,
+                ciphertext_modulus,
+            ),
+            ntru_trace_key: NtruTraceKey::new(
+                polynomial_size,
+                tr_decomp_base_log,
+                tr_decomp_level_count,
+                ciphertext_modulus,
+            ),
+            ntru_ss_key: NtruSchemeSwitchKey::new(
+                fill_with,
+                polynomial_size,
+                ss_decomp_base_log,
+                ss_decomp_level_count,
+                ciphertext_modulus,
+            ),
+            ciphertext_modulus,
+        }
+    }
+}
@@ -1,11 +1,16 @@
 //! Module containing the definition of the NtruKeyswitchKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_keyswitch_key::NtruKeyswitchKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
 use crate::ntru::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruKeyswitchKeyVersions)]
 pub struct NtruKeyswitchKey<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -16,6 +21,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruKeyswitchKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruKeyswitchKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -51,8 +63,9 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruKeyswitchKey<C
             container.container_len(),
         );
         assert!(
-            ciphertext_modulus.is_compatible_with_native_modulus(),
-            "Only support power-of-two modulus currently.",
+            ciphertext_modulus.is_compatible_with_native_modulus()
+                || ciphertext_modulus.get_custom_modulus() % 2 == 1,
+            "Only support power-of-two or odd modulus currently.",
         );
 
         Self {
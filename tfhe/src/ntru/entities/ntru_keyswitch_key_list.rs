@@ -1,5 +1,9 @@
 //! Module containing the definition of the NtruKeyswitchKeyList.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_keyswitch_key_list::NtruKeyswitchKeyListVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::ntru::entities::*;
@@ -7,7 +11,8 @@ use crate::ntru::entities::*;
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct NtruKeyswitchKeyCount(pub usize);
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruKeyswitchKeyListVersions)]
 pub struct NtruKeyswitchKeyList<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -19,6 +24,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruKeyswitchKeyList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruKeyswitchKeyList";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruKeyswitchKeyList<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -0,0 +1,137 @@
+//! Module containing the definition of the NtruMultiBitBootstrapKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_multi_bit_bootstrap_key::NtruMultiBitBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+/// A bootstrap key grouping the input LWE mask elements by `grouping_factor`: each group of
+/// `grouping_factor` key bits is encrypted as `2^grouping_factor` NGSW ciphertexts, one per
+/// product of (possibly inverted) key bits.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruMultiBitBootstrapKeyVersions)]
+pub struct NtruMultiBitBootstrapKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    ngsw_list: NgswCiphertextList<C>,
+    ntru_switching_key: NtruSwitchingKey<C>,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+impl<C: Container> Named for NtruMultiBitBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruMultiBitBootstrapKey";
+}
+
+pub type NtruMultiBitBootstrapKeyView<'data, Scalar> = NtruMultiBitBootstrapKey<&'data [Scalar]>;
+pub type NtruMultiBitBootstrapKeyMutView<'data, Scalar> = NtruMultiBitBootstrapKey<&'data mut [Scalar]>;
+pub type NtruMultiBitBootstrapKeyOwned<Scalar> = NtruMultiBitBootstrapKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruMultiBitBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.multi_bit_input_lwe_dimension().0 * self.grouping_factor.0)
+    }
+
+    pub fn multi_bit_input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(
+            self.ngsw_list.ngsw_ciphertext_count().0
+                / self.grouping_factor.ggsw_per_multi_bit_element().0,
+        )
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ngsw_list.polynomial_size().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.ngsw_list.polynomial_size()
+    }
+
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    pub fn get_ngsw_list(&self) -> NgswCiphertextListView<'_, Scalar> {
+        self.ngsw_list.as_view()
+    }
+
+    pub fn get_ntru_switching_key(&self) -> NtruSwitchingKeyView<'_, Scalar> {
+        self.ntru_switching_key.as_view()
+    }
+
+    pub fn as_view(&self) -> NtruMultiBitBootstrapKeyView<'_, Scalar> {
+        NtruMultiBitBootstrapKeyView::<'_, Scalar> {
+            ngsw_list: self.ngsw_list.as_view(),
+            ntru_switching_key: self.ntru_switching_key.as_view(),
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruMultiBitBootstrapKey<C> {
+    pub fn get_mut_ngsw_list(&mut self) -> NgswCiphertextListMutView<'_, Scalar> {
+        self.ngsw_list.as_mut_view()
+    }
+
+    pub fn get_mut_ntru_switching_key(&mut self) -> NtruSwitchingKeyMutView<'_, Scalar> {
+        self.ntru_switching_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> NtruMultiBitBootstrapKeyMutView<'_, Scalar> {
+        NtruMultiBitBootstrapKeyMutView::<'_, Scalar> {
+            ngsw_list: self.ngsw_list.as_mut_view(),
+            ntru_switching_key: self.ntru_switching_key.as_mut_view(),
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> NtruMultiBitBootstrapKeyOwned<Scalar> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fill_with: Scalar,
+        polynomial_size: PolynomialSize,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        input_lwe_dimension: LweDimension,
+        grouping_factor: LweBskGroupingFactor,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            input_lwe_dimension.0 % grouping_factor.0 == 0,
+            "Multi Bit BSK requires input LWE dimension ({}) to be a multiple of {}",
+            input_lwe_dimension.0,
+            grouping_factor.0
+        );
+        let equivalent_multi_bit_dimension = input_lwe_dimension.0 / grouping_factor.0;
+
+        Self {
+            ngsw_list: NgswCiphertextList::new(
+                fill_with,
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                NgswCiphertextCount(
+                    equivalent_multi_bit_dimension * grouping_factor.ggsw_per_multi_bit_element().0,
+                ),
+                ciphertext_modulus,
+            ),
+            ntru_switching_key: NtruSwitchingKey::new(
+                fill_with,
+                polynomial_size,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                ciphertext_modulus,
+            ),
+            grouping_factor,
+        }
+    }
+}
@@ -1,5 +1,92 @@
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
+use crate::ntru::noise_formulas::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NtruCMuxParametersError {
+    PolynomialSizeNotPowerOfTwo(PolynomialSize),
+    EmptyLweDimension,
+    UnsupportedModulus(CiphertextModulusLog),
+    InputModulusTooSmall {
+        log_input_modulus: CiphertextModulusLog,
+        polynomial_size: PolynomialSize,
+    },
+    NonPositiveStdDev {
+        name: &'static str,
+        std_dev: f64,
+    },
+    InvalidDecomposition {
+        name: &'static str,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        log_modulus: CiphertextModulusLog,
+    },
+    InvalidLutCount {
+        log_lut_count: LutCountLog,
+        decomp_level_count: DecompositionLevelCount,
+        polynomial_size: PolynomialSize,
+    },
+    Insecure {
+        name: &'static str,
+        security_bits: f64,
+        target_security_bits: f64,
+    },
+}
+
+impl std::fmt::Display for NtruCMuxParametersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PolynomialSizeNotPowerOfTwo(polynomial_size) => {
+                write!(f, "Polynomial size {} is not a power of two", polynomial_size.0)
+            }
+            Self::EmptyLweDimension => write!(f, "Input LWE dimension must be positive"),
+            Self::UnsupportedModulus(log_modulus) => {
+                write!(f, "Modulus 2^{} is not in [2, 2^64]", log_modulus.0)
+            }
+            Self::InputModulusTooSmall {
+                log_input_modulus,
+                polynomial_size,
+            } => write!(
+                f,
+                "Input modulus 2^{} is smaller than 2N = {}",
+                log_input_modulus.0,
+                2 * polynomial_size.0,
+            ),
+            Self::NonPositiveStdDev { name, std_dev } => {
+                write!(f, "{name} std dev {std_dev} must be positive")
+            }
+            Self::InvalidDecomposition {
+                name,
+                decomp_base_log,
+                decomp_level_count,
+                log_modulus,
+            } => write!(
+                f,
+                "{name} decomposition (B = 2^{}, l = {}) does not fit in the modulus 2^{}",
+                decomp_base_log.0, decomp_level_count.0, log_modulus.0,
+            ),
+            Self::InvalidLutCount {
+                log_lut_count,
+                decomp_level_count,
+                polynomial_size,
+            } => write!(
+                f,
+                "LUT count 2^{} exceeds the level count {} or the polynomial size {}",
+                log_lut_count.0, decomp_level_count.0, polynomial_size.0,
+            ),
+            Self::Insecure {
+                name,
+                security_bits,
+                target_security_bits,
+            } => write!(
+                f,
+                "{name} instance has {security_bits:.1} bits of security, below the target {target_security_bits:.1} bits",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NtruCMuxParametersError {}
 
 #[derive(Clone, Debug, Copy)]
 pub struct NtruCMuxParameters {
@@ -136,6 +223,148 @@ impl NtruCMuxParameters {
         self.ss_decomp_level_count
     }
 
+    /// Check the internal consistency of the parameters.
+    pub fn validate(&self) -> Result<(), NtruCMuxParametersError> {
+        if !self.polynomial_size.0.is_power_of_two() {
+            return Err(NtruCMuxParametersError::PolynomialSizeNotPowerOfTwo(self.polynomial_size));
+        }
+
+        if self.input_lwe_dimension.0 == 0 {
+            return Err(NtruCMuxParametersError::EmptyLweDimension);
+        }
+
+        for log_modulus in [self.log_output_modulus, self.log_input_modulus] {
+            if log_modulus.0 < 2 || log_modulus.0 > 64 {
+                return Err(NtruCMuxParametersError::UnsupportedModulus(log_modulus));
+            }
+        }
+
+        // The input LWE ciphertexts are switched to the modulus 2N by a right shift
+        if self.log_input_modulus.0 <= self.polynomial_size.log2().0 {
+            return Err(NtruCMuxParametersError::InputModulusTooSmall {
+                log_input_modulus: self.log_input_modulus,
+                polynomial_size: self.polynomial_size,
+            });
+        }
+
+        for (name, std_dev) in [
+            ("NTRU", self.ntru_std_dev),
+            ("RLWE", self.rlwe_std_dev),
+            ("LWE", self.lwe_std_dev),
+        ] {
+            if std_dev.is_nan() || std_dev <= 0.0 {
+                return Err(NtruCMuxParametersError::NonPositiveStdDev { name, std_dev });
+            }
+        }
+
+        for (name, decomp_base_log, decomp_level_count) in [
+            ("Blind rotation", self.br_decomp_base_log, self.br_decomp_level_count),
+            ("Trace", self.tr_decomp_base_log, self.tr_decomp_level_count),
+            ("Keyswitch", self.ksk_decomp_base_log, self.ksk_decomp_level_count),
+            ("Scheme switch", self.ss_decomp_base_log, self.ss_decomp_level_count),
+        ] {
+            self.validate_decomposition(name, decomp_base_log, decomp_level_count)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check the parameters together with the ones of the circuit bootstrapping, where each blind
+    /// rotation outputs 2^log_lut_count levels of the output NGSW ciphertext.
+    pub fn validate_circuit_bootstrap(
+        &self,
+        log_lut_count: LutCountLog,
+        cbs_decomp_base_log: DecompositionBaseLog,
+        cbs_decomp_level_count: DecompositionLevelCount,
+    ) -> Result<(), NtruCMuxParametersError> {
+        self.validate()?;
+        self.validate_decomposition("Circuit bootstrapping", cbs_decomp_base_log, cbs_decomp_level_count)?;
+
+        let lut_count = 1usize.checked_shl(log_lut_count.0 as u32).unwrap_or(usize::MAX);
+        if lut_count > cbs_decomp_level_count.0 || lut_count > self.polynomial_size.0 {
+            return Err(NtruCMuxParametersError::InvalidLutCount {
+                log_lut_count,
+                decomp_level_count: cbs_decomp_level_count,
+                polynomial_size: self.polynomial_size,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn validate_decomposition(
+        &self,
+        name: &'static str,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+    ) -> Result<(), NtruCMuxParametersError> {
+        if decomp_base_log.0 == 0
+            || decomp_level_count.0 == 0
+            || decomp_base_log.0 * decomp_level_count.0 > self.log_output_modulus.0
+        {
+            return Err(NtruCMuxParametersError::InvalidDecomposition {
+                name,
+                decomp_base_log,
+                decomp_level_count,
+                log_modulus: self.log_output_modulus,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Estimate the security of the NTRU, RLWE and LWE instances of the parameters.
+    ///
+    /// The NTRU secret key and errors are Gaussian with std dev ntru_std_dev, while the RLWE and
+    /// LWE secret keys are binary. NTRU is estimated against both the key recovery and the dense
+    /// sublattice attacks, the latter being the cheaper one above the fatigue point.
+    pub fn security_report(&self) -> NtruCMuxSecurityReport {
+        let n = self.input_lwe_dimension.0;
+        let big_n = self.polynomial_size.0;
+
+        NtruCMuxSecurityReport {
+            ntru_security_bits: ntru_key_recovery_security_bits(
+                self.polynomial_size,
+                self.log_output_modulus,
+                self.ntru_std_dev,
+                self.ntru_std_dev,
+            ),
+            ntru_dense_sublattice_security_bits: ntru_dense_sublattice_security_bits(
+                self.polynomial_size,
+                self.log_output_modulus,
+                self.ntru_std_dev,
+                self.ntru_std_dev,
+            ),
+            ntru_fatigue_point_log2: ntru_fatigue_point_log2(
+                self.polynomial_size,
+                self.ntru_std_dev,
+                self.ntru_std_dev,
+            ),
+            log_output_modulus: self.log_output_modulus,
+            rlwe_security_bits: lwe_primal_usvp_security_bits(
+                big_n,
+                2 * big_n,
+                self.log_output_modulus,
+                self.rlwe_std_dev,
+                BINARY_SECRET_STD_DEV,
+            ),
+            lwe_security_bits: lwe_primal_usvp_security_bits(
+                n,
+                2 * n,
+                self.log_input_modulus,
+                self.lwe_std_dev,
+                BINARY_SECRET_STD_DEV,
+            ),
+        }
+    }
+
+    /// Check the consistency of the parameters and that every instance reaches the target
+    /// security, see [`Self::security_report`].
+    pub fn validate_security(&self, target_security_bits: f64) -> Result<(), NtruCMuxParametersError> {
+        self.validate()?;
+        self.security_report().check(target_security_bits)
+    }
+
     pub fn print_info(&self) {
         println!("================ {} ================", self.name);
         println!(
@@ -158,10 +387,64 @@ impl NtruCMuxParameters {
         println!("B_tr: 2^{}, l_tr: {}", self.tr_decomp_base_log.0, self.tr_decomp_level_count.0);
         println!("B_ksk: 2^{}, l_ksk: {}", self.ksk_decomp_base_log.0, self.ksk_decomp_level_count.0);
         println!("B_ss: 2^{}, l_ss: {}", self.ss_decomp_base_log.0, self.ss_decomp_level_count.0);
+        if let Err(err) = self.validate() {
+            println!("Invalid parameters: {err}");
+        } else {
+            self.security_report().print_info();
+        }
         println!();
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NtruCMuxSecurityReport {
+    pub ntru_security_bits: f64,
+    pub ntru_dense_sublattice_security_bits: f64,
+    pub ntru_fatigue_point_log2: f64,
+    pub rlwe_security_bits: f64,
+    pub lwe_security_bits: f64,
+    log_output_modulus: CiphertextModulusLog,
+}
+
+impl NtruCMuxSecurityReport {
+    /// Above the fatigue point, the dense sublattice attack on NTRU is cheaper than the key
+    /// recovery. This is informative only, both attacks are accounted for by [`Self::check`].
+    pub fn is_ntru_overstretched(&self) -> bool {
+        self.log_output_modulus.0 as f64 > self.ntru_fatigue_point_log2
+    }
+
+    pub fn check(&self, target_security_bits: f64) -> Result<(), NtruCMuxParametersError> {
+        for (name, security_bits) in [
+            ("NTRU", self.ntru_security_bits),
+            ("NTRU dense sublattice", self.ntru_dense_sublattice_security_bits),
+            ("RLWE", self.rlwe_security_bits),
+            ("LWE", self.lwe_security_bits),
+        ] {
+            if security_bits < target_security_bits {
+                return Err(NtruCMuxParametersError::Insecure {
+                    name,
+                    security_bits,
+                    target_security_bits,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn print_info(&self) {
+        println!(
+            "NTRU: {:.1} bits (dense sublattice: {:.1} bits, fatigue point: 2^{:.1}{}), RLWE: {:.1} bits, LWE: {:.1} bits",
+            self.ntru_security_bits,
+            self.ntru_dense_sublattice_security_bits,
+            self.ntru_fatigue_point_log2,
+            if self.is_ntru_overstretched() { ", overstretched" } else { "" },
+            self.rlwe_security_bits,
+            self.lwe_security_bits,
+        );
+    }
+}
+
 pub const NTRU_CMUX_STD128B2: NtruCMuxParameters = NtruCMuxParameters {
     name: "STD128B2",
     polynomial_size: PolynomialSize(2048),
@@ -0,0 +1,198 @@
+//! Module containing the definition of the NtruPublicKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_public_key::NtruPublicKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+/// A public key for the NTRU encryption under a secret key f.
+///
+/// The first polynomial is h = g_0 / f, an NTRU encryption of zero. The following polynomials
+/// are NTRU encryptions of the gadget values q / B^j for j = 1, ..., l, where B^l = q, so that
+/// any plaintext can be encrypted as a small combination of them.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruPublicKeyVersions)]
+pub struct NtruPublicKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for NtruPublicKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruPublicKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruPublicKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NtruPublicKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+pub type NtruPublicKeyOwned<Scalar> = NtruPublicKey<Vec<Scalar>>;
+pub type NtruPublicKeyView<'data, Scalar> = NtruPublicKey<&'data [Scalar]>;
+pub type NtruPublicKeyMutView<'data, Scalar> = NtruPublicKey<&'data mut [Scalar]>;
+
+/// Return the number of gadget encryptions of an [`NtruPublicKey`], i.e., log q / log B.
+pub fn ntru_public_key_decomposition_level_count<Scalar: UnsignedInteger>(
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> DecompositionLevelCount {
+    let log_modulus = ciphertext_modulus.into_modulus_log().0;
+    assert!(
+        decomp_base_log.0 > 0 && log_modulus % decomp_base_log.0 == 0,
+        "The decomposition base log {} must divide the log of the ciphertext modulus {}.",
+        decomp_base_log.0,
+        log_modulus,
+    );
+
+    DecompositionLevelCount(log_modulus / decomp_base_log.0)
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruPublicKey<C> {
+    pub fn from_container(
+        container: C,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            ciphertext_modulus.is_compatible_with_native_modulus(),
+            "Only support power-of-two modulus currently.",
+        );
+
+        let decomp_level_count = ntru_public_key_decomposition_level_count(decomp_base_log, ciphertext_modulus);
+        assert!(
+            container.container_len() == (decomp_level_count.0 + 1) * polynomial_size.0,
+            "The provided container length is not valid. \
+            It needs to be (decomp_level_count + 1) * polynomial_size: {}. \
+            Got container length: {}, polynomial size {polynomial_size:?}.",
+            (decomp_level_count.0 + 1) * polynomial_size.0,
+            container.container_len(),
+        );
+
+        Self {
+            data: container,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.data.container_len() / self.polynomial_size.0 - 1)
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    pub fn as_view(&self) -> NtruPublicKeyView<'_, Scalar> {
+        NtruPublicKey::from_container(
+            self.as_ref(),
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    /// Return h = g_0 / f.
+    pub fn get_encryption_of_zero(&self) -> NtruCiphertextView<'_, Scalar> {
+        NtruCiphertext::from_container(
+            &self.as_ref()[..self.polynomial_size.0],
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Return the encryptions of q / B^j for j = 1, ..., l, in this order.
+    pub fn get_gadget_encryption_list(&self) -> NtruCiphertextListView<'_, Scalar> {
+        NtruCiphertextList::from_container(
+            &self.as_ref()[self.polynomial_size.0..],
+            self.polynomial_size,
+            self.ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruPublicKey<C> {
+    pub fn as_mut_view(&mut self) -> NtruPublicKeyMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let ciphertext_modulus = self.ciphertext_modulus;
+
+        NtruPublicKey::from_container(
+            self.as_mut(),
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn get_mut_encryption_of_zero(&mut self) -> NtruCiphertextMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+
+        NtruCiphertext::from_container(
+            &mut self.as_mut()[..polynomial_size.0],
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn get_mut_gadget_encryption_list(&mut self) -> NtruCiphertextListMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+
+        NtruCiphertextList::from_container(
+            &mut self.as_mut()[polynomial_size.0..],
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger> NtruPublicKeyOwned<Scalar> {
+    pub fn new(
+        fill_with: Scalar,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let decomp_level_count = ntru_public_key_decomposition_level_count(decomp_base_log, ciphertext_modulus);
+
+        Self::from_container(
+            vec![fill_with; (decomp_level_count.0 + 1) * polynomial_size.0],
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+}
@@ -1,11 +1,16 @@
 //! Module containing the definition of the NtruSchemeSwitcKey
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_scheme_switch_key::NtruSchemeSwitchKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
 use crate::ntru::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruSchemeSwitchKeyVersions)]
 pub struct NtruSchemeSwitchKey<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -16,6 +21,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruSchemeSwitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruSchemeSwitchKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruSchemeSwitchKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -48,7 +60,7 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruSchemeSwitchKe
         );
         assert!(
             ciphertext_modulus.is_compatible_with_native_modulus(),
-            "Only support power-of-two modulus currently.",
+            "Only support power-of-two modulus currently, scheme switch keys cannot be generated for a prime modulus.",
         );
 
         Self {
@@ -1,5 +1,9 @@
 //! Module containing the definition of the NtruSecretKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_secret_key::NtruSecretKeyVersions;
+use crate::named::Named;
 use crate::ntru::algorithms::*;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
 use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
@@ -9,7 +13,8 @@ use crate::core_crypto::entities::*;
 
 // First half of data contains the secret key polynomial f, and
 // the other half contains the inverse 1/f of the secret key polynomimal
-#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruSecretKeyVersions)]
 pub struct NtruSecretKey<C: Container>
 where
     C::Element: UnsignedInteger,
@@ -19,6 +24,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruSecretKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruSecretKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruSecretKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -141,7 +153,7 @@ where
         generator: &mut SecretRandomGenerator<Gen>,
     ) -> Self
     where
-        Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+        Scalar: UnsignedInteger + RandomGenerable<UniformBinary> + CastInto<i128> + CastFrom<i128>,
         Gen: ByteRandomGenerator,
     {
         let mut ntru_sk = Self::new_empty_key(Scalar::ZERO, polynomial_size, ciphertext_modulus);
@@ -1,52 +1,66 @@
-//! Module containing the definition of NtruToRlweKeyswitchKey.
+//! Module containing the definition of NtruToGlweKeyswitchKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_to_glwe_keyswitch_key::NtruToGlweKeyswitchKeyVersions;
+use crate::named::Named;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct NtruToRlweKeyswitchKey<C: Container>
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruToGlweKeyswitchKeyVersions)]
+pub struct NtruToGlweKeyswitchKey<C: Container>
 where
     C::Element: UnsignedInteger,
 {
     data: C,
+    glwe_size: GlweSize,
     polynomial_size: PolynomialSize,
     decomp_base_log: DecompositionBaseLog,
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
-impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruToRlweKeyswitchKey<C> {
+impl<C: Container> Named for NtruToGlweKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruToGlweKeyswitchKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NtruToGlweKeyswitchKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
     }
 }
 
-impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NtruToRlweKeyswitchKey<C> {
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NtruToGlweKeyswitchKey<C> {
     fn as_mut(&mut self) -> &mut [T] {
         self.data.as_mut()
     }
 }
 
-pub type NtruToRlweKeyswitchKeyOwned<Scalar> = NtruToRlweKeyswitchKey<Vec<Scalar>>;
-pub type NtruToRlweKeyswitchKeyView<'data, Scalar> = NtruToRlweKeyswitchKey<&'data [Scalar]>;
-pub type NtruToRlweKeyswitchKeyMutView<'data, Scalar> = NtruToRlweKeyswitchKey<&'data mut [Scalar]>;
+pub type NtruToGlweKeyswitchKeyOwned<Scalar> = NtruToGlweKeyswitchKey<Vec<Scalar>>;
+pub type NtruToGlweKeyswitchKeyView<'data, Scalar> = NtruToGlweKeyswitchKey<&'data [Scalar]>;
+pub type NtruToGlweKeyswitchKeyMutView<'data, Scalar> = NtruToGlweKeyswitchKey<&'data mut [Scalar]>;
 
-impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruToRlweKeyswitchKey<C> {
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruToGlweKeyswitchKey<C> {
     pub fn from_container(
         container: C,
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         decomp_base_log: DecompositionBaseLog,
         ciphertext_modulus: CiphertextModulus<C::Element>,
     ) -> Self {
         assert!(
             container.container_len() > 0,
-            "Got an empty container to create and NtruToRlweKeyswitchKey"
+            "Got an empty container to create and NtruToGlweKeyswitchKey"
         );
         assert!(
-            container.container_len() % (2 * polynomial_size.0) == 0,
+            container.container_len() % (glwe_size.0 * polynomial_size.0) == 0,
             "The provided container length is not valid. \
-            It needs to be divisible by 2 * polynomial_size. \
-            Got container length: {}, polynomial size {polynomial_size:?}.",
+            It needs to be divisible by glwe_size * polynomial_size. \
+            Got container length: {}, glwe size {glwe_size:?}, polynomial size {polynomial_size:?}.",
             container.container_len(),
         );
         assert!(
@@ -56,12 +70,17 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruToRlweKeyswitc
 
         Self {
             data: container,
+            glwe_size,
             polynomial_size,
             decomp_base_log,
             ciphertext_modulus,
         }
     }
 
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
     pub fn polynomial_size(&self) -> PolynomialSize {
         self.polynomial_size
     }
@@ -72,7 +91,7 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruToRlweKeyswitc
 
     pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
         DecompositionLevelCount(
-            self.data.container_len() / (2 * self.polynomial_size.0)
+            self.data.container_len() / (self.glwe_size.0 * self.polynomial_size.0)
         )
     }
 
@@ -80,9 +99,10 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruToRlweKeyswitc
         self.ciphertext_modulus
     }
 
-    pub fn as_view(&self) -> NtruToRlweKeyswitchKeyView<'_, Scalar> {
-        NtruToRlweKeyswitchKey::from_container(
+    pub fn as_view(&self) -> NtruToGlweKeyswitchKeyView<'_, Scalar> {
+        NtruToGlweKeyswitchKey::from_container(
             self.data.as_ref(),
+            self.glwe_size,
             self.polynomial_size,
             self.decomp_base_log,
             self.ciphertext_modulus,
@@ -103,17 +123,18 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruToRlweKeyswitc
     pub fn as_glwe_ciphertext_list(&self) -> GlweCiphertextListView<'_, Scalar> {
         GlweCiphertextList::from_container(
             self.as_ref(),
-            GlweSize(2),
+            self.glwe_size,
             self.polynomial_size,
             self.ciphertext_modulus,
         )
     }
 }
 
-impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruToRlweKeyswitchKey<C> {
-    pub fn as_mut_view(&mut self) -> NtruToRlweKeyswitchKeyMutView<'_, Scalar> {
-        NtruToRlweKeyswitchKey::from_container(
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruToGlweKeyswitchKey<C> {
+    pub fn as_mut_view(&mut self) -> NtruToGlweKeyswitchKeyMutView<'_, Scalar> {
+        NtruToGlweKeyswitchKey::from_container(
             self.data.as_mut(),
+            self.glwe_size,
             self.polynomial_size,
             self.decomp_base_log,
             self.ciphertext_modulus,
@@ -130,16 +151,17 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruToRlweKeysw
     pub fn as_mut_glwe_ciphertext_list(&mut self) -> GlweCiphertextListMutView<'_, Scalar> {
         GlweCiphertextList::from_container(
             self.data.as_mut(),
-            GlweSize(2),
+            self.glwe_size,
             self.polynomial_size,
             self.ciphertext_modulus,
         )
     }
 }
 
-impl<Scalar: UnsignedInteger> NtruToRlweKeyswitchKeyOwned<Scalar> {
+impl<Scalar: UnsignedInteger> NtruToGlweKeyswitchKeyOwned<Scalar> {
     pub fn new(
         fill_with: Scalar,
+        glwe_size: GlweSize,
         polynomial_size: PolynomialSize,
         decomp_base_log: DecompositionBaseLog,
         decomp_level_count: DecompositionLevelCount,
@@ -148,8 +170,9 @@ impl<Scalar: UnsignedInteger> NtruToRlweKeyswitchKeyOwned<Scalar> {
         Self::from_container(
             vec![
                 fill_with;
-                2 * polynomial_size.0 * decomp_level_count.0
+                glwe_size.0 * polynomial_size.0 * decomp_level_count.0
             ],
+            glwe_size,
             polynomial_size,
             decomp_base_log,
             ciphertext_modulus,
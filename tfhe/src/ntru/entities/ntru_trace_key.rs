@@ -1,10 +1,23 @@
 //! Module containing the definition of the NtruTraceKey.
 
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntru_trace_key::NtruTraceKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::generators::EncryptionRandomGeneratorForkConfig;
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::ntru::entities::*;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// Number of trace levels d, i.e. the trace is taken down to the subring Z[X^{2^d}] with N/2^d slots.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TraceLevelCount(pub usize);
+
+/// Automorphism keys for the last d levels of the trace, i.e. for the automorphisms X -> X^{2^k + 1}
+/// with k = log2(N) - d + 1, ..., log2(N). A full trace key has d = log2(N).
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NtruTraceKeyVersions)]
 pub struct NtruTraceKey<C: Container>
     where C::Element: UnsignedInteger,
 {
@@ -15,6 +28,13 @@ pub struct NtruTraceKey<C: Container>
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> Named for NtruTraceKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NtruTraceKey";
+}
+
 pub type NtruTraceKeyView<'data, Scalar> = NtruTraceKey<&'data [Scalar]>;
 pub type NtruTraceKeyMutView<'data, Scalar> = NtruTraceKey<&'data mut [Scalar]>;
 pub type NtruTraceKeyOwned<Scalar> = NtruTraceKey<Vec<Scalar>>;
@@ -29,8 +49,8 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruTraceKey<C> {
             automorphism_key_count,
         );
 
+        let auto_index = self.automorphism_index(index);
         let ntru_auto_key = self.ntru_auto_keys.get(index);
-        let auto_index = AutomorphismIndex((1 << (index + 1)) + 1);
         NtruAutomorphismKey::from_container(
             ntru_auto_key.into_container(),
             auto_index,
@@ -44,6 +64,17 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruTraceKey<C> {
         self.ntru_auto_keys.ntru_keyswitch_key_count()
     }
 
+    pub fn trace_level_count(&self) -> TraceLevelCount {
+        TraceLevelCount(self.automorphism_key_count().0)
+    }
+
+    /// Automorphism index 2^k + 1 of the key at the given index, with k = log2(N) - d + 1 + index.
+    pub fn automorphism_index(&self, index: usize) -> AutomorphismIndex {
+        let log_polynomial_size = self.polynomial_size.0.ilog2() as usize;
+        let k = log_polynomial_size - self.trace_level_count().0 + 1 + index;
+        AutomorphismIndex((1 << k) + 1)
+    }
+
     pub fn polynomial_size(&self) -> PolynomialSize {
         self.polynomial_size
     }
@@ -60,6 +91,10 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruTraceKey<C> {
         self.ciphertext_modulus
     }
 
+    pub fn get_ntru_keyswitch_key_list(&self) -> NtruKeyswitchKeyListView<'_, Scalar> {
+        self.ntru_auto_keys.as_view()
+    }
+
     pub fn as_view(&self) -> NtruTraceKeyView<'_, Scalar> {
         NtruTraceKeyView::<'_, Scalar> {
             ntru_auto_keys: self.ntru_auto_keys.as_view(),
@@ -69,6 +104,20 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NtruTraceKey<C> {
             ciphertext_modulus: self.ciphertext_modulus,
         }
     }
+
+    pub fn encryption_fork_config<NoiseDistribution>(
+        &self,
+        noise_distribution: NoiseDistribution,
+    ) -> EncryptionRandomGeneratorForkConfig
+    where
+        NoiseDistribution: Distribution,
+        Scalar: RandomGenerable<Uniform, CustomModulus = Scalar>
+            + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    {
+        self.ntru_auto_keys
+            .as_ngsw_ciphertext_list()
+            .encryption_fork_config(noise_distribution)
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruTraceKey<C> {
@@ -81,8 +130,8 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruTraceKey<C>
             automorphism_key_count,
         );
 
+        let auto_index = self.automorphism_index(index);
         let ntru_auto_key = self.ntru_auto_keys.get_mut(index);
-        let auto_index = AutomorphismIndex((1 << (index + 1)) + 1);
         NtruAutomorphismKey::from_container(
             ntru_auto_key.into_container(),
             auto_index,
@@ -92,6 +141,10 @@ impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NtruTraceKey<C>
         )
     }
 
+    pub fn get_mut_ntru_keyswitch_key_list(&mut self) -> NtruKeyswitchKeyListMutView<'_, Scalar> {
+        self.ntru_auto_keys.as_mut_view()
+    }
+
     pub fn as_mut_view(&mut self) -> NtruTraceKeyMutView<'_, Scalar> {
         NtruTraceKeyMutView::<'_, Scalar> {
             ntru_auto_keys: self.ntru_auto_keys.as_mut_view(),
@@ -110,12 +163,35 @@ impl<Scalar: UnsignedInteger> NtruTraceKeyOwned<Scalar> {
         decomp_level_count: DecompositionLevelCount,
         ciphertext_modulus: CiphertextModulus<Scalar>,
     ) -> Self {
+        Self::new_partial(
+            polynomial_size,
+            TraceLevelCount(polynomial_size.0.ilog2() as usize),
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn new_partial(
+        polynomial_size: PolynomialSize,
+        trace_level_count: TraceLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            trace_level_count.0 <= polynomial_size.0.ilog2() as usize,
+            "Trace level count {} should not exceed log2 of the polynomial size {}",
+            trace_level_count.0,
+            polynomial_size.0,
+        );
+
         let ntru_auto_keys = NtruKeyswitchKeyList::new(
             Scalar::ZERO,
             polynomial_size,
             decomp_base_log,
             decomp_level_count,
-            NtruKeyswitchKeyCount(polynomial_size.0.ilog2() as usize),
+            NtruKeyswitchKeyCount(trace_level_count.0),
             ciphertext_modulus,
         );
 
@@ -0,0 +1,185 @@
+//! Module containing the definition of the NttNgswCiphertext.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntt_ngsw_ciphertext::NttNgswCiphertextVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+
+use aligned_vec::{avec, ABox};
+
+/// An [`NGSW ciphertext`](`crate::ntru::entities::NgswCiphertext`) whose polynomials are in the
+/// NTT domain, for use with NTT-friendly prime ciphertext moduli.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NttNgswCiphertextVersions)]
+pub struct NttNgswCiphertext<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for NttNgswCiphertext<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NttNgswCiphertext";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NttNgswCiphertext<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NttNgswCiphertext<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NttNgswCiphertext<C> {
+    pub fn from_container(
+        container: C,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a NttNgswCiphertext"
+        );
+        assert!(
+            container.container_len() % polynomial_size.0 == 0,
+            "The provided container length is not valid.\
+            It needs to be divisible by polynomial_size.\
+            Got container length: {:?} and polynomial_size: {:?}.",
+            container.container_len(),
+            polynomial_size.0,
+        );
+
+        Self {
+            data: container,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(self.data.container_len() / self.polynomial_size.0)
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    pub fn as_view(&self) -> NttNgswCiphertextView<'_, Scalar> {
+        NttNgswCiphertextView::from_container(
+            self.as_ref(),
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    /// Return the NTT polynomials of the NGSW ciphertext, ordered from the highest decomposition
+    /// level to the lowest one, as in the standard domain.
+    pub fn as_polynomial_list(&self) -> PolynomialListView<'_, Scalar> {
+        PolynomialListView::from_container(
+            self.as_ref(),
+            self.polynomial_size,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NttNgswCiphertext<C> {
+    pub fn as_mut_view(&mut self) -> NttNgswCiphertextMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        NttNgswCiphertextMutView::from_container(
+            self.as_mut(),
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_polynomial_list(&mut self) -> PolynomialListMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        PolynomialListMutView::from_container(
+            self.as_mut(),
+            polynomial_size,
+        )
+    }
+}
+
+pub type NttNgswCiphertextOwned<Scalar> = NttNgswCiphertext<ABox<[Scalar]>>;
+pub type NttNgswCiphertextView<'data, Scalar> = NttNgswCiphertext<&'data [Scalar]>;
+pub type NttNgswCiphertextMutView<'data, Scalar> = NttNgswCiphertext<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> NttNgswCiphertextOwned<Scalar> {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let boxed = avec![
+            Scalar::ZERO;
+            polynomial_size.0 * decomp_level_count.0
+        ]
+        .into_boxed_slice();
+
+        Self::from_container(
+            boxed,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct NttNgswCiphertextCreationMetadata<Scalar: UnsignedInteger> {
+    pub polynomial_size: PolynomialSize,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> CreateFrom<C> for NttNgswCiphertext<C> {
+    type Metadata = NttNgswCiphertextCreationMetadata<Scalar>;
+
+    #[inline]
+    fn create_from(from: C, meta: Self::Metadata) -> Self {
+        let NttNgswCiphertextCreationMetadata {
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        } = meta;
+        Self::from_container(
+            from,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+}
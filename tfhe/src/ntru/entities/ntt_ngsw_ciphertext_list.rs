@@ -0,0 +1,235 @@
+//! Module containing the definition of the NttNgswCiphertextList.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntt_ngsw_ciphertext_list::NttNgswCiphertextListVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::{avec, ABox};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NttNgswCiphertextCount(pub usize);
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NttNgswCiphertextListVersions)]
+pub struct NttNgswCiphertextList<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for NttNgswCiphertextList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NttNgswCiphertextList";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NttNgswCiphertextList<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NttNgswCiphertextList<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+pub type NttNgswCiphertextListOwned<Scalar> = NttNgswCiphertextList<ABox<[Scalar]>>;
+pub type NttNgswCiphertextListView<'a, Scalar> = NttNgswCiphertextList<&'a [Scalar]>;
+pub type NttNgswCiphertextListMutView<'a, Scalar> = NttNgswCiphertextList<&'a mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NttNgswCiphertextList<C> {
+    pub fn from_container(
+        container: C,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            container.container_len()
+                % (polynomial_size.0 * decomp_level_count.0) == 0,
+            "The provided container length is not valid. \
+            It needs to be divisible by polynomial size * decomp_level_count. \
+            Got container length: {}, polynomial size {:?}, decomp level count: {:?}.",
+            container.container_len(),
+            polynomial_size,
+            decomp_level_count,
+        );
+
+        Self {
+            data: container,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    pub fn ntt_ngsw_ciphertext_count(&self) -> NttNgswCiphertextCount {
+        NttNgswCiphertextCount(
+            self.data.container_len() / (
+                self.polynomial_size.0 * self.decomp_level_count.0
+            )
+        )
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttNgswCiphertextListView<'_, Scalar> {
+        NttNgswCiphertextList {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NttNgswCiphertextList<C> {
+    pub fn as_mut_view(&mut self) -> NttNgswCiphertextListMutView<'_, Scalar> {
+        NttNgswCiphertextList {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> NttNgswCiphertextListOwned<Scalar> {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_count: NttNgswCiphertextCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let boxed = avec![
+            Scalar::ZERO;
+            polynomial_size.0 * decomp_level_count.0 * ciphertext_count.0
+        ]
+        .into_boxed_slice();
+
+        Self::from_container(
+            boxed,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct NttNgswCiphertextListCreationMetadata<Scalar: UnsignedInteger> {
+    pub polynomial_size: PolynomialSize,
+    pub decomp_base_log: DecompositionBaseLog,
+    pub decomp_level_count: DecompositionLevelCount,
+    pub ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> CreateFrom<C> for NttNgswCiphertextList<C> {
+    type Metadata = NttNgswCiphertextListCreationMetadata<Scalar>;
+
+    #[inline]
+    fn create_from(from: C, meta: Self::Metadata) -> Self {
+        let NttNgswCiphertextListCreationMetadata {
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus
+        } = meta;
+        Self::from_container(
+            from,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl <Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityContainer for NttNgswCiphertextList<C> {
+    type Element = C::Element;
+
+    type EntityViewMetadata = NttNgswCiphertextCreationMetadata<Self::Element>;
+
+    type EntityView<'this>
+        = NttNgswCiphertextView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    type SelfViewMetadata = NttNgswCiphertextListCreationMetadata<Self::Element>;
+
+    type SelfView<'this>
+        = NttNgswCiphertextListView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    fn get_entity_view_creation_metadata(&self) -> Self::EntityViewMetadata {
+        NttNgswCiphertextCreationMetadata {
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    fn get_entity_view_pod_size(&self) -> usize {
+        self.polynomial_size.0 * self.decomp_level_count.0
+    }
+
+    fn get_self_view_creation_metadata(&self) -> Self::SelfViewMetadata {
+        NttNgswCiphertextListCreationMetadata {
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntityContainerMut
+    for NttNgswCiphertextList<C>
+{
+    type EntityMutView<'this>
+        = NttNgswCiphertextMutView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    type SelfMutView<'this>
+        = NttNgswCiphertextListMutView<'this, Self::Element>
+    where
+        Self: 'this;
+}
@@ -0,0 +1,111 @@
+//! Module containing the definition of the NttNtruCMuxBootstrapKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntt_ntru_cmux_bootstrap_key::NttNtruCMuxBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::ABox;
+
+/// An [`NtruCMuxBootstrapKey`] in the NTT domain, used for NTT-friendly prime ciphertext moduli.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NttNtruCMuxBootstrapKeyVersions)]
+pub struct NttNtruCMuxBootstrapKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    ntt_ngsw_list: NttNgswCiphertextList<C>,
+    ntt_ntru_switching_key: NttNtruKeyswitchKey<C>,
+}
+
+impl<C: Container> Named for NttNtruCMuxBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NttNtruCMuxBootstrapKey";
+}
+
+pub type NttNtruCMuxBootstrapKeyView<'data, Scalar> = NttNtruCMuxBootstrapKey<&'data [Scalar]>;
+pub type NttNtruCMuxBootstrapKeyMutView<'data, Scalar> = NttNtruCMuxBootstrapKey<&'data mut [Scalar]>;
+pub type NttNtruCMuxBootstrapKeyOwned<Scalar> = NttNtruCMuxBootstrapKey<ABox<[Scalar]>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NttNtruCMuxBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ntt_ngsw_list.ntt_ngsw_ciphertext_count().0)
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.ntt_ngsw_list.polynomial_size().0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.ntt_ngsw_list.polynomial_size()
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ntt_ngsw_list.ciphertext_modulus()
+    }
+
+    pub fn get_ntt_ngsw_list(&self) -> NttNgswCiphertextListView<'_, Scalar> {
+        self.ntt_ngsw_list.as_view()
+    }
+
+    pub fn get_ntt_ntru_switching_key(&self) -> NttNtruKeyswitchKeyView<'_, Scalar> {
+        self.ntt_ntru_switching_key.as_view()
+    }
+
+    pub fn as_view(&self) -> NttNtruCMuxBootstrapKeyView<'_, Scalar> {
+        NttNtruCMuxBootstrapKeyView::<'_, Scalar> {
+            ntt_ngsw_list: self.ntt_ngsw_list.as_view(),
+            ntt_ntru_switching_key: self.ntt_ntru_switching_key.as_view(),
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NttNtruCMuxBootstrapKey<C> {
+    pub fn get_mut_ntt_ngsw_list(&mut self) -> NttNgswCiphertextListMutView<'_, Scalar> {
+        self.ntt_ngsw_list.as_mut_view()
+    }
+
+    pub fn get_mut_ntt_ntru_switching_key(&mut self) -> NttNtruKeyswitchKeyMutView<'_, Scalar> {
+        self.ntt_ntru_switching_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> NttNtruCMuxBootstrapKeyMutView<'_, Scalar> {
+        NttNtruCMuxBootstrapKeyMutView::<'_, Scalar> {
+            ntt_ngsw_list: self.ntt_ngsw_list.as_mut_view(),
+            ntt_ntru_switching_key: self.ntt_ntru_switching_key.as_mut_view(),
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> NttNtruCMuxBootstrapKeyOwned<Scalar> {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        input_lwe_dimension: LweDimension,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self {
+            ntt_ngsw_list: NttNgswCiphertextList::new(
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                NttNgswCiphertextCount(input_lwe_dimension.0),
+                ciphertext_modulus,
+            ),
+            ntt_ntru_switching_key: NttNtruKeyswitchKey::new(
+                polynomial_size,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                ciphertext_modulus,
+            ),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Module containing the definition of the NttNtruKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::ntt_ntru_keyswitch_key::NttNtruKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+use aligned_vec::{avec, ABox};
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(NttNtruKeyswitchKeyVersions)]
+pub struct NttNtruKeyswitchKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for NttNtruKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::NttNtruKeyswitchKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for NttNtruKeyswitchKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for NttNtruKeyswitchKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+pub type NttNtruKeyswitchKeyOwned<Scalar> = NttNtruKeyswitchKey<ABox<[Scalar]>>;
+pub type NttNtruKeyswitchKeyView<'data, Scalar> = NttNtruKeyswitchKey<&'data [Scalar]>;
+pub type NttNtruKeyswitchKeyMutView<'data, Scalar> = NttNtruKeyswitchKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NttNtruKeyswitchKey<C> {
+    pub fn from_container(
+        container: C,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create an NttNtruKeyswitchKey"
+        );
+        assert!(
+            container.container_len() % polynomial_size.0 == 0,
+            "The provided container length is not valid. \
+            It needs to be divisible by polynomial_size. \
+            Got container length: {}, polynomial size {polynomial_size:?}.",
+            container.container_len(),
+        );
+
+        Self {
+            data: container,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(
+            self.data.container_len() / self.polynomial_size.0
+        )
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    pub fn as_view(&self) -> NttNtruKeyswitchKeyView<'_, Scalar> {
+        NttNtruKeyswitchKey::from_container(
+            self.as_ref(),
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_ntt_ngsw_ciphertext(&self) -> NttNgswCiphertextView<'_, Scalar> {
+        NttNgswCiphertext::from_container(
+            self.as_ref(),
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NttNtruKeyswitchKey<C> {
+    pub fn as_mut_view(&mut self) -> NttNtruKeyswitchKeyMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        NttNtruKeyswitchKey::from_container(
+            self.as_mut(),
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_ntt_ngsw_ciphertext(&mut self) -> NttNgswCiphertextMutView<'_, Scalar> {
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        NttNgswCiphertext::from_container(
+            self.as_mut(),
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger> NttNtruKeyswitchKeyOwned<Scalar> {
+    pub fn new(
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let boxed = avec![
+            Scalar::ZERO;
+            polynomial_size.0 * decomp_level_count.0
+        ]
+        .into_boxed_slice();
+
+        Self::from_container(
+            boxed,
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+}
@@ -0,0 +1,177 @@
+//! Module containing the definition of the RlweToNtruKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::rlwe_to_ntru_keyswitch_key::RlweToNtruKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::generators::EncryptionRandomGeneratorForkConfig;
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::*;
+
+/// NTRU keyswitch keys from each GLWE secret key polynomial S_i to f,
+/// followed by the switching key from the constant 1 to f used for the body.
+///
+/// [`LweToNtruPackingKeyswitchKey`] wraps this key, generated for the constant polynomials
+/// S_i = s_i of the LWE secret key.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(RlweToNtruKeyswitchKeyVersions)]
+pub struct RlweToNtruKeyswitchKey<C: Container>
+    where C::Element: UnsignedInteger,
+{
+    ntru_ksks: NtruKeyswitchKeyList<C>,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for RlweToNtruKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::RlweToNtruKeyswitchKey";
+}
+
+pub type RlweToNtruKeyswitchKeyView<'data, Scalar> = RlweToNtruKeyswitchKey<&'data [Scalar]>;
+pub type RlweToNtruKeyswitchKeyMutView<'data, Scalar> = RlweToNtruKeyswitchKey<&'data mut [Scalar]>;
+pub type RlweToNtruKeyswitchKeyOwned<Scalar> = RlweToNtruKeyswitchKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> RlweToNtruKeyswitchKey<C> {
+    pub fn get_ntru_keyswitch_key(&self, index: usize) -> NtruKeyswitchKeyView<'_, Scalar> {
+        let input_glwe_dimension = self.input_glwe_dimension().0;
+        assert!(
+            index < input_glwe_dimension,
+            "Input index {index} should be smaller than the input GLWE dimension {input_glwe_dimension}",
+        );
+
+        self.ntru_ksks.get(index)
+    }
+
+    pub fn get_ntru_switching_key(&self) -> NtruSwitchingKeyView<'_, Scalar> {
+        let ntru_swk = self.ntru_ksks.get(self.input_glwe_dimension().0);
+        NtruSwitchingKey::from_container(
+            ntru_swk.into_container(),
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn input_glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.ntru_ksks.ntru_keyswitch_key_count().0 - 1)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    pub fn get_ntru_keyswitch_key_list(&self) -> NtruKeyswitchKeyListView<'_, Scalar> {
+        self.ntru_ksks.as_view()
+    }
+
+    pub fn as_view(&self) -> RlweToNtruKeyswitchKeyView<'_, Scalar> {
+        RlweToNtruKeyswitchKeyView::<'_, Scalar> {
+            ntru_ksks: self.ntru_ksks.as_view(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    pub fn encryption_fork_config<NoiseDistribution>(
+        &self,
+        noise_distribution: NoiseDistribution,
+    ) -> EncryptionRandomGeneratorForkConfig
+    where
+        NoiseDistribution: Distribution,
+        Scalar: RandomGenerable<Uniform, CustomModulus = Scalar>
+            + RandomGenerable<NoiseDistribution, CustomModulus = Scalar>,
+    {
+        self.ntru_ksks
+            .as_ngsw_ciphertext_list()
+            .encryption_fork_config(noise_distribution)
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> RlweToNtruKeyswitchKey<C> {
+    pub fn get_mut_ntru_keyswitch_key(&mut self, index: usize) -> NtruKeyswitchKeyMutView<'_, Scalar> {
+        let input_glwe_dimension = self.input_glwe_dimension().0;
+        assert!(
+            index < input_glwe_dimension,
+            "Input index {index} should be smaller than the input GLWE dimension {input_glwe_dimension}",
+        );
+
+        self.ntru_ksks.get_mut(index)
+    }
+
+    pub fn get_mut_ntru_switching_key(&mut self) -> NtruSwitchingKeyMutView<'_, Scalar> {
+        let index = self.input_glwe_dimension().0;
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let ciphertext_modulus = self.ciphertext_modulus;
+
+        let ntru_swk = self.ntru_ksks.get_mut(index);
+        NtruSwitchingKey::from_container(
+            ntru_swk.into_container(),
+            polynomial_size,
+            decomp_base_log,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn get_mut_ntru_keyswitch_key_list(&mut self) -> NtruKeyswitchKeyListMutView<'_, Scalar> {
+        self.ntru_ksks.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> RlweToNtruKeyswitchKeyMutView<'_, Scalar> {
+        RlweToNtruKeyswitchKeyMutView::<'_, Scalar> {
+            ntru_ksks: self.ntru_ksks.as_mut_view(),
+            polynomial_size: self.polynomial_size,
+            decomp_base_log: self.decomp_base_log,
+            decomp_level_count: self.decomp_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> RlweToNtruKeyswitchKeyOwned<Scalar> {
+    pub fn new(
+        input_glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let ntru_ksks = NtruKeyswitchKeyList::new(
+            Scalar::ZERO,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            NtruKeyswitchKeyCount(input_glwe_dimension.0 + 1),
+            ciphertext_modulus,
+        );
+
+        Self {
+            ntru_ksks,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Module containing the definition of the SeededNtruCMuxCircuitBootstrapKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::seeded_ntru_cmux_circuit_bootstrap_key::SeededNtruCMuxCircuitBootstrapKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::math::random::{CompressionSeed, DefaultRandomGenerator};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::algorithms::*;
+use crate::ntru::entities::*;
+
+/// An [`NtruCMuxCircuitBootstrapKey`] whose RLWE-based components, i.e., the NTRU-to-RLWE
+/// keyswitching key and the GLWE scheme switching key, are stored in a seeded form.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SeededNtruCMuxCircuitBootstrapKeyVersions)]
+pub struct SeededNtruCMuxCircuitBootstrapKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    ntru_cmux_bsk: NtruCMuxBootstrapKey<C>,
+    ntru_trace_key: NtruTraceKey<C>,
+    seeded_ntru_to_glwe_ksk: SeededNtruToGlweKeyswitchKey<C>,
+    seeded_glwe_ss_key: SeededGlweSchemeSwitchKey<C>,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for SeededNtruCMuxCircuitBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::SeededNtruCMuxCircuitBootstrapKey";
+}
+
+pub type SeededNtruCMuxCircuitBootstrapKeyView<'data, Scalar> = SeededNtruCMuxCircuitBootstrapKey<&'data [Scalar]>;
+pub type SeededNtruCMuxCircuitBootstrapKeyMutView<'data, Scalar> = SeededNtruCMuxCircuitBootstrapKey<&'data mut [Scalar]>;
+pub type SeededNtruCMuxCircuitBootstrapKeyOwned<Scalar> = SeededNtruCMuxCircuitBootstrapKey<Vec<Scalar>>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> SeededNtruCMuxCircuitBootstrapKey<C> {
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.ntru_cmux_bsk.input_lwe_dimension()
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        self.ntru_cmux_bsk.output_lwe_dimension()
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.ntru_cmux_bsk.polynomial_size()
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.seeded_glwe_ss_key.glwe_size()
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    pub fn get_ntru_cmux_bootstrap_key(&self) -> NtruCMuxBootstrapKeyView<'_, Scalar> {
+        self.ntru_cmux_bsk.as_view()
+    }
+
+    pub fn get_ntru_trace_key(&self) -> NtruTraceKeyView<'_, Scalar> {
+        self.ntru_trace_key.as_view()
+    }
+
+    pub fn get_seeded_ntru_to_glwe_keyswitch_key(&self) -> SeededNtruToGlweKeyswitchKeyView<'_, Scalar> {
+        self.seeded_ntru_to_glwe_ksk.as_view()
+    }
+
+    pub fn get_seeded_glwe_scheme_switch_key(&self) -> SeededGlweSchemeSwitchKeyView<'_, Scalar> {
+        self.seeded_glwe_ss_key.as_view()
+    }
+
+    pub fn as_view(&self) -> SeededNtruCMuxCircuitBootstrapKeyView<'_, Scalar> {
+        SeededNtruCMuxCircuitBootstrapKeyView::<'_, Scalar> {
+            ntru_cmux_bsk: self.ntru_cmux_bsk.as_view(),
+            ntru_trace_key: self.ntru_trace_key.as_view(),
+            seeded_ntru_to_glwe_ksk: self.seeded_ntru_to_glwe_ksk.as_view(),
+            seeded_glwe_ss_key: self.seeded_glwe_ss_key.as_view(),
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    pub fn decompress_into_ntru_cmux_circuit_bootstrap_key(self) -> NtruCMuxCircuitBootstrapKeyOwned<Scalar>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let mut decompressed_cbs_key = NtruCMuxCircuitBootstrapKey::new(
+            Scalar::ZERO,
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.input_lwe_dimension(),
+            self.ntru_cmux_bsk.get_ngsw_list().decomposition_base_log(),
+            self.ntru_cmux_bsk.get_ngsw_list().decomposition_level_count(),
+            self.ntru_cmux_bsk.get_ntru_switching_key().decomposition_base_log(),
+            self.ntru_cmux_bsk.get_ntru_switching_key().decomposition_level_count(),
+            self.ntru_trace_key.decomposition_base_log(),
+            self.ntru_trace_key.decomposition_level_count(),
+            self.seeded_ntru_to_glwe_ksk.decomposition_base_log(),
+            self.seeded_ntru_to_glwe_ksk.decomposition_level_count(),
+            self.seeded_glwe_ss_key.decomposition_base_log(),
+            self.seeded_glwe_ss_key.decomposition_level_count(),
+            self.ciphertext_modulus(),
+        );
+        decompress_seeded_ntru_cmux_circuit_bootstrap_key::<_, _, _, DefaultRandomGenerator>(
+            &mut decompressed_cbs_key,
+            &self,
+        );
+        decompressed_cbs_key
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> SeededNtruCMuxCircuitBootstrapKey<C> {
+    pub fn get_mut_ntru_cmux_bootstrap_key(&mut self) -> NtruCMuxBootstrapKeyMutView<'_, Scalar> {
+        self.ntru_cmux_bsk.as_mut_view()
+    }
+
+    pub fn get_mut_ntru_trace_key(&mut self) -> NtruTraceKeyMutView<'_, Scalar> {
+        self.ntru_trace_key.as_mut_view()
+    }
+
+    pub fn get_mut_seeded_ntru_to_glwe_keyswitch_key(&mut self) -> SeededNtruToGlweKeyswitchKeyMutView<'_, Scalar> {
+        self.seeded_ntru_to_glwe_ksk.as_mut_view()
+    }
+
+    pub fn get_mut_seeded_glwe_scheme_switch_key(&mut self) -> SeededGlweSchemeSwitchKeyMutView<'_, Scalar> {
+        self.seeded_glwe_ss_key.as_mut_view()
+    }
+
+    pub fn as_mut_view(&mut self) -> SeededNtruCMuxCircuitBootstrapKeyMutView<'_, Scalar> {
+        SeededNtruCMuxCircuitBootstrapKeyMutView::<'_, Scalar> {
+            ntru_cmux_bsk: self.ntru_cmux_bsk.as_mut_view(),
+            ntru_trace_key: self.ntru_trace_key.as_mut_view(),
+            seeded_ntru_to_glwe_ksk: self.seeded_ntru_to_glwe_ksk.as_mut_view(),
+            seeded_glwe_ss_key: self.seeded_glwe_ss_key.as_mut_view(),
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> SeededNtruCMuxCircuitBootstrapKeyOwned<Scalar> {
+    // The two seeded components must not share a compression seed, otherwise their masks coincide.
+    pub fn new(
+        fill_with: Scalar,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        input_lwe_dimension: LweDimension,
+        br_decomp_base_log: DecompositionBaseLog,
+        br_decomp_level_count: DecompositionLevelCount,
+        swk_decomp_base_log: DecompositionBaseLog,
+        swk_decomp_level_count: DecompositionLevelCount,
+        tr_decomp_base_log: DecompositionBaseLog,
+        tr_decomp_level_count: DecompositionLevelCount,
+        ksk_decomp_base_log: DecompositionBaseLog,
+        ksk_decomp_level_count: DecompositionLevelCount,
+        ksk_compression_seed: CompressionSeed,
+        ss_decomp_base_log: DecompositionBaseLog,
+        ss_decomp_level_count: DecompositionLevelCount,
+        ss_compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self {
+            ntru_cmux_bsk: NtruCMuxBootstrapKey::new(
+                fill_with,
+                polynomial_size,
+                br_decomp_base_log,
+                br_decomp_level_count,
+                swk_decomp_base_log,
+                swk_decomp_level_count,
+                input_lwe_dimension,
+                ciphertext_modulus,
+            ),
+            ntru_trace_key: NtruTraceKey::new(
+                polynomial_size,
+                tr_decomp_base_log,
+                tr_decomp_level_count,
+                ciphertext_modulus,
+            ),
+            seeded_ntru_to_glwe_ksk: SeededNtruToGlweKeyswitchKey::new(
+                fill_with,
+                glwe_size,
+                polynomial_size,
+                ksk_decomp_base_log,
+                ksk_decomp_level_count,
+                ksk_compression_seed,
+                ciphertext_modulus,
+            ),
+            seeded_glwe_ss_key: SeededGlweSchemeSwitchKey::new(
+                fill_with,
+                glwe_size,
+                polynomial_size,
+                ss_decomp_base_log,
+                ss_decomp_level_count,
+                ss_compression_seed,
+                ciphertext_modulus,
+            ),
+            ciphertext_modulus,
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Module containing the definition of SeededNtruToGlweKeyswitchKey.
+
+use tfhe_versionable::Versionize;
+
+use crate::ntru::backward_compatibility::entities::seeded_ntru_to_glwe_keyswitch_key::SeededNtruToGlweKeyswitchKeyVersions;
+use crate::named::Named;
+use crate::core_crypto::commons::math::random::{CompressionSeed, DefaultRandomGenerator};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::entities::*;
+use crate::ntru::algorithms::*;
+use crate::ntru::entities::*;
+
+/// A compressed [`NtruToGlweKeyswitchKey`] which only stores the bodies of its GLWE ciphertexts,
+/// the masks being regenerated from the [`CompressionSeed`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Versionize)]
+#[versionize(SeededNtruToGlweKeyswitchKeyVersions)]
+pub struct SeededNtruToGlweKeyswitchKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    compression_seed: CompressionSeed,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<C: Container> Named for SeededNtruToGlweKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "ntru::SeededNtruToGlweKeyswitchKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for SeededNtruToGlweKeyswitchKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for SeededNtruToGlweKeyswitchKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+pub type SeededNtruToGlweKeyswitchKeyOwned<Scalar> = SeededNtruToGlweKeyswitchKey<Vec<Scalar>>;
+pub type SeededNtruToGlweKeyswitchKeyView<'data, Scalar> = SeededNtruToGlweKeyswitchKey<&'data [Scalar]>;
+pub type SeededNtruToGlweKeyswitchKeyMutView<'data, Scalar> = SeededNtruToGlweKeyswitchKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> SeededNtruToGlweKeyswitchKey<C> {
+    pub fn from_container(
+        container: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a SeededNtruToGlweKeyswitchKey"
+        );
+        assert!(
+            container.container_len() % polynomial_size.0 == 0,
+            "The provided container length is not valid. \
+            It needs to be divisible by polynomial_size. \
+            Got container length: {}, polynomial size {polynomial_size:?}.",
+            container.container_len(),
+        );
+        assert!(
+            ciphertext_modulus.is_compatible_with_native_modulus(),
+            "Only support power-of-two modulus, currently.",
+        );
+
+        Self {
+            data: container,
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            compression_seed,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        DecompositionLevelCount(
+            self.data.container_len() / self.polynomial_size.0
+        )
+    }
+
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+
+    pub fn as_view(&self) -> SeededNtruToGlweKeyswitchKeyView<'_, Scalar> {
+        SeededNtruToGlweKeyswitchKey::from_container(
+            self.data.as_ref(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.decomp_base_log,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_seeded_glwe_ciphertext_list(&self) -> SeededGlweCiphertextListView<'_, Scalar> {
+        SeededGlweCiphertextList::from_container(
+            self.data.as_ref(),
+            self.glwe_size,
+            self.polynomial_size,
+            self.compression_seed,
+            self.ciphertext_modulus,
+        )
+    }
+
+    pub fn decompress_into_ntru_to_glwe_keyswitch_key(self) -> NtruToGlweKeyswitchKeyOwned<Scalar>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let mut decompressed_ksk = NtruToGlweKeyswitchKey::new(
+            Scalar::ZERO,
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+            self.ciphertext_modulus(),
+        );
+        decompress_seeded_ntru_to_glwe_keyswitch_key::<_, _, _, DefaultRandomGenerator>(
+            &mut decompressed_ksk,
+            &self,
+        );
+        decompressed_ksk
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> SeededNtruToGlweKeyswitchKey<C> {
+    pub fn as_mut_view(&mut self) -> SeededNtruToGlweKeyswitchKeyMutView<'_, Scalar> {
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let decomp_base_log = self.decomp_base_log;
+        let compression_seed = self.compression_seed;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        SeededNtruToGlweKeyswitchKey::from_container(
+            self.data.as_mut(),
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_seeded_glwe_ciphertext_list(&mut self) -> SeededGlweCiphertextListMutView<'_, Scalar> {
+        let glwe_size = self.glwe_size;
+        let polynomial_size = self.polynomial_size;
+        let compression_seed = self.compression_seed;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        SeededGlweCiphertextList::from_container(
+            self.data.as_mut(),
+            glwe_size,
+            polynomial_size,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger> SeededNtruToGlweKeyswitchKeyOwned<Scalar> {
+    pub fn new(
+        fill_with: Scalar,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                polynomial_size.0 * decomp_level_count.0
+            ],
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
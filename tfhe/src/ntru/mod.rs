@@ -1,2 +1,4 @@
 pub mod algorithms;
+pub mod backward_compatibility;
 pub mod entities;
+pub mod noise_formulas;
@@ -0,0 +1,62 @@
+//! Module containing the building blocks shared by the NTRU noise formulas.
+//!
+//! Every variance is given on the torus, i.e., divided by the square of the ciphertext modulus,
+//! as for the noise formulas of [`crate::core_crypto::commons::noise_formulas`].
+
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+
+/// Variance of a digit of the balanced signed decomposition in base 2^decomp_base_log of a
+/// uniformly random value.
+pub fn decomposition_digit_variance(decomp_base_log: DecompositionBaseLog) -> f64 {
+    let base = 2.0f64.powi(decomp_base_log.0 as i32);
+    (base * base + 2.0) / 12.0
+}
+
+/// Variance of the error made by keeping only the first decomp_level_count digits of a uniformly
+/// random value modulo 2^log_modulus.
+pub fn decomposition_rounding_variance(
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+) -> Variance {
+    let log_precision = decomp_base_log.0 * decomp_level_count.0;
+    if log_precision >= log_modulus.0 {
+        return Variance(0.0);
+    }
+
+    Variance(
+        (2.0f64.powi(-2 * log_precision as i32) - 2.0f64.powi(-2 * log_modulus.0 as i32)) / 12.0
+    )
+}
+
+/// Log2 of the probability that a centered Gaussian of the given variance falls outside of
+/// [-bound, bound], i.e., log2(erfc(bound / sqrt(2 * variance))).
+pub fn gaussian_log2_failure_probability(variance: Variance, bound: f64) -> f64 {
+    if variance.0 <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    log2_erfc(bound / (2.0 * variance.0).sqrt())
+}
+
+// Chebyshev approximation of erfc from Numerical Recipes, with a relative error below 1.2e-7.
+// It is evaluated in the log domain so that tiny failure probabilities do not underflow.
+fn log2_erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let ln_erfc_z = t.ln() - z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+
+    if x >= 0.0 {
+        ln_erfc_z / core::f64::consts::LN_2
+    } else {
+        (2.0 - ln_erfc_z.exp()).log2()
+    }
+}
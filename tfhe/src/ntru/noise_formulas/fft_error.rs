@@ -0,0 +1,124 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::common::gaussian_log2_failure_probability;
+
+// Fitted FFT error of the 64-bit Fourier external product of tfhe-rs, see
+// crate::core_crypto::commons::noise_formulas::lwe_programmable_bootstrap.
+const FFT_ERROR_CONSTANT: f64 = 2.06537277069845e-33;
+
+/// Variance of the FFT error of an external product whose key has `output_polynomial_count`
+/// polynomials per decomposition level, i.e., 1 for NGSW and 2 for RLWE based keys.
+///
+/// With a split [`FftType`], the key is cut in parts of split_base_log bits (the most significant
+/// part keeping the remaining bits), and each part carries the vanilla error scaled by its
+/// magnitude. Each part is rounded to the 2^-log_modulus grid before being scaled back by its
+/// weight, so that a part whose error is well below the grid step does not contribute.
+pub fn ntru_fft_error_variance(
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_polynomial_count: usize,
+    log_modulus: CiphertextModulusLog,
+    fft_type: FftType,
+) -> Variance {
+    let base = 2.0f64.powi(decomp_base_log.0 as i32);
+    let polynomial_size = polynomial_size.0 as f64;
+
+    let vanilla_variance = FFT_ERROR_CONSTANT
+        * base * base
+        * decomp_level_count.0 as f64
+        * polynomial_size * polynomial_size
+        * output_polynomial_count as f64;
+
+    let num_split = fft_type.num_split() as i32;
+    let split_base_log = fft_type.split_base_log() as i32;
+    let log_modulus = log_modulus.0 as i32;
+    let grid_step = 2.0f64.powi(-log_modulus);
+
+    Variance(
+        (0..num_split)
+            .map(|split_idx| {
+                // Magnitude of the part on the torus, relative to a full size key
+                let log_magnitude = if split_idx == num_split - 1 {
+                    -split_base_log * split_idx
+                } else {
+                    split_base_log - log_modulus
+                };
+                let part_variance = vanilla_variance * 2.0f64.powi(2 * log_magnitude);
+                let weight = 2.0f64.powi(2 * split_base_log * split_idx);
+
+                rounded_gaussian_variance(part_variance, grid_step) * weight
+            })
+            .sum(),
+    )
+}
+
+/// Selects the [`FftType`] with the fewest splits, i.e., the cheapest one, whose FFT error given
+/// by [`ntru_fft_error_variance`] is at most `max_variance`. Among the configurations with the same
+/// number of splits, the one with the smallest error is returned. Returns `None` if no
+/// configuration meets the target.
+pub fn select_ntru_fft_type(
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_polynomial_count: usize,
+    log_modulus: CiphertextModulusLog,
+    max_variance: Variance,
+) -> Option<FftType> {
+    let fft_error_variance = |fft_type| ntru_fft_error_variance(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        output_polynomial_count,
+        log_modulus,
+        fft_type,
+    ).0;
+
+    if fft_error_variance(FftType::Vanilla) <= max_variance.0 {
+        return Some(FftType::Vanilla);
+    }
+
+    for num_split in 2..=log_modulus.0 {
+        // The most significant part must not be empty
+        let best = (1..=(log_modulus.0 - 1) / (num_split - 1))
+            .map(|split_base_log| {
+                let fft_type = FftType::SplitN(split_base_log, num_split);
+                (fft_type, fft_error_variance(fft_type))
+            })
+            .filter(|(_, variance)| *variance <= max_variance.0)
+            .min_by(|(_, v0), (_, v1)| v0.total_cmp(v1));
+
+        if let Some((fft_type, _)) = best {
+            return Some(fft_type);
+        }
+    }
+
+    None
+}
+
+// Variance of the error left after rounding to the closest multiple of grid_step a value perturbed
+// by a centered Gaussian error of the given variance.
+fn rounded_gaussian_variance(variance: f64, grid_step: f64) -> f64 {
+    if variance <= 0.0 {
+        return 0.0;
+    }
+
+    if variance > 16.0 * grid_step * grid_step {
+        return variance + grid_step * grid_step / 12.0;
+    }
+
+    // The error is rounded to m * grid_step with probability P(|e| > (m - 1/2) * grid_step)
+    // - P(|e| > (m + 1/2) * grid_step), which sums to the following by parts
+    let mut rounded_variance = 0.0;
+    for m in 1..=64 {
+        let bound = (m as f64 - 0.5) * grid_step;
+        let probability = 2.0f64.powf(gaussian_log2_failure_probability(Variance(variance), bound));
+        rounded_variance += (2 * m - 1) as f64 * probability;
+        if probability < f64::EPSILON {
+            break;
+        }
+    }
+
+    rounded_variance * grid_step * grid_step
+}
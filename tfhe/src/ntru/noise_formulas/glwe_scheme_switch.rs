@@ -0,0 +1,45 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::*;
+
+/// Variance of the output of `scheme_switch_glwe_ciphertext` under a binary RLWE secret key S.
+///
+/// The output encrypts m * S, so that the input noise is multiplied by S, and the mask is
+/// keyswitched under S^2.
+pub fn glwe_scheme_switch_variance(
+    input_variance: Variance,
+    polynomial_size: PolynomialSize,
+    ss_decomp_base_log: DecompositionBaseLog,
+    ss_decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    rlwe_noise_variance: Variance,
+    ss_fft_type: FftType,
+) -> Variance {
+    let polynomial_size_f64 = polynomial_size.0 as f64;
+
+    let input_noise_variance = polynomial_size_f64 / 2.0 * input_variance.0;
+
+    let key_noise_variance = ss_decomp_level_count.0 as f64
+        * polynomial_size_f64
+        * decomposition_digit_variance(ss_decomp_base_log)
+        * rlwe_noise_variance.0;
+
+    // Average of E[c_k^2] over the coefficients c_k of S^2 in Z[X] / (X^N + 1)
+    let secret_key_square_square_mean = polynomial_size_f64 * polynomial_size_f64 / 48.0
+        + 3.0 * polynomial_size_f64 / 8.0;
+    let rounding_variance = polynomial_size_f64
+        * secret_key_square_square_mean
+        * decomposition_rounding_variance(ss_decomp_base_log, ss_decomp_level_count, log_modulus).0;
+
+    let fft_variance = ntru_fft_error_variance(
+        polynomial_size,
+        ss_decomp_base_log,
+        ss_decomp_level_count,
+        2,
+        log_modulus,
+        ss_fft_type,
+    );
+
+    Variance(input_noise_variance + key_noise_variance + rounding_variance + fft_variance.0)
+}
@@ -0,0 +1,244 @@
+//! Module containing rough lattice security estimates for the instances of the NTRU CMux
+//! parameter sets.
+//!
+//! The cost of an attack is the cost of BKZ with the smallest block size beta for which the
+//! attack succeeds, modeled as 8 * d calls to an SVP oracle in dimension beta running the sieve of
+//! [BDGL16], i.e., 2^(0.292 * beta + 16.4) operations per call, as in the lattice estimator.
+//! These estimates are meant to reject obviously insecure parameter sets, not to replace it.
+//!
+//! Standard deviations are given in integer units, i.e., not divided by the modulus.
+//!
+//! [BDGL16]: https://eprint.iacr.org/2015/1128
+//! [KF17]: P. Kirchner and P.-A. Fouque, Revisiting lattice attacks on overstretched NTRU
+//! parameters, EUROCRYPT 2017
+
+use crate::core_crypto::commons::parameters::*;
+
+/// Smallest block size considered by the estimates.
+const MIN_BLOCK_SIZE: usize = 50;
+
+/// Standard deviation of a uniform binary secret after centering.
+pub const BINARY_SECRET_STD_DEV: f64 = 0.5;
+
+/// Lattice reduction required by an attack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatticeReductionCost {
+    pub block_size: usize,
+    pub lattice_dimension: usize,
+}
+
+impl LatticeReductionCost {
+    /// Log2 of the cost of a single call to the SVP oracle, i.e., the core-SVP hardness.
+    pub fn core_svp_bits(&self) -> f64 {
+        0.292 * self.block_size as f64
+    }
+
+    /// Log2 of the number of operations of BKZ with the required block size.
+    pub fn security_bits(&self) -> f64 {
+        self.core_svp_bits() + 16.4 + (8.0 * self.lattice_dimension as f64).log2()
+    }
+}
+
+/// Log2 of the root Hermite factor reached by BKZ with block size beta.
+pub fn bkz_log2_root_hermite_factor(block_size: usize) -> f64 {
+    let beta = block_size as f64;
+    let pi = std::f64::consts::PI;
+    let e = std::f64::consts::E;
+
+    ((pi * beta).powf(1.0 / beta) * beta / (2.0 * pi * e)).log2() / (2.0 * (beta - 1.0))
+}
+
+/// Lattice reduction required by the primal uSVP attack to recover the secret of an LWE
+/// instance, following the [ADPS16] success condition
+/// sigma * sqrt(beta) <= delta^(2 * beta - d - 1) * vol^(1 / d).
+///
+/// The attacker uses at most max_sample_count samples and rescales the secret part of the
+/// embedding lattice to balance it with the error. Returns None if the attack does not succeed
+/// for any block size up to the lattice dimension.
+///
+/// [ADPS16]: https://eprint.iacr.org/2015/1092
+pub fn primal_usvp_cost(
+    secret_dimension: usize,
+    max_sample_count: usize,
+    log_modulus: CiphertextModulusLog,
+    error_std_dev: f64,
+    secret_std_dev: f64,
+) -> Option<LatticeReductionCost> {
+    assert!(
+        error_std_dev > 0.0 && secret_std_dev > 0.0,
+        "Standard deviations must be positive"
+    );
+
+    let n = secret_dimension as f64;
+    let log_q = log_modulus.0 as f64;
+    let log_scale = (error_std_dev / secret_std_dev).log2();
+    let log_error_std_dev = error_std_dev.log2();
+
+    // The success condition is monotonic in the block size but not in the sample count, so the
+    // sample count is scanned with a coarse step for each block size.
+    let sample_step = (max_sample_count / 256).max(1);
+    let max_dimension = secret_dimension + max_sample_count + 1;
+
+    (MIN_BLOCK_SIZE..=max_dimension).find_map(|block_size| {
+        let beta = block_size as f64;
+        let log_delta = bkz_log2_root_hermite_factor(block_size);
+        let lhs = log_error_std_dev + 0.5 * beta.log2();
+
+        (1..=max_sample_count)
+            .step_by(sample_step)
+            .map(|sample_count| secret_dimension + sample_count + 1)
+            .find(|&lattice_dimension| {
+                if lattice_dimension < block_size {
+                    return false;
+                }
+                let d = lattice_dimension as f64;
+                let m = (lattice_dimension - secret_dimension - 1) as f64;
+                let log_volume = m * log_q + n * log_scale;
+
+                lhs <= (2.0 * beta - d - 1.0) * log_delta + log_volume / d
+            })
+            .map(|lattice_dimension| LatticeReductionCost {
+                block_size,
+                lattice_dimension,
+            })
+    })
+}
+
+/// Security in bits of an LWE instance against the primal uSVP attack.
+pub fn lwe_primal_usvp_security_bits(
+    secret_dimension: usize,
+    max_sample_count: usize,
+    log_modulus: CiphertextModulusLog,
+    error_std_dev: f64,
+    secret_std_dev: f64,
+) -> f64 {
+    primal_usvp_cost(
+        secret_dimension,
+        max_sample_count,
+        log_modulus,
+        error_std_dev,
+        secret_std_dev,
+    )
+    .map_or(f64::INFINITY, |cost| cost.security_bits())
+}
+
+/// Log2 of the fatigue point of NTRU, i.e., the modulus above which NTRU is overstretched and the
+/// dense sublattice attack becomes cheaper than the secret key recovery.
+///
+/// The fatigue point q = 0.004 * N^2.484 has been measured in [DvW21] for ternary keys, whose
+/// squared norm is (4 / 3) * N on average. As both attacks depend on the ratio between the modulus
+/// and the squared norm of the key, it is rescaled by the squared norm of the given Gaussian keys.
+///
+/// Being above the fatigue point does not make an instance insecure: the cost of the dense
+/// sublattice attack is then given by [`ntru_dense_sublattice_security_bits`].
+///
+/// [DvW21]: https://eprint.iacr.org/2021/999
+pub fn ntru_fatigue_point_log2(
+    polynomial_size: PolynomialSize,
+    secret_std_dev: f64,
+    error_std_dev: f64,
+) -> f64 {
+    let n = polynomial_size.0 as f64;
+    let key_square_norm_ratio =
+        (secret_std_dev * secret_std_dev + error_std_dev * error_std_dev) / (4.0 / 3.0);
+
+    0.004f64.log2() + 2.484 * n.log2() + key_square_norm_ratio.log2()
+}
+
+/// Security in bits of the secret key recovery on an NTRU instance c = e / f with
+/// polynomials of size N, given any number of ciphertexts under the same key f.
+///
+/// Each ciphertext gives N more samples of the LWE-like instance with secret f and error e.
+/// Above the fatigue point (see [`ntru_fatigue_point_log2`]), the dense sublattice attack is
+/// cheaper, see [`ntru_dense_sublattice_security_bits`].
+pub fn ntru_key_recovery_security_bits(
+    polynomial_size: PolynomialSize,
+    log_modulus: CiphertextModulusLog,
+    secret_std_dev: f64,
+    error_std_dev: f64,
+) -> f64 {
+    lwe_primal_usvp_security_bits(
+        polynomial_size.0,
+        2 * polynomial_size.0,
+        log_modulus,
+        error_std_dev,
+        secret_std_dev,
+    )
+}
+
+/// Log2 of the volume of the dense sublattice of the NTRU lattice, spanned by the N rotations of
+/// the key (f, e).
+///
+/// The squared singular values of the rotation matrix of (f, e) are |f(w)|^2 + |e(w)|^2 over the
+/// roots w of X^N + 1. For Gaussian coefficients of variance sigma^2, they follow a Gamma(2, N *
+/// sigma^2) distribution whose log has expectation 1 - gamma + ln(N * sigma^2), gamma being the
+/// Euler-Mascheroni constant. sigma^2 is taken as the average of the variances of f and e.
+pub fn ntru_dense_sublattice_log2_volume(
+    polynomial_size: PolynomialSize,
+    secret_std_dev: f64,
+    error_std_dev: f64,
+) -> f64 {
+    const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+
+    let n = polynomial_size.0 as f64;
+    let variance = 0.5 * (secret_std_dev * secret_std_dev + error_std_dev * error_std_dev);
+
+    0.5 * n * (1.0 - EULER_MASCHERONI + (n * variance).ln()) / std::f64::consts::LN_2
+}
+
+/// Lattice reduction required by the dense sublattice attack on the NTRU lattice of dimension 2N
+/// and volume q^N.
+///
+/// Under the geometric series assumption, BKZ with block size beta reduces the basis to log2
+/// Gram-Schmidt norms log2(q) / 2 + (2N - 1 - 2i) * log2(delta), clamped to [0, log2(q)] as the
+/// q-vectors at the head of the basis are left untouched. By [KF17], once the volume of the dense
+/// sublattice is smaller than the product of the last N Gram-Schmidt norms, the first N basis
+/// vectors span the dense sublattice, from which the key is recovered. This condition is
+/// sufficient, and [DvW21] observe that the dense sublattice is found slightly earlier in
+/// practice. Returns None if the attack does not succeed for any block size up to 2N.
+///
+/// [DvW21]: https://eprint.iacr.org/2021/999
+pub fn ntru_dense_sublattice_cost(
+    polynomial_size: PolynomialSize,
+    log_modulus: CiphertextModulusLog,
+    secret_std_dev: f64,
+    error_std_dev: f64,
+) -> Option<LatticeReductionCost> {
+    assert!(
+        error_std_dev > 0.0 && secret_std_dev > 0.0,
+        "Standard deviations must be positive"
+    );
+
+    let n = polynomial_size.0;
+    let lattice_dimension = 2 * n;
+    let d = lattice_dimension as f64;
+    let log_q = log_modulus.0 as f64;
+    let log_dense_volume =
+        ntru_dense_sublattice_log2_volume(polynomial_size, secret_std_dev, error_std_dev);
+
+    (MIN_BLOCK_SIZE..=lattice_dimension)
+        .find(|&block_size| {
+            let log_delta = bkz_log2_root_hermite_factor(block_size);
+            let log_tail_volume: f64 = (n..lattice_dimension)
+                .map(|i| (0.5 * log_q + (d - 1.0 - 2.0 * i as f64) * log_delta).clamp(0.0, log_q))
+                .sum();
+
+            log_dense_volume < log_tail_volume
+        })
+        .map(|block_size| LatticeReductionCost {
+            block_size,
+            lattice_dimension,
+        })
+}
+
+/// Security in bits of an NTRU instance c = e / f against the dense sublattice attack, see
+/// [`ntru_dense_sublattice_cost`].
+pub fn ntru_dense_sublattice_security_bits(
+    polynomial_size: PolynomialSize,
+    log_modulus: CiphertextModulusLog,
+    secret_std_dev: f64,
+    error_std_dev: f64,
+) -> f64 {
+    ntru_dense_sublattice_cost(polynomial_size, log_modulus, secret_std_dev, error_std_dev)
+        .map_or(f64::INFINITY, |cost| cost.security_bits())
+}
@@ -0,0 +1,21 @@
+pub mod common;
+pub mod fft_error;
+pub mod ngsw_external_product;
+pub mod ntru_cmux_bootstrap;
+pub mod ntru_trace;
+pub mod ntru_to_glwe_keyswitch;
+pub mod ntru_to_lwe_keyswitch;
+pub mod glwe_scheme_switch;
+pub mod ntru_cmux_circuit_bootstrap;
+pub mod lattice_security;
+
+pub use common::*;
+pub use fft_error::*;
+pub use ngsw_external_product::*;
+pub use ntru_cmux_bootstrap::*;
+pub use ntru_trace::*;
+pub use ntru_to_glwe_keyswitch::*;
+pub use ntru_to_lwe_keyswitch::*;
+pub use glwe_scheme_switch::*;
+pub use ntru_cmux_circuit_bootstrap::*;
+pub use lattice_security::*;
@@ -0,0 +1,69 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::*;
+
+/// Variance added by the external product between an NTRU ciphertext and an NGSW ciphertext
+/// encrypting m, on top of m times the noise of the input NTRU ciphertext.
+///
+/// - `ngsw_noise_variance`: variance of the noise used to encrypt the NGSW ciphertext
+/// - `ntru_secret_key_square_mean`: E[f_i^2] of the NTRU secret key f, not scaled to the torus
+/// - `message_square_mean`: E[m^2], e.g., 1/2 for a binary secret and 1 for a keyswitch key
+pub fn ngsw_external_product_additive_variance(
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    ngsw_noise_variance: Variance,
+    ntru_secret_key_square_mean: f64,
+    message_square_mean: f64,
+    fft_type: FftType,
+) -> Variance {
+    let polynomial_size_f64 = polynomial_size.0 as f64;
+
+    // sum_j d_j * e_j
+    let key_noise_variance = decomp_level_count.0 as f64
+        * polynomial_size_f64
+        * decomposition_digit_variance(decomp_base_log)
+        * ngsw_noise_variance.0;
+
+    // m * f * (decomposition rounding error)
+    let rounding_variance = message_square_mean
+        * polynomial_size_f64
+        * ntru_secret_key_square_mean
+        * decomposition_rounding_variance(decomp_base_log, decomp_level_count, log_modulus).0;
+
+    let fft_variance = ntru_fft_error_variance(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        1,
+        log_modulus,
+        fft_type,
+    );
+
+    Variance(key_noise_variance + rounding_variance + fft_variance.0)
+}
+
+/// Variance added by an NTRU keyswitch, e.g., an automorphism or a switching key. It is the
+/// external product with an NGSW-like key encrypting a message of norm 1.
+pub fn ntru_keyswitch_additive_variance(
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    ksk_noise_variance: Variance,
+    ntru_secret_key_square_mean: f64,
+    fft_type: FftType,
+) -> Variance {
+    ngsw_external_product_additive_variance(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        log_modulus,
+        ksk_noise_variance,
+        ntru_secret_key_square_mean,
+        1.0,
+        fft_type,
+    )
+}
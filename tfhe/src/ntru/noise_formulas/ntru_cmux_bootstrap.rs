@@ -0,0 +1,65 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::*;
+
+/// Variance of the modulus switch of an LWE ciphertext under a binary secret key from
+/// 2^log_input_modulus to 2N / 2^log_lut_count, as done before the NTRU CMux blind rotation.
+pub fn ntru_cmux_modulus_switch_variance(
+    input_lwe_dimension: LweDimension,
+    log_input_modulus: CiphertextModulusLog,
+    polynomial_size: PolynomialSize,
+    log_lut_count: LutCountLog,
+) -> Variance {
+    let log_br_modulus = polynomial_size.to_blind_rotation_input_modulus_log().0 - log_lut_count.0;
+    if log_br_modulus >= log_input_modulus.0 {
+        return Variance(0.0);
+    }
+
+    let rounding_variance = (
+        2.0f64.powi(-2 * log_br_modulus as i32) - 2.0f64.powi(-2 * log_input_modulus.0 as i32)
+    ) / 12.0;
+
+    // Rounding of the body and of each mask element multiplied by a binary key bit
+    Variance((1.0 + input_lwe_dimension.0 as f64 / 2.0) * rounding_variance)
+}
+
+/// Variance of the output of the NTRU CMux blind rotation of a trivial accumulator, including
+/// the initial switch of the accumulator to an NTRU ciphertext. Every NGSW of the bootstrapping
+/// key encrypts a bit of a binary LWE secret key.
+pub fn ntru_cmux_blind_rotation_variance(
+    input_lwe_dimension: LweDimension,
+    polynomial_size: PolynomialSize,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+    swk_decomp_base_log: DecompositionBaseLog,
+    swk_decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    ntru_noise_variance: Variance,
+    ntru_secret_key_square_mean: f64,
+    br_fft_type: FftType,
+    swk_fft_type: FftType,
+) -> Variance {
+    let switching_variance = ntru_keyswitch_additive_variance(
+        polynomial_size,
+        swk_decomp_base_log,
+        swk_decomp_level_count,
+        log_modulus,
+        ntru_noise_variance,
+        ntru_secret_key_square_mean,
+        swk_fft_type,
+    );
+
+    let cmux_variance = ngsw_external_product_additive_variance(
+        polynomial_size,
+        br_decomp_base_log,
+        br_decomp_level_count,
+        log_modulus,
+        ntru_noise_variance,
+        ntru_secret_key_square_mean,
+        0.5,
+        br_fft_type,
+    );
+
+    Variance(switching_variance.0 + input_lwe_dimension.0 as f64 * cmux_variance.0)
+}
@@ -0,0 +1,134 @@
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::{FftType, NtruCMuxParameters};
+use crate::ntru::noise_formulas::*;
+
+/// Predicted noise of each stage of the NTRU CMux circuit bootstrapping.
+///
+/// The variances of the input LWE ciphertext are given on the torus of the input modulus, and
+/// the other ones on the torus of the output modulus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NtruCMuxCircuitBootstrapNoise {
+    pub input_lwe_variance: Variance,
+    pub modulus_switch_variance: Variance,
+    pub blind_rotation_variance: Variance,
+    pub trace_variance: Variance,
+    pub keyswitch_variance: Variance,
+    pub scheme_switch_variance: Variance,
+    pub log2_failure_probability: f64,
+    log_output_modulus: CiphertextModulusLog,
+}
+
+impl NtruCMuxCircuitBootstrapNoise {
+    /// Variance of the output GGSW ciphertext, i.e., of its noisiest row.
+    pub fn output_variance(&self) -> Variance {
+        Variance(self.keyswitch_variance.0.max(self.scheme_switch_variance.0))
+    }
+
+    pub fn print_info(&self) {
+        let log_modulus = self.log_output_modulus.0 as f64;
+        let log_std_dev = |variance: Variance| variance.get_log_standard_dev().0 + log_modulus;
+
+        println!(
+            "Predicted std dev (bits): BR {:.3}, Tr {:.3}, KS {:.3}, SS {:.3}, log2 pfail: {:.3}",
+            log_std_dev(self.blind_rotation_variance),
+            log_std_dev(self.trace_variance),
+            log_std_dev(self.keyswitch_variance),
+            log_std_dev(self.scheme_switch_variance),
+            self.log2_failure_probability,
+        );
+    }
+}
+
+/// Predicts the noise of the NTRU CMux circuit bootstrapping for keys generated with a Gaussian
+/// NTRU secret key and binary RLWE and LWE secret keys. The switching key is assumed to share the
+/// decomposition parameters of the bootstrapping key.
+///
+/// The failure probability is the probability that the noise of the modulus switched input
+/// exceeds a quarter of the torus, leading the blind rotation to a wrong bit.
+pub fn ntru_cmux_circuit_bootstrap_noise(
+    param: &NtruCMuxParameters,
+    log_lut_count: LutCountLog,
+    br_fft_type: FftType,
+    swk_fft_type: FftType,
+    tr_fft_type: FftType,
+    ksk_fft_type: FftType,
+    ss_fft_type: FftType,
+) -> NtruCMuxCircuitBootstrapNoise {
+    let polynomial_size = param.polynomial_size();
+    let log_output_modulus = param.log_output_modulus();
+
+    let ntru_noise_variance = Variance(param.torus_ntru_std_dev() * param.torus_ntru_std_dev());
+    let rlwe_noise_variance = Variance(param.torus_rlwe_std_dev() * param.torus_rlwe_std_dev());
+    let ntru_secret_key_square_mean = param.ntru_std_dev() * param.ntru_std_dev();
+
+    let input_lwe_variance = Variance(param.torus_lwe_std_dev() * param.torus_lwe_std_dev());
+    let modulus_switch_variance = ntru_cmux_modulus_switch_variance(
+        param.input_lwe_dimension(),
+        param.log_input_modulus(),
+        polynomial_size,
+        log_lut_count,
+    );
+    let log2_failure_probability = gaussian_log2_failure_probability(
+        Variance(input_lwe_variance.0 + modulus_switch_variance.0),
+        0.25,
+    );
+
+    let blind_rotation_variance = ntru_cmux_blind_rotation_variance(
+        param.input_lwe_dimension(),
+        polynomial_size,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        log_output_modulus,
+        ntru_noise_variance,
+        ntru_secret_key_square_mean,
+        br_fft_type,
+        swk_fft_type,
+    );
+
+    let trace_variance = rev_trace_ntru_variance(
+        blind_rotation_variance,
+        polynomial_size,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        log_output_modulus,
+        ntru_noise_variance,
+        ntru_secret_key_square_mean,
+        tr_fft_type,
+    );
+
+    let keyswitch_variance = Variance(
+        trace_variance.0 + ntru_to_glwe_keyswitch_additive_variance(
+            polynomial_size,
+            param.ksk_decomp_base_log(),
+            param.ksk_decomp_level_count(),
+            log_output_modulus,
+            rlwe_noise_variance,
+            ntru_secret_key_square_mean,
+            ksk_fft_type,
+        ).0
+    );
+
+    let scheme_switch_variance = glwe_scheme_switch_variance(
+        keyswitch_variance,
+        polynomial_size,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        log_output_modulus,
+        rlwe_noise_variance,
+        ss_fft_type,
+    );
+
+    NtruCMuxCircuitBootstrapNoise {
+        input_lwe_variance,
+        modulus_switch_variance,
+        blind_rotation_variance,
+        trace_variance,
+        keyswitch_variance,
+        scheme_switch_variance,
+        log2_failure_probability,
+        log_output_modulus,
+    }
+}
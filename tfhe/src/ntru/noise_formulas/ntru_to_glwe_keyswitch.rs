@@ -0,0 +1,38 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::*;
+
+/// Variance added by `keyswitch_ntru_to_glwe`, whose key encrypts the decomposition of the NTRU
+/// secret key f under the RLWE secret key.
+pub fn ntru_to_glwe_keyswitch_additive_variance(
+    polynomial_size: PolynomialSize,
+    ksk_decomp_base_log: DecompositionBaseLog,
+    ksk_decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    rlwe_noise_variance: Variance,
+    ntru_secret_key_square_mean: f64,
+    ksk_fft_type: FftType,
+) -> Variance {
+    let polynomial_size_f64 = polynomial_size.0 as f64;
+
+    let key_noise_variance = ksk_decomp_level_count.0 as f64
+        * polynomial_size_f64
+        * decomposition_digit_variance(ksk_decomp_base_log)
+        * rlwe_noise_variance.0;
+
+    let rounding_variance = polynomial_size_f64
+        * ntru_secret_key_square_mean
+        * decomposition_rounding_variance(ksk_decomp_base_log, ksk_decomp_level_count, log_modulus).0;
+
+    let fft_variance = ntru_fft_error_variance(
+        polynomial_size,
+        ksk_decomp_base_log,
+        ksk_decomp_level_count,
+        2,
+        log_modulus,
+        ksk_fft_type,
+    );
+
+    Variance(key_noise_variance + rounding_variance + fft_variance.0)
+}
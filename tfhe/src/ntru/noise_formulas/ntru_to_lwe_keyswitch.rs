@@ -0,0 +1,30 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::noise_formulas::*;
+
+/// Variance added by the keyswitch of an LWE ciphertext under the coefficients of the NTRU secret
+/// key f to a binary LWE secret key, whose key encrypts the decomposition of each coefficient of f.
+///
+/// ksk_noise_variance is given on the torus of the output modulus, and the input mask is
+/// decomposed modulo 2^log_modulus.
+pub fn ntru_to_lwe_keyswitch_additive_variance(
+    polynomial_size: PolynomialSize,
+    ksk_decomp_base_log: DecompositionBaseLog,
+    ksk_decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    ksk_noise_variance: Variance,
+    ntru_secret_key_square_mean: f64,
+) -> Variance {
+    let polynomial_size_f64 = polynomial_size.0 as f64;
+
+    let key_noise_variance = ksk_decomp_level_count.0 as f64
+        * polynomial_size_f64
+        * decomposition_digit_variance(ksk_decomp_base_log)
+        * ksk_noise_variance.0;
+
+    let rounding_variance = polynomial_size_f64
+        * ntru_secret_key_square_mean
+        * decomposition_rounding_variance(ksk_decomp_base_log, ksk_decomp_level_count, log_modulus).0;
+
+    Variance(key_noise_variance + rounding_variance)
+}
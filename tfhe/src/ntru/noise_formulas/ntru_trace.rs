@@ -0,0 +1,45 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::*;
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::*;
+
+/// Average variance of the coefficients after `rev_trace_ntru_ciphertext`.
+///
+/// Each of the log2(N) steps maps the ciphertext ct to ct / 2 + psi(ct / 2) for an automorphism psi
+/// of order 2, which projects the noise onto a subspace of dimension N / 2 and thus halves its
+/// average variance, on top of the noise of the rounding by 2 and of the automorphism keyswitch.
+/// Note that the constant coefficient keeps the whole input variance.
+pub fn rev_trace_ntru_variance(
+    input_variance: Variance,
+    polynomial_size: PolynomialSize,
+    tr_decomp_base_log: DecompositionBaseLog,
+    tr_decomp_level_count: DecompositionLevelCount,
+    log_modulus: CiphertextModulusLog,
+    ntru_noise_variance: Variance,
+    ntru_secret_key_square_mean: f64,
+    tr_fft_type: FftType,
+) -> Variance {
+    let modulus = 2.0f64.powi(log_modulus.0 as i32);
+
+    // 2 * (f * r) with r in {0, 1/2}, projected as the input noise
+    let rounding_variance = 2.0
+        * polynomial_size.0 as f64
+        * ntru_secret_key_square_mean
+        / (8.0 * modulus * modulus);
+
+    let automorphism_variance = ntru_keyswitch_additive_variance(
+        polynomial_size,
+        tr_decomp_base_log,
+        tr_decomp_level_count,
+        log_modulus,
+        ntru_noise_variance,
+        ntru_secret_key_square_mean,
+        tr_fft_type,
+    );
+
+    // V_k = V_{k-1} / 2 + A for k = 1, ..., log2(N)
+    let polynomial_size = polynomial_size.0 as f64;
+    let additive_variance = rounding_variance + automorphism_variance.0;
+
+    Variance(input_variance.0 / polynomial_size + 2.0 * (1.0 - 1.0 / polynomial_size) * additive_variance)
+}
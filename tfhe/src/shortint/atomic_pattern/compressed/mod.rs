@@ -24,14 +24,18 @@ pub enum CompressedAtomicPatternServerKey {
 }
 
 impl CompressedAtomicPatternServerKey {
-    pub fn new(cks: &ClientKey, engine: &mut ShortintEngine) -> Self {
+    /// Returns an error if the atomic pattern of the client key has no compressed server key
+    pub fn try_new(cks: &ClientKey, engine: &mut ShortintEngine) -> crate::Result<Self> {
         match &cks.atomic_pattern {
-            AtomicPatternClientKey::Standard(ap_cks) => Self::Standard(
+            AtomicPatternClientKey::Standard(ap_cks) => Ok(Self::Standard(
                 CompressedStandardAtomicPatternServerKey::new(ap_cks, engine),
-            ),
-            AtomicPatternClientKey::KeySwitch32(ap_cks) => {
-                Self::KeySwitch32(CompressedKS32AtomicPatternServerKey::new(ap_cks, engine))
-            }
+            )),
+            AtomicPatternClientKey::KeySwitch32(ap_cks) => Ok(Self::KeySwitch32(
+                CompressedKS32AtomicPatternServerKey::new(ap_cks, engine),
+            )),
+            AtomicPatternClientKey::Ntru(_) => Err(crate::Error::from(
+                "Compressed server keys are not supported by the NTRU Atomic Pattern",
+            )),
         }
     }
 
@@ -189,17 +189,20 @@ impl AtomicPattern for KS32AtomicPatternServerKey {
         seed: Seed,
         random_bits_count: u64,
         full_bits_count: u64,
-    ) -> (LweCiphertextOwned<u64>, Degree) {
-        generate_pseudo_random_from_pbs(
+    ) -> crate::Result<(LweCiphertextOwned<u64>, Degree)> {
+        Ok(generate_pseudo_random_from_pbs(
             &self.bootstrapping_key,
             seed,
             random_bits_count,
             full_bits_count,
             self.ciphertext_modulus(),
-        )
+        ))
     }
 
-    fn switch_modulus_and_compress(&self, ct: &Ciphertext) -> CompressedModulusSwitchedCiphertext {
+    fn switch_modulus_and_compress(
+        &self,
+        ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext> {
         let compressed_modulus_switched_lwe_ciphertext =
             ShortintEngine::with_thread_local_mut(|engine| {
                 let (mut ciphertext_buffer, _) = engine.get_buffers(
@@ -215,20 +218,20 @@ impl AtomicPattern for KS32AtomicPatternServerKey {
                 switch_modulus_and_compress(ciphertext_buffer.as_view(), &self.bootstrapping_key)
             });
 
-        CompressedModulusSwitchedCiphertext {
+        Ok(CompressedModulusSwitchedCiphertext {
             compressed_modulus_switched_lwe_ciphertext,
             degree: ct.degree,
             message_modulus: ct.message_modulus,
             carry_modulus: ct.carry_modulus,
             atomic_pattern: ct.atomic_pattern,
-        }
+        })
     }
 
     fn decompress_and_apply_lookup_table(
         &self,
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         lut: &LookupTableOwned,
-    ) -> Ciphertext {
+    ) -> crate::Result<Ciphertext> {
         let mut output = LweCiphertext::new(
             0,
             self.ciphertext_lwe_dimension().to_lwe_size(),
@@ -246,14 +249,14 @@ impl AtomicPattern for KS32AtomicPatternServerKey {
             );
         });
 
-        Ciphertext::new(
+        Ok(Ciphertext::new(
             output,
             lut.degree,
             NoiseLevel::NOMINAL,
             compressed_ct.message_modulus,
             compressed_ct.carry_modulus,
             compressed_ct.atomic_pattern,
-        )
+        ))
     }
 }
 
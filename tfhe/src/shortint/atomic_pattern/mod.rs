@@ -6,6 +6,7 @@
 
 pub mod compressed;
 pub mod ks32;
+pub mod ntru;
 pub mod standard;
 
 use std::any::Any;
@@ -23,7 +24,7 @@ use super::backward_compatibility::atomic_pattern::*;
 use super::ciphertext::{CompressedModulusSwitchedCiphertext, Degree};
 use super::client_key::atomic_pattern::AtomicPatternClientKey;
 use super::engine::ShortintEngine;
-use super::parameters::{DynamicDistribution, KeySwitch32PBSParameters};
+use super::parameters::{DynamicDistribution, KeySwitch32PBSParameters, NtruPBSParameters};
 use super::prelude::{DecompositionBaseLog, DecompositionLevelCount};
 use super::server_key::{
     apply_ms_blind_rotate, apply_programmable_bootstrap, LookupTableOwned, LookupTableSize,
@@ -36,6 +37,7 @@ use super::{
 };
 
 pub use ks32::*;
+pub use ntru::*;
 pub use standard::*;
 
 /// A choice of atomic pattern
@@ -54,6 +56,12 @@ pub enum AtomicPatternKind {
     /// This allows to reduce the size of the keyswitching key. This AP only supports the KS -> PBS
     /// order.
     KeySwitch32,
+    /// The LWE ciphertexts are encrypted under the coefficients of an NTRU secret key. They are
+    /// keyswitched to a small u32 LWE key as in the KS32 AP, and then bootstrapped with the NTRU
+    /// CMux bootstrapping.
+    ///
+    /// This AP only supports the KS -> PBS order.
+    Ntru,
 }
 
 /// The set of operations needed to implement an Atomic Pattern.
@@ -100,25 +108,35 @@ pub trait AtomicPattern {
     /// bit.
     /// The output in in the form 0000rrr000noise (random_bits_count=3, full_bits_count=7)
     /// The encryted value is oblivious to the server
+    ///
+    /// Returns an error if the Atomic Pattern does not support the oblivious pseudo random
+    /// generation
     fn generate_oblivious_pseudo_random(
         &self,
         seed: Seed,
         random_bits_count: u64,
         full_bits_count: u64,
-    ) -> (LweCiphertextOwned<u64>, Degree);
+    ) -> crate::Result<(LweCiphertextOwned<u64>, Degree)>;
 
     /// Returns true if the Atomic Pattern will execute deterministically
     fn deterministic_execution(&self) -> bool;
 
     /// Compresses a ciphertext to have a smaller serialization size
-    fn switch_modulus_and_compress(&self, ct: &Ciphertext) -> CompressedModulusSwitchedCiphertext;
+    ///
+    /// Returns an error if the Atomic Pattern does not support the modulus switch compression
+    fn switch_modulus_and_compress(
+        &self,
+        ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext>;
 
     /// Decompresses a compressed ciphertext
+    ///
+    /// Returns an error if the Atomic Pattern does not support the modulus switch compression
     fn decompress_and_apply_lookup_table(
         &self,
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         lut: &LookupTableOwned,
-    ) -> Ciphertext;
+    ) -> crate::Result<Ciphertext>;
 }
 
 pub trait AtomicPatternMut: AtomicPattern {
@@ -226,7 +244,7 @@ impl<T: AtomicPattern> AtomicPattern for &T {
         seed: Seed,
         random_bits_count: u64,
         full_bits_count: u64,
-    ) -> (LweCiphertextOwned<u64>, Degree) {
+    ) -> crate::Result<(LweCiphertextOwned<u64>, Degree)> {
         (*self).generate_oblivious_pseudo_random(seed, random_bits_count, full_bits_count)
     }
 
@@ -234,7 +252,10 @@ impl<T: AtomicPattern> AtomicPattern for &T {
         (*self).deterministic_execution()
     }
 
-    fn switch_modulus_and_compress(&self, ct: &Ciphertext) -> CompressedModulusSwitchedCiphertext {
+    fn switch_modulus_and_compress(
+        &self,
+        ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext> {
         (*self).switch_modulus_and_compress(ct)
     }
 
@@ -242,7 +263,7 @@ impl<T: AtomicPattern> AtomicPattern for &T {
         &self,
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         lut: &LookupTableOwned,
-    ) -> Ciphertext {
+    ) -> crate::Result<Ciphertext> {
         (*self).decompress_and_apply_lookup_table(compressed_ct, lut)
     }
 }
@@ -254,6 +275,7 @@ impl<T: AtomicPattern> AtomicPattern for &T {
 pub enum AtomicPatternServerKey {
     Standard(StandardAtomicPatternServerKey),
     KeySwitch32(KS32AtomicPatternServerKey),
+    Ntru(NtruAtomicPatternServerKey),
     #[serde(skip)]
     Dynamic(Box<dyn private::DynamicAtomicPattern>),
 }
@@ -267,6 +289,9 @@ impl AtomicPatternServerKey {
             AtomicPatternClientKey::KeySwitch32(ap_cks) => {
                 Self::KeySwitch32(KS32AtomicPatternServerKey::new(ap_cks, engine))
             }
+            AtomicPatternClientKey::Ntru(ap_cks) => {
+                Self::Ntru(NtruAtomicPatternServerKey::new(ap_cks, engine))
+            }
         }
     }
 }
@@ -276,6 +301,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.ciphertext_lwe_dimension(),
             Self::KeySwitch32(ap) => ap.ciphertext_lwe_dimension(),
+            Self::Ntru(ap) => ap.ciphertext_lwe_dimension(),
             Self::Dynamic(ap) => ap.ciphertext_lwe_dimension(),
         }
     }
@@ -284,6 +310,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.ciphertext_modulus(),
             Self::KeySwitch32(ap) => ap.ciphertext_modulus(),
+            Self::Ntru(ap) => ap.ciphertext_modulus(),
             Self::Dynamic(ap) => ap.ciphertext_modulus(),
         }
     }
@@ -292,6 +319,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.ciphertext_decompression_method(),
             Self::KeySwitch32(ap) => ap.ciphertext_decompression_method(),
+            Self::Ntru(ap) => ap.ciphertext_decompression_method(),
             Self::Dynamic(ap) => ap.ciphertext_decompression_method(),
         }
     }
@@ -300,6 +328,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.apply_lookup_table_assign(ct, acc),
             Self::KeySwitch32(ap) => ap.apply_lookup_table_assign(ct, acc),
+            Self::Ntru(ap) => ap.apply_lookup_table_assign(ct, acc),
             Self::Dynamic(ap) => ap.apply_lookup_table_assign(ct, acc),
         }
     }
@@ -312,6 +341,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.apply_many_lookup_table(ct, lut),
             Self::KeySwitch32(ap) => ap.apply_many_lookup_table(ct, lut),
+            Self::Ntru(ap) => ap.apply_many_lookup_table(ct, lut),
             Self::Dynamic(ap) => ap.apply_many_lookup_table(ct, lut),
         }
     }
@@ -320,6 +350,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.lookup_table_size(),
             Self::KeySwitch32(ap) => ap.lookup_table_size(),
+            Self::Ntru(ap) => ap.lookup_table_size(),
             Self::Dynamic(ap) => ap.lookup_table_size(),
         }
     }
@@ -328,6 +359,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.kind(),
             Self::KeySwitch32(ap) => ap.kind(),
+            Self::Ntru(ap) => ap.kind(),
             Self::Dynamic(ap) => ap.kind(),
         }
     }
@@ -336,6 +368,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.deterministic_execution(),
             Self::KeySwitch32(ap) => ap.deterministic_execution(),
+            Self::Ntru(ap) => ap.deterministic_execution(),
             Self::Dynamic(ap) => ap.deterministic_execution(),
         }
     }
@@ -345,7 +378,7 @@ impl AtomicPattern for AtomicPatternServerKey {
         seed: Seed,
         random_bits_count: u64,
         full_bits_count: u64,
-    ) -> (LweCiphertextOwned<u64>, Degree) {
+    ) -> crate::Result<(LweCiphertextOwned<u64>, Degree)> {
         match self {
             Self::Standard(ap) => {
                 ap.generate_oblivious_pseudo_random(seed, random_bits_count, full_bits_count)
@@ -353,16 +386,23 @@ impl AtomicPattern for AtomicPatternServerKey {
             Self::KeySwitch32(ap) => {
                 ap.generate_oblivious_pseudo_random(seed, random_bits_count, full_bits_count)
             }
+            Self::Ntru(ap) => {
+                ap.generate_oblivious_pseudo_random(seed, random_bits_count, full_bits_count)
+            }
             Self::Dynamic(ap) => {
                 ap.generate_oblivious_pseudo_random(seed, random_bits_count, full_bits_count)
             }
         }
     }
 
-    fn switch_modulus_and_compress(&self, ct: &Ciphertext) -> CompressedModulusSwitchedCiphertext {
+    fn switch_modulus_and_compress(
+        &self,
+        ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext> {
         match self {
             Self::Standard(ap) => ap.switch_modulus_and_compress(ct),
             Self::KeySwitch32(ap) => ap.switch_modulus_and_compress(ct),
+            Self::Ntru(ap) => ap.switch_modulus_and_compress(ct),
             Self::Dynamic(ap) => ap.switch_modulus_and_compress(ct),
         }
     }
@@ -371,10 +411,11 @@ impl AtomicPattern for AtomicPatternServerKey {
         &self,
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         lut: &LookupTableOwned,
-    ) -> Ciphertext {
+    ) -> crate::Result<Ciphertext> {
         match self {
             Self::Standard(ap) => ap.decompress_and_apply_lookup_table(compressed_ct, lut),
             Self::KeySwitch32(ap) => ap.decompress_and_apply_lookup_table(compressed_ct, lut),
+            Self::Ntru(ap) => ap.decompress_and_apply_lookup_table(compressed_ct, lut),
             Self::Dynamic(ap) => ap.decompress_and_apply_lookup_table(compressed_ct, lut),
         }
     }
@@ -385,6 +426,7 @@ impl AtomicPatternMut for AtomicPatternServerKey {
         match self {
             Self::Standard(ap) => ap.set_deterministic_execution(new_deterministic_execution),
             Self::KeySwitch32(ap) => ap.set_deterministic_execution(new_deterministic_execution),
+            Self::Ntru(ap) => ap.set_deterministic_execution(new_deterministic_execution),
             Self::Dynamic(ap) => ap.set_deterministic_execution(new_deterministic_execution),
         }
     }
@@ -396,6 +438,7 @@ impl AtomicPatternMut for AtomicPatternServerKey {
 pub enum AtomicPatternParameters {
     Standard(PBSParameters),
     KeySwitch32(KeySwitch32PBSParameters),
+    Ntru(NtruPBSParameters),
 }
 
 impl From<PBSParameters> for AtomicPatternParameters {
@@ -422,11 +465,18 @@ impl From<KeySwitch32PBSParameters> for AtomicPatternParameters {
     }
 }
 
+impl From<NtruPBSParameters> for AtomicPatternParameters {
+    fn from(value: NtruPBSParameters) -> Self {
+        Self::Ntru(value)
+    }
+}
+
 impl AtomicPatternParameters {
     pub const fn message_modulus(&self) -> MessageModulus {
         match self {
             Self::Standard(parameters) => parameters.message_modulus(),
             Self::KeySwitch32(parameters) => parameters.message_modulus(),
+            Self::Ntru(parameters) => parameters.message_modulus(),
         }
     }
 
@@ -434,6 +484,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.carry_modulus(),
             Self::KeySwitch32(parameters) => parameters.carry_modulus(),
+            Self::Ntru(parameters) => parameters.carry_modulus(),
         }
     }
 
@@ -441,6 +492,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.max_noise_level(),
             Self::KeySwitch32(parameters) => parameters.max_noise_level(),
+            Self::Ntru(parameters) => parameters.max_noise_level(),
         }
     }
 
@@ -448,6 +500,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.encryption_key_choice(),
             Self::KeySwitch32(parameters) => parameters.encryption_key_choice(),
+            Self::Ntru(parameters) => parameters.encryption_key_choice(),
         }
     }
 
@@ -462,6 +515,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.ciphertext_modulus(),
             Self::KeySwitch32(parameters) => parameters.ciphertext_modulus(),
+            Self::Ntru(parameters) => parameters.ciphertext_modulus(),
         }
     }
 
@@ -469,6 +523,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.lwe_dimension(),
             Self::KeySwitch32(parameters) => parameters.lwe_dimension(),
+            Self::Ntru(parameters) => parameters.lwe_dimension(),
         }
     }
 
@@ -476,6 +531,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.glwe_dimension(),
             Self::KeySwitch32(parameters) => parameters.glwe_dimension(),
+            Self::Ntru(parameters) => parameters.glwe_dimension(),
         }
     }
 
@@ -485,6 +541,7 @@ impl AtomicPatternParameters {
             Self::KeySwitch32(parameters) => {
                 parameters.lwe_noise_distribution().to_u64_distribution()
             }
+            Self::Ntru(parameters) => parameters.lwe_noise_distribution().to_u64_distribution(),
         }
     }
 
@@ -492,6 +549,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.glwe_noise_distribution(),
             Self::KeySwitch32(parameters) => parameters.glwe_noise_distribution(),
+            Self::Ntru(parameters) => parameters.glwe_noise_distribution(),
         }
     }
 
@@ -499,6 +557,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.polynomial_size(),
             Self::KeySwitch32(parameters) => parameters.polynomial_size(),
+            Self::Ntru(parameters) => parameters.polynomial_size(),
         }
     }
 
@@ -506,6 +565,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.pbs_base_log(),
             Self::KeySwitch32(parameters) => parameters.pbs_base_log(),
+            Self::Ntru(parameters) => parameters.pbs_base_log(),
         }
     }
 
@@ -513,6 +573,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.pbs_level(),
             Self::KeySwitch32(parameters) => parameters.pbs_level(),
+            Self::Ntru(parameters) => parameters.pbs_level(),
         }
     }
 
@@ -520,6 +581,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.ks_base_log(),
             Self::KeySwitch32(parameters) => parameters.ks_base_log(),
+            Self::Ntru(parameters) => parameters.ks_base_log(),
         }
     }
 
@@ -527,6 +589,7 @@ impl AtomicPatternParameters {
         match self {
             Self::Standard(parameters) => parameters.ks_level(),
             Self::KeySwitch32(parameters) => parameters.ks_level(),
+            Self::Ntru(parameters) => parameters.ks_level(),
         }
     }
 }
@@ -542,6 +605,7 @@ impl ParameterSetConformant for AtomicPatternServerKey {
             (Self::KeySwitch32(ap), AtomicPatternParameters::KeySwitch32(params)) => {
                 ap.is_conformant(params)
             }
+            (Self::Ntru(ap), AtomicPatternParameters::Ntru(params)) => ap.is_conformant(params),
             _ => false,
         }
     }
@@ -559,6 +623,12 @@ impl From<KS32AtomicPatternServerKey> for AtomicPatternServerKey {
     }
 }
 
+impl From<NtruAtomicPatternServerKey> for AtomicPatternServerKey {
+    fn from(value: NtruAtomicPatternServerKey) -> Self {
+        Self::Ntru(value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::shortint::parameters::test_params::TEST_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128;
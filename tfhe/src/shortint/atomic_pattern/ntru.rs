@@ -0,0 +1,459 @@
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+use serde::{Deserialize, Serialize};
+use tfhe_csprng::seeders::Seed;
+use tfhe_versionable::Versionize;
+
+use super::{AtomicPattern, AtomicPatternKind, AtomicPatternMut};
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::prelude::{
+    keyswitch_lwe_ciphertext_with_scalar_change, lwe_ciphertext_modulus_switch,
+    CiphertextModulus as CoreCiphertextModulus, Fft, GlweCiphertextOwned, GlweSize, LweCiphertext,
+    LweCiphertextMutView, LweCiphertextOwned, LweDimension, LweKeyswitchKeyOwned, MonomialDegree,
+    MsDecompressionType, PlaintextList,
+};
+use crate::core_crypto::fft_impl::fft64::math::fft::FftView;
+use crate::ntru::algorithms::{
+    allocate_and_generate_new_ntru_cmux_bootstrap_key,
+    allocate_and_generate_new_ntru_to_lwe_keyswitch_key,
+    convert_standard_ntru_cmux_bootstrap_key_to_fourier, extract_lwe_sample_from_ntru_ciphertext,
+    ntru_cmux_blind_rotate_assign, ntru_cmux_blind_rotate_assign_scratch,
+    switch_to_ntru_ciphertext_mem_optimized, switch_to_ntru_ciphertext_mem_optimized_requirement,
+};
+use crate::ntru::entities::{FftType, FourierNtruCMuxBootstrapKeyOwned, NtruCiphertextMutView};
+use crate::shortint::backward_compatibility::atomic_pattern::NtruAtomicPatternServerKeyVersions;
+use crate::shortint::ciphertext::{CompressedModulusSwitchedCiphertext, Degree, MaxDegree};
+use crate::shortint::client_key::atomic_pattern::{NtruAtomicPatternClientKey, NtruClientKey};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::NtruPBSParameters;
+#[cfg(feature = "pbs-stats")]
+use crate::shortint::server_key::pbs_stats::{Ordering, PBS_COUNT};
+use crate::shortint::server_key::{
+    GenericServerKey, LookupTableOwned, LookupTableSize, ManyLookupTableOwned,
+};
+use crate::shortint::{Ciphertext, CiphertextModulus};
+
+/// The definition of the server key elements used in the [`Ntru`](AtomicPatternKind::Ntru) atomic
+/// pattern
+///
+/// Ciphertexts are keyswitched to a small u32 LWE key, and are then bootstrapped with the NTRU
+/// CMux bootstrapping back to the large LWE key given by the coefficients of the NTRU secret key.
+/// The output of the bootstrapping is computed modulo `ntru_ciphertext_modulus`, which is
+/// compatible with the native `ciphertext_modulus` of the ciphertexts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NtruAtomicPatternServerKeyVersions)]
+pub struct NtruAtomicPatternServerKey {
+    pub key_switching_key: LweKeyswitchKeyOwned<u32>,
+    pub bootstrapping_key: FourierNtruCMuxBootstrapKeyOwned,
+    pub ntru_ciphertext_modulus: CiphertextModulus,
+    pub ciphertext_modulus: CiphertextModulus,
+}
+
+impl ParameterSetConformant for NtruAtomicPatternServerKey {
+    type ParameterSet = NtruPBSParameters;
+
+    fn is_conformant(&self, parameter_set: &Self::ParameterSet) -> bool {
+        let Self {
+            key_switching_key,
+            bootstrapping_key,
+            ntru_ciphertext_modulus,
+            ciphertext_modulus,
+        } = self;
+
+        let ngsw_list = bootstrapping_key.get_fourier_ngsw_list();
+        let switching_key = bootstrapping_key.get_fourier_ntru_switching_key();
+
+        let pbs_key_ok = bootstrapping_key.input_lwe_dimension() == parameter_set.lwe_dimension()
+            && bootstrapping_key.polynomial_size() == parameter_set.polynomial_size()
+            && ngsw_list.decomposition_base_log() == parameter_set.pbs_base_log()
+            && ngsw_list.decomposition_level_count() == parameter_set.pbs_level()
+            && switching_key.decomposition_base_log() == parameter_set.swk_base_log()
+            && switching_key.decomposition_level_count() == parameter_set.swk_level();
+
+        let ks_conformance_params = parameter_set.into();
+
+        let ks_key_ok = key_switching_key.is_conformant(&ks_conformance_params);
+
+        *ntru_ciphertext_modulus == parameter_set.ntru_ciphertext_modulus()
+            && *ciphertext_modulus == parameter_set.ciphertext_modulus()
+            && pbs_key_ok
+            && ks_key_ok
+    }
+}
+
+pub type NtruServerKey = GenericServerKey<NtruAtomicPatternServerKey>;
+
+impl NtruServerKey {
+    pub fn new(cks: &NtruClientKey) -> Self {
+        let params = &cks.atomic_pattern.parameters;
+
+        let atomic_pattern = ShortintEngine::with_thread_local_mut(|engine| {
+            NtruAtomicPatternServerKey::new(&cks.atomic_pattern, engine)
+        });
+
+        Self::from_raw_parts(
+            atomic_pattern,
+            params.message_modulus(),
+            params.carry_modulus(),
+            MaxDegree::from_msg_carry_modulus(params.message_modulus(), params.carry_modulus()),
+            params.max_noise_level(),
+        )
+    }
+}
+
+impl NtruAtomicPatternServerKey {
+    pub fn new(cks: &NtruAtomicPatternClientKey, engine: &mut ShortintEngine) -> Self {
+        let params = &cks.parameters;
+
+        let in_key = cks.small_lwe_secret_key();
+
+        let out_key = cks.ntru_secret_key();
+
+        let bootstrapping_key = allocate_and_generate_new_ntru_cmux_bootstrap_key(
+            &in_key,
+            out_key,
+            params.pbs_base_log(),
+            params.pbs_level(),
+            params.swk_base_log(),
+            params.swk_level(),
+            params.ntru_noise_distribution(),
+            params.ntru_ciphertext_modulus(),
+            &mut engine.encryption_generator,
+        );
+
+        let mut fourier_bootstrapping_key = FourierNtruCMuxBootstrapKeyOwned::new(
+            params.polynomial_size(),
+            params.pbs_base_log(),
+            params.pbs_level(),
+            params.swk_base_log(),
+            params.swk_level(),
+            params.lwe_dimension(),
+            FftType::Vanilla,
+            FftType::Vanilla,
+        );
+        convert_standard_ntru_cmux_bootstrap_key_to_fourier(
+            &bootstrapping_key,
+            &mut fourier_bootstrapping_key,
+        );
+
+        // Creation of the key switching key
+        let key_switching_key = allocate_and_generate_new_ntru_to_lwe_keyswitch_key(
+            out_key,
+            &in_key,
+            params.ks_base_log(),
+            params.ks_level(),
+            params.lwe_noise_distribution(),
+            params.post_keyswitch_ciphertext_modulus(),
+            &mut engine.encryption_generator,
+        );
+
+        Self::from_raw_parts(
+            key_switching_key,
+            fourier_bootstrapping_key,
+            params.ntru_ciphertext_modulus(),
+            params.ciphertext_modulus(),
+        )
+    }
+
+    pub fn from_raw_parts(
+        key_switching_key: LweKeyswitchKeyOwned<u32>,
+        bootstrapping_key: FourierNtruCMuxBootstrapKeyOwned,
+        ntru_ciphertext_modulus: CiphertextModulus,
+        ciphertext_modulus: CiphertextModulus,
+    ) -> Self {
+        assert_eq!(
+            key_switching_key.input_key_lwe_dimension(),
+            bootstrapping_key.output_lwe_dimension(),
+            "Mismatch between the input LweKeyswitchKey LweDimension ({:?}) \
+            and the FourierNtruCMuxBootstrapKey output LweDimension ({:?})",
+            key_switching_key.input_key_lwe_dimension(),
+            bootstrapping_key.output_lwe_dimension()
+        );
+
+        assert_eq!(
+            key_switching_key.output_key_lwe_dimension(),
+            bootstrapping_key.input_lwe_dimension(),
+            "Mismatch between the output LweKeyswitchKey LweDimension ({:?}) \
+            and the FourierNtruCMuxBootstrapKey input LweDimension ({:?})",
+            key_switching_key.output_key_lwe_dimension(),
+            bootstrapping_key.input_lwe_dimension()
+        );
+
+        assert!(
+            ntru_ciphertext_modulus.is_compatible_with_native_modulus()
+                && ciphertext_modulus.is_native_modulus(),
+            "The NTRU atomic pattern only supports a power-of-two NTRU modulus ({ntru_ciphertext_modulus:?}) \
+            and a native ciphertext modulus ({ciphertext_modulus:?})"
+        );
+
+        Self {
+            key_switching_key,
+            bootstrapping_key,
+            ntru_ciphertext_modulus,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn intermediate_lwe_dimension(&self) -> LweDimension {
+        self.key_switching_key.output_key_lwe_dimension()
+    }
+}
+
+impl AtomicPattern for NtruAtomicPatternServerKey {
+    fn ciphertext_lwe_dimension(&self) -> LweDimension {
+        self.key_switching_key.input_key_lwe_dimension()
+    }
+
+    fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
+    fn ciphertext_decompression_method(&self) -> MsDecompressionType {
+        MsDecompressionType::ClassicPbs
+    }
+
+    fn apply_lookup_table_assign(&self, ct: &mut Ciphertext, acc: &LookupTableOwned) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let (mut ciphertext_buffer, buffers) = engine.get_buffers(
+                self.intermediate_lwe_dimension(),
+                self.intermediate_ciphertext_modulus(),
+            );
+
+            let polynomial_size = self.bootstrapping_key.polynomial_size();
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+
+            buffers.resize(
+                self.keyswitch_blind_rotate_requirement(fft)
+                    .unwrap()
+                    .unaligned_bytes_required(),
+            );
+            let (ntru_acc, stack) = buffers
+                .stack()
+                .make_aligned_raw::<u64>(polynomial_size.0, CACHELINE_ALIGN);
+            let mut ntru_acc = NtruCiphertextMutView::from_container(
+                ntru_acc,
+                polynomial_size,
+                self.ntru_ciphertext_modulus,
+            );
+
+            self.keyswitch_blind_rotate(
+                ct,
+                &acc.acc,
+                &mut ciphertext_buffer,
+                &mut ntru_acc,
+                fft,
+                stack,
+            );
+
+            // The output modulus is a power of two, stored in the MSBs, so the output of the
+            // sample extraction is also a valid ciphertext modulo the native modulus
+            extract_lwe_sample_from_ntru_ciphertext(
+                &ntru_acc,
+                &mut LweCiphertext::from_container(ct.ct.as_mut(), self.ntru_ciphertext_modulus),
+                MonomialDegree(0),
+            );
+        });
+    }
+
+    fn apply_many_lookup_table(
+        &self,
+        ct: &Ciphertext,
+        lut: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        let function_count = lut.function_count();
+        let mut outputs = Vec::with_capacity(function_count);
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let (mut ciphertext_buffer, buffers) = engine.get_buffers(
+                self.intermediate_lwe_dimension(),
+                self.intermediate_ciphertext_modulus(),
+            );
+
+            let polynomial_size = self.bootstrapping_key.polynomial_size();
+            let fft = Fft::new(polynomial_size);
+            let fft = fft.as_view();
+
+            buffers.resize(
+                self.keyswitch_blind_rotate_requirement(fft)
+                    .unwrap()
+                    .unaligned_bytes_required(),
+            );
+            let (ntru_acc, stack) = buffers
+                .stack()
+                .make_aligned_raw::<u64>(polynomial_size.0, CACHELINE_ALIGN);
+            let mut ntru_acc = NtruCiphertextMutView::from_container(
+                ntru_acc,
+                polynomial_size,
+                self.ntru_ciphertext_modulus,
+            );
+
+            self.keyswitch_blind_rotate(
+                ct,
+                &lut.acc,
+                &mut ciphertext_buffer,
+                &mut ntru_acc,
+                fft,
+                stack,
+            );
+
+            // The accumulator has been rotated, we can now proceed with the various sample
+            // extractions
+            for (fn_idx, output_degree) in lut.per_function_output_degree.iter().enumerate() {
+                let monomial_degree = MonomialDegree(fn_idx * lut.sample_extraction_stride);
+                let mut output_shortint_ct = ct.clone();
+
+                extract_lwe_sample_from_ntru_ciphertext(
+                    &ntru_acc,
+                    &mut LweCiphertext::from_container(
+                        output_shortint_ct.ct.as_mut(),
+                        self.ntru_ciphertext_modulus,
+                    ),
+                    monomial_degree,
+                );
+
+                output_shortint_ct.degree = *output_degree;
+                output_shortint_ct.set_noise_level_to_nominal();
+                outputs.push(output_shortint_ct);
+            }
+        });
+
+        outputs
+    }
+
+    fn lookup_table_size(&self) -> LookupTableSize {
+        // The lookup table is the plaintext polynomial encrypted by the NTRU switching key
+        LookupTableSize::new(GlweSize(1), self.bootstrapping_key.polynomial_size())
+    }
+
+    fn kind(&self) -> AtomicPatternKind {
+        AtomicPatternKind::Ntru
+    }
+
+    /// The NTRU CMux bootstrapping only uses the sequential FFT, so its execution is always
+    /// deterministic
+    fn deterministic_execution(&self) -> bool {
+        true
+    }
+
+    fn generate_oblivious_pseudo_random(
+        &self,
+        _seed: Seed,
+        _random_bits_count: u64,
+        _full_bits_count: u64,
+    ) -> crate::Result<(LweCiphertextOwned<u64>, Degree)> {
+        Err(crate::Error::from(
+            "Oblivious pseudo random generation is not supported by the NTRU Atomic Pattern",
+        ))
+    }
+
+    fn switch_modulus_and_compress(
+        &self,
+        _ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext> {
+        Err(crate::Error::from(
+            "Modulus switch compression is not supported by the NTRU Atomic Pattern",
+        ))
+    }
+
+    fn decompress_and_apply_lookup_table(
+        &self,
+        _compressed_ct: &CompressedModulusSwitchedCiphertext,
+        _lut: &LookupTableOwned,
+    ) -> crate::Result<Ciphertext> {
+        Err(crate::Error::from(
+            "Modulus switch compression is not supported by the NTRU Atomic Pattern",
+        ))
+    }
+}
+
+impl AtomicPatternMut for NtruAtomicPatternServerKey {
+    /// This is a no-op: the NTRU atomic pattern always executes deterministically, see
+    /// [`AtomicPattern::deterministic_execution`]
+    fn set_deterministic_execution(&mut self, _new_deterministic_execution: bool) {}
+}
+
+impl NtruAtomicPatternServerKey {
+    /// Stack requirement of [`Self::keyswitch_blind_rotate`], the NTRU accumulator it writes to
+    /// being allocated separately by the caller
+    fn keyswitch_blind_rotate_requirement(
+        &self,
+        fft: FftView<'_>,
+    ) -> Result<StackReq, SizeOverflow> {
+        let polynomial_size = self.bootstrapping_key.polynomial_size();
+
+        StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?.try_and(
+            StackReq::try_any_of([
+                StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?.try_and(
+                    switch_to_ntru_ciphertext_mem_optimized_requirement::<u64>(
+                        polynomial_size,
+                        fft,
+                    )?,
+                )?,
+                ntru_cmux_blind_rotate_assign_scratch::<u64>(polynomial_size, fft)?,
+            ])?,
+        )
+    }
+
+    /// Keyswitch the input to the small LWE key and blind rotate the lookup table, which is encoded
+    /// modulo the native modulus and rounded to the NTRU modulus, into `ntru_acc`.
+    fn keyswitch_blind_rotate(
+        &self,
+        ct: &Ciphertext,
+        acc: &GlweCiphertextOwned<u64>,
+        ciphertext_buffer: &mut LweCiphertextMutView<'_, u32>,
+        ntru_acc: &mut NtruCiphertextMutView<'_, u64>,
+        fft: FftView<'_>,
+        stack: &mut PodStack,
+    ) {
+        #[cfg(feature = "pbs-stats")]
+        let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+
+        let unused_bits = u64::BITS as usize - self.ntru_ciphertext_modulus.into_modulus_log().0;
+        {
+            let (accumulator, stack) = stack.collect_aligned(
+                CACHELINE_ALIGN,
+                acc.get_body().as_ref().iter().map(|&value| {
+                    if unused_bits == 0 {
+                        value
+                    } else {
+                        value.wrapping_add(1 << (unused_bits - 1)) >> unused_bits
+                    }
+                }),
+            );
+
+            switch_to_ntru_ciphertext_mem_optimized(
+                &self.bootstrapping_key.get_fourier_ntru_switching_key(),
+                &PlaintextList::from_container(&*accumulator),
+                ntru_acc,
+                fft,
+                stack,
+            );
+        }
+
+        keyswitch_lwe_ciphertext_with_scalar_change(
+            &self.key_switching_key,
+            &ct.ct,
+            ciphertext_buffer,
+        );
+
+        let msed = lwe_ciphertext_modulus_switch(
+            ciphertext_buffer.as_view(),
+            self.bootstrapping_key
+                .polynomial_size()
+                .to_blind_rotation_input_modulus_log(),
+        );
+
+        ntru_cmux_blind_rotate_assign(
+            self.bootstrapping_key.as_view(),
+            ntru_acc.as_mut_view(),
+            &msed,
+            fft,
+            stack,
+        );
+    }
+
+    fn intermediate_ciphertext_modulus(&self) -> CoreCiphertextModulus<u32> {
+        self.key_switching_key.ciphertext_modulus()
+    }
+}
@@ -216,7 +216,7 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
         seed: Seed,
         random_bits_count: u64,
         full_bits_count: u64,
-    ) -> (LweCiphertextOwned<u64>, Degree) {
+    ) -> crate::Result<(LweCiphertextOwned<u64>, Degree)> {
         let (ct, degree) = generate_pseudo_random_from_pbs(
             &self.bootstrapping_key,
             seed,
@@ -225,7 +225,7 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
             self.ciphertext_modulus(),
         );
 
-        match self.pbs_order {
+        Ok(match self.pbs_order {
             PBSOrder::KeyswitchBootstrap => (ct, degree),
             PBSOrder::BootstrapKeyswitch => {
                 let mut ct_ksed = LweCiphertext::new(
@@ -238,10 +238,13 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
 
                 (ct_ksed, degree)
             }
-        }
+        })
     }
 
-    fn switch_modulus_and_compress(&self, ct: &Ciphertext) -> CompressedModulusSwitchedCiphertext {
+    fn switch_modulus_and_compress(
+        &self,
+        ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext> {
         let compressed_modulus_switched_lwe_ciphertext =
             ShortintEngine::with_thread_local_mut(|engine| {
                 let (mut ciphertext_buffer, _) = engine
@@ -262,20 +265,20 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
                 switch_modulus_and_compress(input_ct, &self.bootstrapping_key)
             });
 
-        CompressedModulusSwitchedCiphertext {
+        Ok(CompressedModulusSwitchedCiphertext {
             compressed_modulus_switched_lwe_ciphertext,
             degree: ct.degree,
             message_modulus: ct.message_modulus,
             carry_modulus: ct.carry_modulus,
             atomic_pattern: ct.atomic_pattern,
-        }
+        })
     }
 
     fn decompress_and_apply_lookup_table(
         &self,
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         lut: &LookupTableOwned,
-    ) -> Ciphertext {
+    ) -> crate::Result<Ciphertext> {
         let mut output = LweCiphertext::new(
             0,
             self.ciphertext_lwe_dimension().to_lwe_size(),
@@ -314,14 +317,14 @@ impl AtomicPattern for StandardAtomicPatternServerKey {
             }
         });
 
-        Ciphertext::new(
+        Ok(Ciphertext::new(
             output,
             lut.degree,
             NoiseLevel::NOMINAL,
             compressed_ct.message_modulus,
             compressed_ct.carry_modulus,
             compressed_ct.atomic_pattern,
-        )
+        ))
     }
 }
 
@@ -4,7 +4,8 @@ use crate::shortint::atomic_pattern::compressed::ks32::CompressedKS32AtomicPatte
 use crate::shortint::atomic_pattern::compressed::standard::CompressedStandardAtomicPatternServerKey;
 use crate::shortint::atomic_pattern::compressed::CompressedAtomicPatternServerKey;
 use crate::shortint::atomic_pattern::{
-    AtomicPatternServerKey, KS32AtomicPatternServerKey, StandardAtomicPatternServerKey,
+    AtomicPatternServerKey, KS32AtomicPatternServerKey, NtruAtomicPatternServerKey,
+    StandardAtomicPatternServerKey,
 };
 use crate::shortint::{AtomicPatternKind, AtomicPatternParameters};
 
@@ -33,6 +34,11 @@ pub enum KS32AtomicPatternServerKeyVersions {
     V0(KS32AtomicPatternServerKey),
 }
 
+#[derive(VersionsDispatch)]
+pub enum NtruAtomicPatternServerKeyVersions {
+    V0(NtruAtomicPatternServerKey),
+}
+
 #[derive(VersionsDispatch)]
 pub enum CompressedAtomicPatternServerKeyVersions {
     V0(CompressedAtomicPatternServerKey),
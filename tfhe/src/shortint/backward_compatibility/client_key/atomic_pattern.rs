@@ -1,7 +1,8 @@
 use tfhe_versionable::VersionsDispatch;
 
 use crate::shortint::client_key::atomic_pattern::{
-    AtomicPatternClientKey, KS32AtomicPatternClientKey, StandardAtomicPatternClientKey,
+    AtomicPatternClientKey, KS32AtomicPatternClientKey, NtruAtomicPatternClientKey,
+    StandardAtomicPatternClientKey,
 };
 
 #[derive(VersionsDispatch)]
@@ -18,3 +19,8 @@ pub enum StandardAtomicPatternClientKeyVersions {
 pub enum KS32AtomicPatternClientKeyVersions {
     V0(KS32AtomicPatternClientKey),
 }
+
+#[derive(VersionsDispatch)]
+pub enum NtruAtomicPatternClientKeyVersions {
+    V0(NtruAtomicPatternClientKey),
+}
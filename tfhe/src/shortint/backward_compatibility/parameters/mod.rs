@@ -13,7 +13,7 @@ use crate::shortint::parameters::{
     ShortintParameterSetInner, SupportedCompactPkeZkScheme,
 };
 use crate::shortint::*;
-use parameters::KeySwitch32PBSParameters;
+use parameters::{KeySwitch32PBSParameters, NtruPBSParameters};
 use std::convert::Infallible;
 use tfhe_versionable::{Upgrade, Version, VersionsDispatch};
 
@@ -257,6 +257,11 @@ pub enum KeySwitch32PBSParametersVersions {
     V1(KeySwitch32PBSParameters),
 }
 
+#[derive(VersionsDispatch)]
+pub enum NtruPBSParametersVersions {
+    V0(NtruPBSParameters),
+}
+
 #[derive(VersionsDispatch)]
 pub enum ModulusSwitchTypeVersions {
     V0(ModulusSwitchType),
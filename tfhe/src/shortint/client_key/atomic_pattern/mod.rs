@@ -1,4 +1,5 @@
 pub mod ks32;
+pub mod ntru;
 pub mod standard;
 
 use serde::{Deserialize, Serialize};
@@ -12,6 +13,7 @@ use crate::shortint::{AtomicPatternKind, AtomicPatternParameters, ShortintParame
 use super::{LweSecretKeyOwned, LweSecretKeyView};
 
 pub use ks32::*;
+pub use ntru::*;
 pub use standard::*;
 
 /// An atomic pattern used for encryption
@@ -65,6 +67,7 @@ impl<T: EncryptionAtomicPattern> EncryptionAtomicPattern for &T {
 pub enum AtomicPatternClientKey {
     Standard(StandardAtomicPatternClientKey),
     KeySwitch32(KS32AtomicPatternClientKey),
+    Ntru(NtruAtomicPatternClientKey),
 }
 
 impl AtomicPatternClientKey {
@@ -79,6 +82,9 @@ impl AtomicPatternClientKey {
             AtomicPatternParameters::KeySwitch32(ap_params) => Self::KeySwitch32(
                 KS32AtomicPatternClientKey::new_with_engine(ap_params, engine),
             ),
+            AtomicPatternParameters::Ntru(ap_params) => Self::Ntru(
+                NtruAtomicPatternClientKey::new_with_engine(ap_params, engine),
+            ),
         }
     }
 
@@ -100,6 +106,12 @@ impl AtomicPatternClientKey {
             AtomicPatternParameters::KeySwitch32(ap_params) => Ok(Self::KeySwitch32(
                 KS32AtomicPatternClientKey::try_from_lwe_encryption_key(encryption_key, ap_params)?,
             )),
+            // The NTRU secret key also holds the inverse of f, and the NTRU atomic pattern
+            // requires f to be sampled from the NTRU noise distribution
+            AtomicPatternParameters::Ntru(_) => Err(crate::Error::new(
+                "The NTRU atomic pattern client key cannot be built from an LWE encryption key"
+                    .to_string(),
+            )),
         }
     }
 }
@@ -109,6 +121,7 @@ impl EncryptionAtomicPattern for AtomicPatternClientKey {
         match self {
             Self::Standard(ap) => ap.parameters.into(),
             Self::KeySwitch32(ap) => ap.parameters.into(),
+            Self::Ntru(ap) => ap.parameters.into(),
         }
     }
 
@@ -116,6 +129,7 @@ impl EncryptionAtomicPattern for AtomicPatternClientKey {
         match self {
             Self::Standard(ap) => ap.encryption_key(),
             Self::KeySwitch32(ap) => ap.encryption_key(),
+            Self::Ntru(ap) => ap.encryption_key(),
         }
     }
 
@@ -123,6 +137,7 @@ impl EncryptionAtomicPattern for AtomicPatternClientKey {
         match self {
             Self::Standard(ap) => ap.encryption_noise(),
             Self::KeySwitch32(ap) => ap.encryption_noise(),
+            Self::Ntru(ap) => ap.encryption_noise(),
         }
     }
 
@@ -130,6 +145,7 @@ impl EncryptionAtomicPattern for AtomicPatternClientKey {
         match self {
             Self::Standard(ap_cks) => ap_cks.kind(),
             Self::KeySwitch32(ap_cks) => ap_cks.kind(),
+            Self::Ntru(ap_cks) => ap_cks.kind(),
         }
     }
 }
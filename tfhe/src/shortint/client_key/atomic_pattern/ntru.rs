@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+use crate::core_crypto::prelude::allocate_and_generate_new_binary_lwe_secret_key;
+use crate::ntru::algorithms::allocate_and_generate_new_gaussian_ntru_secret_key;
+use crate::ntru::entities::NtruSecretKeyOwned;
+use crate::shortint::backward_compatibility::client_key::atomic_pattern::NtruAtomicPatternClientKeyVersions;
+use crate::shortint::client_key::{GenericClientKey, LweSecretKeyOwned, LweSecretKeyView};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{DynamicDistribution, NtruPBSParameters};
+use crate::shortint::{AtomicPatternKind, ShortintParameterSet};
+
+use super::EncryptionAtomicPattern;
+
+/// The client key materials of the
+/// [`NtruAtomicPatternServerKey`](crate::shortint::atomic_pattern::NtruAtomicPatternServerKey)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Versionize)]
+#[versionize(NtruAtomicPatternClientKeyVersions)]
+pub struct NtruAtomicPatternClientKey {
+    pub(crate) ntru_secret_key: NtruSecretKeyOwned<u64>,
+    /// The coefficients of f, sign extended to the native modulus, used for encryption
+    pub(crate) large_lwe_secret_key: LweSecretKeyOwned<u64>,
+    /// Key used as the output of the keyswitch operation
+    pub(crate) lwe_secret_key: LweSecretKeyOwned<u32>,
+    pub parameters: NtruPBSParameters,
+}
+
+pub type NtruClientKey = GenericClientKey<NtruAtomicPatternClientKey>;
+
+impl NtruClientKey {
+    pub fn new(parameters: NtruPBSParameters) -> Self {
+        Self {
+            atomic_pattern: NtruAtomicPatternClientKey::new(parameters),
+        }
+    }
+}
+
+impl NtruAtomicPatternClientKey {
+    pub(crate) fn new_with_engine(
+        parameters: NtruPBSParameters,
+        engine: &mut ShortintEngine,
+    ) -> Self {
+        assert!(
+            parameters
+                .ntru_ciphertext_modulus()
+                .is_compatible_with_native_modulus(),
+            "The NTRU atomic pattern only supports power-of-two NTRU moduli"
+        );
+
+        // generate the lwe secret key
+        let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            parameters.lwe_dimension(),
+            &mut engine.secret_generator,
+        );
+
+        // generate the ntru secret key
+        let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+            parameters.polynomial_size(),
+            parameters.ntru_ciphertext_modulus(),
+            parameters.ntru_noise_distribution(),
+            &mut engine.encryption_generator,
+        );
+
+        Self::from_raw_parts(ntru_secret_key, lwe_secret_key, parameters)
+    }
+
+    pub fn new(parameters: NtruPBSParameters) -> Self {
+        ShortintEngine::with_thread_local_mut(|engine| Self::new_with_engine(parameters, engine))
+    }
+
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        NtruSecretKeyOwned<u64>,
+        LweSecretKeyOwned<u32>,
+        NtruPBSParameters,
+    ) {
+        let Self {
+            ntru_secret_key,
+            large_lwe_secret_key: _,
+            lwe_secret_key,
+            parameters,
+        } = self;
+
+        (ntru_secret_key, lwe_secret_key, parameters)
+    }
+
+    pub fn from_raw_parts(
+        ntru_secret_key: NtruSecretKeyOwned<u64>,
+        lwe_secret_key: LweSecretKeyOwned<u32>,
+        parameters: NtruPBSParameters,
+    ) -> Self {
+        assert_eq!(
+            lwe_secret_key.lwe_dimension(),
+            parameters.lwe_dimension(),
+            "Mismatch between the LweSecretKey LweDimension ({:?}) \
+            and the parameters LweDimension ({:?})",
+            lwe_secret_key.lwe_dimension(),
+            parameters.lwe_dimension()
+        );
+        assert_eq!(
+            ntru_secret_key.polynomial_size(),
+            parameters.polynomial_size(),
+            "Mismatch between the NtruSecretKey PolynomialSize ({:?}) \
+            and the parameters PolynomialSize ({:?})",
+            ntru_secret_key.polynomial_size(),
+            parameters.polynomial_size()
+        );
+        assert_eq!(
+            ntru_secret_key.ciphertext_modulus(),
+            parameters.ntru_ciphertext_modulus(),
+            "Mismatch between the NtruSecretKey CiphertextModulus ({:?}) \
+            and the parameters NTRU CiphertextModulus ({:?})",
+            ntru_secret_key.ciphertext_modulus(),
+            parameters.ntru_ciphertext_modulus()
+        );
+
+        // The coefficients of f are stored modulo the NTRU modulus, they are sign extended so that
+        // the key can also decrypt ciphertexts modulo the native modulus
+        let unused_bits =
+            u64::BITS as usize - parameters.ntru_ciphertext_modulus().into_modulus_log().0;
+        let large_lwe_secret_key = LweSecretKeyOwned::from_container(
+            ntru_secret_key
+                .get_secret_key_polynomial()
+                .as_ref()
+                .iter()
+                .map(|&coeff| (((coeff << unused_bits) as i64) >> unused_bits) as u64)
+                .collect(),
+        );
+
+        Self {
+            ntru_secret_key,
+            large_lwe_secret_key,
+            lwe_secret_key,
+            parameters,
+        }
+    }
+
+    pub fn ntru_secret_key(&self) -> &NtruSecretKeyOwned<u64> {
+        &self.ntru_secret_key
+    }
+
+    pub fn large_lwe_secret_key(&self) -> LweSecretKeyView<'_, u64> {
+        self.large_lwe_secret_key.as_view()
+    }
+
+    pub fn small_lwe_secret_key(&self) -> LweSecretKeyView<'_, u32> {
+        self.lwe_secret_key.as_view()
+    }
+}
+
+impl EncryptionAtomicPattern for NtruAtomicPatternClientKey {
+    fn parameters(&self) -> ShortintParameterSet {
+        self.parameters.into()
+    }
+
+    fn encryption_key(&self) -> LweSecretKeyView<'_, u64> {
+        // The NTRU atomic pattern is only supported with the KsPbs order
+        self.large_lwe_secret_key.as_view()
+    }
+
+    fn encryption_noise(&self) -> DynamicDistribution<u64> {
+        // The NTRU atomic pattern is only supported with the KsPbs order
+        self.parameters.ntru_noise_distribution()
+    }
+
+    fn kind(&self) -> AtomicPatternKind {
+        AtomicPatternKind::Ntru
+    }
+}
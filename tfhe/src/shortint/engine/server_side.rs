@@ -298,7 +298,10 @@ impl ShortintEngine {
         )
     }
 
-    pub(crate) fn new_compressed_server_key(&mut self, cks: &ClientKey) -> CompressedServerKey {
+    pub(crate) fn new_compressed_server_key(
+        &mut self,
+        cks: &ClientKey,
+    ) -> crate::Result<CompressedServerKey> {
         // Plaintext Max Value
         let max_value =
             cks.parameters().message_modulus().0 * cks.parameters().carry_modulus().0 - 1;
@@ -312,8 +315,8 @@ impl ShortintEngine {
         &mut self,
         cks: &ClientKey,
         max_degree: MaxDegree,
-    ) -> CompressedServerKey {
-        let compressed_ap_server_key = CompressedAtomicPatternServerKey::new(cks, self);
+    ) -> crate::Result<CompressedServerKey> {
+        let compressed_ap_server_key = CompressedAtomicPatternServerKey::try_new(cks, self)?;
 
         let params = cks.parameters();
         let message_modulus = params.message_modulus();
@@ -321,13 +324,13 @@ impl ShortintEngine {
         let max_noise_level = params.max_noise_level();
 
         // Pack the keys in the server key set:
-        CompressedServerKey {
+        Ok(CompressedServerKey {
             compressed_ap_server_key,
             message_modulus,
             carry_modulus,
             max_degree,
             max_noise_level,
-        }
+        })
     }
 
     pub fn new_compressed_bootstrapping_key_ks32<
@@ -5,6 +5,7 @@ use crate::keycache::*;
 #[cfg(tarpaulin)]
 use crate::shortint::parameters::coverage_parameters::*;
 use crate::shortint::parameters::current_params::*;
+use crate::shortint::parameters::ntru::PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN;
 use crate::shortint::parameters::parameters_wopbs::*;
 use crate::shortint::parameters::*;
 use crate::shortint::wopbs::WopbsKey;
@@ -319,6 +320,8 @@ named_params_impl!( ShortintParameterSet =>
     V1_3_PARAM_MESSAGE_4_CARRY_4_KS_PBS_TUNIFORM_2M128,
     // KS32 AP with TUniform
     V1_3_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128,
+    // NTRU AP
+    PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN,
     // Wopbs
     LEGACY_WOPBS_PARAM_MESSAGE_1_CARRY_0_KS_PBS,
     LEGACY_WOPBS_PARAM_MESSAGE_1_CARRY_1_KS_PBS,
@@ -452,6 +455,12 @@ impl NamedParam for KeySwitch32PBSParameters {
     }
 }
 
+impl NamedParam for NtruPBSParameters {
+    fn name(&self) -> String {
+        ShortintParameterSet::from(*self).name()
+    }
+}
+
 named_params_impl!(ShortintKeySwitchingParameters =>
     V1_3_PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS_GAUSSIAN_2M128,
     ; fallback => ks_params_default_name
@@ -404,6 +404,9 @@ impl TryFrom<(AtomicPatternParameters, NoiseSquashingParameters)>
             AtomicPatternParameters::KeySwitch32(_) => Err(crate::Error::from(
                 "Noise squashing is not supported by the KS32 Atomic Pattern",
             )),
+            AtomicPatternParameters::Ntru(_) => Err(crate::Error::from(
+                "Noise squashing is not supported by the NTRU Atomic Pattern",
+            )),
         }
     }
 }
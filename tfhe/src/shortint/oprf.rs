@@ -262,11 +262,31 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
     /// Uniformly generates a random encrypted value in `[0, 2^random_bits_count[`
     /// `2^random_bits_count` must be smaller than the message modulus
     /// The encryted value is oblivious to the server
+    ///
+    /// # Panics
+    ///
+    /// Panics if the atomic pattern of the key does not support the oblivious pseudo random
+    /// generation, see [`Self::checked_generate_oblivious_pseudo_random`]
     pub fn generate_oblivious_pseudo_random(
         &self,
         seed: Seed,
         random_bits_count: u64,
     ) -> Ciphertext {
+        self.checked_generate_oblivious_pseudo_random(seed, random_bits_count)
+            .unwrap()
+    }
+
+    /// Uniformly generates a random encrypted value in `[0, 2^random_bits_count[`
+    /// `2^random_bits_count` must be smaller than the message modulus
+    /// The encryted value is oblivious to the server
+    ///
+    /// Returns an error if the atomic pattern of the key does not support the oblivious pseudo
+    /// random generation
+    pub fn checked_generate_oblivious_pseudo_random(
+        &self,
+        seed: Seed,
+        random_bits_count: u64,
+    ) -> crate::Result<Ciphertext> {
         assert!(
             random_bits_count < 64,
             "random_bits_count >= 64 is not supported",
@@ -277,16 +297,16 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
             random_bits_count, self.message_modulus.0
         );
 
-        self.generate_oblivious_pseudo_random_message_and_carry(seed, random_bits_count)
+        self.checked_generate_oblivious_pseudo_random_message_and_carry(seed, random_bits_count)
     }
 
     /// Uniformly generates a random value in `[0, 2^random_bits_count[`
     /// The encryted value is oblivious to the server
-    pub(crate) fn generate_oblivious_pseudo_random_message_and_carry(
+    pub(crate) fn checked_generate_oblivious_pseudo_random_message_and_carry(
         &self,
         seed: Seed,
         random_bits_count: u64,
-    ) -> Ciphertext {
+    ) -> crate::Result<Ciphertext> {
         assert!(
             self.message_modulus.0.is_power_of_two(),
             "The message modulus(={}), must be a power of 2 to use the OPRF",
@@ -310,16 +330,16 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
             seed,
             random_bits_count,
             1 + carry_bits_count + message_bits_count,
-        );
+        )?;
 
-        Ciphertext::new(
+        Ok(Ciphertext::new(
             ct,
             degree,
             NoiseLevel::NOMINAL,
             self.message_modulus,
             self.carry_modulus,
             self.atomic_pattern.kind(),
-        )
+        ))
     }
 }
 
@@ -379,6 +399,9 @@ pub(crate) mod test {
             AtomicPatternClientKey::KeySwitch32(ap_ck) => {
                 gen_prf_input(&ap_ck.small_lwe_secret_key(), seed, params)
             }
+            AtomicPatternClientKey::Ntru(ap_ck) => {
+                gen_prf_input(&ap_ck.small_lwe_secret_key(), seed, params)
+            }
         };
 
         let half_negacyclic_part = |x| 2 * (x / poly_delta) + 1;
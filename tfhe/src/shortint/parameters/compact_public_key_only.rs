@@ -38,6 +38,7 @@ impl From<AtomicPatternKind> for CompactCiphertextListExpansionKind {
         match value {
             AtomicPatternKind::Standard(pbsorder) => Self::NoCasting(pbsorder),
             AtomicPatternKind::KeySwitch32 => Self::NoCasting(PBSOrder::KeyswitchBootstrap),
+            AtomicPatternKind::Ntru => Self::NoCasting(PBSOrder::KeyswitchBootstrap),
         }
     }
 }
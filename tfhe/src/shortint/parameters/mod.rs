@@ -45,6 +45,7 @@ pub mod ks32;
 pub mod list_compression;
 pub mod multi_bit;
 pub mod noise_squashing;
+pub mod ntru;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub mod parameters_wopbs_only;
@@ -79,6 +80,7 @@ pub use key_switching::ShortintKeySwitchingParameters;
 pub use ks32::KeySwitch32PBSParameters;
 pub use multi_bit::MultiBitPBSParameters;
 pub use noise_squashing::{NoiseSquashingCompressionParameters, NoiseSquashingParameters};
+pub use ntru::NtruPBSParameters;
 pub use parameters_wopbs::*;
 #[cfg(test)]
 pub use test_params::TestParameters;
@@ -357,6 +359,7 @@ pub(crate) enum ShortintParameterSetInner {
     WopbsOnly(WopbsParameters),
     PBSAndWopbs(PBSParameters, WopbsParameters),
     KS32PBS(KeySwitch32PBSParameters),
+    NtruPBS(NtruPBSParameters),
 }
 
 impl ShortintParameterSetInner {
@@ -398,6 +401,12 @@ impl ShortintParameterSet {
         }
     }
 
+    pub const fn new_ntru_pbs_param_set(params: NtruPBSParameters) -> Self {
+        Self {
+            inner: ShortintParameterSetInner::NtruPBS(params),
+        }
+    }
+
     pub fn try_new_pbs_and_wopbs_param_set<P>(
         (pbs_params, wopbs_params): (P, WopbsParameters),
     ) -> Result<Self, &'static str>
@@ -433,6 +442,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::KS32PBS(params) => {
                 Some(AtomicPatternParameters::KeySwitch32(params))
             }
+            ShortintParameterSetInner::NtruPBS(params) => Some(AtomicPatternParameters::Ntru(params)),
         }
     }
 
@@ -442,6 +452,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(_) => None,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => Some(params),
             ShortintParameterSetInner::KS32PBS(_) => None,
+            ShortintParameterSetInner::NtruPBS(_) => None,
         }
     }
 
@@ -451,6 +462,17 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(_) => None,
             ShortintParameterSetInner::PBSAndWopbs(_, _) => None,
             ShortintParameterSetInner::KS32PBS(params) => Some(params),
+            ShortintParameterSetInner::NtruPBS(_) => None,
+        }
+    }
+
+    pub const fn ntru_parameters(&self) -> Option<NtruPBSParameters> {
+        match self.inner {
+            ShortintParameterSetInner::PBSOnly(_) => None,
+            ShortintParameterSetInner::WopbsOnly(_) => None,
+            ShortintParameterSetInner::PBSAndWopbs(_, _) => None,
+            ShortintParameterSetInner::KS32PBS(_) => None,
+            ShortintParameterSetInner::NtruPBS(params) => Some(params),
         }
     }
 
@@ -460,6 +482,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => Some(params),
             ShortintParameterSetInner::PBSAndWopbs(_, params) => Some(params),
             ShortintParameterSetInner::KS32PBS(_) => None,
+            ShortintParameterSetInner::NtruPBS(_) => None,
         }
     }
 
@@ -469,6 +492,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.lwe_dimension,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.lwe_dimension(),
             ShortintParameterSetInner::KS32PBS(params) => params.lwe_dimension(),
+            ShortintParameterSetInner::NtruPBS(params) => params.lwe_dimension(),
         }
     }
 
@@ -478,6 +502,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.glwe_dimension,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_dimension(),
             ShortintParameterSetInner::KS32PBS(params) => params.glwe_dimension(),
+            ShortintParameterSetInner::NtruPBS(params) => params.glwe_dimension(),
         }
     }
 
@@ -487,6 +512,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.polynomial_size,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.polynomial_size(),
             ShortintParameterSetInner::KS32PBS(params) => params.polynomial_size(),
+            ShortintParameterSetInner::NtruPBS(params) => params.polynomial_size(),
         }
     }
 
@@ -498,6 +524,9 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::KS32PBS(params) => {
                 params.lwe_noise_distribution().to_u64_distribution()
             }
+            ShortintParameterSetInner::NtruPBS(params) => {
+                params.lwe_noise_distribution().to_u64_distribution()
+            }
         }
     }
 
@@ -507,6 +536,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.glwe_noise_distribution,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_noise_distribution(),
             ShortintParameterSetInner::KS32PBS(params) => params.glwe_noise_distribution(),
+            ShortintParameterSetInner::NtruPBS(params) => params.glwe_noise_distribution(),
         }
     }
 
@@ -516,6 +546,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.pbs_base_log,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.pbs_base_log(),
             ShortintParameterSetInner::KS32PBS(params) => params.pbs_base_log(),
+            ShortintParameterSetInner::NtruPBS(params) => params.pbs_base_log(),
         }
     }
 
@@ -525,6 +556,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.pbs_level,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.pbs_level(),
             ShortintParameterSetInner::KS32PBS(params) => params.pbs_level(),
+            ShortintParameterSetInner::NtruPBS(params) => params.pbs_level(),
         }
     }
 
@@ -534,6 +566,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.ks_base_log,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.ks_base_log(),
             ShortintParameterSetInner::KS32PBS(params) => params.ks_base_log(),
+            ShortintParameterSetInner::NtruPBS(params) => params.ks_base_log(),
         }
     }
 
@@ -543,6 +576,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.ks_level,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.ks_level(),
             ShortintParameterSetInner::KS32PBS(params) => params.ks_level(),
+            ShortintParameterSetInner::NtruPBS(params) => params.ks_level(),
         }
     }
 
@@ -552,6 +586,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.message_modulus,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.message_modulus(),
             ShortintParameterSetInner::KS32PBS(params) => params.message_modulus(),
+            ShortintParameterSetInner::NtruPBS(params) => params.message_modulus(),
         }
     }
 
@@ -561,6 +596,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.carry_modulus,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.carry_modulus(),
             ShortintParameterSetInner::KS32PBS(params) => params.carry_modulus(),
+            ShortintParameterSetInner::NtruPBS(params) => params.carry_modulus(),
         }
     }
 
@@ -572,6 +608,7 @@ impl ShortintParameterSet {
             }
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.max_noise_level(),
             ShortintParameterSetInner::KS32PBS(params) => params.max_noise_level(),
+            ShortintParameterSetInner::NtruPBS(params) => params.max_noise_level(),
         }
     }
 
@@ -581,6 +618,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.ciphertext_modulus,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.ciphertext_modulus(),
             ShortintParameterSetInner::KS32PBS(params) => params.ciphertext_modulus(),
+            ShortintParameterSetInner::NtruPBS(params) => params.ciphertext_modulus(),
         }
     }
 
@@ -596,6 +634,7 @@ impl ShortintParameterSet {
                 AtomicPatternKind::Standard(params.encryption_key_choice().into_pbs_order())
             }
             ShortintParameterSetInner::KS32PBS(_params) => AtomicPatternKind::KeySwitch32,
+            ShortintParameterSetInner::NtruPBS(_params) => AtomicPatternKind::Ntru,
         }
     }
 
@@ -605,6 +644,7 @@ impl ShortintParameterSet {
             ShortintParameterSetInner::WopbsOnly(params) => params.encryption_key_choice,
             ShortintParameterSetInner::PBSAndWopbs(params, _) => params.encryption_key_choice(),
             ShortintParameterSetInner::KS32PBS(params) => params.encryption_key_choice(),
+            ShortintParameterSetInner::NtruPBS(params) => params.encryption_key_choice(),
         }
     }
 
@@ -648,6 +688,7 @@ where
             AtomicPatternParameters::KeySwitch32(parameters) => {
                 Self::new_ks32_pbs_param_set(parameters)
             }
+            AtomicPatternParameters::Ntru(parameters) => Self::new_ntru_pbs_param_set(parameters),
         }
     }
 }
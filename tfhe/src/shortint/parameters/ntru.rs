@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use tfhe_versionable::Versionize;
+
+pub use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, LutCountLog};
+use crate::core_crypto::prelude::{LweKeyswitchKeyConformanceParams, UnsignedInteger};
+use crate::ntru::entities::FftType;
+use crate::ntru::noise_formulas::{
+    gaussian_log2_failure_probability, ntru_cmux_blind_rotation_variance,
+    ntru_cmux_modulus_switch_variance, ntru_to_lwe_keyswitch_additive_variance,
+};
+use crate::shortint::backward_compatibility::parameters::NtruPBSParametersVersions;
+use crate::shortint::parameters::StandardDev;
+
+use super::{CarryModulus, CiphertextModulus, CiphertextModulus32, MaxNoiseLevel, MessageModulus};
+
+/// A set of cryptographic parameters used with the
+/// [`NtruAtomicPatternServerKey`](crate::shortint::atomic_pattern::NtruAtomicPatternServerKey)
+///
+/// Ciphertexts are encrypted under the large LWE key given by the coefficients of the NTRU secret
+/// key f. They are keyswitched to a small u32 LWE key as in the
+/// [`KeySwitch32`](crate::shortint::atomic_pattern::AtomicPatternKind::KeySwitch32) atomic
+/// pattern, and then bootstrapped with the NTRU CMux bootstrapping modulo
+/// `ntru_ciphertext_modulus`.
+///
+/// The failure probability is not stored in the parameters but computed from the NTRU noise
+/// formulas, see [`NtruPBSParameters::log2_p_fail`].
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq, Versionize)]
+#[versionize(NtruPBSParametersVersions)]
+pub struct NtruPBSParameters {
+    pub lwe_dimension: LweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub lwe_noise_distribution: DynamicDistribution<u32>,
+    pub ntru_noise_distribution: DynamicDistribution<u64>,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub swk_base_log: DecompositionBaseLog,
+    pub swk_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub max_noise_level: MaxNoiseLevel,
+    pub post_keyswitch_ciphertext_modulus: CiphertextModulus32,
+    pub ntru_ciphertext_modulus: CiphertextModulus,
+    pub ciphertext_modulus: CiphertextModulus,
+}
+
+impl From<&NtruPBSParameters> for LweKeyswitchKeyConformanceParams<u32> {
+    fn from(value: &NtruPBSParameters) -> Self {
+        Self {
+            decomp_base_log: value.ks_base_log(),
+            decomp_level_count: value.ks_level(),
+            output_lwe_size: value.lwe_dimension().to_lwe_size(),
+            input_lwe_dimension: value.encryption_lwe_dimension(),
+            ciphertext_modulus: value.post_keyswitch_ciphertext_modulus(),
+        }
+    }
+}
+
+impl NtruPBSParameters {
+    pub const fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    pub const fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub const fn lwe_noise_distribution(&self) -> DynamicDistribution<u32> {
+        self.lwe_noise_distribution
+    }
+
+    pub const fn ntru_noise_distribution(&self) -> DynamicDistribution<u64> {
+        self.ntru_noise_distribution
+    }
+
+    /// The large LWE key has the same dimension as a GLWE key with a single polynomial
+    pub const fn glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(1)
+    }
+
+    /// The noise of the ciphertexts encrypted under the large LWE key
+    pub const fn glwe_noise_distribution(&self) -> DynamicDistribution<u64> {
+        self.ntru_noise_distribution
+    }
+
+    pub const fn pbs_base_log(&self) -> DecompositionBaseLog {
+        self.pbs_base_log
+    }
+
+    pub const fn pbs_level(&self) -> DecompositionLevelCount {
+        self.pbs_level
+    }
+
+    pub const fn swk_base_log(&self) -> DecompositionBaseLog {
+        self.swk_base_log
+    }
+
+    pub const fn swk_level(&self) -> DecompositionLevelCount {
+        self.swk_level
+    }
+
+    pub const fn ks_base_log(&self) -> DecompositionBaseLog {
+        self.ks_base_log
+    }
+
+    pub const fn ks_level(&self) -> DecompositionLevelCount {
+        self.ks_level
+    }
+
+    pub const fn message_modulus(&self) -> MessageModulus {
+        self.message_modulus
+    }
+
+    pub const fn carry_modulus(&self) -> CarryModulus {
+        self.carry_modulus
+    }
+
+    pub const fn max_noise_level(&self) -> MaxNoiseLevel {
+        self.max_noise_level
+    }
+
+    pub const fn post_keyswitch_ciphertext_modulus(&self) -> CiphertextModulus32 {
+        self.post_keyswitch_ciphertext_modulus
+    }
+
+    pub const fn ntru_ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ntru_ciphertext_modulus
+    }
+
+    pub const fn ciphertext_modulus(&self) -> CiphertextModulus {
+        self.ciphertext_modulus
+    }
+
+    pub const fn encryption_key_choice(&self) -> EncryptionKeyChoice {
+        // The NTRU atomic pattern is only supported with the KsPbs order
+        EncryptionKeyChoice::Big
+    }
+
+    pub const fn encryption_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.polynomial_size.0)
+    }
+
+    /// Log2 of the probability that a bootstrapping outputs a wrong message, when its input is
+    /// the sum of max_noise_level fresh bootstrapping outputs.
+    ///
+    /// It accounts for the noise of the NTRU CMux blind rotation (with the
+    /// [`Vanilla`](FftType::Vanilla) FFT used by the server key), of the keyswitch from the NTRU
+    /// secret key to the small LWE key and of the modulus switch before the blind rotation.
+    pub fn log2_p_fail(&self) -> f64 {
+        let polynomial_size = self.polynomial_size;
+        let log_ntru_modulus = self.ntru_ciphertext_modulus.into_modulus_log();
+        let log_ciphertext_modulus = self.ciphertext_modulus.into_modulus_log();
+        let log_post_keyswitch_modulus = self.post_keyswitch_ciphertext_modulus.into_modulus_log();
+
+        let ntru_noise_variance = torus_variance(self.ntru_noise_distribution, log_ntru_modulus);
+        // The NTRU secret key f is sampled from the NTRU noise distribution
+        let ntru_secret_key_square_mean =
+            ntru_noise_variance.0 * 2.0f64.powi(2 * log_ntru_modulus.0 as i32);
+
+        let blind_rotation_variance = ntru_cmux_blind_rotation_variance(
+            self.lwe_dimension,
+            polynomial_size,
+            self.pbs_base_log,
+            self.pbs_level,
+            self.swk_base_log,
+            self.swk_level,
+            log_ntru_modulus,
+            ntru_noise_variance,
+            ntru_secret_key_square_mean,
+            FftType::Vanilla,
+            FftType::Vanilla,
+        );
+
+        let keyswitch_variance = ntru_to_lwe_keyswitch_additive_variance(
+            polynomial_size,
+            self.ks_base_log,
+            self.ks_level,
+            log_ciphertext_modulus,
+            torus_variance(self.lwe_noise_distribution, log_post_keyswitch_modulus),
+            ntru_secret_key_square_mean,
+        );
+
+        let modulus_switch_variance = ntru_cmux_modulus_switch_variance(
+            self.lwe_dimension,
+            log_post_keyswitch_modulus,
+            polynomial_size,
+            LutCountLog(0),
+        );
+
+        let nu = self.max_noise_level.get() as f64;
+        let total_variance = Variance(
+            nu * nu * blind_rotation_variance.0 + keyswitch_variance.0 + modulus_switch_variance.0,
+        );
+
+        // The message and carry are encoded with a padding bit, a wrong message is output once
+        // the noise exceeds half of the encoding step
+        let bound = 1.0 / (4 * self.message_modulus.0 * self.carry_modulus.0) as f64;
+
+        gaussian_log2_failure_probability(total_variance, bound)
+    }
+}
+
+/// Variance on the torus of a noise distribution used modulo 2^log_modulus.
+fn torus_variance<T: UnsignedInteger>(
+    distribution: DynamicDistribution<T>,
+    log_modulus: CiphertextModulusLog,
+) -> Variance {
+    match distribution {
+        DynamicDistribution::Gaussian(_) => distribution.gaussian_variance(),
+        DynamicDistribution::TUniform(tuniform) => {
+            tuniform.variance(2.0f64.powi(log_modulus.0 as i32))
+        }
+    }
+}
+
+/// 2 bits of message and 2 bits of carry with the NTRU CMux bootstrapping.
+///
+/// The small LWE key is the one of the KS32 parameters for the same message space, and the NTRU
+/// parameters are the ones of [`NTRU_CMUX_STD128B2`](crate::ntru::entities::NTRU_CMUX_STD128B2).
+/// As the input key of the keyswitch is Gaussian instead of binary, the keyswitch decomposition
+/// keeps 20 bits.
+pub const PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN: NtruPBSParameters = NtruPBSParameters {
+    lwe_dimension: LweDimension(918),
+    polynomial_size: PolynomialSize(2048),
+    lwe_noise_distribution: DynamicDistribution::new_t_uniform(13),
+    // 23 / 2^45
+    ntru_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        6.536993168992922e-13,
+    )),
+    pbs_base_log: DecompositionBaseLog(13),
+    pbs_level: DecompositionLevelCount(2),
+    swk_base_log: DecompositionBaseLog(13),
+    swk_level: DecompositionLevelCount(2),
+    ks_base_log: DecompositionBaseLog(4),
+    ks_level: DecompositionLevelCount(5),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(4),
+    max_noise_level: MaxNoiseLevel::new(5),
+    post_keyswitch_ciphertext_modulus: CiphertextModulus32::new_native(),
+    ntru_ciphertext_modulus: CiphertextModulus::new(1 << 45),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+};
@@ -1,7 +1,8 @@
 use super::current_params::*;
+use super::ntru::PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN;
 use super::{
     AtomicPatternParameters, KeySwitch32PBSParameters, NoiseSquashingCompressionParameters,
-    NoiseSquashingParameters,
+    NoiseSquashingParameters, NtruPBSParameters,
 };
 
 use super::{
@@ -225,6 +226,10 @@ pub const TEST_COMP_PARAM_GPU_MULTI_BIT_GROUP_4_MESSAGE_2_CARRY_2_KS_PBS_TUNIFOR
 pub const TEST_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128: KeySwitch32PBSParameters =
     V1_3_PARAM_MESSAGE_2_CARRY_2_KS32_PBS_TUNIFORM_2M128;
 
+// NTRU PBS AP
+pub const TEST_PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN: NtruPBSParameters =
+    PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN;
+
 pub const TEST_PARAM_NOISE_SQUASHING_COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128:
     NoiseSquashingCompressionParameters =
     V1_3_NOISE_SQUASHING_COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M128;
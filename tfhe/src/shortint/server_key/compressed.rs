@@ -236,7 +236,20 @@ impl CompressedServerKey {
     ///
     /// let sks = CompressedServerKey::new(&cks);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the atomic pattern of the client key does not support compressed server keys,
+    /// see [`Self::try_new`]
     pub fn new(client_key: &ClientKey) -> Self {
+        Self::try_new(client_key).unwrap()
+    }
+
+    /// Generate a compressed server key.
+    ///
+    /// Returns an error if the atomic pattern of the client key does not support compressed
+    /// server keys, which is the case of the NTRU atomic pattern.
+    pub fn try_new(client_key: &ClientKey) -> crate::Result<Self> {
         ShortintEngine::with_thread_local_mut(|engine| engine.new_compressed_server_key(client_key))
     }
 
@@ -323,7 +336,23 @@ impl CompressedServerKey {
     }
 
     /// Generate a compressed server key with a chosen maximum degree
+    ///
+    /// # Panics
+    ///
+    /// Panics if the atomic pattern of the client key does not support compressed server keys,
+    /// see [`Self::try_new_with_max_degree`]
     pub fn new_with_max_degree(cks: &ClientKey, max_degree: MaxDegree) -> Self {
+        Self::try_new_with_max_degree(cks, max_degree).unwrap()
+    }
+
+    /// Generate a compressed server key with a chosen maximum degree
+    ///
+    /// Returns an error if the atomic pattern of the client key does not support compressed
+    /// server keys.
+    pub fn try_new_with_max_degree(
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+    ) -> crate::Result<Self> {
         ShortintEngine::with_thread_local_mut(|engine| {
             engine.new_compressed_server_key_with_max_degree(cks, max_degree)
         })
@@ -119,10 +119,26 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
     /// Compresses a ciphertext to have a smaller serialization size
     ///
     /// See [`CompressedModulusSwitchedCiphertext#example`] for usage
+    ///
+    /// # Panics
+    ///
+    /// Panics if the atomic pattern of the key does not support the modulus switch compression,
+    /// see [`Self::checked_switch_modulus_and_compress`]
     pub fn switch_modulus_and_compress(
         &self,
         ct: &Ciphertext,
     ) -> CompressedModulusSwitchedCiphertext {
+        self.checked_switch_modulus_and_compress(ct).unwrap()
+    }
+
+    /// Compresses a ciphertext to have a smaller serialization size
+    ///
+    /// Returns an error if the atomic pattern of the key does not support the modulus switch
+    /// compression
+    pub fn checked_switch_modulus_and_compress(
+        &self,
+        ct: &Ciphertext,
+    ) -> crate::Result<CompressedModulusSwitchedCiphertext> {
         self.atomic_pattern.switch_modulus_and_compress(ct)
     }
 
@@ -172,11 +188,29 @@ impl<AP: AtomicPattern> GenericServerKey<AP> {
     ///
     /// assert_eq!(clear + 1, dec);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the atomic pattern of the key does not support the modulus switch compression,
+    /// see [`Self::checked_decompress_and_apply_lookup_table`]
     pub fn decompress_and_apply_lookup_table(
         &self,
         compressed_ct: &CompressedModulusSwitchedCiphertext,
         acc: &LookupTableOwned,
     ) -> Ciphertext {
+        self.checked_decompress_and_apply_lookup_table(compressed_ct, acc)
+            .unwrap()
+    }
+
+    /// Decompresses a compressed ciphertext and applies a lookup table
+    ///
+    /// Returns an error if the atomic pattern of the key does not support the modulus switch
+    /// compression
+    pub fn checked_decompress_and_apply_lookup_table(
+        &self,
+        compressed_ct: &CompressedModulusSwitchedCiphertext,
+        acc: &LookupTableOwned,
+    ) -> crate::Result<Ciphertext> {
         self.atomic_pattern
             .decompress_and_apply_lookup_table(compressed_ct, acc)
     }
@@ -140,6 +140,70 @@ create_parameterized_test!(
     shortint_encrypt_with_message_and_carry_modulus_unchecked_mul_lsb_small_carry_and_add
 );
 
+// The NTRU atomic pattern is not part of the default parameter list as it supports neither the
+// modulus switch compression nor the oblivious pseudo random generation, so the tests compatible
+// with all parameter sets are instantiated separately, through the shortint ServerKey.
+#[cfg(not(tarpaulin))]
+macro_rules! create_parameterized_test_ntru {
+    ($($name:ident),* $(,)?) => {
+        $(
+        create_parameterized_test!($name { TEST_PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN });
+        )*
+    };
+}
+
+#[cfg(not(tarpaulin))]
+create_parameterized_test_ntru!(
+    shortint_encrypt_decrypt,
+    shortint_encrypt_with_message_modulus_decrypt,
+    shortint_encrypt_decrypt_without_padding,
+    shortint_keyswitch_bootstrap,
+    shortint_keyswitch_programmable_bootstrap,
+    shortint_keyswitch_programmable_bootstrap_many_lut,
+    shortint_carry_extract,
+    shortint_message_extract,
+    shortint_generate_lookup_table,
+    shortint_unchecked_add,
+    shortint_smart_add,
+    shortint_default_add,
+    shortint_smart_mul_lsb,
+    shortint_default_mul_lsb,
+    shortint_unchecked_neg,
+    shortint_smart_neg,
+    shortint_default_neg,
+    shortint_unchecked_scalar_add,
+    shortint_smart_scalar_add,
+    shortint_default_scalar_add,
+    shortint_unchecked_scalar_sub,
+    shortint_smart_scalar_sub,
+    shortint_default_scalar_sub,
+    shortint_unchecked_scalar_mul,
+    shortint_smart_scalar_mul,
+    shortint_default_scalar_mul,
+    shortint_unchecked_right_shift,
+    shortint_default_right_shift,
+    shortint_unchecked_left_shift,
+    shortint_default_left_shift,
+    shortint_unchecked_sub,
+    shortint_smart_sub,
+    shortint_default_sub,
+    shortint_mul_small_carry,
+    shortint_mux,
+    shortint_unchecked_scalar_bitand,
+    shortint_unchecked_scalar_bitor,
+    shortint_unchecked_scalar_bitxor,
+    shortint_smart_scalar_bitand,
+    shortint_smart_scalar_bitor,
+    shortint_smart_scalar_bitxor,
+    shortint_default_scalar_bitand,
+    shortint_default_scalar_bitor,
+    shortint_default_scalar_bitxor,
+    shortint_trivial_pbs,
+    shortint_trivial_pbs_many_lut,
+    shortint_encrypt_with_message_modulus_unchecked_mul_lsb_small_carry_and_add,
+    shortint_encrypt_with_message_and_carry_modulus_unchecked_mul_lsb_small_carry_and_add,
+);
+
 // Public key tests are limited to small parameter sets to avoid blowing up memory and large testing
 // times. Compressed keygen takes 20 minutes for params 2_2 and for encryption as well.
 // 2_2 uncompressed keys take ~2 GB and 3_3 about ~34 GB, hence why we stop at 2_2.
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use rand::Rng;
 use tfhe::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_mul;
 use tfhe::core_crypto::prelude::slice_algorithms::*;
@@ -11,7 +13,8 @@ type Scalar = u64;
 mod utils;
 use utils::*;
 
-pub fn test_rlwe_scheme_switch(
+pub fn test_glwe_scheme_switch(
+    glwe_dimension: GlweDimension,
     polynomial_size: PolynomialSize,
     log_modulus: usize,
     rlwe_std_dev: f64,
@@ -29,13 +32,12 @@ pub fn test_rlwe_scheme_switch(
 
     let rlwe_noise_distribution =
         Gaussian::from_dispersion_parameter(StandardDev(rlwe_std_dev / 2.0.powi(log_modulus as i32)), 0.0);
-    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(GlweDimension(1), polynomial_size, &mut secret_generator);
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(glwe_dimension, polynomial_size, &mut secret_generator);
 
-    let rlwe_sk_poly = rlwe_secret_key.as_polynomial_list();
-    let rlwe_sk_poly = rlwe_sk_poly.get(0);
+    let glwe_sk_poly_list = glwe_secret_key.as_polynomial_list();
 
-    let rlwe_ss_key = allocate_and_generate_new_rlwe_scheme_switch_key(
-        &rlwe_secret_key,
+    let glwe_ss_key = allocate_and_generate_new_glwe_scheme_switch_key(
+        &glwe_secret_key,
         decomp_base_log,
         decomp_level_count,
         rlwe_noise_distribution,
@@ -43,15 +45,16 @@ pub fn test_rlwe_scheme_switch(
         &mut encryption_generator,
     );
 
-    let mut fourier_rlwe_ss_key = FourierRlweSchemeSwitchKey::new(
+    let mut fourier_glwe_ss_key = FourierGlweSchemeSwitchKey::new(
+        glwe_dimension.to_glwe_size(),
         polynomial_size,
         decomp_base_log,
         decomp_level_count,
         fft_type,
     );
-    convert_standard_rlwe_scheme_switch_key_to_fourier(
-        &rlwe_ss_key,
-        &mut fourier_rlwe_ss_key,
+    convert_standard_glwe_scheme_switch_key_to_fourier(
+        &glwe_ss_key,
+        &mut fourier_glwe_ss_key,
     );
 
     let log_message_modulus = 4;
@@ -79,16 +82,17 @@ pub fn test_rlwe_scheme_switch(
         PlaintextCount(polynomial_size.0),
     );
 
-    let mut input_rlwe_ciphertext = GlweCiphertext::new(
+    let mut input_glwe_ciphertext = GlweCiphertext::new(
         Scalar::ZERO,
-        GlweSize(2),
+        glwe_dimension.to_glwe_size(),
         polynomial_size,
         ciphertext_modulus,
     );
-    let mut output_rlwe_ciphertext = GlweCiphertext::new(
+    let mut output_glwe_ciphertext_list = GlweCiphertextList::new(
         Scalar::ZERO,
-        GlweSize(2),
+        glwe_dimension.to_glwe_size(),
         polynomial_size,
+        GlweCiphertextCount(glwe_dimension.0),
         ciphertext_modulus,
     );
 
@@ -107,71 +111,78 @@ pub fn test_rlwe_scheme_switch(
             &mut scaled_input_plaintext_list.as_mut(),
             torus_scaling,
         );
-        polynomial_wrapping_mul(
-            &mut correct_val_list.as_mut_polynomial(),
-            &scaled_input_plaintext_list.as_polynomial(),
-            &rlwe_sk_poly,
-        );
-        slice_wrapping_scalar_div_assign(
-            &mut correct_val_list.as_mut(),
-            torus_scaling.wrapping_mul(delta),
-        );
-        slice_wrapping_opposite_assign(correct_val_list.as_mut());
 
         encrypt_glwe_ciphertext(
-            &rlwe_secret_key,
-            &mut input_rlwe_ciphertext,
+            &glwe_secret_key,
+            &mut input_glwe_ciphertext,
             &input_plaintext_list,
             rlwe_noise_distribution,
             &mut encryption_generator,
         );
 
         let now = Instant::now();
-        scheme_switch_rlwe_ciphertext(
-            &fourier_rlwe_ss_key,
-            &input_rlwe_ciphertext,
-            &mut output_rlwe_ciphertext,
+        scheme_switch_glwe_ciphertext(
+            &fourier_glwe_ss_key,
+            &input_glwe_ciphertext,
+            &mut output_glwe_ciphertext_list,
         );
         let time = now.elapsed();
 
-        decrypt_glwe_ciphertext(
-            &rlwe_secret_key,
-            &output_rlwe_ciphertext,
-            &mut decrypted_plaintext_list,
-        );
-
-        let (avg_err, max_err) = get_avg_and_max_error(
-            &decrypted_plaintext_list,
-            &correct_val_list,
-            torus_scaling,
-            delta,
-        );
-        println!(
-            "[Test {idx}] time: {} μs, avg_err: {:.3} bits, max_err: {:.3} bits",
-            time.as_micros(),
-            avg_err.log2(),
-            (max_err as f64).log2(),
-        );
+        // The i-th output encrypts -S_i * m
+        for (glwe_sk_poly, output_glwe_ciphertext) in glwe_sk_poly_list.iter()
+            .zip(output_glwe_ciphertext_list.iter())
+        {
+            polynomial_wrapping_mul(
+                &mut correct_val_list.as_mut_polynomial(),
+                &scaled_input_plaintext_list.as_polynomial(),
+                &glwe_sk_poly,
+            );
+            slice_wrapping_scalar_div_assign(
+                &mut correct_val_list.as_mut(),
+                torus_scaling.wrapping_mul(delta),
+            );
+            slice_wrapping_opposite_assign(correct_val_list.as_mut());
+
+            decrypt_glwe_ciphertext(
+                &glwe_secret_key,
+                &output_glwe_ciphertext,
+                &mut decrypted_plaintext_list,
+            );
+
+            let (avg_err, max_err) = get_avg_and_max_error(
+                &decrypted_plaintext_list,
+                &correct_val_list,
+                torus_scaling,
+                delta,
+            );
+            println!(
+                "[Test {idx}] time: {} μs, avg_err: {:.3} bits, max_err: {:.3} bits",
+                time.as_micros(),
+                avg_err.log2(),
+                (max_err as f64).log2(),
+            );
+        }
     }
 }
 
 pub fn main() {
-    let polynomial_size = PolynomialSize(2048);
-
     let param_list = [
-        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(3), FftType::Vanilla),
-        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(3), FftType::Split(20)),
+        (GlweDimension(1), PolynomialSize(2048), 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(3), FftType::Vanilla),
+        (GlweDimension(1), PolynomialSize(2048), 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(3), FftType::Split(20)),
+        (GlweDimension(2), PolynomialSize(1024), 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(3), FftType::Vanilla),
     ];
     for param in param_list {
-        let polynomial_size = param.0;
-        let log_modulus = param.1;
-        let rlwe_std_dev = param.2;
-        let decomp_base_log = param.3;
-        let decomp_level_count = param.4;
-        let fft_type = param.5;
+        let glwe_dimension = param.0;
+        let polynomial_size = param.1;
+        let log_modulus = param.2;
+        let rlwe_std_dev = param.3;
+        let decomp_base_log = param.4;
+        let decomp_level_count = param.5;
+        let fft_type = param.6;
 
         println!(
-            "N: {}, Q: 2^{}, rlwe_std_dev: {} (= {} in torus), B: 2^{}, l: {}, FftType: {:?}",
+            "k: {}, N: {}, Q: 2^{}, rlwe_std_dev: {} (= {} in torus), B: 2^{}, l: {}, FftType: {:?}",
+            glwe_dimension.0,
             polynomial_size.0,
             log_modulus,
             rlwe_std_dev,
@@ -180,7 +191,8 @@ pub fn main() {
             decomp_level_count.0,
             fft_type,
         );
-        test_rlwe_scheme_switch(
+        test_glwe_scheme_switch(
+            glwe_dimension,
             polynomial_size,
             log_modulus,
             rlwe_std_dev,
@@ -0,0 +1,155 @@
+#![allow(deprecated)]
+
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+mod utils;
+use utils::*;
+
+type Scalar = u64;
+
+const NUM_REPEAT: usize = 4;
+
+pub fn test_lwe_to_ntru_packing_keyswitch(
+    param: NtruCMuxParameters,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+    parallel_keygen: bool,
+) {
+    let log_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    // The input LWE ciphertexts share the NTRU modulus, so they use the RLWE noise level
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<Scalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let now = Instant::now();
+    let packing_ksk = if parallel_keygen {
+        par_allocate_and_generate_new_lwe_to_ntru_packing_keyswitch_key(
+            &lwe_secret_key,
+            &ntru_secret_key,
+            decomp_base_log,
+            decomp_level_count,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        )
+    } else {
+        allocate_and_generate_new_lwe_to_ntru_packing_keyswitch_key(
+            &lwe_secret_key,
+            &ntru_secret_key,
+            decomp_base_log,
+            decomp_level_count,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        )
+    };
+    println!("Keygen time: {:.3} ms", (now.elapsed().as_micros() as f64) / 1000f64);
+
+    let mut fourier_packing_ksk = FourierLweToNtruPackingKeyswitchKey::new(
+        lwe_dimension,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        fft_type,
+    );
+    convert_standard_lwe_to_ntru_packing_keyswitch_key_to_fourier(&packing_ksk, &mut fourier_packing_ksk);
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_modulus - log_message_modulus);
+
+    let mut output_ntru_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    for idx in 1..=NUM_REPEAT {
+        // The coefficients beyond the packed LWE ciphertexts should decrypt to zero
+        let lwe_count = rand::thread_rng().gen_range(1..=polynomial_size.0);
+
+        let mut message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+        message_list.iter_mut().take(lwe_count)
+            .for_each(|msg| *(msg.0) = rand::thread_rng().gen_range(0..message_modulus));
+
+        let plaintext_list = PlaintextList::from_container(
+            message_list.iter().take(lwe_count).map(|msg| *msg.0 * delta).collect::<Vec<_>>(),
+        );
+
+        let mut lwe_ciphertext_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(lwe_count),
+            ciphertext_modulus,
+        );
+        encrypt_lwe_ciphertext_list(
+            &lwe_secret_key,
+            &mut lwe_ciphertext_list,
+            &plaintext_list,
+            lwe_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let now = Instant::now();
+        keyswitch_lwe_ciphertext_list_and_pack_in_ntru_ciphertext(
+            &fourier_packing_ksk,
+            &lwe_ciphertext_list,
+            &mut output_ntru_ciphertext,
+        );
+        let time = now.elapsed();
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &output_ntru_ciphertext, &mut decrypted_plaintext_list);
+        let max_err = get_max_error(&decrypted_plaintext_list, &message_list, torus_scaling, delta);
+
+        println!(
+            "[Test {idx}] LWE count: {lwe_count}, time: {:.3} ms, max error: {:.3} bits",
+            (time.as_micros() as f64) / 1000f64,
+            (max_err as f64).log2(),
+        );
+        assert!(max_err < delta / 2);
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, DecompositionBaseLog(9), DecompositionLevelCount(3), FftType::Vanilla, false),
+        (NTRU_CMUX_STD128B2, DecompositionBaseLog(13), DecompositionLevelCount(2), FftType::Split(20), true),
+    ];
+
+    for (param, decomp_base_log, decomp_level_count, fft_type, parallel_keygen) in param_list {
+        param.print_info();
+        println!(
+            "Packing KS: B 2^{}, l: {}, FFT: {:?}, parallel keygen: {}",
+            decomp_base_log.0,
+            decomp_level_count.0,
+            fft_type,
+            parallel_keygen,
+        );
+        test_lwe_to_ntru_packing_keyswitch(param, decomp_base_log, decomp_level_count, fft_type, parallel_keygen);
+        println!();
+    }
+}
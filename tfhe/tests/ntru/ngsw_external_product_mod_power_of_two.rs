@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use rand::Rng;
 use tfhe::core_crypto::prelude::*;
 use tfhe::ntru::algorithms::*;
@@ -156,6 +158,7 @@ pub fn main() {
     let param_list = [
         (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), FftType::Vanilla),
         (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), FftType::Split(20)),
+        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), FftType::SplitN(13, 3)),
     ];
     for param in param_list {
         let polynomial_size = param.0;
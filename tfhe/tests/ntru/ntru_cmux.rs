@@ -0,0 +1,241 @@
+#![allow(deprecated)]
+
+use rand::Rng;
+use tfhe::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_div_assign;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+type Scalar = u64;
+
+mod utils;
+use utils::*;
+
+const NUM_TEST: usize = 4;
+
+pub fn test_ntru_cmux(
+    polynomial_size: PolynomialSize,
+    log_modulus: usize,
+    ntru_std_dev: f64,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    tree_depth: usize,
+    fft_type: FftType,
+) {
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(ntru_std_dev / 2.0.powi(log_modulus as i32)), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(polynomial_size, ciphertext_modulus, ntru_noise_distribution, &mut encryption_generator);
+
+    let ntru_switching_key = allocate_and_generate_new_ntru_switching_key(
+        &ntru_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    let mut fourier_ntru_switching_key = FourierNtruSwitchingKey::new(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        fft_type,
+    );
+    convert_standard_ntru_switching_key_to_fourier(&ntru_switching_key, &mut fourier_ntru_switching_key);
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_modulus - log_message_modulus);
+
+    let leaf_count = 1 << tree_depth;
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    let scratch_bytes = [
+        ntru_cmux_assign_scratch::<Scalar>(polynomial_size, fft),
+        ntru_cmux_tree_lut_scratch::<Scalar>(polynomial_size, PolynomialCount(leaf_count), fft),
+        ntru_blind_rotate_assign_scratch::<Scalar>(polynomial_size, fft),
+    ]
+    .into_iter()
+    .map(|req| req.unwrap().unaligned_bytes_required())
+    .max()
+    .unwrap();
+    buffers.resize(scratch_bytes);
+
+    let mut ngsw_ciphertext = NgswCiphertext::new(
+        Scalar::ZERO,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+    );
+
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    for idx in 1..=NUM_TEST {
+        // Bits of the selector, ordered from the msb to the lsb
+        let bits: Vec<Scalar> = (0..tree_depth)
+            .map(|_| rand::thread_rng().gen_range(0..2))
+            .collect();
+        let selected = bits.iter().fold(0usize, |acc, bit| (acc << 1) | *bit as usize);
+
+        let mut fourier_ngsw_list = FourierNgswCiphertextList::new(
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            FourierNgswCiphertextCount(tree_depth),
+            fft_type,
+        );
+        for (bit, mut fourier_ngsw) in bits.iter().zip(fourier_ngsw_list.iter_mut()) {
+            encrypt_constant_ngsw_ciphertext(
+                &ntru_secret_key,
+                &mut ngsw_ciphertext,
+                Cleartext(*bit),
+                ntru_noise_distribution,
+                &mut encryption_generator,
+            );
+            convert_standard_ngsw_ciphertext_to_fourier(&ngsw_ciphertext, &mut fourier_ngsw);
+        }
+
+        let mut lut_message_list = PolynomialList::new(Scalar::ZERO, polynomial_size, PolynomialCount(leaf_count));
+        let mut lut_list = PolynomialList::new(Scalar::ZERO, polynomial_size, PolynomialCount(leaf_count));
+        for (msg, lut) in lut_message_list.as_mut().iter_mut().zip(lut_list.as_mut().iter_mut()) {
+            *msg = rand::thread_rng().gen_range(0..message_modulus);
+            *lut = (*msg).wrapping_mul(delta);
+        }
+
+        // CMux on the msb
+        let mut ct0 = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+        let mut ct1 = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+        encrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &mut ct0,
+            &PlaintextList::from_container(lut_list.get(0).as_ref()),
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        );
+        encrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &mut ct1,
+            &PlaintextList::from_container(lut_list.get(1).as_ref()),
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let now = Instant::now();
+        ntru_cmux_assign(
+            &mut ct0.as_mut_view(),
+            &mut ct1.as_mut_view(),
+            fourier_ngsw_list.get(0),
+            fft,
+            buffers.stack(),
+        );
+        let cmux_time = now.elapsed();
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &ct0, &mut decrypted_plaintext_list);
+        let cmux_max_err = get_max_error(
+            &decrypted_plaintext_list,
+            &PlaintextList::from_container(lut_message_list.get(bits[0] as usize).as_ref()),
+            torus_scaling,
+            delta,
+        );
+
+        // CMux tree on the LUT polynomials
+        let mut tree_output = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+
+        let now = Instant::now();
+        ntru_cmux_tree_lut_mem_optimized(
+            &mut tree_output,
+            &lut_list,
+            &fourier_ntru_switching_key,
+            fourier_ngsw_list.as_view(),
+            fft,
+            buffers.stack(),
+        );
+        let tree_time = now.elapsed();
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &tree_output, &mut decrypted_plaintext_list);
+        let tree_max_err = get_max_error(
+            &decrypted_plaintext_list,
+            &PlaintextList::from_container(lut_message_list.get(selected).as_ref()),
+            torus_scaling,
+            delta,
+        );
+
+        // Blind rotation of the first LUT polynomial by the selector
+        let mut rotated = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+        switch_to_ntru_ciphertext(
+            &fourier_ntru_switching_key,
+            &PlaintextList::from_container(lut_list.get(0).as_ref()),
+            &mut rotated,
+        );
+
+        let now = Instant::now();
+        ntru_blind_rotate_assign(
+            &mut rotated.as_mut_view(),
+            fourier_ngsw_list.as_view(),
+            fft,
+            buffers.stack(),
+        );
+        let br_time = now.elapsed();
+
+        let mut rotated_message = Polynomial::new(Scalar::ZERO, polynomial_size);
+        rotated_message.as_mut().copy_from_slice(lut_message_list.get(0).as_ref());
+        polynomial_wrapping_monic_monomial_div_assign(&mut rotated_message, MonomialDegree(selected));
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &rotated, &mut decrypted_plaintext_list);
+        let br_max_err = get_max_error(
+            &decrypted_plaintext_list,
+            &PlaintextList::from_container(rotated_message.as_ref()),
+            torus_scaling,
+            delta,
+        );
+
+        println!(
+            "[Test {idx}] bits: {bits:?} | CMux: {} µs, max err {:.3} bits | CMux tree: {} µs, max err {:.3} bits | blind rotation: {} µs, max err {:.3} bits",
+            cmux_time.as_micros(),
+            (cmux_max_err as f64).log2(),
+            tree_time.as_micros(),
+            (tree_max_err as f64).log2(),
+            br_time.as_micros(),
+            (br_max_err as f64).log2(),
+        );
+
+        assert!(cmux_max_err < delta / 2, "CMux output does not decrypt to the selected input");
+        assert!(tree_max_err < delta / 2, "CMux tree output does not decrypt to LUT {selected}");
+        assert!(br_max_err < delta / 2, "Blind rotation output does not decrypt to the LUT times X^-{selected}");
+    }
+}
+
+pub fn main() {
+    let polynomial_size = PolynomialSize(2048);
+
+    let param_list = [
+        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), 3, FftType::Vanilla),
+        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), 3, FftType::Split(20)),
+    ];
+    for (polynomial_size, log_modulus, ntru_std_dev, decomp_base_log, decomp_level_count, tree_depth, fft_type) in param_list {
+        println!(
+            "N: {}, Q: 2^{}, std_dev: {} (= {} in torus), B: 2^{}, l: {}, depth: {}, FftType: {:?}",
+            polynomial_size.0,
+            log_modulus,
+            ntru_std_dev,
+            ntru_std_dev / 2.0.powi(log_modulus as i32),
+            decomp_base_log.0,
+            decomp_level_count.0,
+            tree_depth,
+            fft_type,
+        );
+        test_ntru_cmux(polynomial_size, log_modulus, ntru_std_dev, decomp_base_log, decomp_level_count, tree_depth, fft_type);
+        println!();
+    }
+}
@@ -80,24 +80,13 @@ pub fn test_ntru_cmux_boot(param: NtruCMuxParameters, fft_type: FftType) {
         ciphertext_modulus,
     );
 
-    let mut acc = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
-    {
-        let box_size = polynomial_size.0 / message_modulus;
-        for i in 0..message_modulus {
-            let index = i * box_size;
-            acc.as_mut()[index..index + box_size]
-                .iter_mut()
-                .for_each(|a| *a = Scalar::cast_from(i).wrapping_mul(delta));
-        }
-
-        let half_box_size = box_size / 2;
-
-        for a_i in acc.as_mut()[0..half_box_size].iter_mut() {
-            *a_i = (*a_i).wrapping_neg();
-        }
-
-        acc.as_mut().rotate_left(half_box_size);
-    }
+    let acc = generate_programmable_bootstrap_ntru_lut(
+        polynomial_size,
+        message_modulus,
+        1,
+        ciphertext_modulus,
+        |x| x,
+    );
 
     let num_test = 10;
     for idx in 1..=num_test {
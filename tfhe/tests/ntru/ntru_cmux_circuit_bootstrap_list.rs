@@ -0,0 +1,229 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+const NUM_CBS: usize = 16;
+
+pub fn test_ntru_cmux_cbs_list(
+    param: NtruCMuxParameters,
+    log_lut_count: LutCountLog,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let ntru_cmux_cbs_key = par_allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+        ntru_cmux_cbs_key.glwe_size(),
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        fft_type,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier(&ntru_cmux_cbs_key, &mut fourier_ntru_cmux_cbs_key);
+
+    let msg_bits: Vec<SmallScalar> = (0..NUM_CBS).map(|_| rand::thread_rng().gen_range(0..2)).collect();
+
+    let mut lwe_list_in = LweCiphertextList::new(
+        SmallScalar::ZERO,
+        lwe_dimension.to_lwe_size(),
+        LweCiphertextCount(NUM_CBS),
+        small_ciphertext_modulus,
+    );
+    for (bit, mut lwe_in) in msg_bits.iter().zip(lwe_list_in.iter_mut()) {
+        encrypt_lwe_ciphertext(
+            &lwe_secret_key,
+            &mut lwe_in,
+            Plaintext(bit << (log_input_modulus - 1)),
+            lwe_noise_distribution,
+            &mut encryption_generator,
+        );
+    }
+
+    let new_ggsw_list = || GgswCiphertextList::new(
+        Scalar::ZERO,
+        GlweSize(2),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        GgswCiphertextCount(NUM_CBS),
+        ciphertext_modulus,
+    );
+
+    let mut ggsw_list_single = new_ggsw_list();
+    let now = Instant::now();
+    for (lwe_in, mut ggsw_out) in lwe_list_in.iter().zip(ggsw_list_single.iter_mut()) {
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext(
+            &lwe_in,
+            &mut ggsw_out,
+            &fourier_ntru_cmux_cbs_key,
+            log_lut_count,
+        );
+    }
+    let single_time = now.elapsed();
+
+    let mut ggsw_list = new_ggsw_list();
+    let now = Instant::now();
+    ntru_cmux_circuit_bootstrap_lwe_ciphertext_list(
+        &lwe_list_in,
+        &mut ggsw_list,
+        &fourier_ntru_cmux_cbs_key,
+        log_lut_count,
+    );
+    let list_time = now.elapsed();
+
+    let mut par_ggsw_list = new_ggsw_list();
+    let now = Instant::now();
+    par_ntru_cmux_circuit_bootstrap_lwe_ciphertext_list(
+        &lwe_list_in,
+        &mut par_ggsw_list,
+        &fourier_ntru_cmux_cbs_key,
+        log_lut_count,
+    );
+    let par_time = now.elapsed();
+
+    println!(
+        "{NUM_CBS} CBS: {:.3} ms (one by one) | {:.3} ms (list) | {:.3} ms (parallel list)",
+        (single_time.as_micros() as f64) / 1000f64,
+        (list_time.as_micros() as f64) / 1000f64,
+        (par_time.as_micros() as f64) / 1000f64,
+    );
+
+    assert_eq!(ggsw_list_single, ggsw_list);
+    assert_eq!(ggsw_list_single, par_ggsw_list);
+
+    // Each output RGSW is checked by an external product with an encryption of delta
+    let delta = Scalar::ONE << (log_output_modulus - 1);
+    let plaintext_list = PlaintextList::new(delta, PlaintextCount(polynomial_size.0));
+    let mut rlwe_ciphertext_in = GlweCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, ciphertext_modulus);
+    encrypt_glwe_ciphertext(
+        &rlwe_secret_key,
+        &mut rlwe_ciphertext_in,
+        &plaintext_list,
+        rlwe_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ggsw = FourierGgswCiphertext::new(GlweSize(2), polynomial_size, decomp_base_log, decomp_level_count);
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    for (bit, ggsw) in msg_bits.iter().zip(par_ggsw_list.iter()) {
+        convert_standard_ggsw_ciphertext_to_fourier(&ggsw, &mut fourier_ggsw);
+
+        let mut rlwe_ciphertext_out = GlweCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, ciphertext_modulus);
+        add_external_product_assign(
+            &mut rlwe_ciphertext_out,
+            &fourier_ggsw,
+            &rlwe_ciphertext_in,
+        );
+
+        decrypt_glwe_ciphertext(
+            &rlwe_secret_key,
+            &rlwe_ciphertext_out,
+            &mut decrypted_plaintext_list,
+        );
+
+        let rounding = delta >> 1;
+        for decrypted in decrypted_plaintext_list.iter() {
+            let decoded = (decrypted.0.wrapping_add(rounding) % (Scalar::ONE << log_output_modulus)) / delta;
+            assert_eq!(decoded, *bit as Scalar);
+        }
+    }
+    println!("All {NUM_CBS} outputs decrypt correctly");
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+        (NTRU_CMUX_STD128B3, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+    ];
+
+    for (param, log_lut_count, decomp_base_log, decomp_level_count, fft_type) in param_list {
+        param.print_info();
+        println!(
+            "LutCountLog: {:?}, B 2^{}, l: {}, FFT: {:?}",
+            log_lut_count,
+            decomp_base_log.0,
+            decomp_level_count.0,
+            fft_type,
+        );
+        test_ntru_cmux_cbs_list(param, log_lut_count, decomp_base_log, decomp_level_count, fft_type);
+        println!();
+    }
+}
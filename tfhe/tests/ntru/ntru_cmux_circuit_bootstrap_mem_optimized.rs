@@ -0,0 +1,216 @@
+#![allow(deprecated)]
+
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Instant;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+const NUM_CBS: usize = 8;
+
+// Counts the heap allocations made while COUNT_ALLOCATIONS is set
+struct CountingAllocator;
+
+static COUNT_ALLOCATIONS: AtomicBool = AtomicBool::new(false);
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNT_ALLOCATIONS.load(Ordering::Relaxed) {
+            ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+pub fn test_ntru_cmux_cbs_mem_optimized(
+    param: NtruCMuxParameters,
+    log_lut_count: LutCountLog,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let ntru_cmux_cbs_key = par_allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+        ntru_cmux_cbs_key.glwe_size(),
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        fft_type,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier(&ntru_cmux_cbs_key, &mut fourier_ntru_cmux_cbs_key);
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    // The stack is sized with the scratch requirement only: any underestimation makes the CBS panic
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext_scratch::<Scalar>(
+            GlweSize(2),
+            polynomial_size,
+            decomp_level_count,
+            param.tr_decomp_level_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let mut lwe_in = LweCiphertext::new(SmallScalar::ZERO, lwe_dimension.to_lwe_size(), small_ciphertext_modulus);
+    let mut ggsw_out = GgswCiphertext::new(
+        Scalar::ZERO,
+        GlweSize(2),
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+    );
+    let mut expected_ggsw_out = ggsw_out.clone();
+
+    for idx in 1..=NUM_CBS {
+        let bit = rand::thread_rng().gen_range(0..2);
+        encrypt_lwe_ciphertext(
+            &lwe_secret_key,
+            &mut lwe_in,
+            Plaintext(bit << (log_input_modulus - 1)),
+            lwe_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        ALLOCATION_COUNT.store(0, Ordering::Relaxed);
+        COUNT_ALLOCATIONS.store(true, Ordering::Relaxed);
+        let now = Instant::now();
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext_mem_optimized(
+            &lwe_in,
+            &mut ggsw_out,
+            &fourier_ntru_cmux_cbs_key,
+            log_lut_count,
+            fft,
+            buffers.stack(),
+        );
+        let time = now.elapsed();
+        COUNT_ALLOCATIONS.store(false, Ordering::Relaxed);
+        let allocation_count = ALLOCATION_COUNT.load(Ordering::Relaxed);
+
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext(
+            &lwe_in,
+            &mut expected_ggsw_out,
+            &fourier_ntru_cmux_cbs_key,
+            log_lut_count,
+        );
+
+        println!(
+            "[Test {idx}] time: {:.3} ms, heap allocations: {allocation_count}",
+            (time.as_micros() as f64) / 1000f64,
+        );
+        assert_eq!(allocation_count, 0);
+        assert_eq!(ggsw_out, expected_ggsw_out);
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Split(20)),
+        (NTRU_CMUX_STD128B3, LutCountLog(1), DecompositionBaseLog(4), DecompositionLevelCount(3), FftType::Vanilla),
+    ];
+
+    for (param, log_lut_count, decomp_base_log, decomp_level_count, fft_type) in param_list {
+        param.print_info();
+        println!(
+            "LutCountLog: {:?}, B 2^{}, l: {}, FFT: {:?}",
+            log_lut_count,
+            decomp_base_log.0,
+            decomp_level_count.0,
+            fft_type,
+        );
+        test_ntru_cmux_cbs_mem_optimized(param, log_lut_count, decomp_base_log, decomp_level_count, fft_type);
+        println!();
+    }
+}
@@ -15,6 +15,7 @@ const NUM_TEST: usize = 4;
 
 pub fn test_ntru_cmux_cbs(
     param: NtruCMuxParameters,
+    glwe_dimension: GlweDimension,
     log_lut_count: LutCountLog,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
@@ -55,13 +56,13 @@ pub fn test_ntru_cmux_cbs(
         &mut encryption_generator,
     );
 
-    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
-        GlweDimension(1),
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
         polynomial_size,
         &mut secret_generator,
     );
-    let rlwe_sk_poly = rlwe_secret_key.as_polynomial_list();
-    let rlwe_sk_poly= rlwe_sk_poly.get(0);
+    let glwe_sk_poly_list = glwe_secret_key.as_polynomial_list();
+    let glwe_size = glwe_dimension.to_glwe_size();
 
     let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
         lwe_dimension,
@@ -72,7 +73,7 @@ pub fn test_ntru_cmux_cbs(
     let ntru_cmux_cbs_key = allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
         &lwe_secret_key,
         &ntru_secret_key,
-        &rlwe_secret_key,
+        &glwe_secret_key,
         param.br_decomp_base_log(),
         param.br_decomp_level_count(),
         param.br_decomp_base_log(),
@@ -90,6 +91,7 @@ pub fn test_ntru_cmux_cbs(
     );
 
     let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+        ntru_cmux_cbs_key.glwe_size(),
         polynomial_size,
         param.input_lwe_dimension(),
         param.br_decomp_base_log(),
@@ -124,12 +126,12 @@ pub fn test_ntru_cmux_cbs(
             &mut encryption_generator,
         );
 
-        let mut output_rgsw = GgswCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, decomp_base_log, decomp_level_count, ciphertext_modulus);
+        let mut output_ggsw = GgswCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, decomp_base_log, decomp_level_count, ciphertext_modulus);
 
         let now = Instant::now();
         ntru_cmux_circuit_bootstrap_lwe_ciphertext(
             &input_lwe,
-            &mut output_rgsw,
+            &mut output_ggsw,
             &fourier_ntru_cmux_cbs_key,
             log_lut_count,
         );
@@ -138,44 +140,49 @@ pub fn test_ntru_cmux_cbs(
         let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ONE, PlaintextCount(polynomial_size.0));
 
         println!("[Test {idx}] input: {msg_bit}, time: {:.3} ms", (time.as_micros() as f64) / 1000f64);
-        for (k, rgsw_level_matrix) in output_rgsw.iter().enumerate() {
+        for (k, ggsw_level_matrix) in output_ggsw.iter().enumerate() {
             let factor = if msg_bit == SmallScalar::ONE {
                 Scalar::ONE << (Scalar::BITS as usize - (decomp_level_count.0 - k) * decomp_base_log.0)
             } else {
                 Scalar::ZERO
             };
 
-            let rlwe = rgsw_level_matrix.as_glwe_list();
-
-            let mut test = GlweCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, ciphertext_modulus);
-            test.as_mut().clone_from_slice(rlwe.get(0).as_ref());
-
-            let mut test_body = test.get_mut_body();
-            let mut test_body = test_body.as_mut_polynomial();
-            slice_wrapping_add_scalar_mul_assign(
-                test_body.as_mut(),
-                rlwe_sk_poly.as_ref(),
-                factor,
-            );
-
-            decrypt_glwe_ciphertext(
-                &rlwe_secret_key,
-                &test,
-                &mut decrypted_plaintext_list,
-            );
-
-            let (avg_err, max_err) = get_avg_and_max_error(
-                &decrypted_plaintext_list,
-                &PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0)),
-                torus_scaling,
-                Scalar::ONE,
-            );
-
-            println!("\t[Level {} CBS] avg_err: {:.3} bits | max_err: {:.3} bits", decomp_level_count.0 - k, avg_err.log2(), (max_err as f64).log2());
+            // The i-th row encrypts -S_i * m * q / B^k for i < glwe dimension, the last row m * q / B^k
+            for (i, glwe) in ggsw_level_matrix.as_glwe_list().iter().enumerate() {
+                let mut test = GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+                test.as_mut().clone_from_slice(glwe.as_ref());
+
+                let mut test_body = test.get_mut_body();
+                let mut test_body = test_body.as_mut_polynomial();
+                if i < glwe_dimension.0 {
+                    slice_wrapping_add_scalar_mul_assign(
+                        test_body.as_mut(),
+                        glwe_sk_poly_list.get(i).as_ref(),
+                        factor,
+                    );
+                } else {
+                    test_body.as_mut()[0] = test_body.as_ref()[0].wrapping_sub(factor);
+                }
+
+                decrypt_glwe_ciphertext(
+                    &glwe_secret_key,
+                    &test,
+                    &mut decrypted_plaintext_list,
+                );
+
+                let (avg_err, max_err) = get_avg_and_max_error(
+                    &decrypted_plaintext_list,
+                    &PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0)),
+                    torus_scaling,
+                    Scalar::ONE,
+                );
+
+                println!("\t[Level {} CBS, row {i}] avg_err: {:.3} bits | max_err: {:.3} bits", decomp_level_count.0 - k, avg_err.log2(), (max_err as f64).log2());
+            }
         }
 
-        let mut fourier_rgsw_output = FourierGgswCiphertext::new(GlweSize(2), polynomial_size, decomp_base_log, decomp_level_count);
-        convert_standard_ggsw_ciphertext_to_fourier(&output_rgsw, &mut fourier_rgsw_output);
+        let mut fourier_ggsw_output = FourierGgswCiphertext::new(glwe_size, polynomial_size, decomp_base_log, decomp_level_count);
+        convert_standard_ggsw_ciphertext_to_fourier(&output_ggsw, &mut fourier_ggsw_output);
 
         let log_message_modulus = 1;
         let message_modulus = Scalar::ONE << log_message_modulus;
@@ -190,25 +197,25 @@ pub fn test_ntru_cmux_cbs(
             plaintext_list.as_mut()[i] = rand_elem.wrapping_mul(delta);
         }
 
-        let mut rlwe_ciphertext_in = GlweCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, ciphertext_modulus);
+        let mut glwe_ciphertext_in = GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
         encrypt_glwe_ciphertext(
-            &rlwe_secret_key,
-            &mut rlwe_ciphertext_in,
+            &glwe_secret_key,
+            &mut glwe_ciphertext_in,
             &plaintext_list,
             rlwe_noise_distribution,
             &mut encryption_generator,
         );
-        let mut rlwe_ciphertext_out = GlweCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, ciphertext_modulus);
+        let mut glwe_ciphertext_out = GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
 
         add_external_product_assign(
-            &mut rlwe_ciphertext_out,
-            &fourier_rgsw_output,
-            &rlwe_ciphertext_in,
+            &mut glwe_ciphertext_out,
+            &fourier_ggsw_output,
+            &glwe_ciphertext_in,
         );
 
         decrypt_glwe_ciphertext(
-            &rlwe_secret_key,
-            &rlwe_ciphertext_out,
+            &glwe_secret_key,
+            &glwe_ciphertext_out,
             &mut decrypted_plaintext_list,
         );
 
@@ -230,15 +237,38 @@ pub fn test_ntru_cmux_cbs(
 }
 
 pub fn main() {
+    // A toy parameter set with N = 1024 to exercise GLWE dimension 2 outputs
+    let std128b2 = NTRU_CMUX_STD128B2;
+    let glwe_test_param = NtruCMuxParameters::new(
+        "NTRU_CMUX_GLWE2_TEST",
+        PolynomialSize(1024),
+        std128b2.input_lwe_dimension(),
+        std128b2.log_output_modulus(),
+        std128b2.log_input_modulus(),
+        std128b2.ntru_std_dev(),
+        std128b2.rlwe_std_dev(),
+        std128b2.lwe_std_dev(),
+        std128b2.br_decomp_base_log(),
+        std128b2.br_decomp_level_count(),
+        std128b2.tr_decomp_base_log(),
+        std128b2.tr_decomp_level_count(),
+        std128b2.ksk_decomp_base_log(),
+        std128b2.ksk_decomp_level_count(),
+        std128b2.ss_decomp_base_log(),
+        std128b2.ss_decomp_level_count(),
+    );
+
     let param_list = [
-        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
-        (NTRU_CMUX_STD128B3, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
+        (NTRU_CMUX_STD128B2, GlweDimension(1), LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
+        (NTRU_CMUX_STD128B3, GlweDimension(1), LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
+        (glwe_test_param, GlweDimension(2), LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
     ];
 
-    for (param, log_lut_count, decomp_base_log, decomp_level_count, br_fft_type, swk_fft_type, tr_fft_type, ksk_fft_type, ss_fft_type) in param_list {
+    for (param, glwe_dimension, log_lut_count, decomp_base_log, decomp_level_count, br_fft_type, swk_fft_type, tr_fft_type, ksk_fft_type, ss_fft_type) in param_list {
         param.print_info();
         println!(
-            "LutCountLog: {:?}, B 2^{}, l: {}, BR: {:?}, SWK: {:?}, Tr: {:?}, KS: {:?}, SS: {:?}",
+            "GlweDimension: {}, LutCountLog: {:?}, B 2^{}, l: {}, BR: {:?}, SWK: {:?}, Tr: {:?}, KS: {:?}, SS: {:?}",
+            glwe_dimension.0,
             log_lut_count,
             decomp_base_log.0,
             decomp_level_count.0,
@@ -248,7 +278,7 @@ pub fn main() {
             ksk_fft_type,
             ss_fft_type,
         );
-        test_ntru_cmux_cbs(param, log_lut_count, decomp_base_log, decomp_level_count, br_fft_type, swk_fft_type, tr_fft_type, ksk_fft_type, ss_fft_type);
+        test_ntru_cmux_cbs(param, glwe_dimension, log_lut_count, decomp_base_log, decomp_level_count, br_fft_type, swk_fft_type, tr_fft_type, ksk_fft_type, ss_fft_type);
         println!();
     }
 }
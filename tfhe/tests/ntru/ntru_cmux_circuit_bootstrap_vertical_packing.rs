@@ -0,0 +1,205 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+const NUM_TEST: usize = 4;
+
+// Input message bits, split between the CMux tree and the blind rotation of the vertical packing
+const LOG_INPUT_MESSAGE: usize = 8;
+const LOG_CMUX_TREE: usize = 2;
+
+// Boolean LUTs, the outputs being bits
+fn lut_function(lut_idx: usize, x: usize) -> usize {
+    match lut_idx {
+        0 => ((x * x + 1) >> 3) & 1,
+        _ => (x.count_ones() as usize) & 1,
+    }
+}
+
+pub fn test_ntru_cmux_cbs_vertical_packing(
+    param: NtruCMuxParameters,
+    log_lut_count: LutCountLog,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut secret_generator,
+    );
+    let output_lwe_secret_key = rlwe_secret_key.clone().into_lwe_secret_key();
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let ntru_cmux_cbs_key = par_allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+        ntru_cmux_cbs_key.glwe_size(),
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        fft_type,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier(&ntru_cmux_cbs_key, &mut fourier_ntru_cmux_cbs_key);
+
+    // Each LUT is split in 2^LOG_CMUX_TREE polynomials, the lsb of the input indexing coefficients
+    let lut_count = 2;
+    let poly_per_lut = 1 << LOG_CMUX_TREE;
+    let log_br = LOG_INPUT_MESSAGE - LOG_CMUX_TREE;
+    let delta = Scalar::ONE << (log_output_modulus - 1);
+
+    let mut big_lut = PolynomialList::new(Scalar::ZERO, polynomial_size, PolynomialCount(lut_count * poly_per_lut));
+    for (idx, mut poly) in big_lut.iter_mut().enumerate() {
+        let lut_idx = idx / poly_per_lut;
+        let msb = idx % poly_per_lut;
+        for lsb in 0..(1 << log_br) {
+            let x = (msb << log_br) | lsb;
+            poly.as_mut()[lsb] = (lut_function(lut_idx, x) as Scalar) * delta;
+        }
+    }
+
+    for idx in 1..=NUM_TEST {
+        let msg = rand::thread_rng().gen_range(0..(1 << LOG_INPUT_MESSAGE));
+
+        // Bits are ordered from the msb to the lsb
+        let mut lwe_list_in = LweCiphertextList::new(
+            SmallScalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            LweCiphertextCount(LOG_INPUT_MESSAGE),
+            small_ciphertext_modulus,
+        );
+        for (i, mut lwe_in) in lwe_list_in.iter_mut().enumerate() {
+            let bit = ((msg >> (LOG_INPUT_MESSAGE - 1 - i)) & 1) as SmallScalar;
+            encrypt_lwe_ciphertext(
+                &lwe_secret_key,
+                &mut lwe_in,
+                Plaintext(bit << (log_input_modulus - 1)),
+                lwe_noise_distribution,
+                &mut encryption_generator,
+            );
+        }
+
+        let mut lwe_list_out = LweCiphertextList::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(lut_count),
+            ciphertext_modulus,
+        );
+
+        let now = Instant::now();
+        ntru_cmux_circuit_bootstrap_vertical_packing_lwe_ciphertext_list(
+            &lwe_list_in,
+            &mut lwe_list_out,
+            &big_lut,
+            &fourier_ntru_cmux_cbs_key,
+            log_lut_count,
+            decomp_base_log,
+            decomp_level_count,
+        );
+        let time = now.elapsed();
+
+        println!("[Test {idx}] input: {msg}, time: {:.3} ms", (time.as_micros() as f64) / 1000f64);
+        for (lut_idx, lwe_out) in lwe_list_out.iter().enumerate() {
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &lwe_out).0;
+            let rounding = delta >> 1;
+            let decoded = (decrypted.wrapping_add(rounding) % (Scalar::ONE << log_output_modulus)) / delta;
+            let expected = lut_function(lut_idx, msg) as Scalar;
+
+            let err = decrypted.wrapping_sub(expected * delta) % (Scalar::ONE << log_output_modulus);
+            let err = std::cmp::min(err, (Scalar::ONE << log_output_modulus) - err);
+            println!("\t[LUT {lut_idx}] output: {decoded}, expected: {expected}, err: {:.3} bits", (err as f64).log2());
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+        (NTRU_CMUX_STD128B3, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+    ];
+
+    for (param, log_lut_count, decomp_base_log, decomp_level_count, fft_type) in param_list {
+        param.print_info();
+        println!(
+            "LutCountLog: {:?}, B 2^{}, l: {}, FFT: {:?}",
+            log_lut_count,
+            decomp_base_log.0,
+            decomp_level_count.0,
+            fft_type,
+        );
+        test_ntru_cmux_cbs_vertical_packing(param, log_lut_count, decomp_base_log, decomp_level_count, fft_type);
+        println!();
+    }
+}
@@ -0,0 +1,273 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+mod utils;
+use utils::*;
+
+const NUM_TEST: usize = 4;
+
+pub fn test_ntru_cmux_ngsw_cbs(
+    param: NtruCMuxParameters,
+    log_lut_count: LutCountLog,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    br_fft_type: FftType,
+    swk_fft_type: FftType,
+    tr_fft_type: FftType,
+    ss_fft_type: FftType,
+    ngsw_fft_type: FftType,
+) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+    let lwe_size = lwe_secret_key.lwe_dimension().to_lwe_size();
+
+    let ntru_cmux_ngsw_cbs_key = allocate_and_generate_new_ntru_cmux_ngsw_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_ngsw_cbs_key = FourierNtruCMuxNgswCircuitBootstrapKey::new(
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        br_fft_type,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        swk_fft_type,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        tr_fft_type,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ss_fft_type,
+    );
+
+    convert_standard_ntru_cmux_ngsw_circuit_bootstrap_key_to_fourier(&ntru_cmux_ngsw_cbs_key, &mut fourier_ntru_cmux_ngsw_cbs_key);
+
+    let log_message_modulus = 2;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_output_modulus - log_message_modulus);
+
+    let zero = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    for idx in 1..=NUM_TEST {
+        // Two input bits, ordered from the msb to the lsb, selecting one of four leaves
+        let tree_depth = 2;
+        let leaf_count = 1 << tree_depth;
+
+        let msg_bits: Vec<SmallScalar> = (0..tree_depth)
+            .map(|_| rand::thread_rng().gen_range(0..2) as SmallScalar)
+            .collect();
+
+        let mut input_lwe_list = LweCiphertextList::new(
+            SmallScalar::ZERO,
+            lwe_size,
+            LweCiphertextCount(tree_depth),
+            small_ciphertext_modulus,
+        );
+        for (msg_bit, mut input_lwe) in msg_bits.iter().zip(input_lwe_list.iter_mut()) {
+            encrypt_lwe_ciphertext(
+                &lwe_secret_key,
+                &mut input_lwe,
+                Plaintext(*msg_bit << (log_input_modulus - 1)),
+                lwe_noise_distribution,
+                &mut encryption_generator,
+            );
+        }
+
+        let mut output_ngsw_list = NgswCiphertextList::new(
+            Scalar::ZERO,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            NgswCiphertextCount(tree_depth),
+            ciphertext_modulus,
+        );
+
+        let now = Instant::now();
+        ntru_cmux_ngsw_circuit_bootstrap_lwe_ciphertext_list(
+            &input_lwe_list,
+            &mut output_ngsw_list,
+            &fourier_ntru_cmux_ngsw_cbs_key,
+            log_lut_count,
+        );
+        let time = now.elapsed();
+
+        println!("[Test {idx}] input: {msg_bits:?}, time: {:.3} ms", (time.as_micros() as f64) / 1000f64);
+        for (msg_bit, output_ngsw) in msg_bits.iter().zip(output_ngsw_list.iter()) {
+            for (k, ntru) in output_ngsw.iter().enumerate() {
+                let factor = if *msg_bit == SmallScalar::ONE {
+                    Scalar::ONE << (Scalar::BITS as usize - (decomp_level_count.0 - k) * decomp_base_log.0)
+                } else {
+                    Scalar::ZERO
+                };
+
+                // Each row is g / f + m * q / B^k, which decrypts to the small g once m * q / B^k is removed
+                let mut test = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+                test.as_mut().clone_from_slice(ntru.as_ref());
+                let mut test_poly = test.as_mut_polynomial();
+                test_poly.as_mut()[0] = test_poly.as_ref()[0].wrapping_sub(factor);
+
+                decrypt_ntru_ciphertext(
+                    &ntru_secret_key,
+                    &test,
+                    &mut decrypted_plaintext_list,
+                );
+
+                let (avg_err, max_err) = get_avg_and_max_error(
+                    &decrypted_plaintext_list,
+                    &zero,
+                    torus_scaling,
+                    Scalar::ONE,
+                );
+
+                println!("\t[Bit {msg_bit}, level {} CBS] avg_err: {:.3} bits | max_err: {:.3} bits", decomp_level_count.0 - k, avg_err.log2(), (max_err as f64).log2());
+            }
+        }
+
+        let mut fourier_ngsw_list = FourierNgswCiphertextList::new(
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            FourierNgswCiphertextCount(tree_depth),
+            ngsw_fft_type,
+        );
+        for (output_ngsw, mut fourier_ngsw) in output_ngsw_list.iter().zip(fourier_ngsw_list.iter_mut()) {
+            convert_standard_ngsw_ciphertext_to_fourier(&output_ngsw, &mut fourier_ngsw);
+        }
+
+        let mut leaves = NtruCiphertextList::new(
+            Scalar::ZERO,
+            polynomial_size,
+            NtruCiphertextCount(leaf_count),
+            ciphertext_modulus,
+        );
+        let mut message_lists = Vec::with_capacity(leaf_count);
+        for mut leaf in leaves.iter_mut() {
+            let mut message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+            let mut plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+            for (msg, ptxt) in message_list.iter_mut().zip(plaintext_list.iter_mut()) {
+                *msg.0 = rand::thread_rng().gen_range(0..message_modulus);
+                *ptxt.0 = (*msg.0).wrapping_mul(delta);
+            }
+
+            encrypt_ntru_ciphertext(
+                &ntru_secret_key,
+                &mut leaf,
+                &plaintext_list,
+                ntru_noise_distribution,
+                &mut encryption_generator,
+            );
+            message_lists.push(message_list);
+        }
+
+        let fft = Fft::new(polynomial_size);
+        let fft = fft.as_view();
+        let mut buffers = ComputationBuffers::new();
+        buffers.resize(
+            ntru_cmux_tree_scratch::<Scalar>(polynomial_size, NtruCiphertextCount(leaf_count), fft)
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+
+        let mut output_ntru = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+        ntru_cmux_tree_mem_optimized(
+            &mut output_ntru,
+            &leaves,
+            fourier_ngsw_list.as_view(),
+            fft,
+            buffers.stack(),
+        );
+
+        decrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &output_ntru,
+            &mut decrypted_plaintext_list,
+        );
+
+        let selected = msg_bits.iter().fold(0usize, |acc, bit| (acc << 1) | *bit as usize);
+        let (avg_err, max_err) = get_avg_and_max_error(
+            &decrypted_plaintext_list,
+            &message_lists[selected],
+            torus_scaling,
+            delta,
+        );
+
+        println!("[Test {idx}] CMux tree: avg err {:.3} bits | max err {:.3} bits", avg_err.log2(), (max_err as f64).log2());
+        assert!(max_err < delta / 2, "CMux tree output does not decrypt to leaf {selected}");
+        println!();
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
+        (NTRU_CMUX_STD128B3, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla, FftType::Vanilla),
+    ];
+
+    for (param, log_lut_count, decomp_base_log, decomp_level_count, br_fft_type, swk_fft_type, tr_fft_type, ss_fft_type, ngsw_fft_type) in param_list {
+        param.print_info();
+        println!(
+            "LutCountLog: {:?}, B 2^{}, l: {}, BR: {:?}, SWK: {:?}, Tr: {:?}, SS: {:?}, NGSW: {:?}",
+            log_lut_count,
+            decomp_base_log.0,
+            decomp_level_count.0,
+            br_fft_type,
+            swk_fft_type,
+            tr_fft_type,
+            ss_fft_type,
+            ngsw_fft_type,
+        );
+        test_ntru_cmux_ngsw_cbs(param, log_lut_count, decomp_base_log, decomp_level_count, br_fft_type, swk_fft_type, tr_fft_type, ss_fft_type, ngsw_fft_type);
+        println!();
+    }
+}
@@ -0,0 +1,112 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+
+type Scalar = u64;
+
+// NTT-friendly prime 2^45 - 204799, q = 1 mod 2^13
+const PRIME_MODULUS: u128 = 35184371884033;
+
+pub fn test_ntru_encryption_mod_prime(param: NtruCMuxParameters) {
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new(PRIME_MODULUS).unwrap();
+    let modulus = PRIME_MODULUS as Scalar;
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let mut ntru_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = modulus / message_modulus;
+    let mut input_message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut input_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    let num_test = 10;
+    for idx in 1..=num_test {
+        let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(polynomial_size, ciphertext_modulus, ntru_noise_distribution, &mut encryption_generator);
+
+        input_message_list.iter_mut().zip(input_plaintext_list.iter_mut())
+            .for_each(|(msg, ptxt)| {
+            *(msg.0) = rand::thread_rng().gen_range(0..message_modulus);
+            *(ptxt.0) = *(msg.0) * delta;
+        });
+
+        encrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &mut ntru_ciphertext,
+            &input_plaintext_list,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+        decrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &ntru_ciphertext,
+            &mut decrypted_plaintext_list,
+        );
+
+        let mut max_err = Scalar::ZERO;
+        let mut num_fail = 0;
+        decrypted_plaintext_list.iter().zip(input_message_list.iter())
+            .for_each(|(decrypted, msg)| {
+                let correct_val = *msg.0 * delta;
+                let d0 = decrypted.0.wrapping_sub_custom_mod(correct_val, modulus);
+                let d1 = correct_val.wrapping_sub_custom_mod(*decrypted.0, modulus);
+                let err = std::cmp::min(d0, d1);
+                max_err = std::cmp::max(max_err, err);
+
+                if err >= delta / 2 {
+                    num_fail += 1;
+                }
+            });
+
+        println!("[Test {idx}] Max error: {:.3} bits, fail: {num_fail}", (max_err as f64).log2());
+        assert_eq!(num_fail, 0);
+    }
+}
+
+pub fn test_odd_prime_modulus_check() {
+    assert!(is_odd_prime(PRIME_MODULUS as u64));
+    // 2^61 - 1 is a Mersenne prime
+    assert!(is_odd_prime((1u64 << 61) - 1));
+    // Product of two primes close to 2^22, and a Carmichael number
+    assert!(!is_odd_prime(4194301 * 4194287));
+    assert!(!is_odd_prime(561));
+    // The largest 64-bit prime
+    assert!(is_odd_prime(u64::MAX - 58));
+    assert!(std::panic::catch_unwind(|| is_odd_prime_modulus(1u128 << 64 | 1)).is_err());
+    assert!(!is_odd_prime(PRIME_MODULUS as u64 + 2));
+
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new(4194301 * 4194287).unwrap();
+    let keygen_result = std::panic::catch_unwind(|| {
+        let mut seeder = new_seeder();
+        let seeder = seeder.as_mut();
+        let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+        allocate_and_generate_new_gaussian_ntru_secret_key(
+            PolynomialSize(1024),
+            ciphertext_modulus,
+            Gaussian::from_dispersion_parameter(StandardDev(1e-10), 0.0),
+            &mut encryption_generator,
+        )
+    });
+    assert!(keygen_result.is_err(), "Key generation should reject a composite odd modulus");
+}
+
+pub fn main() {
+    test_odd_prime_modulus_check();
+
+    let param_list = [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3];
+    for param in param_list {
+        param.print_info();
+        test_ntru_encryption_mod_prime(param);
+        println!();
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use rand::Rng;
 use tfhe::core_crypto::prelude::*;
 use tfhe::ntru::algorithms::*;
@@ -0,0 +1,162 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::core_crypto::prelude::polynomial_algorithms::polynomial_wrapping_monic_monomial_mul_assign;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+
+mod utils;
+use utils::*;
+
+type Scalar = u64;
+
+pub fn test_ntru_linear_algebra(
+    param: NtruCMuxParameters,
+    pk_decomp_base_log: DecompositionBaseLog,
+) {
+    let log_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    let ntru_public_key = allocate_and_generate_new_ntru_public_key(
+        &ntru_secret_key,
+        pk_decomp_base_log,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_modulus - log_message_modulus);
+
+    // Messages are kept as unreduced integers: the error check reduces them on the torus
+    let ciphertext_count = 4;
+    let plaintext_count = PlaintextCount(polynomial_size.0 * ciphertext_count);
+    let mut lhs_message_list = PlaintextList::new(Scalar::ZERO, plaintext_count);
+    let mut rhs_message_list = PlaintextList::new(Scalar::ZERO, plaintext_count);
+    lhs_message_list.iter_mut().chain(rhs_message_list.iter_mut())
+        .for_each(|msg| *(msg.0) = rand::thread_rng().gen_range(0..message_modulus));
+
+    let mut lhs_plaintext_list = lhs_message_list.clone();
+    let mut rhs_plaintext_list = rhs_message_list.clone();
+    lhs_plaintext_list.iter_mut().chain(rhs_plaintext_list.iter_mut())
+        .for_each(|ptxt| *(ptxt.0) *= delta);
+
+    let mut lhs_list = NtruCiphertextList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        NtruCiphertextCount(ciphertext_count),
+        ciphertext_modulus,
+    );
+    let mut rhs_list = lhs_list.clone();
+
+    encrypt_ntru_ciphertext_list(
+        &ntru_secret_key,
+        &mut lhs_list,
+        &lhs_plaintext_list,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    par_encrypt_ntru_ciphertext_list(
+        &ntru_secret_key,
+        &mut rhs_list,
+        &rhs_plaintext_list,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let mut decrypted_list = PlaintextList::new(Scalar::ZERO, plaintext_count);
+    for (ntru_list, message_list) in [(&lhs_list, &lhs_message_list), (&rhs_list, &rhs_message_list)] {
+        decrypt_ntru_ciphertext_list(&ntru_secret_key, ntru_list, &mut decrypted_list);
+        let max_err = get_max_error(&decrypted_list, message_list, torus_scaling, delta);
+        println!("[List] max error: {:.3} bits", (max_err as f64).log2());
+        assert!(max_err < delta / 2);
+    }
+
+    let mut output = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut expected_message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    let mut check = |name: &str, output: &NtruCiphertextOwned<Scalar>, expected: &PlaintextListOwned<Scalar>| {
+        decrypt_ntru_ciphertext(&ntru_secret_key, output, &mut decrypted_plaintext_list);
+        let max_err = get_max_error(&decrypted_plaintext_list, expected, torus_scaling, delta);
+        println!("[{name}] max error: {:.3} bits", (max_err as f64).log2());
+        assert!(max_err < delta / 2, "{name} failed");
+    };
+
+    for (((lhs, rhs), lhs_msg), rhs_msg) in lhs_list.iter()
+        .zip(rhs_list.iter())
+        .zip(lhs_message_list.chunks_exact(polynomial_size.0))
+        .zip(rhs_message_list.chunks_exact(polynomial_size.0))
+    {
+        let rhs_ptxt = PlaintextList::from_container(
+            rhs_msg.iter().map(|m| *m.0 * delta).collect::<Vec<_>>(),
+        );
+
+        ntru_ciphertext_add(&mut output, &lhs, &rhs);
+        expected_message_list.iter_mut().zip(lhs_msg.iter().zip(rhs_msg.iter()))
+            .for_each(|(e, (l, r))| *e.0 = l.0.wrapping_add(*r.0));
+        check("add", &output, &expected_message_list);
+
+        ntru_ciphertext_sub(&mut output, &lhs, &rhs);
+        expected_message_list.iter_mut().zip(lhs_msg.iter().zip(rhs_msg.iter()))
+            .for_each(|(e, (l, r))| *e.0 = l.0.wrapping_sub(*r.0));
+        check("sub", &output, &expected_message_list);
+
+        output.as_mut().copy_from_slice(lhs.as_ref());
+        ntru_ciphertext_opposite_assign(&mut output);
+        expected_message_list.iter_mut().zip(lhs_msg.iter())
+            .for_each(|(e, l)| *e.0 = l.0.wrapping_neg());
+        check("opposite", &output, &expected_message_list);
+
+        let cleartext = rand::thread_rng().gen_range(0..message_modulus);
+        ntru_ciphertext_cleartext_mul(&mut output, &lhs, Cleartext(cleartext));
+        expected_message_list.iter_mut().zip(lhs_msg.iter())
+            .for_each(|(e, l)| *e.0 = l.0.wrapping_mul(cleartext));
+        check("cleartext mul", &output, &expected_message_list);
+
+        output.as_mut().copy_from_slice(lhs.as_ref());
+        ntru_ciphertext_plaintext_list_add_assign(&mut output, &rhs_ptxt, &ntru_public_key);
+        expected_message_list.iter_mut().zip(lhs_msg.iter().zip(rhs_msg.iter()))
+            .for_each(|(e, (l, r))| *e.0 = l.0.wrapping_add(*r.0));
+        check("plaintext add", &output, &expected_message_list);
+
+        output.as_mut().copy_from_slice(lhs.as_ref());
+        ntru_ciphertext_plaintext_list_sub_assign(&mut output, &rhs_ptxt, &ntru_public_key);
+        expected_message_list.iter_mut().zip(lhs_msg.iter().zip(rhs_msg.iter()))
+            .for_each(|(e, (l, r))| *e.0 = l.0.wrapping_sub(*r.0));
+        check("plaintext sub", &output, &expected_message_list);
+
+        let monomial_degree = MonomialDegree(rand::thread_rng().gen_range(0..2 * polynomial_size.0));
+        output.as_mut().copy_from_slice(lhs.as_ref());
+        ntru_ciphertext_monic_monomial_mul_assign(&mut output, monomial_degree);
+        expected_message_list.as_mut().copy_from_slice(lhs_msg.as_ref());
+        polynomial_wrapping_monic_monomial_mul_assign(&mut expected_message_list.as_mut_polynomial(), monomial_degree);
+        check("monomial mul", &output, &expected_message_list);
+
+        ntru_ciphertext_monic_monomial_div_assign(&mut output, monomial_degree);
+        assert_eq!(output.as_ref(), lhs.as_ref());
+    }
+}
+
+pub fn main() {
+    let param_list = [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3];
+    for param in param_list {
+        param.print_info();
+        test_ntru_linear_algebra(param, DecompositionBaseLog(9));
+        println!();
+    }
+}
@@ -0,0 +1,342 @@
+#![allow(deprecated)]
+
+use rand::Rng;
+use tfhe::core_crypto::prelude::slice_algorithms::*;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use tfhe::ntru::noise_formulas::*;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+mod utils;
+use utils::*;
+
+const NUM_TEST: usize = 10;
+
+// The measured std dev should not exceed the predicted one, and the prediction should stay tight
+const MAX_OVERESTIMATION_BITS: f64 = 1.0;
+const MAX_UNDERESTIMATION_BITS: f64 = 0.5;
+
+fn check_prediction(label: &str, measured_variance: f64, predicted_variance: Variance, log_modulus: usize) {
+    let measured_bits = measured_variance.sqrt().log2();
+    let predicted_bits = predicted_variance.get_log_standard_dev().0 + log_modulus as f64;
+
+    println!("\t[{label}] measured std dev: {measured_bits:.3} bits | predicted std dev: {predicted_bits:.3} bits");
+
+    assert!(
+        measured_bits <= predicted_bits + MAX_UNDERESTIMATION_BITS,
+        "[{label}] The noise is underestimated: measured {measured_bits:.3} bits, predicted {predicted_bits:.3} bits",
+    );
+    assert!(
+        measured_bits >= predicted_bits - MAX_OVERESTIMATION_BITS,
+        "[{label}] The noise is overestimated: measured {measured_bits:.3} bits, predicted {predicted_bits:.3} bits",
+    );
+}
+
+pub fn test_external_product_noise(
+    polynomial_size: PolynomialSize,
+    log_modulus: usize,
+    ntru_std_dev: f64,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+) {
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let torus_ntru_std_dev = ntru_std_dev / 2.0.powi(log_modulus as i32);
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(torus_ntru_std_dev), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(polynomial_size, ciphertext_modulus, ntru_noise_distribution, &mut encryption_generator);
+
+    let mut plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    let mut ntru_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let mut ngsw_ciphertext = NgswCiphertext::new(Scalar::ZERO, polynomial_size, decomp_base_log, decomp_level_count, ciphertext_modulus);
+    let mut fourier_ngsw_ciphertext = FourierNgswCiphertext::new(polynomial_size, decomp_base_log, decomp_level_count, fft_type);
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+
+    let mut computation_buffers = ComputationBuffers::new();
+    computation_buffers.resize(
+        add_ntru_external_product_assign_scratch::<Scalar>(polynomial_size, fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = computation_buffers.stack();
+
+    let mut sum_variance = 0f64;
+    for _ in 0..NUM_TEST {
+        encrypt_constant_ngsw_ciphertext(
+            &ntru_secret_key,
+            &mut ngsw_ciphertext,
+            Cleartext(Scalar::ONE),
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        );
+        convert_standard_ngsw_ciphertext_to_fourier(&ngsw_ciphertext, &mut fourier_ngsw_ciphertext);
+
+        for elt in plaintext_list.as_mut().iter_mut() {
+            *elt = rand::thread_rng().gen_range(0..Scalar::ONE << log_modulus);
+        }
+
+        encrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &mut ntru_ciphertext,
+            &plaintext_list,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let mut output = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+        add_ntru_external_product_assign(
+            &mut output.as_mut_view(),
+            fourier_ngsw_ciphertext.as_view(),
+            ntru_ciphertext.as_view(),
+            fft,
+            stack,
+        );
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &output, &mut decrypted_plaintext_list);
+
+        sum_variance += get_error_variance(&decrypted_plaintext_list, &plaintext_list, torus_scaling, Scalar::ONE);
+    }
+
+    let predicted_variance = Variance(
+        torus_ntru_std_dev * torus_ntru_std_dev
+            + ngsw_external_product_additive_variance(
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+                CiphertextModulusLog(log_modulus),
+                Variance(torus_ntru_std_dev * torus_ntru_std_dev),
+                ntru_std_dev * ntru_std_dev,
+                1.0,
+                fft_type,
+            ).0
+    );
+
+    check_prediction("External product", sum_variance / NUM_TEST as f64, predicted_variance, log_modulus);
+}
+
+pub fn test_ntru_cmux_cbs_noise(
+    param: NtruCMuxParameters,
+    log_lut_count: LutCountLog,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut secret_generator,
+    );
+    let rlwe_sk_poly = rlwe_secret_key.as_polynomial_list();
+    let rlwe_sk_poly = rlwe_sk_poly.get(0);
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let ntru_cmux_cbs_key = par_allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+        ntru_cmux_cbs_key.glwe_size(),
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        fft_type,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier(&ntru_cmux_cbs_key, &mut fourier_ntru_cmux_cbs_key);
+
+    let noise = ntru_cmux_circuit_bootstrap_noise(
+        &param,
+        log_lut_count,
+        fft_type,
+        fft_type,
+        fft_type,
+        fft_type,
+        fft_type,
+    );
+    noise.print_info();
+
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut correct_val_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let zero = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    let mut sum_ks_variance = 0f64;
+    let mut sum_ss_variance = 0f64;
+    for _ in 0..NUM_TEST {
+        let msg_bit = rand::thread_rng().gen_range(0..2) as SmallScalar;
+        let mut input_lwe = LweCiphertext::new(SmallScalar::ZERO, lwe_dimension.to_lwe_size(), small_ciphertext_modulus);
+        encrypt_lwe_ciphertext(
+            &lwe_secret_key,
+            &mut input_lwe,
+            Plaintext(msg_bit << (log_input_modulus - 1)),
+            lwe_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let mut output_rgsw = GgswCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, decomp_base_log, decomp_level_count, ciphertext_modulus);
+        ntru_cmux_circuit_bootstrap_lwe_ciphertext(
+            &input_lwe,
+            &mut output_rgsw,
+            &fourier_ntru_cmux_cbs_key,
+            log_lut_count,
+        );
+
+        for (k, rgsw_level_matrix) in output_rgsw.iter().enumerate() {
+            let factor = if msg_bit == SmallScalar::ONE {
+                Scalar::ONE << (Scalar::BITS as usize - (decomp_level_count.0 - k) * decomp_base_log.0)
+            } else {
+                Scalar::ZERO
+            };
+            let rlwe_list = rgsw_level_matrix.as_glwe_list();
+
+            // The first row encrypts -S * m * q / B^k, the output of the scheme switching
+            let mut rlwe = GlweCiphertext::new(Scalar::ZERO, GlweSize(2), polynomial_size, ciphertext_modulus);
+            rlwe.as_mut().clone_from_slice(rlwe_list.get(0).as_ref());
+            let mut rlwe_body = rlwe.get_mut_body();
+            let mut rlwe_body = rlwe_body.as_mut_polynomial();
+            slice_wrapping_add_scalar_mul_assign(rlwe_body.as_mut(), rlwe_sk_poly.as_ref(), factor);
+
+            decrypt_glwe_ciphertext(&rlwe_secret_key, &rlwe, &mut decrypted_plaintext_list);
+            sum_ss_variance += get_error_variance(&decrypted_plaintext_list, &zero, torus_scaling, Scalar::ONE);
+
+            // The second row encrypts m * q / B^k, the output of the NTRU-to-GLWE keyswitching
+            decrypt_glwe_ciphertext(&rlwe_secret_key, &rlwe_list.get(1), &mut decrypted_plaintext_list);
+            correct_val_list.as_mut()[0] = factor / torus_scaling;
+            sum_ks_variance += get_error_variance(&decrypted_plaintext_list, &correct_val_list, torus_scaling, Scalar::ONE);
+        }
+    }
+
+    let sample_count = (NUM_TEST * decomp_level_count.0) as f64;
+    check_prediction("NTRU-to-GLWE keyswitch", sum_ks_variance / sample_count, noise.keyswitch_variance, log_output_modulus);
+    check_prediction("Scheme switch", sum_ss_variance / sample_count, noise.scheme_switch_variance, log_output_modulus);
+}
+
+pub fn main() {
+    let polynomial_size = PolynomialSize(2048);
+    let param_list = [
+        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), FftType::Vanilla),
+        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), FftType::Split(20)),
+        (polynomial_size, 39, 2.96, DecompositionBaseLog(12), DecompositionLevelCount(2), FftType::SplitN(13, 3)),
+        (polynomial_size, 45, 23.0, DecompositionBaseLog(13), DecompositionLevelCount(2), FftType::Vanilla),
+        (polynomial_size, 45, 23.0, DecompositionBaseLog(10), DecompositionLevelCount(3), FftType::Vanilla),
+    ];
+    for (polynomial_size, log_modulus, ntru_std_dev, decomp_base_log, decomp_level_count, fft_type) in param_list {
+        println!(
+            "N: {}, Q: 2^{}, std_dev: {}, B: 2^{}, l: {}, FftType: {:?}",
+            polynomial_size.0,
+            log_modulus,
+            ntru_std_dev,
+            decomp_base_log.0,
+            decomp_level_count.0,
+            fft_type,
+        );
+        test_external_product_noise(polynomial_size, log_modulus, ntru_std_dev, decomp_base_log, decomp_level_count, fft_type);
+    }
+    println!();
+
+    // At Q = 2^63 the vanilla FFT error dominates the external product noise, a split FFT brings
+    // it back below the key noise
+    let (log_modulus, decomp_base_log, decomp_level_count) = (63, DecompositionBaseLog(12), DecompositionLevelCount(4));
+    let select_fft_type = |log2_max_variance| select_ntru_fft_type(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        1,
+        CiphertextModulusLog(log_modulus),
+        Variance(2.0f64.powi(log2_max_variance)),
+    ).unwrap();
+
+    let fft_type = select_fft_type(-80);
+    println!("Selected FftType for Q: 2^{log_modulus}, B: 2^{}, l: {}: {fft_type:?}", decomp_base_log.0, decomp_level_count.0);
+    assert_eq!(fft_type.num_split(), 2);
+    test_external_product_noise(polynomial_size, log_modulus, 2.96, decomp_base_log, decomp_level_count, fft_type);
+
+    // The least significant part of a two-way split cannot go below the rounding grid as long as
+    // the most significant one is exact
+    assert_eq!(select_fft_type(-120).num_split(), 3);
+    println!();
+
+    let param_list = [
+        (NTRU_CMUX_STD128B2, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+        (NTRU_CMUX_STD128B3, LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4), FftType::Vanilla),
+    ];
+    for (param, log_lut_count, decomp_base_log, decomp_level_count, fft_type) in param_list {
+        param.print_info();
+        test_ntru_cmux_cbs_noise(param, log_lut_count, decomp_base_log, decomp_level_count, fft_type);
+        println!();
+    }
+}
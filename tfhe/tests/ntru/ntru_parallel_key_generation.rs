@@ -0,0 +1,172 @@
+use rand::Rng;
+use tfhe::core_crypto::commons::generators::DeterministicSeeder;
+use tfhe::core_crypto::commons::math::random::Seed;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+// NTT-friendly prime 2^45 - 204799, q = 1 mod 2^13
+const PRIME_MODULUS: u128 = 35184371884033;
+
+fn new_encryption_generator(mask_seed: u128, noise_seed: u128) -> EncryptionRandomGenerator<DefaultRandomGenerator> {
+    let mut noise_seeder = DeterministicSeeder::<DefaultRandomGenerator>::new(Seed(noise_seed));
+    EncryptionRandomGenerator::<DefaultRandomGenerator>::new(Seed(mask_seed), &mut noise_seeder)
+}
+
+pub fn test_ntru_parallel_cbs_key_generation(param: NtruCMuxParameters) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        param.input_lwe_dimension(),
+        &mut secret_generator,
+    );
+
+    let mask_seed = rand::thread_rng().gen::<u128>();
+    let noise_seed = rand::thread_rng().gen::<u128>();
+
+    let now = Instant::now();
+    let ntru_cmux_cbs_key = allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut new_encryption_generator(mask_seed, noise_seed),
+    );
+    let time = now.elapsed();
+
+    let now = Instant::now();
+    let par_ntru_cmux_cbs_key = par_allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut new_encryption_generator(mask_seed, noise_seed),
+    );
+    let par_time = now.elapsed();
+
+    println!(
+        "CBS key generation: {:.3} ms (sequential) | {:.3} ms (parallel)",
+        (time.as_micros() as f64) / 1000f64,
+        (par_time.as_micros() as f64) / 1000f64,
+    );
+    assert_eq!(ntru_cmux_cbs_key, par_ntru_cmux_cbs_key);
+}
+
+pub fn test_ntru_parallel_key_generation_mod_prime(param: NtruCMuxParameters) {
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new(PRIME_MODULUS).unwrap();
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        param.input_lwe_dimension(),
+        &mut secret_generator,
+    );
+
+    let mask_seed = rand::thread_rng().gen::<u128>();
+    let noise_seed = rand::thread_rng().gen::<u128>();
+
+    let ntru_cmux_bsk = allocate_and_generate_new_ntru_cmux_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        ntru_noise_distribution,
+        ciphertext_modulus,
+        &mut new_encryption_generator(mask_seed, noise_seed),
+    );
+
+    let par_ntru_cmux_bsk = par_allocate_and_generate_new_ntru_cmux_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        ntru_noise_distribution,
+        ciphertext_modulus,
+        &mut new_encryption_generator(mask_seed, noise_seed),
+    );
+    assert_eq!(ntru_cmux_bsk, par_ntru_cmux_bsk);
+
+    println!("Odd prime modulus: sequential and parallel keys match");
+}
+
+pub fn main() {
+    let param_list = [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3];
+    for param in param_list {
+        param.print_info();
+        test_ntru_parallel_cbs_key_generation(param);
+        test_ntru_parallel_key_generation_mod_prime(param);
+        println!();
+    }
+}
@@ -0,0 +1,164 @@
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+const TARGET_SECURITY_BITS: f64 = 128.0;
+
+fn modified_param(
+    polynomial_size: PolynomialSize,
+    ntru_std_dev: f64,
+    lwe_std_dev: f64,
+    br_decomp_base_log: DecompositionBaseLog,
+    br_decomp_level_count: DecompositionLevelCount,
+) -> NtruCMuxParameters {
+    let param = NTRU_CMUX_STD128B2;
+
+    NtruCMuxParameters::new(
+        "MODIFIED",
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.log_output_modulus(),
+        param.log_input_modulus(),
+        ntru_std_dev,
+        param.rlwe_std_dev(),
+        lwe_std_dev,
+        br_decomp_base_log,
+        br_decomp_level_count,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+    )
+}
+
+fn test_valid_param(param: NtruCMuxParameters) {
+    param.print_info();
+
+    assert_eq!(param.validate(), Ok(()));
+    assert_eq!(param.validate_security(TARGET_SECURITY_BITS), Ok(()));
+    assert_eq!(
+        param.validate_circuit_bootstrap(LutCountLog(2), DecompositionBaseLog(3), DecompositionLevelCount(4)),
+        Ok(()),
+    );
+
+    let report = param.security_report();
+    assert!(report.ntru_security_bits >= TARGET_SECURITY_BITS);
+    assert!(report.ntru_dense_sublattice_security_bits >= TARGET_SECURITY_BITS);
+    assert!(report.rlwe_security_bits >= TARGET_SECURITY_BITS);
+    assert!(report.lwe_security_bits >= TARGET_SECURITY_BITS);
+}
+
+fn test_invalid_param() {
+    let param = NTRU_CMUX_STD128B2;
+
+    let invalid = modified_param(PolynomialSize(2000), param.ntru_std_dev(), param.lwe_std_dev(), param.br_decomp_base_log(), param.br_decomp_level_count());
+    assert_eq!(
+        invalid.validate(),
+        Err(NtruCMuxParametersError::PolynomialSizeNotPowerOfTwo(PolynomialSize(2000))),
+    );
+
+    let invalid = modified_param(param.polynomial_size(), param.ntru_std_dev(), param.lwe_std_dev(), DecompositionBaseLog(16), DecompositionLevelCount(3));
+    assert!(matches!(
+        invalid.validate(),
+        Err(NtruCMuxParametersError::InvalidDecomposition { name: "Blind rotation", .. }),
+    ));
+
+    let invalid = modified_param(param.polynomial_size(), param.ntru_std_dev(), 0.0, param.br_decomp_base_log(), param.br_decomp_level_count());
+    assert!(matches!(
+        invalid.validate(),
+        Err(NtruCMuxParametersError::NonPositiveStdDev { name: "LWE", .. }),
+    ));
+
+    // Input LWE ciphertexts modulo 2^12 cannot be switched to 2N = 2^13
+    let invalid = modified_param(PolynomialSize(4096), param.ntru_std_dev(), param.lwe_std_dev(), param.br_decomp_base_log(), param.br_decomp_level_count());
+    assert!(matches!(
+        invalid.validate(),
+        Err(NtruCMuxParametersError::InputModulusTooSmall { .. }),
+    ));
+
+    assert!(matches!(
+        param.validate_circuit_bootstrap(LutCountLog(3), DecompositionBaseLog(3), DecompositionLevelCount(4)),
+        Err(NtruCMuxParametersError::InvalidLutCount { .. }),
+    ));
+    assert!(matches!(
+        param.validate_circuit_bootstrap(LutCountLog(2), DecompositionBaseLog(12), DecompositionLevelCount(4)),
+        Err(NtruCMuxParametersError::InvalidDecomposition { name: "Circuit bootstrapping", .. }),
+    ));
+}
+
+fn test_insecure_param() {
+    let param = NTRU_CMUX_STD128B2;
+
+    let insecure = modified_param(param.polynomial_size(), param.ntru_std_dev(), 0.25, param.br_decomp_base_log(), param.br_decomp_level_count());
+    insecure.print_info();
+    assert_eq!(insecure.validate(), Ok(()));
+    assert!(matches!(
+        insecure.security_report().check(TARGET_SECURITY_BITS),
+        Err(NtruCMuxParametersError::Insecure { name: "LWE", .. }),
+    ));
+
+    // With a unit std dev, the modulus 2^45 is far above the fatigue point for N = 2048 and the
+    // dense sublattice attack breaks NTRU
+    let overstretched = modified_param(param.polynomial_size(), 1.0, param.lwe_std_dev(), param.br_decomp_base_log(), param.br_decomp_level_count());
+    overstretched.print_info();
+    let report = overstretched.security_report();
+    assert!(report.is_ntru_overstretched());
+    assert!(report.ntru_dense_sublattice_security_bits < report.ntru_security_bits);
+    assert!(matches!(
+        overstretched.validate_security(TARGET_SECURITY_BITS),
+        Err(NtruCMuxParametersError::Insecure { name: "NTRU dense sublattice", .. }),
+    ));
+
+    test_keygen_rejects_param(&insecure);
+    test_keygen_rejects_param(&overstretched);
+}
+
+fn test_keygen_rejects_param(param: &NtruCMuxParameters) {
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(param.log_output_modulus().0).unwrap();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        param.polynomial_size(),
+        ciphertext_modulus,
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0),
+        &mut encryption_generator,
+    );
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        param.polynomial_size(),
+        &mut secret_generator,
+    );
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        param.input_lwe_dimension(),
+        &mut secret_generator,
+    );
+
+    let result = allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key_from_parameters(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &glwe_secret_key,
+        param,
+        TARGET_SECURITY_BITS,
+        &mut encryption_generator,
+    );
+    assert_eq!(result.err(), param.validate_security(TARGET_SECURITY_BITS).err());
+    assert!(result.is_err());
+}
+
+fn main() {
+    for param in [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3] {
+        test_valid_param(param);
+    }
+
+    test_invalid_param();
+    test_insecure_param();
+}
@@ -0,0 +1,177 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+fn decode(decrypted: Scalar, delta: Scalar, torus_scaling: Scalar, full_message_modulus: u64) -> u64 {
+    let scaled_delta = delta.wrapping_mul(torus_scaling);
+    let scaled_decrypted = decrypted.wrapping_mul(torus_scaling);
+    let rounding = (scaled_decrypted & (scaled_delta >> 1)) << 1;
+    (scaled_decrypted.wrapping_add(rounding) / scaled_delta) % full_message_modulus
+}
+
+pub fn test_ntru_programmable_bootstrap_lut(param: NtruCMuxParameters, log_lut_count: LutCountLog) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    let large_lwe_secret_key = ntru_secret_key.clone().into_lwe_secret_key();
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let decomp_base_log = param.br_decomp_base_log();
+    let decomp_level_count = param.br_decomp_level_count();
+
+    let ntru_cmux_bsk = allocate_and_generate_new_ntru_cmux_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        decomp_base_log,
+        decomp_level_count,
+        ntru_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_bsk = FourierNtruCMuxBootstrapKey::new(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        decomp_base_log,
+        decomp_level_count,
+        lwe_dimension,
+        FftType::Vanilla,
+        FftType::Vanilla,
+    );
+    convert_standard_ntru_cmux_bootstrap_key_to_fourier(&ntru_cmux_bsk, &mut fourier_ntru_cmux_bsk);
+
+    // 2 bits of message and 1 bit of carry
+    let message_modulus = 4usize;
+    let carry_modulus = 2usize;
+    let full_message_modulus = (message_modulus * carry_modulus) as u64;
+    let log_full_message_modulus = full_message_modulus.ilog2() as usize;
+    let delta = Scalar::ONE << (log_output_modulus - 1 - log_full_message_modulus);
+    let small_delta = SmallScalar::ONE << (log_input_modulus - 1 - log_full_message_modulus);
+
+    let square = |x: u64| x * x;
+    let double_msg = |x: u64| (2 * x) % message_modulus as u64;
+    let carry = |x: u64| x / message_modulus as u64;
+    let functions: [&dyn Fn(u64) -> u64; 3] = [&square, &double_msg, &carry];
+
+    let acc = generate_programmable_bootstrap_ntru_lut(
+        polynomial_size,
+        message_modulus,
+        carry_modulus,
+        ciphertext_modulus,
+        square,
+    );
+
+    let acc_many = generate_programmable_bootstrap_ntru_lut_many(
+        polynomial_size,
+        message_modulus,
+        carry_modulus,
+        ciphertext_modulus,
+        log_lut_count,
+        &functions,
+    );
+
+    let mut lwe_out = LweCiphertext::new(
+        Scalar::ZERO,
+        ntru_cmux_bsk.output_lwe_dimension().to_lwe_size(),
+        ciphertext_modulus,
+    );
+    let mut lwe_out_list = LweCiphertextList::new(
+        Scalar::ZERO,
+        ntru_cmux_bsk.output_lwe_dimension().to_lwe_size(),
+        LweCiphertextCount(functions.len()),
+        ciphertext_modulus,
+    );
+
+    let num_test = 10;
+    for idx in 1..=num_test {
+        let input_message = rand::thread_rng().gen_range(0..full_message_modulus);
+
+        let mut lwe_in = LweCiphertext::new(SmallScalar::ZERO, lwe_dimension.to_lwe_size(), small_ciphertext_modulus);
+        encrypt_lwe_ciphertext(
+            &lwe_secret_key,
+            &mut lwe_in,
+            Plaintext(input_message as SmallScalar * small_delta),
+            lwe_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        ntru_cmux_bootstrap_lwe_ciphertext(
+            &lwe_in,
+            &mut lwe_out,
+            &acc,
+            &fourier_ntru_cmux_bsk,
+        );
+
+        let decoded = decode(
+            decrypt_lwe_ciphertext(&large_lwe_secret_key, &lwe_out).0,
+            delta,
+            torus_scaling,
+            full_message_modulus,
+        );
+        println!("[Test {idx}] input: {input_message}, square: {decoded}");
+        assert_eq!(decoded, square(input_message) % full_message_modulus);
+
+        ntru_cmux_bootstrap_lwe_ciphertext_lut_many(
+            &lwe_in,
+            &mut lwe_out_list,
+            &acc_many,
+            log_lut_count,
+            &fourier_ntru_cmux_bsk,
+        );
+
+        for (k, (lwe_out, f)) in lwe_out_list.iter().zip(functions.iter()).enumerate() {
+            let decoded = decode(
+                decrypt_lwe_ciphertext(&large_lwe_secret_key, &lwe_out).0,
+                delta,
+                torus_scaling,
+                full_message_modulus,
+            );
+            println!("\t[{k}] output: {decoded}");
+            assert_eq!(decoded, f(input_message) % full_message_modulus);
+        }
+    }
+}
+
+pub fn main() {
+    let param_list = [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3];
+    for param in param_list {
+        param.print_info();
+        test_ntru_programmable_bootstrap_lut(param, LutCountLog(2));
+        println!();
+    }
+}
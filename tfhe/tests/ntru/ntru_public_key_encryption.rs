@@ -0,0 +1,114 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+
+mod utils;
+use utils::*;
+
+type Scalar = u64;
+
+pub fn test_ntru_public_key_encryption(
+    param: NtruCMuxParameters,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    pk_decomp_base_log: DecompositionBaseLog,
+) {
+    let log_modulus = ciphertext_modulus.into_modulus_log().0;
+    let polynomial_size = param.polynomial_size();
+    let torus_scaling = if ciphertext_modulus.is_native_modulus() {
+        Scalar::ONE
+    } else {
+        ciphertext_modulus.get_power_of_two_scaling_to_native_torus()
+    };
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    // The torus std dev scaled to the native modulus is too large for a Gaussian secret key
+    let ntru_secret_key = if ciphertext_modulus.is_native_modulus() {
+        allocate_and_generate_new_binary_ntru_secret_key(polynomial_size, ciphertext_modulus, &mut secret_generator)
+    } else {
+        allocate_and_generate_new_gaussian_ntru_secret_key(polynomial_size, ciphertext_modulus, ntru_noise_distribution, &mut encryption_generator)
+    };
+    let ntru_public_key = allocate_and_generate_new_ntru_public_key(
+        &ntru_secret_key,
+        pk_decomp_base_log,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    assert_eq!(
+        ntru_public_key.decomposition_level_count().0 * pk_decomp_base_log.0,
+        log_modulus,
+    );
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_modulus - log_message_modulus);
+
+    let ciphertext_count = 10;
+    let mut input_message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0 * ciphertext_count));
+    let mut input_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0 * ciphertext_count));
+
+    input_message_list.iter_mut().zip(input_plaintext_list.iter_mut())
+        .for_each(|(msg, ptxt)| {
+        *(msg.0) = rand::thread_rng().gen_range(0..message_modulus);
+        *(ptxt.0) = *(msg.0) * delta;
+    });
+
+    let mut ntru_ciphertext_list = NtruCiphertextList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        NtruCiphertextCount(ciphertext_count),
+        ciphertext_modulus,
+    );
+
+    encrypt_ntru_ciphertext_list_with_public_key(
+        &ntru_public_key,
+        &mut ntru_ciphertext_list,
+        &input_plaintext_list,
+        ntru_noise_distribution,
+        &mut secret_generator,
+        &mut encryption_generator,
+    );
+
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    for (idx, (ntru_ciphertext, message_list)) in ntru_ciphertext_list.iter()
+        .zip(input_message_list.chunks_exact(polynomial_size.0))
+        .enumerate()
+    {
+        decrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &ntru_ciphertext,
+            &mut decrypted_plaintext_list,
+        );
+
+        let max_err = get_max_error(
+            &decrypted_plaintext_list,
+            &message_list,
+            torus_scaling,
+            delta,
+        );
+        println!("[Test {}] Max error: {:.3} bits", idx + 1, (max_err as f64).log2());
+        assert!(max_err < delta / 2);
+    }
+}
+
+pub fn main() {
+    let param_list = [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3];
+    for param in param_list {
+        param.print_info();
+
+        let log_modulus = param.log_output_modulus().0;
+        let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+        test_ntru_public_key_encryption(param, ciphertext_modulus, DecompositionBaseLog(9));
+        println!();
+
+        test_ntru_public_key_encryption(param, CiphertextModulus::new_native(), DecompositionBaseLog(8));
+        println!();
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use rand::Rng;
 use tfhe::core_crypto::prelude::*;
 use tfhe::ntru::algorithms::*;
@@ -85,6 +87,21 @@ pub fn test_ntru_rev_trace(param: NtruCMuxParameters, fft_type: FftType) {
         ciphertext_modulus,
     );
 
+    let mut ref_ntru_trace_ciphertext = ntru_trace_ciphertext.clone();
+    let mut ntru_auto_ciphertext = ntru_trace_ciphertext.clone();
+
+    // The reference trace reuses its buffers as the trace does, so that the timings compare the
+    // automorphisms only
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        automorphism_ntru_ciphertext_mem_optimized_requirement::<Scalar>(polynomial_size, fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+    let stack = buffers.stack();
+
     let num_test = 10;
     for idx in 1..=num_test {
         for i in 0..polynomial_size.0 {
@@ -123,11 +140,45 @@ pub fn test_ntru_rev_trace(param: NtruCMuxParameters, fft_type: FftType) {
             delta,
         );
 
+        // Reference trace with one standard-domain automorphism per level, key index 0 being
+        // X -> X^3 and the last one X -> X^{N+1}
+        let now = Instant::now();
+        ref_ntru_trace_ciphertext.as_mut().clone_from_slice(ntru_ciphertext.as_ref());
+        for key_idx in 0..fourier_ntru_trace_key.automorphism_key_count().0 {
+            ntru_ciphertext_round_by_2_assign(&mut ref_ntru_trace_ciphertext);
+            automorphism_ntru_ciphertext_mem_optimized(
+                &fourier_ntru_trace_key.get_automorphism_key(key_idx),
+                &ref_ntru_trace_ciphertext,
+                &mut ntru_auto_ciphertext,
+                fft,
+                stack,
+            );
+            ntru_ciphertext_add_assign(&mut ref_ntru_trace_ciphertext, &ntru_auto_ciphertext);
+        }
+        let ref_time = now.elapsed();
+
+        decrypt_ntru_ciphertext(
+            &ntru_secret_key,
+            &ref_ntru_trace_ciphertext,
+            &mut decrypted_plaintext_list,
+        );
+
+        let ref_max_err = get_max_error(
+            &decrypted_plaintext_list,
+            &correct_message_list,
+            ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+            delta,
+        );
+
         println!(
-            "[Test {idx}] time: {} µs, max error: {:.3} bits",
+            "[Test {idx}] time: {} µs, max error: {:.3} bits / reference time: {} µs, max error: {:.3} bits",
             time.as_micros(),
             (max_err as f64).log2(),
+            ref_time.as_micros(),
+            (ref_max_err as f64).log2(),
         );
+        assert!(max_err < delta / 2);
+        assert!(ref_max_err < delta / 2);
     }
 }
 
@@ -137,6 +188,7 @@ pub fn main() {
     let param_list = [
         (NTRU_CMUX_STD128B2, FftType::Vanilla),
         (NTRU_CMUX_STD128B3, FftType::Vanilla),
+        (NTRU_CMUX_STD128B2, FftType::Split(20)),
         ];
 
     for (param, fft_type) in param_list {
@@ -0,0 +1,199 @@
+#![allow(deprecated)]
+
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+mod utils;
+use utils::*;
+
+type Scalar = u64;
+
+const NUM_REPEAT: usize = 2;
+
+pub fn test_ntru_ring_packing(
+    param: NtruCMuxParameters,
+    trace_level_count: TraceLevelCount,
+    fft_type: FftType,
+    parallel_keygen: bool,
+) {
+    let log_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    // Only the automorphism keys of the required trace levels are generated
+    let now = Instant::now();
+    let ntru_trace_key = if parallel_keygen {
+        par_allocate_and_generate_new_partial_ntru_trace_key(
+            &ntru_secret_key,
+            trace_level_count,
+            param.tr_decomp_base_log(),
+            param.tr_decomp_level_count(),
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        )
+    } else {
+        allocate_and_generate_new_partial_ntru_trace_key(
+            &ntru_secret_key,
+            trace_level_count,
+            param.tr_decomp_base_log(),
+            param.tr_decomp_level_count(),
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        )
+    };
+    println!("Keygen time: {:.3} ms", (now.elapsed().as_micros() as f64) / 1000f64);
+    assert_eq!(ntru_trace_key.automorphism_key_count().0, trace_level_count.0);
+
+    let mut fourier_ntru_trace_key = FourierNtruTraceKey::new_partial(
+        polynomial_size,
+        trace_level_count,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_trace_key_to_fourier(&ntru_trace_key, &mut fourier_ntru_trace_key);
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_modulus - log_message_modulus);
+
+    let ntru_ciphertext_count = NtruCiphertextCount(1 << trace_level_count.0);
+    let slot_stride = 1 << trace_level_count.0;
+
+    let mut message_list = PlaintextList::new(
+        Scalar::ZERO,
+        PlaintextCount(ntru_ciphertext_count.0 * polynomial_size.0),
+    );
+    let mut plaintext_list = message_list.clone();
+    let mut correct_message_list = message_list.clone();
+    let mut decrypted_plaintext_list = message_list.clone();
+
+    let mut packed_message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut trace_message_list = packed_message_list.clone();
+    let mut decrypted_packed_plaintext_list = packed_message_list.clone();
+
+    let mut ntru_ciphertext_list = NtruCiphertextList::new(
+        Scalar::ZERO,
+        polynomial_size,
+        ntru_ciphertext_count,
+        ciphertext_modulus,
+    );
+    let mut unpacked_ntru_ciphertext_list = ntru_ciphertext_list.clone();
+    let mut ntru_packed_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let mut ntru_trace_ciphertext = ntru_packed_ciphertext.clone();
+
+    for idx in 1..=NUM_REPEAT {
+        message_list.iter_mut().zip(plaintext_list.iter_mut())
+            .for_each(|(msg, ptxt)| {
+                *msg.0 = rand::thread_rng().gen_range(0..message_modulus);
+                *ptxt.0 = *msg.0 * delta;
+            });
+
+        // Packed: coefficient j + 2^d * t holds the coefficient 2^d * t of m_j
+        // Unpacked: the j-th ciphertext holds the coefficients 2^d * t of m_j only
+        correct_message_list.as_mut().fill(Scalar::ZERO);
+        for (j, (msg, mut correct_msg)) in message_list.chunks_exact(polynomial_size.0)
+            .zip(correct_message_list.chunks_exact_mut(polynomial_size.0))
+            .enumerate()
+        {
+            for t in (0..polynomial_size.0).step_by(slot_stride) {
+                packed_message_list.as_mut()[j + t] = msg.as_ref()[t];
+                correct_msg.as_mut()[t] = msg.as_ref()[t];
+            }
+        }
+        trace_message_list.as_mut()
+            .copy_from_slice(&correct_message_list.as_ref()[..polynomial_size.0]);
+
+        encrypt_ntru_ciphertext_list(
+            &ntru_secret_key,
+            &mut ntru_ciphertext_list,
+            &plaintext_list,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let now = Instant::now();
+        partial_rev_trace_ntru_ciphertext(
+            &fourier_ntru_trace_key,
+            trace_level_count,
+            &ntru_ciphertext_list.get(0),
+            &mut ntru_trace_ciphertext,
+        );
+        let trace_time = now.elapsed();
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &ntru_trace_ciphertext, &mut decrypted_packed_plaintext_list);
+        let trace_max_err = get_max_error(&decrypted_packed_plaintext_list, &trace_message_list, torus_scaling, delta);
+
+        let now = Instant::now();
+        pack_ntru_ciphertext_list(
+            &fourier_ntru_trace_key,
+            &ntru_ciphertext_list,
+            &mut ntru_packed_ciphertext,
+        );
+        let pack_time = now.elapsed();
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &ntru_packed_ciphertext, &mut decrypted_packed_plaintext_list);
+        let pack_max_err = get_max_error(&decrypted_packed_plaintext_list, &packed_message_list, torus_scaling, delta);
+
+        let now = Instant::now();
+        unpack_ntru_ciphertext(
+            &fourier_ntru_trace_key,
+            &ntru_packed_ciphertext,
+            &mut unpacked_ntru_ciphertext_list,
+        );
+        let unpack_time = now.elapsed();
+
+        decrypt_ntru_ciphertext_list(&ntru_secret_key, &unpacked_ntru_ciphertext_list, &mut decrypted_plaintext_list);
+        let unpack_max_err = get_max_error(&decrypted_plaintext_list, &correct_message_list, torus_scaling, delta);
+
+        println!(
+            "[Test {idx}] partial trace: {:.3} ms, {:.3} bits / pack: {:.3} ms, {:.3} bits / unpack: {:.3} ms, {:.3} bits",
+            (trace_time.as_micros() as f64) / 1000f64,
+            (trace_max_err as f64).log2(),
+            (pack_time.as_micros() as f64) / 1000f64,
+            (pack_max_err as f64).log2(),
+            (unpack_time.as_micros() as f64) / 1000f64,
+            (unpack_max_err as f64).log2(),
+        );
+        assert!(trace_max_err < delta / 2);
+        assert!(pack_max_err < delta / 2);
+        assert!(unpack_max_err < delta / 2);
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, TraceLevelCount(2), FftType::Vanilla, false),
+        (NTRU_CMUX_STD128B2, TraceLevelCount(5), FftType::Split(20), true),
+    ];
+
+    for (param, trace_level_count, fft_type, parallel_keygen) in param_list {
+        param.print_info();
+        println!(
+            "Trace levels: {}, FFT: {:?}, parallel keygen: {}",
+            trace_level_count.0,
+            fft_type,
+            parallel_keygen,
+        );
+        test_ntru_ring_packing(param, trace_level_count, fft_type, parallel_keygen);
+        println!();
+    }
+}
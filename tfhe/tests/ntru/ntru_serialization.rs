@@ -0,0 +1,146 @@
+#![allow(deprecated)]
+
+use tfhe::core_crypto::prelude::*;
+use tfhe::named::Named;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use tfhe::safe_serialization::{safe_deserialize, safe_serialize};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tfhe_versionable::{Unversionize, Versionize};
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+const SIZE_LIMIT: u64 = 1 << 32;
+
+fn roundtrip<T>(object: &T) -> T
+where
+    T: Serialize + DeserializeOwned + Versionize + Unversionize + Named,
+{
+    let mut buffer = vec![];
+    safe_serialize(object, &mut buffer, SIZE_LIMIT).unwrap();
+    println!("{}: {} bytes", T::NAME, buffer.len());
+    safe_deserialize(buffer.as_slice(), SIZE_LIMIT).unwrap()
+}
+
+pub fn test_ntru_serialization(param: NtruCMuxParameters, fft_type: FftType) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        GlweDimension(1),
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        param.input_lwe_dimension(),
+        &mut secret_generator,
+    );
+
+    let deserialized_ntru_secret_key = roundtrip(&ntru_secret_key);
+    assert_eq!(ntru_secret_key, deserialized_ntru_secret_key);
+
+    let ntru_public_key = allocate_and_generate_new_ntru_public_key(
+        &ntru_secret_key,
+        DecompositionBaseLog(9),
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let deserialized_ntru_public_key = roundtrip(&ntru_public_key);
+    assert_eq!(ntru_public_key, deserialized_ntru_public_key);
+
+    let mut ntru_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let plaintext_list = PlaintextList::new(Scalar::ONE << (Scalar::BITS - 2), PlaintextCount(polynomial_size.0));
+    encrypt_ntru_ciphertext(
+        &ntru_secret_key,
+        &mut ntru_ciphertext,
+        &plaintext_list,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let deserialized_ntru_ciphertext = roundtrip(&ntru_ciphertext);
+    assert_eq!(ntru_ciphertext, deserialized_ntru_ciphertext);
+
+    let ntru_cmux_cbs_key = allocate_and_generate_new_ntru_cmux_circuit_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        &rlwe_secret_key,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        ntru_noise_distribution,
+        rlwe_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let deserialized_ntru_cmux_cbs_key = roundtrip(&ntru_cmux_cbs_key);
+    assert_eq!(ntru_cmux_cbs_key, deserialized_ntru_cmux_cbs_key);
+
+    let mut fourier_ntru_cmux_cbs_key = FourierNtruCMuxCircuitBootstrapKey::new(
+        ntru_cmux_cbs_key.glwe_size(),
+        polynomial_size,
+        param.input_lwe_dimension(),
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.br_decomp_base_log(),
+        param.br_decomp_level_count(),
+        fft_type,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+        param.ksk_decomp_base_log(),
+        param.ksk_decomp_level_count(),
+        fft_type,
+        param.ss_decomp_base_log(),
+        param.ss_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_cmux_circuit_bootstrap_key_to_fourier(&ntru_cmux_cbs_key, &mut fourier_ntru_cmux_cbs_key);
+
+    let deserialized_fourier_ntru_cmux_cbs_key: FourierNtruCMuxCircuitBootstrapKeyOwned = roundtrip(&fourier_ntru_cmux_cbs_key);
+    assert_eq!(fourier_ntru_cmux_cbs_key, deserialized_fourier_ntru_cmux_cbs_key);
+}
+
+pub fn main() {
+    let param_list = [NTRU_CMUX_STD128B2, NTRU_CMUX_STD128B3];
+    for param in param_list {
+        param.print_info();
+        for fft_type in [FftType::Vanilla, FftType::Split(20)] {
+            println!("FFT type: {fft_type:?}");
+            test_ntru_serialization(param, fft_type);
+        }
+        println!();
+    }
+}
@@ -0,0 +1,91 @@
+use rand::Rng;
+use std::time::{Duration, Instant};
+use tfhe::conformance::ParameterSetConformant;
+use tfhe::core_crypto::commons::math::random::Seed;
+use tfhe::shortint::atomic_pattern::{AtomicPattern, AtomicPatternServerKey};
+use tfhe::shortint::ciphertext::MaxDegree;
+use tfhe::shortint::parameters::ntru::PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN;
+use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use tfhe::shortint::{gen_keys, AtomicPatternKind, Ciphertext, CompressedServerKey, ServerKey};
+
+fn average_pbs_latency(server_key: &ServerKey, ct: &Ciphertext, num_pbs: u32) -> Duration {
+    let lut = server_key.generate_lookup_table(|x| x);
+
+    let now = Instant::now();
+    for _ in 0..num_pbs {
+        let _ = server_key.apply_lookup_table(ct, &lut);
+    }
+    now.elapsed() / num_pbs
+}
+
+pub fn main() {
+    let param = PARAM_MESSAGE_2_CARRY_2_NTRU_PBS_GAUSSIAN;
+    let msg_modulus = param.message_modulus().0;
+    let full_modulus = msg_modulus * param.carry_modulus().0;
+
+    let log2_p_fail = param.log2_p_fail();
+    println!("NTRU AP: log2 pfail {log2_p_fail:.3}");
+    assert!(log2_p_fail < -60.0);
+
+    // The NTRU atomic pattern is used through the shortint keys like the other atomic patterns
+    let (client_key, server_key) = gen_keys(param);
+    assert_eq!(server_key.atomic_pattern.kind(), AtomicPatternKind::Ntru);
+    assert!(server_key.is_conformant(&(
+        param.into(),
+        MaxDegree::from_msg_carry_modulus(param.message_modulus(), param.carry_modulus()),
+    )));
+
+    let AtomicPatternServerKey::Ntru(ntru_atomic_pattern) = &server_key.atomic_pattern else {
+        panic!("We know from parameters that AP is NTRU")
+    };
+
+    let lut_square = server_key.generate_lookup_table(|x| (x * x) % msg_modulus);
+    let many_lut = server_key.generate_many_lookup_table(&[&|x| x % msg_modulus, &|x| x / msg_modulus]);
+
+    let mut rng = rand::thread_rng();
+    let num_test = 10;
+    for idx in 1..=num_test {
+        let lhs = rng.gen_range(0..msg_modulus);
+        let rhs = rng.gen_range(0..msg_modulus);
+
+        let ct_lhs = client_key.encrypt(lhs);
+        let ct_rhs = client_key.encrypt(rhs);
+        assert_eq!(ct_lhs.atomic_pattern, AtomicPatternKind::Ntru);
+
+        let ct_add = server_key.unchecked_add(&ct_lhs, &ct_rhs);
+        assert_eq!(client_key.decrypt_message_and_carry(&ct_add), lhs + rhs);
+
+        let ct_square = server_key.apply_lookup_table(&ct_lhs, &lut_square);
+        assert_eq!(client_key.decrypt(&ct_square), (lhs * lhs) % msg_modulus);
+
+        let ct_many = server_key.apply_many_lookup_table(&ct_add, &many_lut);
+        assert_eq!(client_key.decrypt(&ct_many[0]), (lhs + rhs) % msg_modulus);
+        assert_eq!(client_key.decrypt(&ct_many[1]), (lhs + rhs) / msg_modulus);
+
+        let ct_mul = server_key.mul(&ct_lhs, &ct_rhs);
+        let decrypted_mul = client_key.decrypt(&ct_mul);
+        println!("[Test {idx}] {lhs} * {rhs} mod {msg_modulus}: {decrypted_mul}");
+        assert_eq!(decrypted_mul, (lhs * rhs) % msg_modulus);
+    }
+
+    // The features the NTRU atomic pattern does not support are rejected with an error
+    let ct = client_key.encrypt(1);
+    assert!(CompressedServerKey::try_new(&client_key).is_err());
+    assert!(server_key.checked_switch_modulus_and_compress(&ct).is_err());
+    assert!(server_key.checked_generate_oblivious_pseudo_random(Seed(0), 1).is_err());
+
+    // Compare with the standard atomic pattern for the same message space
+    let (std_client_key, std_server_key) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let num_pbs = 100;
+    let msg = rng.gen_range(0..full_modulus);
+    let ntru_latency = average_pbs_latency(&server_key, &client_key.encrypt(msg), num_pbs);
+    let std_latency = average_pbs_latency(&std_server_key, &std_client_key.encrypt(msg), num_pbs);
+
+    let ntru_bsk_size = bincode::serialized_size(&ntru_atomic_pattern.bootstrapping_key).unwrap();
+    let ntru_ksk_size = bincode::serialized_size(&ntru_atomic_pattern.key_switching_key).unwrap();
+    let std_key_size = bincode::serialized_size(&std_server_key).unwrap();
+
+    println!("NTRU AP: PBS {ntru_latency:?}, BSK {} kB, KSK {} kB", ntru_bsk_size / 1000, ntru_ksk_size / 1000);
+    println!("Standard AP: PBS {std_latency:?}, server key {} kB", std_key_size / 1000);
+}
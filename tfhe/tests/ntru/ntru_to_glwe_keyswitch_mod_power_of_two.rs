@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use rand::Rng;
 use tfhe::core_crypto::prelude::*;
 use tfhe::ntru::algorithms::*;
@@ -9,7 +11,8 @@ type Scalar = u64;
 mod utils;
 use utils::*;
 
-pub fn test_ntru_to_rlwe_keyswitch(
+pub fn test_ntru_to_glwe_keyswitch(
+    glwe_dimension: GlweDimension,
     polynomial_size: PolynomialSize,
     log_modulus: usize,
     ntru_std_dev: f64,
@@ -33,11 +36,11 @@ pub fn test_ntru_to_rlwe_keyswitch(
 
 
     let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(polynomial_size, ciphertext_modulus, ntru_noise_distribution, &mut encryption_generator);
-    let rlwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(GlweDimension(1), polynomial_size, &mut secret_generator);
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(glwe_dimension, polynomial_size, &mut secret_generator);
 
-    let ntru_to_rlwe_ksk = allocate_and_generate_new_ntru_to_rlwe_keyswitch_key(
+    let ntru_to_glwe_ksk = allocate_and_generate_new_ntru_to_glwe_keyswitch_key(
         &ntru_secret_key,
-        &rlwe_secret_key,
+        &glwe_secret_key,
         decomp_base_log,
         decomp_level_count,
         rlwe_noise_distribution,
@@ -45,15 +48,16 @@ pub fn test_ntru_to_rlwe_keyswitch(
         &mut encryption_generator,
     );
 
-    let mut fourier_ntru_to_rlwe_ksk = FourierNtruToRlweKeyswitchKey::new(
+    let mut fourier_ntru_to_glwe_ksk = FourierNtruToGlweKeyswitchKey::new(
+        glwe_dimension.to_glwe_size(),
         polynomial_size,
         decomp_base_log,
         decomp_level_count,
         fft_type,
     );
-    convert_standard_ntru_to_rlwe_keyswitch_key_to_fourier(
-        &ntru_to_rlwe_ksk,
-        &mut fourier_ntru_to_rlwe_ksk,
+    convert_standard_ntru_to_glwe_keyswitch_key_to_fourier(
+        &ntru_to_glwe_ksk,
+        &mut fourier_ntru_to_glwe_ksk,
     );
 
     let log_message_modulus = 4;
@@ -79,9 +83,9 @@ pub fn test_ntru_to_rlwe_keyswitch(
         ciphertext_modulus,
     );
 
-    let mut rlwe_ciphertext = GlweCiphertext::new(
+    let mut glwe_ciphertext = GlweCiphertext::new(
         Scalar::ZERO,
-        GlweSize(2),
+        glwe_dimension.to_glwe_size(),
         polynomial_size,
         ciphertext_modulus,
     );
@@ -103,16 +107,16 @@ pub fn test_ntru_to_rlwe_keyswitch(
         );
 
         let now = Instant::now();
-        keyswitch_ntru_to_rlwe(
-            &fourier_ntru_to_rlwe_ksk,
+        keyswitch_ntru_to_glwe(
+            &fourier_ntru_to_glwe_ksk,
             &ntru_ciphertext,
-            &mut rlwe_ciphertext,
+            &mut glwe_ciphertext,
         );
         let time = now.elapsed();
 
         decrypt_glwe_ciphertext(
-            &rlwe_secret_key,
-            &rlwe_ciphertext,
+            &glwe_secret_key,
+            &glwe_ciphertext,
             &mut decrypted_plaintext_list,
         );
 
@@ -133,23 +137,24 @@ pub fn test_ntru_to_rlwe_keyswitch(
 }
 
 pub fn main() {
-    let polynomial_size = PolynomialSize(2048);
-
     let param_list = [
-        (polynomial_size, 39, 2.96, 2.96, DecompositionBaseLog(8), DecompositionLevelCount(4), FftType::Vanilla),
-        (polynomial_size, 39, 2.96, 2.96, DecompositionBaseLog(8), DecompositionLevelCount(4), FftType::Split(20)),
+        (GlweDimension(1), PolynomialSize(2048), 39, 2.96, 2.96, DecompositionBaseLog(8), DecompositionLevelCount(4), FftType::Vanilla),
+        (GlweDimension(1), PolynomialSize(2048), 39, 2.96, 2.96, DecompositionBaseLog(8), DecompositionLevelCount(4), FftType::Split(20)),
+        (GlweDimension(2), PolynomialSize(1024), 39, 2.96, 2.96, DecompositionBaseLog(8), DecompositionLevelCount(4), FftType::Vanilla),
     ];
     for param in param_list {
-        let polynomial_size = param.0;
-        let log_modulus = param.1;
-        let ntru_std_dev = param.2;
-        let rlwe_std_dev = param.3;
-        let decomp_base_log = param.4;
-        let decomp_level_count = param.5;
-        let fft_type = param.6;
+        let glwe_dimension = param.0;
+        let polynomial_size = param.1;
+        let log_modulus = param.2;
+        let ntru_std_dev = param.3;
+        let rlwe_std_dev = param.4;
+        let decomp_base_log = param.5;
+        let decomp_level_count = param.6;
+        let fft_type = param.7;
 
         println!(
-            "N: {}, Q: 2^{}, ntru_std_dev: {} (= {} in torus), rlwe_std_dev: {} (= {} in torus), B: 2^{}, l: {}, FftType: {:?}",
+            "k: {}, N: {}, Q: 2^{}, ntru_std_dev: {} (= {} in torus), rlwe_std_dev: {} (= {} in torus), B: 2^{}, l: {}, FftType: {:?}",
+            glwe_dimension.0,
             polynomial_size.0,
             log_modulus,
             ntru_std_dev,
@@ -160,7 +165,8 @@ pub fn main() {
             decomp_level_count.0,
             fft_type,
         );
-        test_ntru_to_rlwe_keyswitch(
+        test_ntru_to_glwe_keyswitch(
+            glwe_dimension,
             polynomial_size,
             log_modulus,
             ntru_std_dev,
@@ -0,0 +1,232 @@
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use tfhe::ntru::noise_formulas::*;
+
+type Scalar = u64;
+type SmallScalar = u32;
+
+// The keyswitch noise has to stay far below the gate margin q/8, which requires a smaller LWE
+// noise than in the bootstrapping parameter sets, compensated by a larger LWE dimension
+const LWE_DIMENSION: LweDimension = LweDimension(630);
+const TORUS_LWE_STD_DEV: f64 = 6.103515625e-5; // 2^-14
+const KSK_DECOMP_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(1);
+const KSK_DECOMP_LEVEL_COUNT: DecompositionLevelCount = DecompositionLevelCount(18);
+
+fn encode_bool(bit: bool, log_modulus: usize) -> SmallScalar {
+    let eighth = SmallScalar::ONE << (log_modulus - 3);
+    if bit { eighth } else { eighth.wrapping_neg() }
+}
+
+fn decrypt_bool<KeyCont, CtCont>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<CtCont>,
+    torus_scaling: Scalar,
+) -> bool
+where
+    KeyCont: Container<Element = Scalar>,
+    CtCont: Container<Element = Scalar>,
+{
+    let decrypted = decrypt_lwe_ciphertext(lwe_secret_key, lwe_ciphertext).0.wrapping_mul(torus_scaling);
+    decrypted < Scalar::ONE << (Scalar::BITS - 1)
+}
+
+/// Return (lhs + rhs) * scalar + constant, the linear part of the boolean gates.
+fn linear_gate(
+    lhs: &LweCiphertextOwned<SmallScalar>,
+    rhs: &LweCiphertextOwned<SmallScalar>,
+    scalar: SmallScalar,
+    constant: SmallScalar,
+) -> LweCiphertextOwned<SmallScalar> {
+    let mut output = lhs.clone();
+    lwe_ciphertext_add_assign(&mut output, rhs);
+    lwe_ciphertext_cleartext_mul_assign(&mut output, Cleartext(scalar));
+    lwe_ciphertext_plaintext_add_assign(&mut output, Plaintext(constant));
+    output
+}
+
+pub fn test_ntru_to_lwe_keyswitch(param: NtruCMuxParameters) {
+    let log_output_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_output_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+
+    let log_input_modulus = param.log_input_modulus().0;
+    let small_ciphertext_modulus = CiphertextModulus::<SmallScalar>::try_new_power_of_2(log_input_modulus).unwrap();
+    let ksk_ciphertext_modulus = CiphertextModulus::<SmallScalar>::new_native();
+
+    let polynomial_size = param.polynomial_size();
+    let lwe_dimension = param.input_lwe_dimension();
+
+    let ksk_security_bits = lwe_primal_usvp_security_bits(
+        lwe_dimension.0,
+        polynomial_size.0 * KSK_DECOMP_LEVEL_COUNT.0,
+        CiphertextModulusLog(SmallScalar::BITS as usize),
+        TORUS_LWE_STD_DEV * 2.0f64.powi(SmallScalar::BITS as i32),
+        BINARY_SECRET_STD_DEV,
+    );
+    println!("KSK: {ksk_security_bits:.1} bits");
+    assert!(ksk_security_bits >= 128.0);
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let lwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_lwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    let large_lwe_secret_key = ntru_secret_key.clone().into_lwe_secret_key();
+
+    let lwe_secret_key: LweSecretKeyOwned<SmallScalar> = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut secret_generator,
+    );
+
+    let decomp_base_log = param.br_decomp_base_log();
+    let decomp_level_count = param.br_decomp_level_count();
+
+    let ntru_cmux_bsk = allocate_and_generate_new_ntru_cmux_bootstrap_key(
+        &lwe_secret_key,
+        &ntru_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        decomp_base_log,
+        decomp_level_count,
+        ntru_noise_distribution,
+        ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    let mut fourier_ntru_cmux_bsk = FourierNtruCMuxBootstrapKey::new(
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        decomp_base_log,
+        decomp_level_count,
+        lwe_dimension,
+        FftType::Vanilla,
+        FftType::Vanilla,
+    );
+    convert_standard_ntru_cmux_bootstrap_key_to_fourier(&ntru_cmux_bsk, &mut fourier_ntru_cmux_bsk);
+
+    let ntru_to_lwe_ksk = allocate_and_generate_new_ntru_to_lwe_keyswitch_key(
+        &ntru_secret_key,
+        &lwe_secret_key,
+        KSK_DECOMP_BASE_LOG,
+        KSK_DECOMP_LEVEL_COUNT,
+        Gaussian::from_dispersion_parameter(StandardDev(TORUS_LWE_STD_DEV), 0.0),
+        ksk_ciphertext_modulus,
+        &mut encryption_generator,
+    );
+
+    // The sign of the phase gives the output: +q/8 on [0, q/2) and -q/8 on [q/2, q)
+    let accumulator = PlaintextList::new(Scalar::ONE << (log_output_modulus - 3), PlaintextCount(polynomial_size.0));
+
+    let bootstrap = |lwe_in: &LweCiphertextOwned<SmallScalar>| {
+        let mut lwe_out = LweCiphertext::new(
+            Scalar::ZERO,
+            ntru_cmux_bsk.output_lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        ntru_cmux_bootstrap_lwe_ciphertext(
+            lwe_in,
+            &mut lwe_out,
+            &accumulator,
+            &fourier_ntru_cmux_bsk,
+        );
+        lwe_out
+    };
+
+    let keyswitch = |lwe_in: &LweCiphertextOwned<Scalar>| {
+        let mut lwe_out = LweCiphertext::new(
+            SmallScalar::ZERO,
+            lwe_dimension.to_lwe_size(),
+            small_ciphertext_modulus,
+        );
+        keyswitch_ntru_to_lwe(&ntru_to_lwe_ksk, lwe_in, &mut lwe_out);
+        lwe_out
+    };
+
+    let eighth = SmallScalar::ONE << (log_input_modulus - 3);
+    let nand = |lhs: &LweCiphertextOwned<SmallScalar>, rhs: &LweCiphertextOwned<SmallScalar>| {
+        linear_gate(lhs, rhs, SmallScalar::MAX, eighth)
+    };
+    let and = |lhs: &LweCiphertextOwned<SmallScalar>, rhs: &LweCiphertextOwned<SmallScalar>| {
+        linear_gate(lhs, rhs, SmallScalar::ONE, eighth.wrapping_neg())
+    };
+    let xor = |lhs: &LweCiphertextOwned<SmallScalar>, rhs: &LweCiphertextOwned<SmallScalar>| {
+        linear_gate(lhs, rhs, 2, eighth << 1)
+    };
+
+    let num_test = 10;
+    for idx in 1..=num_test {
+        let bits: [bool; 3] = rand::thread_rng().gen();
+
+        let inputs = bits.map(|bit| {
+            let mut lwe_in = LweCiphertext::new(SmallScalar::ZERO, lwe_dimension.to_lwe_size(), small_ciphertext_modulus);
+            encrypt_lwe_ciphertext(
+                &lwe_secret_key,
+                &mut lwe_in,
+                Plaintext(encode_bool(bit, log_input_modulus)),
+                lwe_noise_distribution,
+                &mut encryption_generator,
+            );
+            lwe_in
+        });
+
+        // ((a NAND b) XOR c) AND a
+        let nand_out = bootstrap(&nand(&inputs[0], &inputs[1]));
+        let expected_nand = !(bits[0] && bits[1]);
+        assert_eq!(decrypt_bool(&large_lwe_secret_key, &nand_out, torus_scaling), expected_nand);
+
+        let xor_out = bootstrap(&xor(&keyswitch(&nand_out), &inputs[2]));
+        let expected_xor = expected_nand ^ bits[2];
+        assert_eq!(decrypt_bool(&large_lwe_secret_key, &xor_out, torus_scaling), expected_xor);
+
+        let and_out = bootstrap(&and(&keyswitch(&xor_out), &inputs[0]));
+        let expected_and = expected_xor && bits[0];
+        let decrypted_and = decrypt_bool(&large_lwe_secret_key, &and_out, torus_scaling);
+        println!("[Test {idx}] inputs: {bits:?}, ((a NAND b) XOR c) AND a: {decrypted_and}");
+        assert_eq!(decrypted_and, expected_and);
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, "STD128B2_KS"),
+        (NTRU_CMUX_STD128B3, "STD128B3_KS"),
+    ];
+    for (base_param, name) in param_list {
+        let param = NtruCMuxParameters::new(
+            name,
+            base_param.polynomial_size(),
+            LWE_DIMENSION,
+            base_param.log_output_modulus(),
+            base_param.log_input_modulus(),
+            base_param.ntru_std_dev(),
+            base_param.rlwe_std_dev(),
+            TORUS_LWE_STD_DEV * 2.0f64.powi(base_param.log_input_modulus().0 as i32),
+            base_param.br_decomp_base_log(),
+            base_param.br_decomp_level_count(),
+            base_param.tr_decomp_base_log(),
+            base_param.tr_decomp_level_count(),
+            KSK_DECOMP_BASE_LOG,
+            KSK_DECOMP_LEVEL_COUNT,
+            base_param.ss_decomp_base_log(),
+            base_param.ss_decomp_level_count(),
+        );
+        param.print_info();
+        test_ntru_to_lwe_keyswitch(param);
+        println!();
+    }
+}
@@ -0,0 +1,184 @@
+#![allow(deprecated)]
+
+use rand::Rng;
+use tfhe::core_crypto::prelude::*;
+use tfhe::ntru::algorithms::*;
+use tfhe::ntru::entities::*;
+use std::time::Instant;
+
+mod utils;
+use utils::*;
+
+type Scalar = u64;
+
+const NUM_REPEAT: usize = 4;
+
+pub fn test_rlwe_to_ntru_keyswitch(
+    param: NtruCMuxParameters,
+    glwe_dimension: GlweDimension,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    fft_type: FftType,
+    parallel_keygen: bool,
+) {
+    let log_modulus = param.log_output_modulus().0;
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::try_new_power_of_2(log_modulus).unwrap();
+    let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+    let polynomial_size = param.polynomial_size();
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator = SecretRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator = EncryptionRandomGenerator::<DefaultRandomGenerator>::new(seeder.seed(), seeder);
+
+    let ntru_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_ntru_std_dev()), 0.0);
+
+    let rlwe_noise_distribution =
+        Gaussian::from_dispersion_parameter(StandardDev(param.torus_rlwe_std_dev()), 0.0);
+
+    let ntru_secret_key = allocate_and_generate_new_gaussian_ntru_secret_key(
+        polynomial_size,
+        ciphertext_modulus,
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut secret_generator,
+    );
+
+    let now = Instant::now();
+    let rlwe_to_ntru_ksk = if parallel_keygen {
+        par_allocate_and_generate_new_rlwe_to_ntru_keyswitch_key(
+            &glwe_secret_key,
+            &ntru_secret_key,
+            decomp_base_log,
+            decomp_level_count,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        )
+    } else {
+        allocate_and_generate_new_rlwe_to_ntru_keyswitch_key(
+            &glwe_secret_key,
+            &ntru_secret_key,
+            decomp_base_log,
+            decomp_level_count,
+            ntru_noise_distribution,
+            &mut encryption_generator,
+        )
+    };
+    println!("Keygen time: {:.3} ms", (now.elapsed().as_micros() as f64) / 1000f64);
+
+    let mut fourier_rlwe_to_ntru_ksk = FourierRlweToNtruKeyswitchKey::new(
+        glwe_dimension,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        fft_type,
+    );
+    convert_standard_rlwe_to_ntru_keyswitch_key_to_fourier(&rlwe_to_ntru_ksk, &mut fourier_rlwe_to_ntru_ksk);
+
+    // The keyswitched ciphertexts are NTRU ciphertexts that NTRU-native operations accept
+    let ntru_trace_key = allocate_and_generate_new_ntru_trace_key(
+        &ntru_secret_key,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        ntru_noise_distribution,
+        &mut encryption_generator,
+    );
+    let mut fourier_ntru_trace_key = FourierNtruTraceKey::new(
+        polynomial_size,
+        param.tr_decomp_base_log(),
+        param.tr_decomp_level_count(),
+        fft_type,
+    );
+    convert_standard_ntru_trace_key_to_fourier(&ntru_trace_key, &mut fourier_ntru_trace_key);
+
+    let log_message_modulus = 4;
+    let message_modulus = Scalar::ONE << log_message_modulus;
+    let delta = Scalar::ONE << (log_modulus - log_message_modulus);
+
+    let mut message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut trace_message_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+    let mut decrypted_plaintext_list = PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+    let mut glwe_ciphertext = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+    let mut ntru_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+    let mut ntru_trace_ciphertext = NtruCiphertext::new(Scalar::ZERO, polynomial_size, ciphertext_modulus);
+
+    for idx in 1..=NUM_REPEAT {
+        message_list.iter_mut().zip(plaintext_list.iter_mut())
+            .for_each(|(msg, ptxt)| {
+                *msg.0 = rand::thread_rng().gen_range(0..message_modulus);
+                *ptxt.0 = *msg.0 * delta;
+            });
+        trace_message_list.as_mut()[0] = message_list.as_ref()[0];
+
+        encrypt_glwe_ciphertext(
+            &glwe_secret_key,
+            &mut glwe_ciphertext,
+            &plaintext_list,
+            rlwe_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        let now = Instant::now();
+        keyswitch_rlwe_to_ntru(
+            &fourier_rlwe_to_ntru_ksk,
+            &glwe_ciphertext,
+            &mut ntru_ciphertext,
+        );
+        let time = now.elapsed();
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &ntru_ciphertext, &mut decrypted_plaintext_list);
+        let max_err = get_max_error(&decrypted_plaintext_list, &message_list, torus_scaling, delta);
+
+        rev_trace_ntru_ciphertext(
+            &fourier_ntru_trace_key,
+            &ntru_ciphertext,
+            &mut ntru_trace_ciphertext,
+        );
+
+        decrypt_ntru_ciphertext(&ntru_secret_key, &ntru_trace_ciphertext, &mut decrypted_plaintext_list);
+        let trace_max_err = get_max_error(&decrypted_plaintext_list, &trace_message_list, torus_scaling, delta);
+
+        println!(
+            "[Test {idx}] time: {:.3} ms, max error: {:.3} bits, after trace: {:.3} bits",
+            (time.as_micros() as f64) / 1000f64,
+            (max_err as f64).log2(),
+            (trace_max_err as f64).log2(),
+        );
+        assert!(max_err < delta / 2);
+        assert!(trace_max_err < delta / 2);
+    }
+}
+
+pub fn main() {
+    let param_list = [
+        (NTRU_CMUX_STD128B2, GlweDimension(1), DecompositionBaseLog(9), DecompositionLevelCount(3), FftType::Vanilla, false),
+        (NTRU_CMUX_STD128B2, GlweDimension(2), DecompositionBaseLog(13), DecompositionLevelCount(2), FftType::Split(20), true),
+    ];
+
+    for (param, glwe_dimension, decomp_base_log, decomp_level_count, fft_type, parallel_keygen) in param_list {
+        param.print_info();
+        println!(
+            "GLWE dimension: {}, KS: B 2^{}, l: {}, FFT: {:?}, parallel keygen: {}",
+            glwe_dimension.0,
+            decomp_base_log.0,
+            decomp_level_count.0,
+            fft_type,
+            parallel_keygen,
+        );
+        test_rlwe_to_ntru_keyswitch(param, glwe_dimension, decomp_base_log, decomp_level_count, fft_type, parallel_keygen);
+        println!();
+    }
+}
@@ -82,6 +82,46 @@ pub fn get_avg_and_max_error<Scalar, PtxtCont, MsgCont>(
     (avg_err, max_err)
 }
 
+#[allow(unused)]
+pub fn get_error_variance<Scalar, PtxtCont, MsgCont>(
+    input_plaintext_list: &PlaintextList<PtxtCont>,
+    correct_message_list: &PlaintextList<MsgCont>,
+    torus_scaling: Scalar,
+    delta: Scalar,
+) -> f64 where
+    Scalar: UnsignedInteger + CastInto<f64>,
+    PtxtCont: Container<Element = Scalar>,
+    MsgCont: Container<Element = Scalar>,
+{
+    assert!(
+        input_plaintext_list.plaintext_count().0 == correct_message_list.plaintext_count().0,
+        "Mismatch between PlaintextCount of input plaintext and correct message. \
+        Got {:?} in input plaintext, and {:?} in message.",
+        input_plaintext_list.plaintext_count().0,
+        correct_message_list.plaintext_count().0,
+    );
+
+    let mut sum_squared_err = 0f64;
+    input_plaintext_list.iter().zip(correct_message_list.iter())
+        .for_each(|(input, correct_val)| {
+            let input = (*input.0).wrapping_mul(torus_scaling);
+            let correct_val = (*correct_val.0)
+                .wrapping_mul(delta)
+                .wrapping_mul(torus_scaling);
+
+            let abs_err = {
+                let d0 = input.wrapping_sub(correct_val);
+                let d1 = correct_val.wrapping_sub(input);
+                std::cmp::min(d0, d1) / torus_scaling
+            };
+            let abs_err: f64 = abs_err.cast_into();
+
+            sum_squared_err += abs_err * abs_err;
+        });
+
+    sum_squared_err / (input_plaintext_list.plaintext_count().0 as f64)
+}
+
 /* -------- Polynomial to String -------- */
 #[allow(unused)]
 pub fn polynomial_to_string_mod_power_of_two<Scalar, InputCont>(input: &Polynomial<InputCont>, power: usize)